@@ -0,0 +1,31 @@
+use std::env;
+
+use whalecrab_engine::{
+    eval_params::EvalParams,
+    tuning::{Tuner, TuningPosition},
+};
+
+/// Usage: tune <positions> [iterations] [starting params]
+/// Prints the tuned params as JSON
+fn main() {
+    let mut args = env::args().skip(1);
+    let positions = args.next().expect("Missing the positions file");
+    let iterations = args
+        .next()
+        .map_or(10, |i| i.parse().expect("Invalid iterations"));
+    let params = match args.next() {
+        Some(path) => EvalParams::load(path).unwrap_or_else(|e| panic!("{}", e)),
+        None => EvalParams::default(),
+    };
+
+    let positions = TuningPosition::load(positions).unwrap_or_else(|e| panic!("{}", e));
+    eprintln!("Loaded {} positions", positions.len());
+
+    let mut tuner = Tuner::new(positions);
+    eprintln!("Fitted k: {}", tuner.fit_k(&params));
+    eprintln!("Error before: {}", tuner.error(&params));
+
+    let tuned = tuner.tune(&params, iterations);
+    eprintln!("Error after: {}", tuner.error(&tuned));
+    println!("{}", tuned.to_json());
+}
@@ -0,0 +1,290 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use whalecrab_lib::{
+    pgn::{MoveAnnotation, PgnTags, result_token},
+    position::{
+        game::{Game, State},
+        history::GameHistory,
+    },
+};
+
+use crate::{
+    engine::Engine,
+    score::{Score, ScoreKind},
+    search::limits::SearchLimits,
+};
+
+/// Evaluations are capped to this many centipawns before working out how much a move lost, so
+/// that trading one winning line for another is not counted against the move
+pub const ANALYSIS_EVAL_CAP: i32 = 1000;
+
+/// How good a move was compared to the best move the engine found, going by the centipawns it
+/// lost. The thresholds are the ones Lichess uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MoveClassification {
+    /// The move the engine would have played
+    Best,
+    /// Lost less than 50 centipawns
+    Good,
+    /// Lost less than 100 centipawns
+    Inaccuracy,
+    /// Lost less than 300 centipawns
+    Mistake,
+    Blunder,
+}
+
+impl MoveClassification {
+    pub fn from_loss(loss: u16) -> Self {
+        match loss {
+            0..50 => Self::Good,
+            50..100 => Self::Inaccuracy,
+            100..300 => Self::Mistake,
+            _ => Self::Blunder,
+        }
+    }
+
+    /// The Numeric Annotation Glyph for moves this bad, such as 4 (`??`) for a blunder
+    pub fn nag(self) -> Option<u8> {
+        match self {
+            Self::Best | Self::Good => None,
+            Self::Inaccuracy => Some(6),
+            Self::Mistake => Some(2),
+            Self::Blunder => Some(4),
+        }
+    }
+}
+
+/// An evaluation from white's side, written to JSON as `{"cp": 35}` or `{"mate": -2}`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Evaluation {
+    /// Hundredths of a pawn
+    Cp(i16),
+    /// Mate in this many moves, negative when black mates
+    Mate(i16),
+}
+
+/// Written in pawns such as `-1.05`, or as `#3` for mates, like in `[%eval]` comments
+impl fmt::Display for Evaluation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cp(cp) => {
+                let sign = if *cp < 0 { "-" } else { "" };
+                let cp = cp.unsigned_abs();
+                write!(f, "{sign}{}.{:02}", cp / 100, cp % 100)
+            }
+            Self::Mate(moves) => write!(f, "#{moves}"),
+        }
+    }
+}
+
+impl From<Score> for Evaluation {
+    fn from(score: Score) -> Self {
+        match score.kind() {
+            ScoreKind::Centipawns(cp) => Self::Cp(cp),
+            ScoreKind::Mate(moves) => Self::Mate(moves),
+        }
+    }
+}
+
+/// What the engine thought of a single move
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MoveAnalysis {
+    /// Counted from 1 for the first move of the game
+    pub ply: usize,
+    pub san: String,
+    pub uci: String,
+    /// The position after the move
+    pub fen: String,
+    /// The evaluation after the move
+    pub eval: Evaluation,
+    /// What the engine would have played instead, if it found anything
+    pub best_san: Option<String>,
+    pub best_uci: Option<String>,
+    /// The evaluation after the engine's move
+    pub best_eval: Evaluation,
+    /// How many centipawns the move lost compared to the engine's move, see
+    /// [`ANALYSIS_EVAL_CAP`]
+    pub loss: u16,
+    pub classification: MoveClassification,
+}
+
+/// A whole game along with the engine's verdict on each move. Its JSON is meant to be read by
+/// web viewers and notebooks, see [`GameAnalysis::to_json`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameAnalysis {
+    pub start_fen: String,
+    pub moves: Vec<MoveAnalysis>,
+    /// The PGN result token, see [`result_token`]
+    pub result: String,
+}
+
+impl MoveAnalysis {
+    /// The move's classification as a NAG and its evaluation as an `[%eval]` comment, which
+    /// chess GUIs show as an evaluation graph. Checkmates get no evaluation.
+    pub fn annotation(&self) -> MoveAnnotation {
+        MoveAnnotation {
+            nag: self.classification.nag(),
+            comment: (self.eval != Evaluation::Mate(0)).then(|| format!("[%eval {}]", self.eval)),
+        }
+    }
+}
+
+impl GameAnalysis {
+    /// Writes `game`, which should be the game that was analyzed, as a PGN annotated with
+    /// [`MoveAnalysis::annotation`]
+    pub fn to_pgn(&self, game: &Game, tags: &PgnTags) -> String {
+        let annotations: Vec<_> = self.moves.iter().map(MoveAnalysis::annotation).collect();
+        game.to_annotated_pgn(tags, &annotations)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Game analyses are always serializable")
+    }
+}
+
+/// `score` from white's side, capped for working out losses
+fn capped_centipawns(score: Score) -> i32 {
+    match score.kind() {
+        ScoreKind::Centipawns(cp) => (cp as i32).clamp(-ANALYSIS_EVAL_CAP, ANALYSIS_EVAL_CAP),
+        ScoreKind::Mate(_) if score > 0 => ANALYSIS_EVAL_CAP,
+        ScoreKind::Mate(_) => -ANALYSIS_EVAL_CAP,
+    }
+}
+
+impl Engine {
+    /// Searches every position of `game` within `limits` and grades each move against the one
+    /// the engine found. The engine's own game is left as it was.
+    pub fn analyze_game(&mut self, game: &Game, limits: SearchLimits) -> GameAnalysis {
+        let history = GameHistory::from_game(game);
+        let mut positions = vec![history.start().clone()];
+        for entry in history.entries() {
+            let mut next = positions[positions.len() - 1].clone();
+            next.play(&entry.m);
+            positions.push(next);
+        }
+
+        let original = std::mem::take(&mut self.game);
+        let searches: Vec<_> = positions
+            .iter()
+            .map(|position| match position.state {
+                State::InProgress => {
+                    self.with_new_game(position.clone());
+                    let result = self.search_with_limits(limits);
+                    (result.best_move, result.info.score)
+                }
+                // The player to move is the one who got checkmated
+                State::Checkmate => (None, Score::mated_in(0).for_color(position.turn)),
+                _ => (None, Score::default()),
+            })
+            .collect();
+        self.with_new_game(original);
+
+        let moves = history
+            .entries()
+            .iter()
+            .enumerate()
+            .map(|(ply, entry)| {
+                let before = &positions[ply];
+                let (best_move, best_score) = searches[ply];
+                let score = searches[ply + 1].1;
+
+                let mover = before.turn;
+                let best = capped_centipawns(best_score.for_color(mover));
+                let played = capped_centipawns(score.for_color(mover));
+                let loss = (best - played).max(0) as u16;
+
+                MoveAnalysis {
+                    ply: ply + 1,
+                    san: entry.san.clone(),
+                    uci: entry.m.to_uci(before),
+                    fen: positions[ply + 1].to_fen(),
+                    eval: score.into(),
+                    best_san: best_move.map(|m| m.to_san(before)),
+                    best_uci: best_move.map(|m| m.to_uci(before)),
+                    best_eval: best_score.into(),
+                    loss,
+                    classification: if best_move == Some(entry.m) {
+                        MoveClassification::Best
+                    } else {
+                        MoveClassification::from_loss(loss)
+                    },
+                }
+            })
+            .collect();
+
+        GameAnalysis {
+            start_fen: history.start().to_fen(),
+            moves,
+            result: result_token(game.outcome()).to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_blunder_in_scholars_mate() {
+        let mut game = Game::default();
+        game.apply_uci_moves(["e2e4", "e7e5", "f1c4", "b8c6", "d1h5", "g8f6", "h5f7"])
+            .unwrap();
+
+        let mut engine = Engine::default();
+        let analysis = engine.analyze_game(&game, SearchLimits::depth(3));
+        assert_eq!(engine.game, Game::default());
+
+        assert_eq!(analysis.moves.len(), 7);
+        assert_eq!(analysis.result, "1-0");
+
+        let blunder = &analysis.moves[5];
+        assert_eq!(blunder.san, "Nf6");
+        assert_eq!(blunder.classification, MoveClassification::Blunder);
+        assert_eq!(blunder.eval, Evaluation::Mate(1));
+
+        let mate = &analysis.moves[6];
+        assert_eq!(mate.uci, "h5f7");
+        assert_eq!(mate.best_uci.as_deref(), Some("h5f7"));
+        assert_eq!(mate.classification, MoveClassification::Best);
+        assert_eq!(mate.eval, Evaluation::Mate(0));
+    }
+
+    #[test]
+    fn annotated_pgn() {
+        let mut game = Game::default();
+        game.apply_uci_moves(["e2e4", "e7e5", "f1c4", "b8c6", "d1h5", "g8f6", "h5f7"])
+            .unwrap();
+        let analysis = Engine::default().analyze_game(&game, SearchLimits::depth(3));
+
+        let pgn = analysis.to_pgn(&game, &PgnTags::default());
+        assert!(pgn.contains(" 3... Nf6?? {[%eval #1]} 4. Qxf7# 1-0\n"));
+        assert_eq!(pgn.matches("[%eval").count(), 6);
+    }
+
+    #[test]
+    fn evaluations_in_pawns() {
+        assert_eq!(Evaluation::Cp(35).to_string(), "0.35");
+        assert_eq!(Evaluation::Cp(-105).to_string(), "-1.05");
+        assert_eq!(Evaluation::Cp(-5).to_string(), "-0.05");
+        assert_eq!(Evaluation::Mate(-2).to_string(), "#-2");
+    }
+
+    #[test]
+    fn json_round_trips() {
+        let mut game = Game::default();
+        game.apply_uci_moves(["d2d4", "d7d5"]).unwrap();
+        let analysis = Engine::default().analyze_game(&game, SearchLimits::depth(1));
+
+        let json = analysis.to_json();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert!(value["moves"][0]["eval"]["cp"].is_i64());
+        assert!(value["moves"][1]["classification"].is_string());
+        assert_eq!(
+            serde_json::from_str::<GameAnalysis>(&json).unwrap(),
+            analysis
+        );
+    }
+}
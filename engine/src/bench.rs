@@ -0,0 +1,90 @@
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+use whalecrab_lib::position::game::Game;
+
+use crate::engine::Engine;
+
+/// The depth `bench` searches to when none is given
+pub const DEFAULT_BENCH_DEPTH: u8 = 5;
+
+/// A fixed mix of openings, middlegames and endgames. Changing these changes the node count of
+/// every bench, so only do so on purpose.
+pub const BENCH_FENS: [&str; 8] = [
+    "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+    "rnbqkbnr/pp1ppppp/8/2p5/4P3/8/PPPP1PPP/RNBQKBNR w KQkq c6 0 2",
+    "r1bq1rk1/ppp2ppp/2n2n2/2bp4/2B1P3/3P1N2/PPP2PPP/RNBQR1K1 w - - 0 8",
+    "r1b1k2r/pppp1ppp/2n1pn2/8/P1PPq3/2b1P2N/3NBPPP/1RBQ1RK1 b kq - 6 10",
+    "r1k2b1r/1p4p1/p1p4P/4B3/2p5/3P3P/NP2P1B1/2K2R2 w - - 0 29",
+    "4rrk1/pp3ppp/2p5/8/3P4/2N2Q2/PP3PPP/R4RK1 w - - 0 20",
+    "8/5pk1/6p1/8/3R4/6P1/5PK1/3r4 w - - 0 40",
+    "8/8/8/8/3k4/8/3P4/3K4 w - - 0 50",
+];
+
+/// The totals of a bench run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchResult {
+    pub nodes: u64,
+    pub elapsed: Duration,
+}
+
+impl BenchResult {
+    /// Nodes searched per second
+    pub fn nps(&self) -> u64 {
+        let seconds = self.elapsed.as_secs_f64();
+        if seconds == 0.0 {
+            return 0;
+        }
+        (self.nodes as f64 / seconds) as u64
+    }
+}
+
+impl fmt::Display for BenchResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} nodes {} nps {} ms",
+            self.nodes,
+            self.nps(),
+            self.elapsed.as_millis()
+        )
+    }
+}
+
+impl Engine {
+    /// Searches every position of `BENCH_FENS` to `depth`, starting with empty caches. With a
+    /// single thread the node count only changes when the search does, which makes it a cheap
+    /// check for unintended changes, while the nps shows performance regressions. The current
+    /// game is replaced.
+    pub fn bench(&mut self, depth: u8) -> BenchResult {
+        let mut nodes = 0;
+        let start = Instant::now();
+        self.clear_persistant_cache();
+
+        for fen in BENCH_FENS {
+            self.with_new_game(Game::from_fen(fen).expect("Invalid bench fen"));
+            nodes += self.search(Duration::MAX, depth).stats.nodes;
+        }
+
+        BenchResult {
+            nodes,
+            elapsed: start.elapsed(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bench_is_deterministic() {
+        let mut engine = Engine::default();
+        let first = engine.bench(2);
+        let second = Engine::default().bench(2);
+        assert!(first.nodes > 0);
+        assert_eq!(first.nodes, second.nodes);
+    }
+}
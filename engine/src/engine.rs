@@ -1,28 +1,93 @@
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 
-use crate::transposition_table::TranspositionTable;
-use whalecrab_lib::position::game::Game;
+use rand::{Rng, SeedableRng, rngs::Xoshiro256PlusPlus};
+
+use crate::{
+    eval_params::EvalParams,
+    material_cache::MaterialCache,
+    search::{killers::KillerMoves, skill::SkillLevel, stats::SearchStats, trace::SearchTrace},
+    transposition_table::TranspositionTable,
+};
+use whalecrab_lib::position::{fen::FenError, game::Game};
+
+#[cfg(feature = "nnue")]
+use crate::nnue::NnueState;
 
 pub static TRANSPOSITION_TABLE_MEMORY_BUDGET_IN_KILOBYTES: OnceLock<usize> = OnceLock::new();
 
-#[derive(Default, Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Engine {
     /// Use self.with_new_game(game) instead of self.game = game if you want to replace this value
     pub game: Game,
-    pub(crate) transposition_table: TranspositionTable,
+    /// Shared with every clone of the engine, so that search threads can share their results
+    pub(crate) transposition_table: Arc<TranspositionTable>,
+    pub(crate) killers: KillerMoves,
+    /// How many moves deep the search currently is from the root
+    pub(crate) ply: u8,
+    /// How many threads search the position at once
+    pub(crate) threads: usize,
+    /// Counters for the current search
+    pub(crate) stats: SearchStats,
+    /// The search stops once `nodes` reaches this
+    pub(crate) node_limit: u64,
+    /// Skips quiet moves near the horizon that can not raise alpha
+    pub(crate) futility_pruning: bool,
+    /// How strong the engine plays
+    pub(crate) skill: SkillLevel,
+    /// Every random choice the engine makes goes through this, so that seeding it makes the
+    /// engine reproducible
+    pub(crate) rng: Xoshiro256PlusPlus,
+    /// Records the searched tree when enabled
+    pub(crate) trace: Option<SearchTrace>,
+    /// The weights used by the evaluation. Shared with every clone of the engine
+    pub(crate) params: Arc<EvalParams>,
+    /// Endgame knowledge for the material signatures seen so far
+    pub(crate) material_cache: MaterialCache,
+    /// Replaces the hand-crafted evaluation when a network is loaded
+    #[cfg(feature = "nnue")]
+    pub(crate) nnue: Option<NnueState>,
+}
+
+impl Default for Engine {
+    fn default() -> Self {
+        Engine::from_game(Game::default())
+    }
 }
 
 impl Engine {
     pub fn from_game(game: Game) -> Engine {
+        Engine::with_table(game, TranspositionTable::default())
+    }
+
+    /// Same as `from_game`, but with a transposition table of about `megabytes`. Small tables are
+    /// much quicker to create, for engines that barely search.
+    pub fn from_game_with_hash_size(game: Game, megabytes: usize) -> Engine {
+        Engine::with_table(game, TranspositionTable::from_size(megabytes.max(1) * 1024))
+    }
+
+    fn with_table(game: Game, transposition_table: TranspositionTable) -> Engine {
         Engine {
             game,
-            transposition_table: TranspositionTable::default(),
+            transposition_table: Arc::new(transposition_table),
+            killers: KillerMoves::default(),
+            ply: 0,
+            threads: 1,
+            stats: SearchStats::default(),
+            node_limit: u64::MAX,
+            futility_pruning: true,
+            skill: SkillLevel::MAX,
+            rng: rand::make_rng(),
+            trace: None,
+            params: Arc::new(EvalParams::default()),
+            material_cache: MaterialCache::default(),
+            #[cfg(feature = "nnue")]
+            nnue: None,
         }
     }
 
     /// Creates a position from fen and wraps the engine around it
-    pub fn from_fen(fen: &str) -> Option<Engine> {
-        Some(Engine::from_game(Game::from_fen(fen)?))
+    pub fn from_fen(fen: &str) -> Result<Engine, FenError> {
+        Ok(Engine::from_game(Game::from_fen(fen)?))
     }
 
     /// Resets any temporary engine values or caches and switches over to analyzing the new game.
@@ -31,6 +96,48 @@ impl Engine {
         self.game = game
     }
 
+    /// Sets how many threads to search with. Helper threads share the transposition table with
+    /// the main thread, which is what speeds up the search
+    pub fn set_threads(&mut self, threads: usize) {
+        self.threads = threads.max(1);
+    }
+
+    /// Replaces the transposition table with an empty one of about `megabytes`. The table only
+    /// comes in powers of two entries, so it may take up to twice as much.
+    pub fn set_hash_size(&mut self, megabytes: usize) {
+        self.transposition_table = Arc::new(TranspositionTable::from_size(megabytes.max(1) * 1024));
+    }
+
+    /// How many megabytes the transposition table takes
+    pub fn hash_size(&self) -> usize {
+        self.transposition_table.kilobytes() / 1024
+    }
+
+    /// How many megabytes the transposition table of a new engine is meant to take
+    pub fn default_hash_size() -> usize {
+        TranspositionTable::default_kilobytes() / 1024
+    }
+
+    /// Replaces the weights used by the evaluation
+    pub fn set_eval_params(&mut self, params: EvalParams) {
+        self.params = Arc::new(params);
+    }
+
+    pub fn eval_params(&self) -> &EvalParams {
+        &self.params
+    }
+
+    /// Seeds the random number generator, so that the engine makes the same choices every time
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+    }
+
+    /// Draws a seed from the engine's random number generator, such as for the engines of
+    /// [`crate::toys::EngineKind::build`], so that seeding this engine seeds them too
+    pub fn next_seed(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
     /// Clears caches that do not need bo be reset each game. This should only be called for
     /// testing and benchmarking purposes
     pub fn clear_persistant_cache(&mut self) {
@@ -82,7 +189,20 @@ mod tests {
         println!("{:?}", engine.game);
         let grade = engine.grade_position();
         println!("{:?}", engine.game);
-        assert_eq!(grade, Score::default());
+        // Only the side to move is ahead, by the tempo bonus
+        assert_eq!(grade, Score::new(engine.eval_params().tempo));
+    }
+
+    #[test]
+    fn side_to_move_gets_the_tempo() {
+        let fen = "r1bq1rk1/ppp2ppp/2n2n2/2bp4/2B1P3/3P1N2/PPP2PPP/RNBQR1K1";
+        let mut white = Engine::from_fen(&format!("{} w - - 0 8", fen)).unwrap();
+        let mut black = Engine::from_fen(&format!("{} b - - 0 8", fen)).unwrap();
+        let tempo = white.eval_params().tempo;
+        assert_eq!(
+            white.grade_position() - black.grade_position(),
+            Score::new(tempo * 2)
+        );
     }
 
     #[test]
@@ -100,6 +220,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn lazy_grading_is_exact_inside_the_window() {
+        let mut engine =
+            Engine::from_fen("r1bq1rk1/ppp2ppp/2n2n2/2bp4/2B1P3/3P1N2/PPP2PPP/RNBQR1K1 w - - 0 8")
+                .unwrap();
+        let exact = engine.grade_position_relative();
+        assert_eq!(engine.grade_position_lazy(Score::MIN, Score::MAX), exact);
+        assert_eq!(engine.stats.lazy_evals, 0);
+    }
+
+    #[test]
+    fn lazy_grading_stays_outside_the_window() {
+        // White is up a queen, so the cheap terms alone decide the window
+        let mut engine = Engine::from_fen("4k3/pppp4/8/8/8/8/PPPP4/3QK3 w - - 0 1").unwrap();
+        let exact = engine.grade_position_relative();
+
+        let lazy = engine.grade_position_lazy(Score::new(-100), Score::new(100));
+        assert_eq!(engine.stats.lazy_evals, 1);
+        assert!(lazy >= Score::new(100));
+        assert!(exact >= Score::new(100));
+
+        engine.with_new_game(Game::from_fen("4k3/pppp4/8/8/8/8/PPPP4/3QK3 b - - 0 1").unwrap());
+        assert!(engine.grade_position_lazy(Score::new(-100), Score::new(100)) <= Score::new(-100));
+    }
+
+    #[test]
+    fn square_scores_add_up_to_the_cheap_terms() {
+        let mut engine =
+            Engine::from_fen("r1bq1rk1/ppp2ppp/2n2n2/2bp4/2B1P3/3P1N2/PPP2PPP/RNBQR1K1 w - - 0 8")
+                .unwrap();
+        let scores = engine.square_scores();
+        let breakdown = engine.explain_eval();
+        let (white, black) = (breakdown.white, breakdown.black);
+
+        let sum = scores
+            .iter()
+            .fold(Score::default(), |sum, &score| sum + score);
+        assert_eq!(
+            sum,
+            white.material + white.piece_squares - black.material - black.piece_squares
+        );
+        assert_eq!(scores[Square::A3.index()], Score::default());
+        assert!(scores[Square::D8.index()] < Score::default());
+    }
+
     #[test]
     fn black_always_takes_king() {
         let fen = "k6r/pp4r1/8/pp6/Qp6/pp6/7K/8 w - - 0 1";
@@ -289,4 +454,16 @@ mod tests {
         let result = engine.search(Duration::MAX, 2);
         assert_eq!(result.best_move, Some(expected));
     }
+
+    #[test]
+    fn resizes_the_hash() {
+        let default = Engine::default_hash_size();
+        let mut engine = Engine::default();
+        assert!((default..default * 2).contains(&engine.hash_size()));
+
+        let shared = engine.clone();
+        engine.set_hash_size(2);
+        assert!((2..4).contains(&engine.hash_size()));
+        assert!(shared.hash_size() >= default);
+    }
 }
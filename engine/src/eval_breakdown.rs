@@ -0,0 +1,132 @@
+use std::fmt;
+
+use crate::{material_cache::NORMAL_SCALE, score::Score};
+
+/// Every term of the hand-crafted evaluation for one side
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SideBreakdown {
+    pub material: Score,
+    /// How well the pieces suit each other and the amount of pawns
+    pub imbalance: Score,
+    pub piece_squares: Score,
+    /// Hanging enemy pieces and enemy pieces attacked by lesser pieces
+    pub threats: Score,
+    /// The pawn shield minus the danger from enemy attacks on the king zone
+    pub king_safety: Score,
+    pub castling_rights: Score,
+    pub pawns: Score,
+    /// Bishop pair, rook files and knight outposts
+    pub pieces: Score,
+    /// Driving the enemy king to the edge in won endgames
+    pub mop_up: Score,
+    /// Only given to the side to move
+    pub tempo: Score,
+}
+
+impl SideBreakdown {
+    pub fn total(&self) -> Score {
+        self.material
+            + self.imbalance
+            + self.piece_squares
+            + self.threats
+            + self.king_safety
+            + self.castling_rights
+            + self.pawns
+            + self.pieces
+            + self.mop_up
+            + self.tempo
+    }
+
+    fn terms(&self) -> [(&'static str, Score); 10] {
+        [
+            ("material", self.material),
+            ("imbalance", self.imbalance),
+            ("piece squares", self.piece_squares),
+            ("threats", self.threats),
+            ("king safety", self.king_safety),
+            ("castling rights", self.castling_rights),
+            ("pawns", self.pawns),
+            ("pieces", self.pieces),
+            ("mop up", self.mop_up),
+            ("tempo", self.tempo),
+        ]
+    }
+}
+
+/// Why the engine likes a position, term by term. Returned by `Engine::explain_eval`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct EvalBreakdown {
+    pub white: SideBreakdown,
+    pub black: SideBreakdown,
+    /// How far the game is from the endgame, where 1.0 is the starting material
+    pub phase: f64,
+    /// How many 64ths of the difference of the terms are kept. Lower in endgames that are hard to
+    /// win, such as bishops of opposite colors
+    pub scale: i16,
+    /// The final evaluation for white. Differs from the difference of the terms when the game is
+    /// over, when the endgame is scaled down or when a network replaces the hand-crafted
+    /// evaluation
+    pub total: Score,
+}
+
+impl fmt::Display for EvalBreakdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<16}{:>8}{:>8}", "term", "white", "black")?;
+        for ((name, white), (_, black)) in self.white.terms().into_iter().zip(self.black.terms()) {
+            writeln!(
+                f,
+                "{:<16}{:>8}{:>8}",
+                name,
+                white.to_string(),
+                black.to_string()
+            )?;
+        }
+        writeln!(
+            f,
+            "{:<16}{:>8}{:>8}",
+            "sum",
+            self.white.total().to_string(),
+            self.black.total().to_string()
+        )?;
+        writeln!(f, "phase {:.2}", self.phase)?;
+        writeln!(f, "scale {}/{}", self.scale, NORMAL_SCALE)?;
+        write!(f, "total {}", self.total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{engine::Engine, material_cache::NORMAL_SCALE, score::Score};
+
+    #[test]
+    fn terms_add_up_to_the_evaluation() {
+        let mut engine =
+            Engine::from_fen("r1bq1rk1/ppp2ppp/2n2n2/2bp4/2B1P3/3P1N2/PPP2PPP/RNBQR1K1 w - - 0 8")
+                .unwrap();
+        let breakdown = engine.explain_eval();
+        assert_eq!(breakdown.total, engine.grade_position());
+        assert_eq!(
+            breakdown.white.total() - breakdown.black.total(),
+            breakdown.total
+        );
+    }
+
+    #[test]
+    fn starting_position_is_balanced() {
+        let mut engine = Engine::default();
+        let breakdown = engine.explain_eval();
+        let tempo = Score::new(engine.eval_params().tempo);
+        assert_eq!(breakdown.white.tempo, tempo);
+        assert_eq!(breakdown.black.tempo, Score::default());
+        assert_eq!(breakdown.total, tempo);
+        assert!(breakdown.to_string().contains("king safety"));
+    }
+
+    #[test]
+    fn drawish_endgames_are_scaled() {
+        let mut engine = Engine::from_fen("4k3/8/8/3r4/8/8/8/2BRK3 w - - 0 1").unwrap();
+        let breakdown = engine.explain_eval();
+        assert!(breakdown.scale < NORMAL_SCALE);
+        assert!(breakdown.total < breakdown.white.total() - breakdown.black.total());
+    }
+}
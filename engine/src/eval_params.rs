@@ -0,0 +1,332 @@
+use std::{fmt, fs, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+use whalecrab_lib::movegen::pieces::piece::PieceType;
+
+use crate::{
+    piece_eval::{material_value, tables},
+    score::Score,
+};
+
+/// Serializes the 64 squares of a piece-square table, since serde only derives arrays of up to
+/// 32 elements
+mod square_table {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub fn serialize<S: Serializer>(table: &[i32; 64], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(table)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[i32; 64], D::Error> {
+        let squares = Vec::<i32>::deserialize(deserializer)?;
+        let len = squares.len();
+        squares
+            .try_into()
+            .map_err(|_| D::Error::invalid_length(len, &"64 squares"))
+    }
+}
+
+/// The positional value of a piece on every square, for white. Black uses the table flipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PieceSquareTable {
+    #[serde(with = "square_table")]
+    pub midgame: [i32; 64],
+    #[serde(with = "square_table")]
+    pub endgame: [i32; 64],
+}
+
+impl PieceSquareTable {
+    const fn new(midgame: [i32; 64], endgame: [i32; 64]) -> Self {
+        Self { midgame, endgame }
+    }
+}
+
+#[derive(Debug)]
+pub enum EvalParamsError {
+    Io(io::Error),
+    Parse(serde_json::Error),
+}
+
+impl fmt::Display for EvalParamsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "Failed to read the eval params: {}", e),
+            Self::Parse(e) => write!(f, "Failed to parse the eval params: {}", e),
+        }
+    }
+}
+
+/// Every weight used by the evaluation. These can be changed at runtime, for example by loading
+/// them from a JSON file, so that weights can be experimented with without recompiling. Fields
+/// missing from a file keep their default value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EvalParams {
+    /// Indexed by `PieceType::to_int`
+    pub material: [i16; 6],
+    /// Indexed by `PieceType::to_int`
+    pub piece_square_tables: [PieceSquareTable; 6],
+
+    /// Bonus for every friendly pawn on the files around the king
+    pub pawn_shield: i16,
+    /// Bonus for every castling right that is left
+    pub castling_right: i16,
+    /// Bonus for every enemy piece that is attacked more times than it is defended
+    pub hanging_piece: i16,
+    /// Bonus for every enemy piece attacked by a pawn, not counting pawns
+    pub threat_by_pawn: i16,
+    /// Bonus for every enemy piece attacked by a knight, bishop or rook worth less than it
+    pub threat_by_lesser_piece: i16,
+
+    pub doubled_pawn: i16,
+    pub isolated_pawn: i16,
+    pub backward_pawn: i16,
+    /// Bonus for a passed pawn, indexed by how many ranks it has advanced from its own back rank
+    pub passed_pawn: [i16; 8],
+    /// Bonus per square of distance between the enemy king and the square in front of a passed
+    /// pawn
+    pub passed_enemy_king_distance: i16,
+    /// Penalty per square of distance between our king and the square in front of a passed pawn
+    pub passed_own_king_distance: i16,
+    /// Bonus for a passed pawn in a pawn endgame that the enemy king can not catch
+    pub unstoppable_passer: i16,
+
+    /// How much each attacked square of the king zone counts towards the danger. Indexed by
+    /// `PieceType::to_int`
+    pub king_attack_weight: [i32; 6],
+    /// Scales the danger by the amount of attacking pieces, in percent
+    pub king_attacker_scale: [i32; 8],
+    /// The most a king can be penalized, no matter how many attackers there are
+    pub max_king_danger: i32,
+
+    pub bishop_pair: i16,
+    /// A file without any pawns
+    pub rook_open_file: i16,
+    /// A file without friendly pawns, but with enemy pawns
+    pub rook_semi_open_file: i16,
+    pub rook_on_seventh: i16,
+    /// A knight that is defended by a pawn and can never be chased away by an enemy pawn
+    pub knight_outpost: i16,
+
+    /// Added to every knight for each own pawn above five, and taken away for each below
+    pub knight_pawn_adjustment: i16,
+    /// Taken away from every rook for each own pawn above five, and added for each below
+    pub rook_pawn_adjustment: i16,
+    /// Bonus for a queen and knight against a queen and bishops
+    pub queen_knight_synergy: i16,
+
+    /// How far ahead in material a side without pawns needs to be to start driving the enemy
+    /// king to the edge
+    pub mop_up_margin: i16,
+    /// Bonus per step the enemy king is away from the center
+    pub mop_up_edge: i16,
+    /// Bonus per step the kings are closer than the maximum distance of 14
+    pub mop_up_proximity: i16,
+
+    /// Bonus for the side to move, since having the move is worth something
+    pub tempo: i16,
+
+    /// How far material and piece squares can be outside the search window before the rest of
+    /// the evaluation is skipped
+    pub lazy_eval_margin: i16,
+}
+
+impl Default for EvalParams {
+    fn default() -> Self {
+        Self {
+            material: [
+                PieceType::Pawn,
+                PieceType::Knight,
+                PieceType::Bishop,
+                PieceType::Rook,
+                PieceType::Queen,
+                PieceType::King,
+            ]
+            .map(|piece| material_value(piece).to_int()),
+            piece_square_tables: [
+                PieceSquareTable::new(tables::PAWN_MID, tables::PAWN_END),
+                PieceSquareTable::new(tables::KNIGHT_MID, tables::KNIGHT_END),
+                PieceSquareTable::new(tables::BISHOP_MID, tables::BISHOP_END),
+                PieceSquareTable::new(tables::ROOK_MID, tables::ROOK_END),
+                PieceSquareTable::new(tables::QUEEN_MID, tables::QUEEN_END),
+                PieceSquareTable::new(tables::KING_MID, tables::KING_END),
+            ],
+
+            pawn_shield: 15,
+            castling_right: 2,
+            hanging_piece: 30,
+            threat_by_pawn: 40,
+            threat_by_lesser_piece: 25,
+
+            doubled_pawn: 15,
+            isolated_pawn: 15,
+            backward_pawn: 10,
+            passed_pawn: [0, 5, 10, 20, 35, 60, 100, 0],
+            passed_enemy_king_distance: 5,
+            passed_own_king_distance: 2,
+            unstoppable_passer: 500,
+
+            king_attack_weight: [0, 2, 2, 3, 5, 0],
+            king_attacker_scale: [0, 0, 50, 75, 88, 94, 97, 100],
+            max_king_danger: 500,
+
+            bishop_pair: 30,
+            rook_open_file: 25,
+            rook_semi_open_file: 10,
+            rook_on_seventh: 20,
+            knight_outpost: 25,
+
+            knight_pawn_adjustment: 6,
+            rook_pawn_adjustment: 12,
+            queen_knight_synergy: 15,
+
+            mop_up_margin: 400,
+            mop_up_edge: 10,
+            mop_up_proximity: 4,
+
+            tempo: 10,
+
+            lazy_eval_margin: 500,
+        }
+    }
+}
+
+impl EvalParams {
+    pub fn material(&self, piece: PieceType) -> Score {
+        Score::new(self.material[piece.to_int() as usize])
+    }
+
+    pub fn piece_square_table(&self, piece: PieceType) -> &PieceSquareTable {
+        &self.piece_square_tables[piece.to_int() as usize]
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, EvalParamsError> {
+        serde_json::from_str(json).map_err(EvalParamsError::Parse)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Eval params are always serializable")
+    }
+
+    /// Reads the params from a JSON file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, EvalParamsError> {
+        let json = fs::read_to_string(path).map_err(EvalParamsError::Io)?;
+        Self::from_json(&json)
+    }
+
+    /// Calls `f` on every weight, always in the same order, so that the params can be treated as
+    /// a flat list of numbers. The lazy eval margin is left out, since it only affects the search.
+    pub fn for_each_weight(&mut self, mut f: impl FnMut(&mut i32)) {
+        fn narrow(weight: &mut i16, f: &mut dyn FnMut(&mut i32)) {
+            let mut wide = *weight as i32;
+            f(&mut wide);
+            *weight = wide.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+        }
+
+        let f = &mut f as &mut dyn FnMut(&mut i32);
+
+        self.material
+            .iter_mut()
+            .for_each(|weight| narrow(weight, f));
+        for table in &mut self.piece_square_tables {
+            table.midgame.iter_mut().for_each(&mut *f);
+            table.endgame.iter_mut().for_each(&mut *f);
+        }
+
+        for weight in [
+            &mut self.pawn_shield,
+            &mut self.castling_right,
+            &mut self.hanging_piece,
+            &mut self.threat_by_pawn,
+            &mut self.threat_by_lesser_piece,
+            &mut self.doubled_pawn,
+            &mut self.isolated_pawn,
+            &mut self.backward_pawn,
+        ] {
+            narrow(weight, f);
+        }
+        self.passed_pawn
+            .iter_mut()
+            .for_each(|weight| narrow(weight, f));
+        narrow(&mut self.passed_enemy_king_distance, f);
+        narrow(&mut self.passed_own_king_distance, f);
+        narrow(&mut self.unstoppable_passer, f);
+
+        self.king_attack_weight.iter_mut().for_each(&mut *f);
+        self.king_attacker_scale.iter_mut().for_each(&mut *f);
+        f(&mut self.max_king_danger);
+
+        for weight in [
+            &mut self.bishop_pair,
+            &mut self.rook_open_file,
+            &mut self.rook_semi_open_file,
+            &mut self.rook_on_seventh,
+            &mut self.knight_outpost,
+            &mut self.knight_pawn_adjustment,
+            &mut self.rook_pawn_adjustment,
+            &mut self.queen_knight_synergy,
+            &mut self.mop_up_margin,
+            &mut self.mop_up_edge,
+            &mut self.mop_up_proximity,
+            &mut self.tempo,
+        ] {
+            narrow(weight, f);
+        }
+    }
+
+    /// Every weight, in the order of `for_each_weight`
+    pub fn weights(&self) -> Vec<i32> {
+        let mut weights = Vec::new();
+        self.clone().for_each_weight(|weight| weights.push(*weight));
+        weights
+    }
+
+    /// Overwrites every weight, in the order of `for_each_weight`
+    pub fn set_weights(&mut self, weights: &[i32]) {
+        let mut weights = weights.iter();
+        self.for_each_weight(|weight| {
+            *weight = *weights.next().expect("Not enough weights");
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_round_trip() {
+        let mut params = EvalParams {
+            bishop_pair: 50,
+            ..Default::default()
+        };
+        params.piece_square_tables[0].midgame[12] = -20;
+        assert_eq!(EvalParams::from_json(&params.to_json()).unwrap(), params);
+    }
+
+    #[test]
+    fn missing_fields_keep_their_default() {
+        let params = EvalParams::from_json(r#"{"doubled_pawn": 40}"#).unwrap();
+        assert_eq!(params.doubled_pawn, 40);
+        assert_eq!(params.isolated_pawn, EvalParams::default().isolated_pawn);
+    }
+
+    #[test]
+    fn weights_round_trip() {
+        let mut params = EvalParams::default();
+        let mut weights = params.weights();
+        weights.iter_mut().for_each(|weight| *weight += 1);
+        params.set_weights(&weights);
+        assert_eq!(
+            params.knight_outpost,
+            EvalParams::default().knight_outpost + 1
+        );
+        assert_eq!(params.weights(), weights);
+    }
+
+    #[test]
+    fn tables_must_have_64_squares() {
+        let json = r#"{"piece_square_tables": [{"midgame": [1, 2, 3], "endgame": []}]}"#;
+        assert!(EvalParams::from_json(json).is_err());
+    }
+}
@@ -1,8 +1,20 @@
+pub mod analysis;
+pub mod bench;
 pub mod engine;
+pub mod eval_breakdown;
+pub mod eval_params;
+mod material_cache;
+pub mod mcts;
 pub mod move_result;
+#[cfg(feature = "nnue")]
+pub mod nnue;
 mod piece_eval;
 pub mod score;
 pub mod scoring;
 pub mod search;
+#[cfg(test)]
+mod test_utils;
 pub mod timers;
+pub mod toys;
 mod transposition_table;
+pub mod tuning;
@@ -0,0 +1,280 @@
+use whalecrab_lib::{
+    bitboard::{BitBoard, EMPTY},
+    file::File,
+    movegen::pieces::piece::{PieceColor, PieceType},
+    position::game::Game,
+    square::Square,
+};
+
+use crate::{piece_eval::material_value, score::Score};
+
+/// How many material signatures are remembered. Games only go through a few dozen of them, so
+/// this rarely overwrites anything.
+const CACHE_SIZE: usize = 1024;
+
+/// Scores are scaled by this many 64ths, so this leaves them untouched
+pub(crate) const NORMAL_SCALE: i16 = 64;
+/// Bishops of opposite colors with only pawns left are very hard to win with
+const OPPOSITE_BISHOPS_SCALE: i16 = 32;
+/// Being a minor piece up without any pawns is rarely enough to win
+const NO_PAWNS_SCALE: i16 = 8;
+/// The stronger side can not win at all
+const DRAWN_SCALE: i16 = 0;
+
+const PIECES: [PieceType; 5] = [
+    PieceType::Pawn,
+    PieceType::Knight,
+    PieceType::Bishop,
+    PieceType::Rook,
+    PieceType::Queen,
+];
+
+/// How many pieces of every type each side has, ignoring the kings. Positions with the same
+/// signature share their endgame knowledge, no matter where the pieces stand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct MaterialSignature {
+    /// Indexed by `PieceColor::to_int`, then by `PieceType::to_int`
+    counts: [[u8; 5]; 2],
+}
+
+impl MaterialSignature {
+    fn new(game: &Game) -> Self {
+        let material = game.material();
+        let count = |color: PieceColor| PIECES.map(|piece| material.count(piece, color).min(15));
+        Self {
+            counts: [count(PieceColor::White), count(PieceColor::Black)],
+        }
+    }
+
+    /// Packs the counts into four bits each
+    fn key(&self) -> u64 {
+        self.counts
+            .iter()
+            .flatten()
+            .fold(0, |key, &count| key << 4 | count as u64)
+    }
+
+    fn count(&self, color: PieceColor, piece: PieceType) -> u8 {
+        self.counts[color.to_int() as usize][piece.to_int() as usize]
+    }
+
+    /// The material of every piece other than pawns
+    fn non_pawn_material(&self, color: PieceColor) -> i16 {
+        PIECES[1..]
+            .iter()
+            .map(|&piece| material_value(piece).to_int() * self.count(color, piece) as i16)
+            .sum()
+    }
+
+    /// How much to scale the score by when `strong` is the side that is ahead
+    fn scale(&self, strong: PieceColor) -> i16 {
+        let weak = strong.opponent();
+        let surplus = self.non_pawn_material(strong) - self.non_pawn_material(weak);
+
+        if self.count(strong, PieceType::Pawn) > 0 {
+            NORMAL_SCALE
+        } else if self.non_pawn_material(strong) < material_value(PieceType::Rook).to_int() {
+            // A lone minor piece can not mate
+            DRAWN_SCALE
+        } else if surplus <= material_value(PieceType::Bishop).to_int() {
+            // Covers a rook against a minor piece and a rook and bishop against a rook, among
+            // others
+            NO_PAWNS_SCALE
+        } else {
+            NORMAL_SCALE
+        }
+    }
+
+    /// Whether `strong` has nothing but a bishop and pawns against a lone king, which might be a
+    /// wrong bishop for its rook pawns
+    fn bishop_and_pawns(&self, strong: PieceColor) -> bool {
+        let weak = strong.opponent();
+        self.count(strong, PieceType::Pawn) > 0
+            && self.count(strong, PieceType::Bishop) == 1
+            && self.non_pawn_material(strong) == material_value(PieceType::Bishop).to_int()
+            && self.non_pawn_material(weak) == 0
+    }
+
+    /// Whether each side has one bishop and nothing else but pawns
+    fn lone_bishops(&self) -> bool {
+        [PieceColor::White, PieceColor::Black]
+            .into_iter()
+            .all(|color| {
+                self.count(color, PieceType::Bishop) == 1
+                    && self.non_pawn_material(color) == material_value(PieceType::Bishop).to_int()
+            })
+    }
+}
+
+/// The endgame knowledge of one material signature
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct MaterialEntry {
+    key: u64,
+    /// Indexed by `PieceColor::to_int` of the side that is ahead
+    scale: [i16; 2],
+    /// The bishops still need to be checked for being on opposite colors
+    lone_bishops: bool,
+    /// Indexed like `scale`. The bishop still needs to be checked for being the wrong one
+    bishop_and_pawns: [bool; 2],
+}
+
+/// Checks if every pawn of `strong` is on the same rook file, its bishop can not control the
+/// promotion square and the enemy king already guards it. The king can never be driven out of
+/// the corner, so the game is a draw.
+fn wrong_bishop(game: &Game, strong: PieceColor) -> bool {
+    let pawns = *game.get_pieces(&PieceType::Pawn, &strong);
+    let file = if (pawns & !File::A.mask()) == EMPTY {
+        File::A
+    } else if (pawns & !File::H.mask()) == EMPTY {
+        File::H
+    } else {
+        return false;
+    };
+
+    let promotion = Square::make_square(strong.final_rank(), file);
+    let bishop = *game.get_pieces(&PieceType::Bishop, &strong);
+    let light_bishop = (bishop & BitBoard::LIGHT_SQUARES) != EMPTY;
+    if light_bishop == promotion.in_bitboard(&BitBoard::LIGHT_SQUARES) {
+        return false;
+    }
+
+    game.get_king(strong.opponent())
+        .to_square()
+        .distance(promotion)
+        <= 1
+}
+
+impl MaterialEntry {
+    fn new(signature: &MaterialSignature) -> Self {
+        Self {
+            key: signature.key(),
+            scale: [
+                signature.scale(PieceColor::White),
+                signature.scale(PieceColor::Black),
+            ],
+            lone_bishops: signature.lone_bishops(),
+            bishop_and_pawns: [
+                signature.bishop_and_pawns(PieceColor::White),
+                signature.bishop_and_pawns(PieceColor::Black),
+            ],
+        }
+    }
+
+    /// How many 64ths of `score`, from white's perspective, should be kept in `game`
+    pub(crate) fn scale_factor(&self, game: &Game, score: Score) -> i16 {
+        let strong = if score >= Score::default() {
+            PieceColor::White
+        } else {
+            PieceColor::Black
+        };
+        let mut scale = self.scale[strong.to_int() as usize];
+
+        let bishops = game.white_bishops | game.black_bishops;
+        let on_both_colors = |squares: BitBoard| (bishops & squares) != EMPTY;
+        if self.lone_bishops
+            && on_both_colors(BitBoard::LIGHT_SQUARES)
+            && on_both_colors(BitBoard::DARK_SQUARES)
+        {
+            scale = scale.min(OPPOSITE_BISHOPS_SCALE);
+        }
+
+        if self.bishop_and_pawns[strong.to_int() as usize] && wrong_bishop(game, strong) {
+            scale = DRAWN_SCALE;
+        }
+
+        scale
+    }
+}
+
+/// Remembers the endgame knowledge of material signatures, so that it only has to be worked out
+/// once. Kept apart from the transposition table, since it is keyed by the material alone.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct MaterialCache {
+    entries: Box<[Option<MaterialEntry>]>,
+}
+
+impl Default for MaterialCache {
+    fn default() -> Self {
+        Self {
+            entries: vec![None; CACHE_SIZE].into_boxed_slice(),
+        }
+    }
+}
+
+impl MaterialCache {
+    /// Looks up the entry for the material of `game`, working it out on a miss
+    pub(crate) fn probe(&mut self, game: &Game) -> MaterialEntry {
+        let signature = MaterialSignature::new(game);
+        let key = signature.key();
+        let slot = &mut self.entries[key as usize % CACHE_SIZE];
+
+        match slot {
+            Some(entry) if entry.key == key => *entry,
+            _ => *slot.insert(MaterialEntry::new(&signature)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scale(fen: &str, score: i16) -> i16 {
+        let game = Game::from_fen(fen).unwrap();
+        MaterialCache::default()
+            .probe(&game)
+            .scale_factor(&game, Score::new(score))
+    }
+
+    #[test]
+    fn opposite_bishops_are_drawish() {
+        let opposite = "4k1b1/5p2/8/8/8/8/2PP4/2B1K3 w - - 0 1";
+        let same = "4kb2/5p2/8/8/8/8/2PP4/2B1K3 w - - 0 1";
+        assert_eq!(scale(opposite, 100), OPPOSITE_BISHOPS_SCALE);
+        assert_eq!(scale(same, 100), NORMAL_SCALE);
+    }
+
+    #[test]
+    fn rook_and_bishop_against_rook_is_drawish() {
+        let fen = "4k3/8/8/3r4/8/8/8/2BRK3 w - - 0 1";
+        assert_eq!(scale(fen, 300), NO_PAWNS_SCALE);
+    }
+
+    #[test]
+    fn wrong_bishop_with_rook_pawn_is_drawn() {
+        assert_eq!(scale("1k6/8/8/P7/8/8/8/2B1K3 w - - 0 1", 300), DRAWN_SCALE);
+        // The right bishop, or the king being too far away, still wins
+        assert_eq!(scale("1k6/8/8/P7/8/8/8/3BK3 w - - 0 1", 300), NORMAL_SCALE);
+        assert_eq!(scale("8/8/8/P7/8/8/6k1/2B1K3 w - - 0 1", 300), NORMAL_SCALE);
+        assert_eq!(scale("4k3/7p/8/8/8/2b5/8/6K1 b - - 0 1", -300), DRAWN_SCALE);
+    }
+
+    #[test]
+    fn lone_minor_piece_can_not_win() {
+        assert_eq!(scale("4k3/pp6/8/8/8/8/8/1N2K3 w - - 0 1", 100), DRAWN_SCALE);
+        // The pawns can still win for black
+        assert_eq!(
+            scale("4k3/pp6/8/8/8/8/8/1N2K3 w - - 0 1", -100),
+            NORMAL_SCALE
+        );
+    }
+
+    #[test]
+    fn pawns_keep_the_score() {
+        assert_eq!(
+            scale("4k3/8/8/3r4/8/8/4P3/2BRK3 w - - 0 1", 400),
+            NORMAL_SCALE
+        );
+        assert_eq!(scale("4k3/8/8/8/8/8/8/Q3K3 w - - 0 1", 900), NORMAL_SCALE);
+    }
+
+    #[test]
+    fn signatures_are_cached() {
+        let mut cache = MaterialCache::default();
+        let game = Game::default();
+        let entry = cache.probe(&game);
+        let key = MaterialSignature::new(&game).key();
+        assert_eq!(cache.entries[key as usize % CACHE_SIZE], Some(entry));
+        assert_eq!(cache.probe(&game), entry);
+    }
+}
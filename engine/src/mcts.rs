@@ -0,0 +1,248 @@
+use std::{
+    sync::{Arc, atomic::AtomicBool},
+    time::Duration,
+};
+
+use whalecrab_lib::{
+    engine::{Engine as Mover, SearchLimits},
+    movegen::moves::Move,
+    position::game::{Game, State},
+};
+
+use crate::{
+    engine::Engine,
+    platform_timer,
+    timers::{MoveTimer, infinite::Infinite, stoppable::Stoppable},
+};
+
+/// How many iterations are run when the limits would let the search run forever
+const DEFAULT_ITERATIONS: u64 = 5000;
+
+/// Balances exploring rarely visited moves against exploiting good ones. The usual `sqrt(2)`
+const EXPLORATION: f64 = std::f64::consts::SQRT_2;
+
+struct Node {
+    /// The move leading to this node. Only the root has none
+    m: Option<Move>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    /// Legal moves that do not have a child yet
+    unexpanded: Vec<Move>,
+    visits: u32,
+    /// The summed results for the player who played `m`, where a win is 1 and a draw is 0.5
+    value: f64,
+    /// Whether `m` checkmated the opponent
+    is_mate: bool,
+}
+
+impl Node {
+    fn new(m: Option<Move>, parent: Option<usize>, game: &Game) -> Node {
+        Node {
+            m,
+            parent,
+            children: Vec::new(),
+            unexpanded: game.legal_moves().to_vec(),
+            visits: 0,
+            value: 0.0,
+            is_mate: game.state == State::Checkmate,
+        }
+    }
+
+    /// The upper confidence bound used to pick which child to explore
+    fn uct(&self, parent_visits: u32) -> f64 {
+        let visits = self.visits as f64;
+        self.value / visits + EXPLORATION * ((parent_visits as f64).ln() / visits).sqrt()
+    }
+}
+
+/// Searches with Monte-Carlo tree search. Instead of random playouts, leaves are graded with the
+/// static evaluation, which is turned into a win probability.
+#[derive(Debug, Clone)]
+pub struct MctsMover {
+    /// Used for grading positions
+    evaluator: Engine,
+    /// Ends the search early once set, like the `stop` flag of the minimax search
+    stop: Arc<AtomicBool>,
+}
+
+impl Default for MctsMover {
+    fn default() -> Self {
+        Self {
+            // Only the static evaluation is used, so the transposition table is kept small
+            evaluator: Engine::from_game_with_hash_size(Game::default(), 1),
+            stop: Arc::default(),
+        }
+    }
+}
+
+impl MctsMover {
+    /// Creates a mover whose searches end early once `stop` is set
+    pub fn with_stop(stop: Arc<AtomicBool>) -> Self {
+        Self {
+            stop,
+            ..Default::default()
+        }
+    }
+
+    /// The chance that the player to move wins, judged by the static evaluation
+    fn evaluate(&mut self) -> f64 {
+        match self.evaluator.game.state {
+            // The player to move got checkmated
+            State::Checkmate => 0.0,
+            State::InProgress => {
+                let score = self.evaluator.grade_position_relative().to_int() as f64;
+                1.0 / (1.0 + 10f64.powf(-score / 400.0))
+            }
+            _ => 0.5,
+        }
+    }
+
+    /// Picks the child to explore. A checkmate is always picked, since no other move can be better
+    fn select(tree: &[Node], node: usize) -> usize {
+        let children = &tree[node].children;
+        if let Some(&mate) = children.iter().find(|&&child| tree[child].is_mate) {
+            return mate;
+        }
+
+        let parent_visits = tree[node].visits;
+        *children
+            .iter()
+            .max_by(|&&a, &&b| {
+                tree[a]
+                    .uct(parent_visits)
+                    .total_cmp(&tree[b].uct(parent_visits))
+            })
+            .expect("Selected a node without children")
+    }
+
+    /// Runs one iteration of selection, expansion, evaluation and backpropagation
+    fn iterate(&mut self, tree: &mut Vec<Node>) {
+        let game = &mut self.evaluator.game;
+        let mut path = Vec::new();
+        let mut node = 0;
+
+        // Selection
+        while tree[node].unexpanded.is_empty() && !tree[node].children.is_empty() {
+            node = Self::select(tree, node);
+            let m = tree[node].m.expect("Only the root has no move");
+            game.play(&m);
+            path.push(m);
+        }
+
+        // Expansion
+        if let Some(m) = tree[node].unexpanded.pop() {
+            game.play(&m);
+            path.push(m);
+            let child = Node::new(Some(m), Some(node), game);
+            tree.push(child);
+            let index = tree.len() - 1;
+            tree[node].children.push(index);
+            node = index;
+        }
+
+        // The result for the player who moved into the leaf
+        let mut result = 1.0 - self.evaluate();
+
+        // Backpropagation
+        let mut current = Some(node);
+        while let Some(index) = current {
+            tree[index].visits += 1;
+            tree[index].value += result;
+            result = 1.0 - result;
+            current = tree[index].parent;
+        }
+
+        for m in path.iter().rev() {
+            self.evaluator.game.unplay(m);
+        }
+    }
+
+    /// Grows the search tree until the timer is over or `iterations` were run
+    fn search<T: MoveTimer>(&mut self, timer: &T, iterations: u64) -> Option<Move> {
+        let mut tree = vec![Node::new(None, None, &self.evaluator.game)];
+
+        for _ in 0..iterations {
+            if timer.over() {
+                break;
+            }
+            self.iterate(&mut tree);
+        }
+
+        // The most visited move is the most robust choice, unless there is a checkmate
+        let best = tree[0]
+            .children
+            .iter()
+            .max_by_key(|&&child| (tree[child].is_mate, tree[child].visits))
+            .and_then(|&child| tree[child].m);
+
+        // Fall back to any legal move if not a single iteration was run
+        best.or_else(|| tree[0].unexpanded.last().copied())
+    }
+}
+
+impl Mover for MctsMover {
+    fn best_move(&mut self, game: &mut Game, limits: &SearchLimits) -> Option<Move> {
+        self.evaluator.with_new_game(game.clone());
+
+        let iterations = if limits.nodes == u64::MAX && limits.duration == Duration::MAX {
+            DEFAULT_ITERATIONS
+        } else {
+            limits.nodes
+        };
+
+        let stop = self.stop.clone();
+        if limits.duration == Duration::MAX {
+            self.search(&Stoppable::new(Infinite, stop), iterations)
+        } else {
+            self.search(
+                &Stoppable::new(platform_timer!(limits.duration), stop),
+                iterations,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use whalecrab_lib::square::Square;
+
+    use super::*;
+
+    #[test]
+    fn mcts_takes_the_queen() {
+        let fen = "rnb1kbnr/pppp1ppp/8/4p1q1/3PP3/8/PPP2PPP/RNBQKBNR w KQkq - 1 3";
+        let mut game = Game::from_fen(fen).unwrap();
+        let before = game.clone();
+        let expected = Move::infer(Square::C1, Square::G5, &game);
+        let m = MctsMover::default().best_move(&mut game, &SearchLimits::nodes(2000));
+        assert_eq!(m, Some(expected));
+        assert_eq!(game, before);
+    }
+
+    #[test]
+    fn mcts_finds_mate_in_one() {
+        let fen = "7k/8/8/8/8/8/5R2/K5R1 w - - 0 1";
+        let mut game = Game::from_fen(fen).unwrap();
+        let expected = Move::infer(Square::F2, Square::H2, &game);
+        let m = MctsMover::default().best_move(&mut game, &SearchLimits::nodes(2000));
+        assert_eq!(m, Some(expected));
+    }
+
+    #[test]
+    fn stopped_searches_still_move() {
+        let mut game = Game::default();
+        let stop = Arc::new(AtomicBool::new(true));
+        let start = std::time::Instant::now();
+        let m = MctsMover::with_stop(stop)
+            .best_move(&mut game, &SearchLimits::duration(Duration::from_secs(60)));
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert!(m.is_some_and(|m| game.legal_moves().contains(&m)));
+    }
+
+    #[test]
+    fn mcts_without_iterations_still_moves() {
+        let mut game = Game::default();
+        let m = MctsMover::default().best_move(&mut game, &SearchLimits::nodes(0));
+        assert!(m.is_some_and(|m| game.legal_moves().contains(&m)));
+    }
+}
@@ -2,7 +2,7 @@ use std::{fmt, ops::AddAssign};
 
 use whalecrab_lib::movegen::moves::Move;
 
-use crate::score::Score;
+use crate::{score::Score, search::stats::SearchStats};
 
 /// Provides relevant information about the completed search
 #[derive(Debug)]
@@ -60,17 +60,29 @@ impl fmt::Display for SearchInfo {
     }
 }
 
-#[derive(Debug, Default, PartialEq)]
+#[derive(Debug, Default)]
 pub struct SearchResult {
     pub best_move: Option<Move>,
+    /// The line of play the engine expects, starting with `best_move`
+    pub pv: Vec<Move>,
     pub info: SearchInfo,
+    /// Counters collected by the search
+    pub stats: SearchStats,
+}
+
+impl PartialEq for SearchResult {
+    fn eq(&self, other: &Self) -> bool {
+        self.best_move == other.best_move && self.info == other.info
+    }
 }
 
 impl SearchResult {
     pub const fn new(score: Score, depth: u8) -> SearchResult {
         SearchResult {
             best_move: None,
+            pv: Vec::new(),
             info: SearchInfo::new(score, depth),
+            stats: SearchStats::new(),
         }
     }
 }
@@ -89,6 +101,12 @@ impl AddAssign<&SearchResult> for SearchResult {
 
 impl fmt::Display for SearchResult {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Best: {:?}\n{}", self.best_move, self.info)
+        let pv = self
+            .pv
+            .iter()
+            .map(|m| m.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "Best: {:?}\nPV: [{}]\n{}", self.best_move, pv, self.info)
     }
 }
@@ -0,0 +1,423 @@
+//! An efficiently updatable neural network that can replace the hand-crafted evaluation.
+//!
+//! The network has one input for every piece of every color on every square, seen from the
+//! perspective of each side. Both perspectives share the same weights and are fed into one hidden
+//! layer each, which are kept in an accumulator. Since a move only changes a couple of inputs,
+//! the accumulator is updated by adding and removing the weights of those inputs, instead of
+//! recalculating the whole layer for every position.
+
+use std::{fmt, fs, io, path::Path, sync::Arc};
+
+use whalecrab_lib::{
+    bitboard::BitBoard,
+    movegen::pieces::piece::{ALL_PIECE_TYPES, PieceColor, PieceType},
+    position::game::Game,
+    square::Square,
+};
+
+use crate::{
+    engine::Engine,
+    score::{MAX_MATE_PLY, Score},
+};
+
+/// Every piece type of both colors on every square
+pub const INPUTS: usize = 2 * 6 * 64;
+/// Identifies network files
+pub const MAGIC: [u8; 4] = *b"WCNN";
+
+/// The hidden layer is clipped to this, which is 1.0 in the quantized network
+const QA: i32 = 255;
+/// 1.0 for the output weights in the quantized network
+const QB: i32 = 64;
+/// Converts the output of the network to centipawns
+const SCALE: i32 = 400;
+
+#[derive(Debug)]
+pub enum NnueError {
+    Io(io::Error),
+    Format(String),
+}
+
+impl fmt::Display for NnueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "Failed to read the network: {}", e),
+            Self::Format(e) => write!(f, "Invalid network: {}", e),
+        }
+    }
+}
+
+/// Which input a piece on a square is, from the perspective of `perspective`. The board is
+/// flipped for black, so that both perspectives see their own pieces coming from the bottom.
+fn feature(perspective: PieceColor, piece: PieceType, color: PieceColor, sq: Square) -> usize {
+    let (side, sq) = match perspective {
+        PieceColor::White => (color.to_int() as usize, sq.index()),
+        PieceColor::Black => (color.opponent().to_int() as usize, sq.index() ^ 56),
+    };
+    side * 384 + piece.to_int() as usize * 64 + sq
+}
+
+/// The bitboards of every piece, indexed by color and then piece type
+fn piece_boards(game: &Game) -> [BitBoard; 12] {
+    let mut boards = [BitBoard::default(); 12];
+    for color in [PieceColor::White, PieceColor::Black] {
+        for piece in ALL_PIECE_TYPES {
+            boards[color.to_int() as usize * 6 + piece.to_int() as usize] =
+                *game.get_pieces(&piece, &color);
+        }
+    }
+    boards
+}
+
+/// A quantized network with a single hidden layer
+///
+/// The file format is little endian: the magic bytes, the size of the hidden layer as a u32,
+/// then the i16 input weights grouped by input, the i16 hidden biases, the i16 output weights for
+/// the side to move followed by the other side, and finally the i32 output bias.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Network {
+    hidden: usize,
+    input_weights: Vec<i16>,
+    hidden_bias: Vec<i16>,
+    output_weights: Vec<i16>,
+    output_bias: i32,
+}
+
+impl Network {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, NnueError> {
+        let mut reader = bytes;
+        let mut take = |len: usize| -> Result<&[u8], NnueError> {
+            if reader.len() < len {
+                return Err(NnueError::Format("The network is truncated".to_string()));
+            }
+            let (taken, rest) = reader.split_at(len);
+            reader = rest;
+            Ok(taken)
+        };
+
+        if take(4)? != MAGIC {
+            return Err(NnueError::Format("Missing the magic bytes".to_string()));
+        }
+
+        let hidden = u32::from_le_bytes(take(4)?.try_into().unwrap()) as usize;
+        if hidden == 0 {
+            return Err(NnueError::Format("The hidden layer is empty".to_string()));
+        }
+
+        let mut i16s = |len: usize| -> Result<Vec<i16>, NnueError> {
+            Ok(take(len * 2)?
+                .chunks_exact(2)
+                .map(|b| i16::from_le_bytes([b[0], b[1]]))
+                .collect())
+        };
+
+        let input_weights = i16s(INPUTS * hidden)?;
+        let hidden_bias = i16s(hidden)?;
+        let output_weights = i16s(2 * hidden)?;
+        let output_bias = i32::from_le_bytes(take(4)?.try_into().unwrap());
+
+        if !reader.is_empty() {
+            return Err(NnueError::Format(format!(
+                "{} unexpected trailing bytes",
+                reader.len()
+            )));
+        }
+
+        Ok(Self {
+            hidden,
+            input_weights,
+            hidden_bias,
+            output_weights,
+            output_bias,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend((self.hidden as u32).to_le_bytes());
+        for weight in self
+            .input_weights
+            .iter()
+            .chain(&self.hidden_bias)
+            .chain(&self.output_weights)
+        {
+            bytes.extend(weight.to_le_bytes());
+        }
+        bytes.extend(self.output_bias.to_le_bytes());
+        bytes
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, NnueError> {
+        Self::from_bytes(&fs::read(path).map_err(NnueError::Io)?)
+    }
+
+    fn weights(&self, feature: usize) -> &[i16] {
+        &self.input_weights[feature * self.hidden..(feature + 1) * self.hidden]
+    }
+
+    /// Calculates the accumulator of `game` from scratch
+    pub fn accumulate(&self, game: &Game) -> Accumulator {
+        let mut accumulator = Accumulator {
+            boards: piece_boards(game),
+            white: self.hidden_bias.clone(),
+            black: self.hidden_bias.clone(),
+        };
+
+        for color in [PieceColor::White, PieceColor::Black] {
+            for piece in ALL_PIECE_TYPES {
+                for sq in *game.get_pieces(&piece, &color) {
+                    accumulator.add(self, piece, color, sq);
+                }
+            }
+        }
+
+        accumulator
+    }
+
+    /// Evaluates the position for white
+    pub fn evaluate(&self, accumulator: &Accumulator, turn: PieceColor) -> Score {
+        let (us, them) = match turn {
+            PieceColor::White => (&accumulator.white, &accumulator.black),
+            PieceColor::Black => (&accumulator.black, &accumulator.white),
+        };
+
+        let (our_weights, their_weights) = self.output_weights.split_at(self.hidden);
+        let activate = |hidden: &[i16], weights: &[i16]| -> i32 {
+            hidden
+                .iter()
+                .zip(weights)
+                .map(|(&h, &w)| (h as i32).clamp(0, QA) * w as i32)
+                .sum()
+        };
+
+        let output = activate(us, our_weights) + activate(them, their_weights) + self.output_bias;
+        // Never let the network claim a mate
+        let limit = (Score::MAX.to_int() - MAX_MATE_PLY - 1) as i64;
+        let score = (output as i64 * SCALE as i64 / (QA * QB) as i64).clamp(-limit, limit);
+
+        Score::new(score as i16).for_color(turn)
+    }
+}
+
+/// The hidden layer of both perspectives for one position
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Accumulator {
+    /// The pieces of the position this accumulator is for, used to find what a move changed
+    boards: [BitBoard; 12],
+    white: Vec<i16>,
+    black: Vec<i16>,
+}
+
+impl Accumulator {
+    fn update(
+        &mut self,
+        network: &Network,
+        piece: PieceType,
+        color: PieceColor,
+        sq: Square,
+        sign: i16,
+    ) {
+        for (perspective, hidden) in [
+            (PieceColor::White, &mut self.white),
+            (PieceColor::Black, &mut self.black),
+        ] {
+            let weights = network.weights(feature(perspective, piece, color, sq));
+            for (h, &w) in hidden.iter_mut().zip(weights) {
+                *h = h.wrapping_add(w.wrapping_mul(sign));
+            }
+        }
+    }
+
+    fn add(&mut self, network: &Network, piece: PieceType, color: PieceColor, sq: Square) {
+        self.update(network, piece, color, sq, 1);
+    }
+
+    fn remove(&mut self, network: &Network, piece: PieceType, color: PieceColor, sq: Square) {
+        self.update(network, piece, color, sq, -1);
+    }
+
+    /// Creates the accumulator of `game`, which must only differ from this one by a move
+    fn after_move(&self, network: &Network, game: &Game) -> Self {
+        let boards = piece_boards(game);
+        let mut next = self.clone();
+
+        for (i, (&before, &after)) in self.boards.iter().zip(&boards).enumerate() {
+            let color = PieceColor::from_int((i / 6) as u8).unwrap();
+            let piece = PieceType::from_int((i % 6) as u8).unwrap();
+            for sq in before & !after {
+                next.remove(network, piece, color, sq);
+            }
+            for sq in after & !before {
+                next.add(network, piece, color, sq);
+            }
+        }
+
+        next.boards = boards;
+        next
+    }
+}
+
+/// A network together with the accumulators of the positions along the current line
+#[derive(Debug, Clone, PartialEq)]
+pub struct NnueState {
+    network: Arc<Network>,
+    accumulators: Vec<Accumulator>,
+}
+
+impl NnueState {
+    fn new(network: Network) -> Self {
+        Self {
+            network: Arc::new(network),
+            accumulators: Vec::new(),
+        }
+    }
+
+    /// Makes sure the last accumulator belongs to `game`, recalculating it if it does not. This
+    /// keeps things correct when the game is replaced from the outside.
+    fn sync(&mut self, game: &Game) {
+        if self.accumulators.last().map(|a| a.boards) != Some(piece_boards(game)) {
+            self.accumulators.clear();
+            self.accumulators.push(self.network.accumulate(game));
+        }
+    }
+
+    /// Called right after a move is played
+    fn push(&mut self, game: &Game) {
+        if let Some(last) = self.accumulators.last() {
+            let next = last.after_move(&self.network, game);
+            self.accumulators.push(next);
+        }
+    }
+
+    /// Called right after a move is unplayed
+    fn pop(&mut self) {
+        self.accumulators.pop();
+    }
+
+    fn evaluate(&mut self, game: &Game) -> Score {
+        self.sync(game);
+        let accumulator = self.accumulators.last().expect("Synced above");
+        self.network.evaluate(accumulator, game.turn)
+    }
+}
+
+impl Engine {
+    /// Evaluates positions with `network` instead of the hand-crafted evaluation
+    pub fn set_network(&mut self, network: Network) {
+        self.nnue = Some(NnueState::new(network));
+    }
+
+    /// Goes back to the hand-crafted evaluation
+    pub fn clear_network(&mut self) {
+        self.nnue = None;
+    }
+
+    pub fn network(&self) -> Option<&Network> {
+        self.nnue.as_ref().map(|nnue| nnue.network.as_ref())
+    }
+
+    /// Called by the search before playing a move
+    pub(crate) fn nnue_sync(&mut self) {
+        if let Some(nnue) = &mut self.nnue {
+            nnue.sync(&self.game);
+        }
+    }
+
+    /// Called by the search after playing a move
+    pub(crate) fn nnue_push(&mut self) {
+        if let Some(nnue) = &mut self.nnue {
+            nnue.push(&self.game);
+        }
+    }
+
+    /// Called by the search after unplaying a move
+    pub(crate) fn nnue_pop(&mut self) {
+        if let Some(nnue) = &mut self.nnue {
+            nnue.pop();
+        }
+    }
+
+    /// The network's evaluation for white, if one is loaded
+    pub(crate) fn nnue_evaluate(&mut self) -> Option<Score> {
+        self.nnue.as_mut().map(|nnue| nnue.evaluate(&self.game))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rand::{RngExt, SeedableRng, rngs::Xoshiro256PlusPlus};
+
+    use super::*;
+
+    fn random_network(hidden: usize) -> Network {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(0);
+        let mut weights = |len: usize| (0..len).map(|_| rng.random_range(-64..64)).collect();
+        Network {
+            hidden,
+            input_weights: weights(INPUTS * hidden),
+            hidden_bias: weights(hidden),
+            output_weights: weights(2 * hidden),
+            output_bias: 100,
+        }
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let network = random_network(8);
+        assert_eq!(Network::from_bytes(&network.to_bytes()).unwrap(), network);
+
+        let bytes = network.to_bytes();
+        assert!(Network::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(Network::from_bytes(b"nope").is_err());
+    }
+
+    #[test]
+    fn incremental_updates_match_a_full_refresh() {
+        let network = random_network(16);
+        let mut game =
+            Game::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+                .unwrap();
+        let start = network.accumulate(&game);
+
+        for m in game.legal_moves() {
+            game.play(&m);
+            assert_eq!(
+                start.after_move(&network, &game),
+                network.accumulate(&game),
+                "{}",
+                m
+            );
+            game.unplay(&m);
+        }
+    }
+
+    #[test]
+    fn evaluation_is_symmetric() {
+        let network = random_network(16);
+        let white = Game::from_fen("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1").unwrap();
+        let black = Game::from_fen("4k3/4p3/8/8/8/8/8/4K3 b - - 0 1").unwrap();
+        assert_eq!(
+            network.evaluate(&network.accumulate(&white), white.turn),
+            -network.evaluate(&network.accumulate(&black), black.turn)
+        );
+    }
+
+    #[test]
+    fn engine_uses_the_network() {
+        let network = random_network(16);
+        let mut engine = Engine::default();
+        let expected = network.evaluate(&network.accumulate(&engine.game), engine.game.turn);
+
+        engine.set_network(network);
+        assert_eq!(engine.grade_position(), expected);
+
+        // The accumulators must stay in sync during a search
+        engine.search(Duration::MAX, 3);
+        assert_eq!(engine.grade_position(), expected);
+
+        engine.clear_network();
+        assert!(engine.network().is_none());
+    }
+}
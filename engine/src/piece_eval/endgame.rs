@@ -0,0 +1,90 @@
+use whalecrab_lib::{
+    bitboard::EMPTY,
+    movegen::pieces::piece::{PieceColor, PieceType},
+    position::game::Game,
+    square::Square,
+};
+
+use crate::{eval_params::EvalParams, score::Score};
+
+/// How many orthogonal steps `sq` is away from the four center squares
+fn center_distance(sq: Square) -> i16 {
+    let rank = sq.get_rank().to_int() as i16;
+    let file = sq.get_file().to_int() as i16;
+    (3 - rank).max(rank - 4) + (3 - file).max(file - 4)
+}
+
+/// Helps `color` convert a won endgame without pawns, such as KRK or KQK. Without this, every
+/// quiet move looks the same to the evaluation, so nothing drives the enemy king to the edge
+/// where it can be mated. Rewards pushing the enemy king away from the center and bringing our
+/// own king closer to it.
+pub(crate) fn mop_up(
+    game: &Game,
+    color: PieceColor,
+    material_advantage: Score,
+    params: &EvalParams,
+) -> Score {
+    if material_advantage.to_int() < params.mop_up_margin
+        || *game.get_pieces(&PieceType::Pawn, &color) != EMPTY
+    {
+        return Score::default();
+    }
+
+    let king = game.get_king(color).to_square();
+    let enemy_king = game.get_king(color.opponent()).to_square();
+
+    Score::new(
+        center_distance(enemy_king) * params.mop_up_edge
+            + (14 - king.manhattan_distance(enemy_king) as i16) * params.mop_up_proximity,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(fen: &str) -> Score {
+        let params = EvalParams::default();
+        mop_up(
+            &Game::from_fen(fen).unwrap(),
+            PieceColor::White,
+            params.material(PieceType::Rook),
+            &params,
+        )
+    }
+
+    #[test]
+    fn enemy_king_belongs_on_the_edge() {
+        let center = score("8/8/8/4k3/8/8/8/R3K3 w - - 0 1");
+        let edge = score("4k3/8/8/8/8/8/8/R3K3 w - - 0 1");
+        assert!(edge > center);
+    }
+
+    #[test]
+    fn kings_belong_together() {
+        let far = score("k7/8/8/8/8/8/8/6RK w - - 0 1");
+        let close = score("k7/8/1K6/8/8/8/8/6R1 w - - 0 1");
+        assert!(close > far);
+    }
+
+    #[test]
+    fn only_without_pawns_and_a_large_advantage() {
+        let params = EvalParams::default();
+        let game = Game::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 0 1").unwrap();
+        assert_eq!(
+            mop_up(&game, PieceColor::White, Score::new(100), &params),
+            Score::default()
+        );
+
+        let pawns = Game::from_fen("4k3/8/8/8/8/8/P7/R3K3 w - - 0 1").unwrap();
+        assert_eq!(
+            mop_up(
+                &pawns,
+                PieceColor::White,
+                params.material(PieceType::Rook),
+                &params
+            ),
+            Score::default()
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use whalecrab_lib::{
+    movegen::pieces::piece::{PieceColor, PieceType},
+    position::game::Game,
+};
+
+use crate::{eval_params::EvalParams, score::Score};
+
+/// The pawn count that the material values are balanced around
+const BALANCED_PAWNS: i16 = 5;
+
+/// Adjusts the material of `color` for how well its pieces suit each other. Knights need pawns to
+/// find outposts, while rooks need open files, so their values shift with the amount of pawns.
+/// A queen also works better with a knight than a bishop when the enemy queen is still around.
+pub(crate) fn score_imbalance(game: &Game, color: PieceColor, params: &EvalParams) -> Score {
+    let count =
+        |piece: PieceType, color: PieceColor| game.get_pieces(&piece, &color).popcnt() as i16;
+    let enemy = color.opponent();
+
+    let pawns = count(PieceType::Pawn, color);
+    let knights = count(PieceType::Knight, color);
+    let rooks = count(PieceType::Rook, color);
+
+    let mut score = knights * (pawns - BALANCED_PAWNS) * params.knight_pawn_adjustment
+        - rooks * (pawns - BALANCED_PAWNS) * params.rook_pawn_adjustment;
+
+    if count(PieceType::Queen, color) > 0
+        && knights > 0
+        && count(PieceType::Queen, enemy) > 0
+        && count(PieceType::Knight, enemy) == 0
+        && count(PieceType::Bishop, enemy) > 0
+    {
+        score += params.queen_knight_synergy;
+    }
+
+    Score::new(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn imbalance(fen: &str, color: PieceColor) -> Score {
+        score_imbalance(&Game::from_fen(fen).unwrap(), color, &EvalParams::default())
+    }
+
+    #[test]
+    fn knights_like_pawns() {
+        let many = imbalance("4k3/8/8/8/8/8/PPPPPPPP/1N2K3 w - - 0 1", PieceColor::White);
+        let few = imbalance("4k3/8/8/8/8/8/PP6/1N2K3 w - - 0 1", PieceColor::White);
+        assert!(many > few);
+    }
+
+    #[test]
+    fn rooks_like_open_boards() {
+        let many = imbalance("4k3/8/8/8/8/8/PPPPPPPP/R3K3 w - - 0 1", PieceColor::White);
+        let few = imbalance("4k3/8/8/8/8/8/PP6/R3K3 w - - 0 1", PieceColor::White);
+        assert!(few > many);
+    }
+
+    #[test]
+    fn queen_and_knight_beat_queen_and_bishop() {
+        let params = EvalParams::default();
+        let fen = "3qkb2/8/8/8/8/8/8/3QKN2 w - - 0 1";
+        assert_eq!(
+            imbalance(fen, PieceColor::White) - imbalance(fen, PieceColor::Black),
+            Score::new(params.queen_knight_synergy - 5 * params.knight_pawn_adjustment)
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use whalecrab_lib::{
+    bitboard::{BitBoard, EMPTY},
+    movegen::pieces::{
+        king, knight,
+        piece::{PieceColor, PieceType},
+    },
+    position::game::Game,
+};
+
+use crate::{eval_params::EvalParams, score::Score};
+
+/// The squares around the king, plus the three squares two ranks in front of it
+fn king_zone(game: &Game, color: PieceColor) -> BitBoard {
+    let king = game.get_king(color).to_square();
+    let around = king::attacks(king) | BitBoard::from_square(king);
+    let front = match color {
+        PieceColor::White => around.north_one(),
+        PieceColor::Black => around.south_one(),
+    };
+    around | front
+}
+
+/// How much danger the king of `color` is in from enemy pieces attacking its zone. Grows
+/// quadratically with the weighted amount of attacks, so that a few attacks barely matter while a
+/// full attack is heavily penalized. A lone attacker rarely gets anywhere, so the danger is also
+/// scaled by the amount of attacking pieces.
+pub(crate) fn king_danger(game: &Game, color: PieceColor, params: &EvalParams) -> Score {
+    let zone = king_zone(game, color);
+    let enemy = color.opponent();
+
+    let mut attackers = 0;
+    let mut units = 0;
+
+    for piece in [
+        PieceType::Knight,
+        PieceType::Bishop,
+        PieceType::Rook,
+        PieceType::Queen,
+    ] {
+        for sq in *game.get_pieces(&piece, &enemy) {
+            let attacks = match piece {
+                PieceType::Knight => knight::attacks(sq),
+                _ => piece.magic_attacks(sq, game.occupied),
+            } & zone;
+
+            if attacks != EMPTY {
+                attackers += 1;
+                units +=
+                    params.king_attack_weight[piece.to_int() as usize] * attacks.popcnt() as i32;
+            }
+        }
+    }
+
+    let scale = params.king_attacker_scale[attackers.min(params.king_attacker_scale.len() - 1)];
+    let danger = (units * units / 4).min(params.max_king_danger) * scale / 100;
+    Score::new(danger as i16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn danger(fen: &str, color: PieceColor) -> Score {
+        king_danger(&Game::from_fen(fen).unwrap(), color, &EvalParams::default())
+    }
+
+    #[test]
+    fn no_danger_at_the_start() {
+        let game = Game::default();
+        let params = EvalParams::default();
+        assert_eq!(
+            king_danger(&game, PieceColor::White, &params),
+            Score::default()
+        );
+        assert_eq!(
+            king_danger(&game, PieceColor::Black, &params),
+            Score::default()
+        );
+    }
+
+    #[test]
+    fn lone_attacker_is_not_dangerous() {
+        let fen = "6k1/5ppp/8/8/8/3Q4/5PPP/6K1 b - - 0 1";
+        assert_eq!(danger(fen, PieceColor::Black), Score::default());
+    }
+
+    #[test]
+    fn more_attackers_are_more_dangerous() {
+        let two = danger("6k1/5ppp/8/6N1/8/3Q4/5PPP/6K1 b - - 0 1", PieceColor::Black);
+        let three = danger(
+            "6k1/5ppp/8/6N1/8/3Q1R2/5PPP/6K1 b - - 0 1",
+            PieceColor::Black,
+        );
+        assert!(two > Score::default());
+        assert!(three > two);
+    }
+
+    #[test]
+    fn danger_is_symmetric() {
+        let fen = "6k1/5ppp/3q4/6N1/6n1/3Q4/5PPP/6K1 w - - 0 1";
+        let white = danger(fen, PieceColor::White);
+        assert!(white > Score::default());
+        assert_eq!(white, danger(fen, PieceColor::Black));
+    }
+}
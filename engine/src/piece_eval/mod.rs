@@ -1,11 +1,17 @@
-mod tables;
+pub(crate) mod endgame;
+pub(crate) mod imbalance;
+pub(crate) mod king_safety;
+pub(crate) mod pawns;
+pub(crate) mod positional;
+pub(crate) mod tables;
+pub(crate) mod threats;
 
 use whalecrab_lib::{
     movegen::pieces::piece::{PieceColor, PieceType},
     square::Square,
 };
 
-use crate::score::Score;
+use crate::{eval_params::EvalParams, score::Score};
 
 /// Gets the pieces value, for example, a pawn is 1.0. Does not consider turn.
 pub const fn material_value(piece_type: PieceType) -> Score {
@@ -22,41 +28,21 @@ pub const fn material_value(piece_type: PieceType) -> Score {
 }
 
 /// Gets the positional value of a piece using a piece-square table
-pub fn square_value(piece_type: PieceType, sq: Square, color: PieceColor, ratio: f64) -> Score {
+pub fn square_value(
+    piece_type: PieceType,
+    sq: Square,
+    color: PieceColor,
+    ratio: f64,
+    params: &EvalParams,
+) -> Score {
     let index = match color {
         PieceColor::White => sq,
         PieceColor::Black => sq.flip_side(),
     }
     .index();
 
-    let (midgame, endgame) = unsafe {
-        match piece_type {
-            PieceType::Pawn => (
-                *tables::PAWN_MID.get_unchecked(index),
-                *tables::PAWN_END.get_unchecked(index),
-            ),
-            PieceType::Knight => (
-                *tables::KNIGHT_MID.get_unchecked(index),
-                *tables::KNIGHT_END.get_unchecked(index),
-            ),
-            PieceType::Bishop => (
-                *tables::BISHOP_MID.get_unchecked(index),
-                *tables::BISHOP_END.get_unchecked(index),
-            ),
-            PieceType::Rook => (
-                *tables::ROOK_MID.get_unchecked(index),
-                *tables::ROOK_END.get_unchecked(index),
-            ),
-            PieceType::Queen => (
-                *tables::QUEEN_MID.get_unchecked(index),
-                *tables::QUEEN_END.get_unchecked(index),
-            ),
-            PieceType::King => (
-                *tables::KING_MID.get_unchecked(index),
-                *tables::KING_END.get_unchecked(index),
-            ),
-        }
-    };
+    let table = params.piece_square_table(piece_type);
+    let (midgame, endgame) = (table.midgame[index], table.endgame[index]);
 
     let ratio_endgame = ratio;
     let ratio_midgame = 1.0 - ratio_endgame;
@@ -71,6 +57,7 @@ mod tests {
 
     #[test]
     fn balanced_square_value() {
+        let params = EvalParams::default();
         for (piece, sq) in [
             (PieceType::Pawn, Square::E4),
             (PieceType::Pawn, Square::D2),
@@ -92,8 +79,8 @@ mod tests {
             (PieceType::King, Square::D4),
         ] {
             assert_eq!(
-                square_value(piece, sq, PieceColor::White, 0.5),
-                square_value(piece, sq.flip_side(), PieceColor::Black, 0.5),
+                square_value(piece, sq, PieceColor::White, 0.5, &params),
+                square_value(piece, sq.flip_side(), PieceColor::Black, 0.5, &params),
                 "Failed for {:?} at {:?}",
                 piece,
                 sq
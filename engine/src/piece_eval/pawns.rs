@@ -0,0 +1,217 @@
+use whalecrab_lib::{
+    bitboard::{BitBoard, EMPTY},
+    file::File,
+    movegen::pieces::piece::{PieceColor, PieceType},
+    position::game::Game,
+    square::Square,
+};
+
+use crate::{eval_params::EvalParams, score::Score};
+
+/// The files directly next to `file`
+pub(crate) fn adjacent_files(file: File) -> BitBoard {
+    let mask = file.mask();
+    mask.east_one() | mask.west_one()
+}
+
+/// Every square on the ranks in front of `sq`, from the perspective of `color`
+pub(crate) fn ranks_ahead(sq: Square, color: PieceColor) -> BitBoard {
+    let rank = sq.get_rank().to_int() as u32;
+    match color {
+        PieceColor::White => BitBoard::new(u64::MAX.checked_shl(8 * (rank + 1)).unwrap_or(0)),
+        PieceColor::Black => BitBoard::new((1u64 << (8 * rank)) - 1),
+    }
+}
+
+/// How many ranks `sq` is away from the back rank of `color`
+pub(crate) fn relative_rank(sq: Square, color: PieceColor) -> usize {
+    match color {
+        PieceColor::White => sq.get_rank().to_int() as usize,
+        PieceColor::Black => 7 - sq.get_rank().to_int() as usize,
+    }
+}
+
+/// Checks if the passed pawn on `sq` promotes before the enemy king can catch it, by the rule of
+/// the square. Only meaningful when the enemy has nothing but pawns left to stop it with.
+fn is_unstoppable(game: &Game, sq: Square, color: PieceColor) -> bool {
+    let path = sq.get_file().mask() & ranks_ahead(sq, color);
+    if (game.occupied & path) != EMPTY {
+        return false;
+    }
+
+    // A pawn on its starting rank can move two squares at once
+    let moves = (7 - relative_rank(sq, color) as i16).min(5);
+    let promotion = Square::make_square(color.final_rank(), sq.get_file());
+    let mut king_distance = game
+        .get_king(color.opponent())
+        .to_square()
+        .distance(promotion) as i16;
+    if game.turn != color {
+        king_distance -= 1;
+    }
+
+    king_distance > moves
+}
+
+/// Scores the pawn structure of `color`. Doubled, isolated and backward pawns are penalized,
+/// while passed pawns get a bonus that grows as they advance and as the enemy king is further
+/// away from them than our own.
+pub(crate) fn score_pawns(game: &Game, color: PieceColor, params: &EvalParams) -> Score {
+    let pawns = *game.get_pieces(&PieceType::Pawn, &color);
+    let enemy_pawns = *game.get_pieces(&PieceType::Pawn, &color.opponent());
+    let king = game.get_king(color).to_square();
+    let enemy_king = game.get_king(color.opponent()).to_square();
+
+    // Without pieces, only the king can stop a passed pawn
+    let pawn_endgame = (*game.get_occupied(&color.opponent())
+        & !(enemy_pawns | *game.get_king(color.opponent())))
+        == EMPTY;
+
+    let mut score = 0;
+
+    for sq in pawns {
+        let file = sq.get_file();
+        let neighbours = adjacent_files(file);
+        let ahead = ranks_ahead(sq, color);
+
+        // Only the pawns behind the first are counted as doubled
+        if (pawns & file.mask() & ahead) != EMPTY {
+            score -= params.doubled_pawn;
+        }
+
+        let isolated = (pawns & neighbours) == EMPTY;
+        if isolated {
+            score -= params.isolated_pawn;
+        }
+
+        let stop = sq.forward(&color);
+
+        // No neighbouring pawn can ever defend it, and advancing loses it to an enemy pawn
+        if !isolated
+            && (pawns & neighbours & !ahead) == EMPTY
+            && let Some(stop) = stop
+        {
+            let stop_attackers = [stop.fleft(&color), stop.fright(&color)]
+                .into_iter()
+                .flatten()
+                .any(|attacker| attacker.in_bitboard(&enemy_pawns));
+            if stop_attackers {
+                score -= params.backward_pawn;
+            }
+        }
+
+        if (enemy_pawns & (file.mask() | neighbours) & ahead) == EMPTY {
+            let rank = relative_rank(sq, color);
+            score += params.passed_pawn[rank];
+            if let Some(stop) = stop {
+                // The proximity of the kings matters more the further the pawn has advanced
+                let proximity = enemy_king.distance(stop) as i16
+                    * params.passed_enemy_king_distance
+                    - king.distance(stop) as i16 * params.passed_own_king_distance;
+                score += proximity * rank as i16 / 4;
+            }
+
+            if pawn_endgame && is_unstoppable(game, sq, color) {
+                score += params.unstoppable_passer;
+            }
+        }
+    }
+
+    Score::new(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(fen: &str, color: PieceColor) -> Score {
+        score_pawns(&Game::from_fen(fen).unwrap(), color, &EvalParams::default())
+    }
+
+    #[test]
+    fn starting_pawns_are_neutral() {
+        let game = Game::default();
+        let params = EvalParams::default();
+        assert_eq!(
+            score_pawns(&game, PieceColor::White, &params),
+            Score::default()
+        );
+        assert_eq!(
+            score_pawns(&game, PieceColor::Black, &params),
+            Score::default()
+        );
+    }
+
+    #[test]
+    fn pawn_structure_is_symmetric() {
+        let fen = "4k3/pp3p1p/2p5/3p4/3P4/2P5/PP3P1P/4K3 w - - 0 1";
+        assert_eq!(score(fen, PieceColor::White), score(fen, PieceColor::Black));
+    }
+
+    #[test]
+    fn doubled_pawns_are_penalized() {
+        let healthy = score("4k3/3ppp2/8/8/8/8/3PP3/4K3 w - - 0 1", PieceColor::White);
+        let doubled = score("4k3/3ppp2/8/8/8/4P3/4P3/4K3 w - - 0 1", PieceColor::White);
+        assert!(doubled < healthy);
+    }
+
+    #[test]
+    fn isolated_pawns_are_penalized() {
+        let connected = score("4k3/pppp4/8/8/8/8/3PP3/4K3 w - - 0 1", PieceColor::White);
+        let isolated = score("4k3/pppp4/8/8/8/8/2P1P3/4K3 w - - 0 1", PieceColor::White);
+        assert_eq!(
+            connected - isolated,
+            Score::new(EvalParams::default().isolated_pawn * 2)
+        );
+    }
+
+    #[test]
+    fn backward_pawns_are_penalized() {
+        // The d3 pawn can not advance without being taken by the c5 pawn
+        let backward = score("4k3/8/8/2p5/4P3/3P4/8/4K3 w - - 0 1", PieceColor::White);
+        let safe = score("4k3/8/2p5/8/4P3/3P4/8/4K3 w - - 0 1", PieceColor::White);
+        assert_eq!(
+            safe - backward,
+            Score::new(EvalParams::default().backward_pawn)
+        );
+    }
+
+    #[test]
+    fn passed_pawns_are_better_when_advanced() {
+        let far = score("4k3/8/8/8/8/8/P7/4K3 w - - 0 1", PieceColor::White);
+        let close = score("4k3/8/P7/8/8/8/8/4K3 w - - 0 1", PieceColor::White);
+        let blocked = score("4k3/p7/8/8/8/8/P7/4K3 w - - 0 1", PieceColor::White);
+        assert!(close > far);
+        assert!(far > blocked);
+    }
+
+    #[test]
+    fn rule_of_the_square() {
+        let params = EvalParams::default();
+        let caught = score("8/2k5/8/P7/8/8/8/K7 w - - 0 1", PieceColor::White);
+        let runs = score("7k/8/8/P7/8/8/8/K7 w - - 0 1", PieceColor::White);
+        assert!(runs - caught > Score::new(params.unstoppable_passer / 2));
+
+        // Whoever is to move decides the race
+        let white_to_move = score("8/4k3/8/P7/8/8/8/K7 w - - 0 1", PieceColor::White);
+        let black_to_move = score("8/4k3/8/P7/8/8/8/K7 b - - 0 1", PieceColor::White);
+        assert_eq!(
+            white_to_move - black_to_move,
+            Score::new(params.unstoppable_passer)
+        );
+    }
+
+    #[test]
+    fn pieces_can_stop_passed_pawns() {
+        let runs = score("7k/8/8/P7/8/8/8/K7 w - - 0 1", PieceColor::White);
+        let rook = score("7k/8/8/P7/8/8/8/K6r w - - 0 1", PieceColor::White);
+        assert!(rook < runs);
+    }
+
+    #[test]
+    fn passed_pawns_prefer_a_distant_enemy_king() {
+        let escorted = score("8/8/1K6/P7/8/8/8/7k w - - 0 1", PieceColor::White);
+        let caught = score("k7/8/1K6/P7/8/8/8/8 w - - 0 1", PieceColor::White);
+        assert!(escorted > caught);
+    }
+}
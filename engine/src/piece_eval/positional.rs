@@ -0,0 +1,125 @@
+use whalecrab_lib::{
+    bitboard::EMPTY,
+    movegen::pieces::piece::{PieceColor, PieceType},
+    position::game::Game,
+};
+
+use crate::{
+    eval_params::EvalParams,
+    piece_eval::pawns::{adjacent_files, ranks_ahead, relative_rank},
+    score::Score,
+};
+
+/// Scores the placement of the minor and major pieces of `color`
+pub(crate) fn score_pieces(game: &Game, color: PieceColor, params: &EvalParams) -> Score {
+    let pawns = *game.get_pieces(&PieceType::Pawn, &color);
+    let enemy_pawns = *game.get_pieces(&PieceType::Pawn, &color.opponent());
+
+    let mut score = 0;
+
+    if game.get_pieces(&PieceType::Bishop, &color).popcnt() >= 2 {
+        score += params.bishop_pair;
+    }
+
+    for sq in *game.get_pieces(&PieceType::Rook, &color) {
+        let file = sq.get_file().mask();
+        if (pawns & file) == EMPTY {
+            score += if (enemy_pawns & file) == EMPTY {
+                params.rook_open_file
+            } else {
+                params.rook_semi_open_file
+            };
+        }
+
+        if relative_rank(sq, color) == 6 {
+            score += params.rook_on_seventh;
+        }
+    }
+
+    for sq in *game.get_pieces(&PieceType::Knight, &color) {
+        if !(3..=5).contains(&relative_rank(sq, color)) {
+            continue;
+        }
+
+        // Pawns defending the knight are diagonally behind it
+        let defended = [sq.fleft(&color.opponent()), sq.fright(&color.opponent())]
+            .into_iter()
+            .flatten()
+            .any(|defender| defender.in_bitboard(&pawns));
+        let chaseable =
+            (enemy_pawns & adjacent_files(sq.get_file()) & ranks_ahead(sq, color)) != EMPTY;
+
+        if defended && !chaseable {
+            score += params.knight_outpost;
+        }
+    }
+
+    Score::new(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(fen: &str, color: PieceColor) -> Score {
+        score_pieces(&Game::from_fen(fen).unwrap(), color, &EvalParams::default())
+    }
+
+    #[test]
+    fn starting_pieces_have_the_bishop_pair() {
+        let game = Game::default();
+        let params = EvalParams::default();
+        assert_eq!(
+            score_pieces(&game, PieceColor::White, &params),
+            Score::new(params.bishop_pair)
+        );
+        assert_eq!(
+            score_pieces(&game, PieceColor::Black, &params),
+            Score::new(params.bishop_pair)
+        );
+    }
+
+    #[test]
+    fn rooks_prefer_open_files() {
+        let params = EvalParams::default();
+        let open = score("4k3/pp6/8/8/8/8/PP6/2R1K3 w - - 0 1", PieceColor::White);
+        let semi_open = score("4k3/ppp5/8/8/8/8/PP6/2R1K3 w - - 0 1", PieceColor::White);
+        let closed = score("4k3/ppp5/8/8/8/8/PPP5/2R1K3 w - - 0 1", PieceColor::White);
+        assert_eq!(open, Score::new(params.rook_open_file));
+        assert_eq!(semi_open, Score::new(params.rook_semi_open_file));
+        assert_eq!(closed, Score::default());
+    }
+
+    #[test]
+    fn rook_on_seventh() {
+        let params = EvalParams::default();
+        let fen = "4k3/1R3ppp/8/8/8/8/r4PPP/4K3 w - - 0 1";
+        assert_eq!(
+            score(fen, PieceColor::White),
+            Score::new(params.rook_open_file + params.rook_on_seventh)
+        );
+        assert_eq!(
+            score(fen, PieceColor::Black),
+            Score::new(params.rook_open_file + params.rook_on_seventh)
+        );
+    }
+
+    #[test]
+    fn knight_outposts() {
+        let params = EvalParams::default();
+        // Defended by the d4 pawn and no black pawn can attack e5
+        let outpost = "4k3/p6p/8/4N3/3P4/8/8/4K3 w - - 0 1";
+        assert_eq!(
+            score(outpost, PieceColor::White),
+            Score::new(params.knight_outpost)
+        );
+
+        // The f7 pawn can chase the knight away
+        let chaseable = "4k3/p4p1p/8/4N3/3P4/8/8/4K3 w - - 0 1";
+        assert_eq!(score(chaseable, PieceColor::White), Score::default());
+
+        // Nothing defends the knight
+        let undefended = "4k3/p6p/8/4N3/8/8/8/4K3 w - - 0 1";
+        assert_eq!(score(undefended, PieceColor::White), Score::default());
+    }
+}
@@ -0,0 +1,126 @@
+use whalecrab_lib::{
+    bitboard::EMPTY,
+    movegen::pieces::{
+        knight, pawn,
+        piece::{PieceColor, PieceType},
+    },
+    position::game::Game,
+};
+
+use crate::{eval_params::EvalParams, score::Score};
+
+/// Scores the threats `color` makes against enemy pieces. Pieces that are attacked more times
+/// than they are defended, pieces attacked by pawns and pieces attacked by something worth less
+/// than them are all likely to lose material.
+pub(crate) fn score_threats(game: &Game, color: PieceColor, params: &EvalParams) -> Score {
+    let attacks = *game.get_attacks(&color);
+    let pawn_attacks = pawn::attacks(*game.get_pieces(&PieceType::Pawn, &color), color);
+    let enemy = color.opponent();
+
+    let mut score = 0;
+
+    for piece in [
+        PieceType::Pawn,
+        PieceType::Knight,
+        PieceType::Bishop,
+        PieceType::Rook,
+        PieceType::Queen,
+    ] {
+        let value = params.material(piece);
+
+        for sq in *game.get_pieces(&piece, &enemy) & attacks {
+            if game.num_attackers(sq) > game.num_defenders(sq) {
+                score += params.hanging_piece;
+            }
+
+            if piece == PieceType::Pawn {
+                continue;
+            }
+
+            if sq.in_bitboard(&pawn_attacks) {
+                score += params.threat_by_pawn;
+                continue;
+            }
+
+            let lesser_attacker = [PieceType::Knight, PieceType::Bishop, PieceType::Rook]
+                .into_iter()
+                .filter(|&attacker| params.material(attacker) < value)
+                .any(|attacker| {
+                    let reach = match attacker {
+                        PieceType::Knight => knight::attacks(sq),
+                        _ => attacker.magic_attacks(sq, game.occupied),
+                    };
+                    (reach & *game.get_pieces(&attacker, &color)) != EMPTY
+                });
+            if lesser_attacker {
+                score += params.threat_by_lesser_piece;
+            }
+        }
+    }
+
+    Score::new(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn threats(fen: &str, color: PieceColor) -> Score {
+        score_threats(&Game::from_fen(fen).unwrap(), color, &EvalParams::default())
+    }
+
+    #[test]
+    fn no_threats_at_the_start() {
+        let game = Game::default();
+        let params = EvalParams::default();
+        assert_eq!(
+            score_threats(&game, PieceColor::White, &params),
+            Score::default()
+        );
+        assert_eq!(
+            score_threats(&game, PieceColor::Black, &params),
+            Score::default()
+        );
+    }
+
+    #[test]
+    fn hanging_pieces() {
+        let params = EvalParams::default();
+        // The d5 knight is attacked by the rook and defended by nothing
+        let hanging = threats("4k3/8/8/3n4/8/8/8/3RK3 w - - 0 1", PieceColor::White);
+        assert_eq!(hanging, Score::new(params.hanging_piece));
+
+        // The e6 pawn defends it now
+        let defended = threats("4k3/8/4p3/3n4/8/8/8/3RK3 w - - 0 1", PieceColor::White);
+        assert_eq!(defended, Score::default());
+
+        // The a2 bishop joins the attack, which is one more than the pawn can defend against
+        let outnumbered = threats("4k3/8/4p3/3n4/8/8/B7/3RK3 w - - 0 1", PieceColor::White);
+        assert_eq!(outnumbered, Score::new(params.hanging_piece));
+    }
+
+    #[test]
+    fn pawns_threaten_pieces() {
+        let params = EvalParams::default();
+        let fen = "4k3/8/4p3/3n4/4P3/8/8/4K3 w - - 0 1";
+        assert_eq!(
+            threats(fen, PieceColor::White),
+            Score::new(params.threat_by_pawn)
+        );
+    }
+
+    #[test]
+    fn lesser_pieces_threaten_greater_ones() {
+        let params = EvalParams::default();
+        // The knight attacks the defended queen
+        let fen = "3rk3/8/8/3q4/8/4N3/8/4K3 w - - 0 1";
+        assert_eq!(
+            threats(fen, PieceColor::White),
+            Score::new(params.threat_by_lesser_piece)
+        );
+
+        // A queen attacking a defended rook is not a threat
+        let even = "4k3/4r3/8/8/8/8/8/Q3K3 w - - 0 1";
+        assert_eq!(threats(even, PieceColor::White), Score::default());
+    }
+}
@@ -1,6 +1,11 @@
 use std::fmt;
 use whalecrab_lib::{implement_operations, movegen::pieces::piece::PieceColor};
 
+/// The most plies a mate can be away from the root
+pub const MAX_MATE_PLY: i16 = u8::MAX as i16;
+
+/// A score in centipawns. Scores close to `MAX` and `MIN` are mates, which are counted in plies
+/// from the root, so that closer mates score higher.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Score(i16);
 
@@ -14,17 +19,31 @@ implement_operations!(
     ]
 );
 
+/// What a score means
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoreKind {
+    /// A normal evaluation in hundredths of a pawn
+    Centipawns(i16),
+    /// Mate in this many moves, or getting mated in this many moves when negative
+    Mate(i16),
+}
+
 impl fmt::Display for Score {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let pawns = self.0 / 100;
-        let centipawns = self.0 % 100;
-        write!(f, "{}.{}", pawns, centipawns.abs())
+        match self.kind() {
+            ScoreKind::Centipawns(cp) => {
+                let sign = if cp < 0 { "-" } else { "" };
+                write!(f, "{}{}.{:02}", sign, (cp / 100).abs(), (cp % 100).abs())
+            }
+            ScoreKind::Mate(moves) => write!(f, "#{}", moves),
+        }
     }
 }
 
 impl Score {
     pub const MAX: Score = Score(i16::MAX);
-    pub const MIN: Score = Score(i16::MIN);
+    /// Kept symmetric with `MAX` so that scores can always be negated
+    pub const MIN: Score = Score(-i16::MAX);
 
     pub const fn new(value: i16) -> Self {
         Self(value)
@@ -40,6 +59,74 @@ impl Score {
     pub const fn to_int(self) -> i16 {
         self.0
     }
+
+    /// Mate in `ply` plies for the player the score is relative to
+    pub const fn mate_in(ply: u8) -> Self {
+        Self(Self::MAX.0 - ply as i16)
+    }
+
+    /// Getting mated in `ply` plies for the player the score is relative to
+    pub const fn mated_in(ply: u8) -> Self {
+        Self(Self::MIN.0 + ply as i16)
+    }
+
+    /// Checks if this is a checkmate score, which is adjusted by up to `u8::MAX` for distance
+    pub const fn is_mate(self) -> bool {
+        self.0.abs() >= i16::MAX - MAX_MATE_PLY
+    }
+
+    /// How many plies away the mate is, negative when getting mated
+    pub const fn mate_plies(self) -> Option<i16> {
+        if !self.is_mate() {
+            None
+        } else if self.0 > 0 {
+            Some(Self::MAX.0 - self.0)
+        } else {
+            Some(Self::MIN.0 - self.0)
+        }
+    }
+
+    pub const fn kind(self) -> ScoreKind {
+        match self.mate_plies() {
+            // Rounded away from zero, so that mate in one ply is mate in one move
+            Some(plies) if self.0 > 0 => ScoreKind::Mate((plies + 1) / 2),
+            Some(plies) => ScoreKind::Mate((plies - 1) / 2),
+            None => ScoreKind::Centipawns(self.0),
+        }
+    }
+
+    /// Formats the score the way the score field of a UCI info line expects, for example
+    /// "cp 35" or "mate -2"
+    pub fn to_uci(self) -> String {
+        match self.kind() {
+            ScoreKind::Centipawns(cp) => format!("cp {}", cp),
+            ScoreKind::Mate(moves) => format!("mate {}", moves),
+        }
+    }
+
+    /// Mate scores count plies from the root, which means something different in every other
+    /// node. This makes them count from the node at `ply` instead, so that they can be stored in
+    /// the transposition table.
+    pub const fn to_tt(self, ply: u8) -> Self {
+        if !self.is_mate() {
+            self
+        } else if self.0 > 0 {
+            Self(self.0 + ply as i16)
+        } else {
+            Self(self.0 - ply as i16)
+        }
+    }
+
+    /// Undoes `to_tt` for a score found in the transposition table at `ply`
+    pub const fn from_tt(self, ply: u8) -> Self {
+        if !self.is_mate() {
+            self
+        } else if self.0 > 0 {
+            Self(self.0 - ply as i16)
+        } else {
+            Self(self.0 + ply as i16)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -51,4 +138,46 @@ mod tests {
         assert_eq!(Score::new(5019).to_string(), "50.19".to_string());
         assert_eq!(Score::new(-5019).to_string(), "-50.19".to_string());
     }
+
+    #[test]
+    fn display_pads_centipawns() {
+        assert_eq!(Score::new(5).to_string(), "0.05");
+        assert_eq!(Score::new(-50).to_string(), "-0.50");
+        assert_eq!(Score::mate_in(3).to_string(), "#2");
+    }
+
+    #[test]
+    fn mate_scores() {
+        assert!(Score::MAX.is_mate());
+        assert!((Score::MIN + 200).is_mate());
+        assert!(!Score::new(5019).is_mate());
+    }
+
+    #[test]
+    fn mate_kinds() {
+        assert_eq!(Score::new(35).kind(), ScoreKind::Centipawns(35));
+        assert_eq!(Score::mate_in(1).kind(), ScoreKind::Mate(1));
+        assert_eq!(Score::mate_in(3).kind(), ScoreKind::Mate(2));
+        assert_eq!(Score::mated_in(2).kind(), ScoreKind::Mate(-1));
+        assert_eq!(Score::mated_in(4).kind(), ScoreKind::Mate(-2));
+        assert_eq!(-Score::mate_in(5), Score::mated_in(5));
+        assert!(Score::mate_in(1) > Score::mate_in(3));
+        assert!(Score::mated_in(4) > Score::mated_in(2));
+    }
+
+    #[test]
+    fn uci() {
+        assert_eq!(Score::new(-120).to_uci(), "cp -120");
+        assert_eq!(Score::mate_in(5).to_uci(), "mate 3");
+        assert_eq!(Score::mated_in(2).to_uci(), "mate -1");
+    }
+
+    #[test]
+    fn transposition_table_round_trip() {
+        let mate = Score::mate_in(7);
+        assert_eq!(mate.to_tt(3), Score::mate_in(4));
+        assert_eq!(mate.to_tt(3).from_tt(3), mate);
+        assert_eq!(Score::mated_in(6).to_tt(2), Score::mated_in(4));
+        assert_eq!(Score::new(80).to_tt(5), Score::new(80));
+    }
 }
@@ -1,10 +1,14 @@
 use crate::{
     engine::Engine,
-    piece_eval::{material_value, square_value},
+    eval_breakdown::{EvalBreakdown, SideBreakdown},
+    material_cache::NORMAL_SCALE,
+    piece_eval::{
+        endgame::mop_up, imbalance::score_imbalance, king_safety::king_danger, pawns::score_pawns,
+        positional::score_pieces, square_value, threats::score_threats,
+    },
     score::Score,
 };
 use whalecrab_lib::{
-    file::File,
     movegen::pieces::piece::{PieceColor, PieceType},
     position::game::State,
     square::Square,
@@ -14,11 +18,11 @@ impl Engine {
     fn score_white_material(&self) -> Score {
         let mut score = Score::default();
 
-        score += material_value(PieceType::Pawn) * self.game.white_pawns.popcnt() as i16;
-        score += material_value(PieceType::Knight) * self.game.white_knights.popcnt() as i16;
-        score += material_value(PieceType::Bishop) * self.game.white_bishops.popcnt() as i16;
-        score += material_value(PieceType::Rook) * self.game.white_rooks.popcnt() as i16;
-        score += material_value(PieceType::Queen) * self.game.white_queens.popcnt() as i16;
+        score += self.params.material(PieceType::Pawn) * self.game.white_pawns.popcnt() as i16;
+        score += self.params.material(PieceType::Knight) * self.game.white_knights.popcnt() as i16;
+        score += self.params.material(PieceType::Bishop) * self.game.white_bishops.popcnt() as i16;
+        score += self.params.material(PieceType::Rook) * self.game.white_rooks.popcnt() as i16;
+        score += self.params.material(PieceType::Queen) * self.game.white_queens.popcnt() as i16;
 
         score
     }
@@ -26,21 +30,21 @@ impl Engine {
     fn score_black_material(&self) -> Score {
         let mut score = Score::default();
 
-        score += material_value(PieceType::Pawn) * self.game.black_pawns.popcnt() as i16;
-        score += material_value(PieceType::Knight) * self.game.black_knights.popcnt() as i16;
-        score += material_value(PieceType::Bishop) * self.game.black_bishops.popcnt() as i16;
-        score += material_value(PieceType::Rook) * self.game.black_rooks.popcnt() as i16;
-        score += material_value(PieceType::Queen) * self.game.black_queens.popcnt() as i16;
+        score += self.params.material(PieceType::Pawn) * self.game.black_pawns.popcnt() as i16;
+        score += self.params.material(PieceType::Knight) * self.game.black_knights.popcnt() as i16;
+        score += self.params.material(PieceType::Bishop) * self.game.black_bishops.popcnt() as i16;
+        score += self.params.material(PieceType::Rook) * self.game.black_rooks.popcnt() as i16;
+        score += self.params.material(PieceType::Queen) * self.game.black_queens.popcnt() as i16;
 
         score
     }
 
     fn midgame_to_lategame_ratio(&self, total_material: Score) -> f64 {
-        let max_material = material_value(PieceType::Queen) * 1
-            + material_value(PieceType::Rook) * 2
-            + material_value(PieceType::Bishop) * 2
-            + material_value(PieceType::Knight) * 2
-            + material_value(PieceType::Pawn) * 8;
+        let max_material = self.params.material(PieceType::Queen) * 1
+            + self.params.material(PieceType::Rook) * 2
+            + self.params.material(PieceType::Bishop) * 2
+            + self.params.material(PieceType::Knight) * 2
+            + self.params.material(PieceType::Pawn) * 8;
 
         let material_ratio =
             total_material.min(max_material).to_int() as f64 / max_material.to_int() as f64;
@@ -53,9 +57,8 @@ impl Engine {
     fn score_white_piece_positions(&self, ratio: f64) -> Score {
         let mut score = Score::default();
 
-        for sq in self.game.white_occupied {
-            let (piece, color) = self.game.piece_lookup(sq).unwrap();
-            score += square_value(piece, sq, color, ratio);
+        for (sq, piece, color) in self.game.pieces_of(PieceColor::White) {
+            score += square_value(piece, sq, color, ratio, &self.params);
         }
 
         score
@@ -65,56 +68,47 @@ impl Engine {
     fn score_black_piece_positions(&self, ratio: f64) -> Score {
         let mut score = Score::default();
 
-        for sq in self.game.black_occupied {
-            let (piece, color) = self.game.piece_lookup(sq).unwrap();
-            score += square_value(piece, sq, color, ratio);
+        for (sq, piece, color) in self.game.pieces_of(PieceColor::Black) {
+            score += square_value(piece, sq, color, ratio, &self.params);
         }
 
         score
     }
 
-    /// Scores king safety. Primarily based on whether the king has friendly pawns next to him.
+    /// Scores king safety. Based on whether the king has friendly pawns next to him and how many
+    /// enemy pieces attack the squares around him.
     fn score_white_king_safety(&self) -> Score {
         let calculate_pawn_area = |king: &Square| {
-            let file = king.get_file();
-            let mut pawn_area = file.mask();
-            if file > File::A {
-                pawn_area |= file.left().mask();
-            }
-            if file < File::H {
-                pawn_area |= file.right().mask();
-            }
-            pawn_area
+            let file = king.get_file().mask();
+            file | file.east_one() | file.west_one()
         };
 
         let white_king = self.game.white_kings.to_square();
         let white_pawn_area = calculate_pawn_area(&white_king);
-        Score::new(((white_pawn_area & self.game.white_pawns).popcnt() * 15) as i16)
+        Score::new(
+            (white_pawn_area & self.game.white_pawns).popcnt() as i16 * self.params.pawn_shield,
+        ) - king_danger(&self.game, PieceColor::White, &self.params)
     }
 
-    /// Scores king safety. Primarily based on whether the king has friendly pawns next to him.
+    /// Scores king safety. Based on whether the king has friendly pawns next to him and how many
+    /// enemy pieces attack the squares around him.
     fn score_black_king_safety(&self) -> Score {
         let calculate_pawn_area = |king: &Square| {
-            let file = king.get_file();
-            let mut pawn_area = file.mask();
-            if file > File::A {
-                pawn_area |= file.left().mask();
-            }
-            if file < File::H {
-                pawn_area |= file.right().mask();
-            }
-            pawn_area
+            let file = king.get_file().mask();
+            file | file.east_one() | file.west_one()
         };
 
         let black_king = self.game.black_kings.to_square();
         let black_pawn_area = calculate_pawn_area(&black_king);
-        Score::new(((black_pawn_area & self.game.black_pawns).popcnt() * 15) as i16)
+        Score::new(
+            (black_pawn_area & self.game.black_pawns).popcnt() as i16 * self.params.pawn_shield,
+        ) - king_danger(&self.game, PieceColor::Black, &self.params)
     }
 
     /// Scores the position castling rights
     fn score_white_castling_rights(&self) -> Score {
         let mut score = Score::default();
-        let value = 2;
+        let value = self.params.castling_right;
 
         if self.game.castling_rights.white_queenside() {
             score += value;
@@ -130,7 +124,7 @@ impl Engine {
     /// Scores the position castling rights
     fn score_black_castling_rights(&self) -> Score {
         let mut score = Score::default();
-        let value = 2;
+        let value = self.params.castling_right;
 
         if self.game.castling_rights.black_queenside() {
             score += value;
@@ -143,71 +137,196 @@ impl Engine {
         score
     }
 
-    fn score_white_attackers(&self) -> Score {
-        Score::new(((self.game.white_attacks & self.game.occupied).popcnt() * 10) as i16)
+    /// Scores the expensive terms for black, on top of the cheap ones already in `breakdown`
+    fn score_black_expensive(&self, breakdown: &mut SideBreakdown, enemy_material: Score) {
+        breakdown.threats = score_threats(&self.game, PieceColor::Black, &self.params);
+        breakdown.king_safety = self.score_black_king_safety();
+        breakdown.castling_rights = self.score_black_castling_rights();
+        breakdown.pawns = score_pawns(&self.game, PieceColor::Black, &self.params);
+        breakdown.pieces = score_pieces(&self.game, PieceColor::Black, &self.params);
+        breakdown.mop_up = mop_up(
+            &self.game,
+            PieceColor::Black,
+            breakdown.material - enemy_material,
+            &self.params,
+        );
     }
 
-    fn score_black_attackers(&self) -> Score {
-        Score::new(((self.game.black_attacks & self.game.occupied).popcnt() * 10) as i16)
+    /// Scores the expensive terms for white, on top of the cheap ones already in `breakdown`
+    fn score_white_expensive(&self, breakdown: &mut SideBreakdown, enemy_material: Score) {
+        breakdown.threats = score_threats(&self.game, PieceColor::White, &self.params);
+        breakdown.king_safety = self.score_white_king_safety();
+        breakdown.castling_rights = self.score_white_castling_rights();
+        breakdown.pawns = score_pawns(&self.game, PieceColor::White, &self.params);
+        breakdown.pieces = score_pieces(&self.game, PieceColor::White, &self.params);
+        breakdown.mop_up = mop_up(
+            &self.game,
+            PieceColor::White,
+            breakdown.material - enemy_material,
+            &self.params,
+        );
     }
 
-    /// Score everything related to black's position
-    fn score_black(&self, black_material: Score, ratio: f64) -> Score {
-        black_material
-            + self.score_black_piece_positions(ratio)
-            + self.score_black_attackers()
-            + self.score_black_king_safety()
-            + self.score_black_castling_rights()
+    /// Scores material, imbalance, piece squares and tempo for both sides, along with the phase. Everything
+    /// else is left at zero.
+    fn score_sides_cheap(&self) -> (SideBreakdown, SideBreakdown, f64) {
+        let white_material = self.score_white_material();
+        let black_material = self.score_black_material();
+        let ratio = self.midgame_to_lategame_ratio(white_material + black_material);
+
+        let tempo = Score::new(self.params.tempo);
+        let white = SideBreakdown {
+            material: white_material,
+            imbalance: score_imbalance(&self.game, PieceColor::White, &self.params),
+            piece_squares: self.score_white_piece_positions(ratio),
+            tempo: if self.game.turn == PieceColor::White {
+                tempo
+            } else {
+                Score::default()
+            },
+            ..Default::default()
+        };
+        let black = SideBreakdown {
+            material: black_material,
+            imbalance: score_imbalance(&self.game, PieceColor::Black, &self.params),
+            piece_squares: self.score_black_piece_positions(ratio),
+            tempo: if self.game.turn == PieceColor::Black {
+                tempo
+            } else {
+                Score::default()
+            },
+            ..Default::default()
+        };
+
+        (white, black, ratio)
     }
 
-    /// Score everything related to whites position
-    fn score_white(&self, white_material: Score, ratio: f64) -> Score {
-        white_material
-            + self.score_white_piece_positions(ratio)
-            + self.score_white_attackers()
-            + self.score_white_king_safety()
-            + self.score_white_castling_rights()
+    /// Fills in the terms left out by `score_sides_cheap`
+    fn score_sides_expensive(&self, white: &mut SideBreakdown, black: &mut SideBreakdown) {
+        let (white_material, black_material) = (white.material, black.material);
+        self.score_white_expensive(white, black_material);
+        self.score_black_expensive(black, white_material);
+    }
+
+    /// Scores every term of the hand-crafted evaluation for both sides, along with the phase
+    fn score_sides(&self) -> (SideBreakdown, SideBreakdown, f64) {
+        let (mut white, mut black, ratio) = self.score_sides_cheap();
+        self.score_sides_expensive(&mut white, &mut black);
+        (white, black, ratio)
     }
 
     /// This is meant to be called on states other than InProgress. InProgress will return 0.0
     fn score_state(&self, for_color: PieceColor) -> Score {
         match self.game.state {
-            State::Checkmate => match for_color {
-                PieceColor::White => Score::MAX,
-                PieceColor::Black => Score::MIN,
-            },
+            // The player to move is the one who got checkmated
+            State::Checkmate => {
+                if self.game.turn == for_color {
+                    Score::MIN
+                } else {
+                    Score::MAX
+                }
+            }
             State::Stalemate => Score::default(),
             // TODO. Timing out should result in a win for the opponent if the opponent has
             // sufficent checkmating material
             State::Timeout => Score::default(),
             State::Repetition => Score::default(),
-            _ => Score::default(),
+            State::InsufficientMaterial => Score::default(),
+            State::InProgress => Score::default(),
         }
     }
 
+    /// How many 64ths of `score`, for white, to keep in endgames that are hard to win, going by
+    /// the material left on the board
+    fn material_scale(&mut self, score: Score) -> i16 {
+        self.material_cache
+            .probe(&self.game)
+            .scale_factor(&self.game, score)
+    }
+
+    /// Scales `score`, for white, down in drawish endgames
+    fn scale_for_material(&mut self, score: Score) -> Score {
+        let scale = self.material_scale(score) as i32;
+        Score::new((score.to_int() as i32 * scale / NORMAL_SCALE as i32) as i16)
+    }
+
     /// Grades the position for white
     pub fn grade_position(&mut self) -> Score {
         if self.game.state != State::InProgress {
             return self.score_state(PieceColor::White);
         }
 
-        let white_material = self.score_white_material();
-        let black_material = self.score_black_material();
-        let ratio = self.midgame_to_lategame_ratio(white_material + black_material);
+        #[cfg(feature = "nnue")]
+        if let Some(score) = self.nnue_evaluate() {
+            return score;
+        }
+
+        let (white, black, _) = self.score_sides();
+        self.scale_for_material(white.total() - black.total())
+    }
+
+    /// Splits the evaluation up into its terms, to show why the engine likes a position
+    pub fn explain_eval(&mut self) -> EvalBreakdown {
+        let (white, black, phase) = self.score_sides();
+        EvalBreakdown {
+            white,
+            black,
+            phase,
+            scale: self.material_scale(white.total() - black.total()),
+            total: self.grade_position(),
+        }
+    }
+
+    /// How much every piece adds to the evaluation for white where it stands, being its material
+    /// and piece-square value. Black pieces are negative and empty squares are zero.
+    pub fn square_scores(&self) -> [Score; 64] {
+        let ratio = self
+            .midgame_to_lategame_ratio(self.score_white_material() + self.score_black_material());
+
+        let mut scores = [Score::default(); 64];
+        for (sq, piece, color) in self.game.pieces() {
+            scores[sq.index()] = (self.params.material(piece)
+                + square_value(piece, sq, color, ratio, &self.params))
+            .for_color(color);
+        }
 
-        self.score_white(white_material, ratio) - self.score_black(black_material, ratio)
+        scores
     }
 
     /// Grades the position for the current player's turn
     pub fn grade_position_relative(&mut self) -> Score {
+        self.grade_position().for_color(self.game.turn)
+    }
+
+    /// Grades the position for the current player's turn, but only as precisely as a search with
+    /// the window of `alpha` and `beta` needs. Material and piece squares are scored first, and
+    /// when they are further outside the window than the remaining terms could make up for, the
+    /// remaining terms are skipped. The score is then only good enough to tell which side of the
+    /// window it falls on.
+    pub(crate) fn grade_position_lazy(&mut self, alpha: Score, beta: Score) -> Score {
         if self.game.state != State::InProgress {
-            return self.score_state(self.game.turn);
+            return self.grade_position_relative();
         }
 
-        let white_material = self.score_white_material();
-        let black_material = self.score_black_material();
-        let ratio = self.midgame_to_lategame_ratio(white_material + black_material);
+        #[cfg(feature = "nnue")]
+        if self.nnue.is_some() {
+            return self.grade_position_relative();
+        }
+
+        let turn = self.game.turn;
+        let (mut white, mut black, _) = self.score_sides_cheap();
+
+        let cheap = self
+            .scale_for_material(white.total() - black.total())
+            .for_color(turn);
+        let margin = self.params.lazy_eval_margin;
+        if cheap + margin <= alpha || cheap - margin >= beta {
+            self.stats.lazy_evals += 1;
+            return cheap;
+        }
 
-        self.score_white(white_material, ratio) + self.score_black(black_material, ratio)
+        self.score_sides_expensive(&mut white, &mut black);
+        self.scale_for_material(white.total() - black.total())
+            .for_color(turn)
     }
 }
@@ -0,0 +1,83 @@
+use whalecrab_lib::movegen::moves::Move;
+
+use crate::{engine::Engine, score::Score};
+
+/// How much a quiet move can be expected to improve the position, indexed by remaining depth
+const FUTILITY_MARGINS: [i16; 3] = [0, 200, 500];
+
+/// Checks if a move leaves the material on the board untouched
+pub(crate) fn is_quiet(m: &Move) -> bool {
+    !m.is_capture() && !matches!(m, Move::Promotion { .. })
+}
+
+impl Engine {
+    /// Checks if the node is close enough to the horizon that quiet moves can not raise alpha,
+    /// even with a generous margin added to the static evaluation
+    pub(crate) fn is_futile(&mut self, alpha: Score, depth: u8) -> bool {
+        let Some(&margin) = FUTILITY_MARGINS.get(depth as usize) else {
+            return false;
+        };
+
+        if !self.futility_pruning
+            || depth == 0
+            || alpha.is_mate()
+            || self.game.is_in_check(self.game.turn)
+        {
+            return false;
+        }
+
+        self.grade_position_relative() + margin <= alpha
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::search::limits::SearchLimits;
+
+    use super::*;
+
+    fn search_without_futility(fen: &str, depth: u8) -> (Option<Move>, u64) {
+        let mut engine = Engine::from_fen(fen).unwrap();
+        engine.futility_pruning = false;
+        let result = engine.search_with_limits(SearchLimits::depth(depth));
+        (result.best_move, result.stats.nodes)
+    }
+
+    #[test]
+    fn futility_pruning_searches_fewer_nodes() {
+        for fen in [
+            "r1k2b1r/1p4p1/p1p4P/4B3/2p5/3P3P/NP2P1B1/2K2R2 w - - 0 29",
+            "rnbqk1nr/ppp2pp1/7p/3pp3/1b1PP3/8/PPPB1PPP/RN1QKBNR w KQkq - 0 1",
+            "r1b1k2r/pppp1ppp/2n1pn2/8/P1PPq3/2b1P2N/3NBPPP/1RBQ1RK1 b kq - 6 10",
+        ] {
+            let (_, unpruned) = search_without_futility(fen, 3);
+            let mut engine = Engine::from_fen(fen).unwrap();
+            let pruned = engine.search(Duration::MAX, 3).stats;
+            assert!(pruned.futility_prunes > 0);
+            assert!(
+                pruned.nodes < unpruned,
+                "Futility pruning searched {} nodes, but {} without it on {}",
+                pruned.nodes,
+                unpruned,
+                fen
+            );
+        }
+    }
+
+    #[test]
+    fn futility_pruning_keeps_tactics() {
+        let fen = "rnbqk1nr/ppp2pp1/7p/3pp3/1b1PP3/8/PPPB1PPP/RN1QKBNR w KQkq - 0 1";
+        let (expected, _) = search_without_futility(fen, 2);
+        let mut engine = Engine::from_fen(fen).unwrap();
+        assert_eq!(engine.search(Duration::MAX, 2).best_move, expected);
+    }
+
+    #[test]
+    fn never_futile_in_check() {
+        let fen = "4k3/8/8/8/8/8/4q3/4K3 w - - 0 1";
+        let mut engine = Engine::from_fen(fen).unwrap();
+        assert!(!engine.is_futile(Score::new(2000), 1));
+    }
+}
@@ -1,35 +1,130 @@
-use std::time::Duration;
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
 
 use crate::{
     engine::Engine,
     move_result::SearchResult,
     platform_timer,
-    timers::{MoveTimer, infinite::Infinite},
+    search::{progress::SearchProgress, stats::SearchStats},
+    timers::{MoveTimer, infinite::Infinite, stoppable::Stoppable},
 };
 
+/// The stack each search thread gets, as much as a main thread gets since the search recurses
+/// deeply
+pub const SEARCH_STACK_SIZE: usize = 8 * 1024 * 1024;
+
 impl Engine {
     /// Same as `search` but you can use your own timer
-    pub fn search_with_timer<T: MoveTimer>(&mut self, timer: &T, max_depth: u8) -> SearchResult {
-        let mut depth = 0;
+    pub fn search_with_timer<T: MoveTimer + Sync>(
+        &mut self,
+        timer: &T,
+        max_depth: u8,
+    ) -> SearchResult {
+        self.search_with_timer_and_progress(timer, max_depth, |_| {})
+    }
+
+    /// Same as `search_with_timer`, but calls `on_iteration` each time the main thread finishes
+    /// a depth. Weakened engines do not report their progress.
+    pub fn search_with_timer_and_progress<T: MoveTimer + Sync>(
+        &mut self,
+        timer: &T,
+        max_depth: u8,
+        mut on_iteration: impl FnMut(&SearchProgress),
+    ) -> SearchResult {
+        if !self.skill.is_full_strength() {
+            return self.search_weakened(timer, max_depth);
+        }
+
+        if self.threads <= 1 {
+            return self.iterative_deepening(timer, max_depth, 0, &mut on_iteration);
+        }
+
+        // Lazy SMP: helper threads search the same position and only communicate through the
+        // shared transposition table. They are stopped once the main thread has its result.
+        let stop = Arc::new(AtomicBool::new(false));
+        std::thread::scope(|scope| {
+            let helpers: Vec<_> = (1..self.threads)
+                .map(|id| {
+                    let mut helper = self.clone();
+                    helper.trace = None;
+                    let timer = Stoppable::new(timer, stop.clone());
+                    // Odd helpers skip a depth so that they search ahead of the main thread
+                    std::thread::Builder::new()
+                        .name(format!("search helper {id}"))
+                        .stack_size(SEARCH_STACK_SIZE)
+                        .spawn_scoped(scope, move || {
+                            helper.iterative_deepening(&timer, max_depth, id as u8 % 2, &mut |_| {})
+                        })
+                        .expect("Failed to spawn a search helper")
+                })
+                .collect();
+
+            let mut result = self.iterative_deepening(timer, max_depth, 0, &mut on_iteration);
+            stop.store(true, Ordering::Relaxed);
+
+            for helper in helpers {
+                if let Ok(helper) = helper.join() {
+                    result.info.nodes += helper.info.nodes;
+                    result.stats += &helper.stats;
+                }
+            }
+
+            result
+        })
+    }
+
+    /// Searches one depth deeper each iteration, starting at `depth`, until `max_depth` is
+    /// searched or the timer is over. `on_iteration` is called after every finished depth.
+    fn iterative_deepening<T: MoveTimer>(
+        &mut self,
+        timer: &T,
+        max_depth: u8,
+        mut depth: u8,
+        on_iteration: &mut dyn FnMut(&SearchProgress),
+    ) -> SearchResult {
+        let start = Instant::now();
         let mut result = SearchResult::default();
+        self.killers.clear();
+        self.stats = SearchStats::default();
+        self.clear_trace();
 
         loop {
             let node = self.minimax(timer, depth);
             result += &node;
 
-            if node.best_move.is_none() || timer.over() {
+            if node.best_move.is_none() || self.search_over(timer) {
+                // Fall back to the interrupted search if no depth was completed
+                if result.best_move.is_none() {
+                    result.best_move = node.best_move;
+                    result.info.score = node.info.score;
+                    result.pv = node.pv;
+                }
                 break;
             }
 
             result.best_move = node.best_move;
             result.info.score = node.info.score;
+            result.pv = node.pv;
+            on_iteration(&SearchProgress {
+                depth,
+                score: result.info.score,
+                nodes: self.stats.nodes,
+                elapsed: start.elapsed(),
+                pv: &result.pv,
+            });
 
-            if depth == max_depth {
+            if depth >= max_depth {
                 break;
             }
             depth += 1;
         }
 
+        result.stats = self.stats;
         result
     }
 
@@ -41,18 +136,37 @@ impl Engine {
             self.search_with_timer(&platform_timer!(duration), max_depth)
         }
     }
+
+    /// Same as `search`, but the search also ends as soon as `stop` is set. The best move found so
+    /// far is still returned.
+    pub fn search_with_stop(
+        &mut self,
+        duration: Duration,
+        max_depth: u8,
+        stop: Arc<AtomicBool>,
+    ) -> SearchResult {
+        if duration == Duration::MAX {
+            self.search_with_timer(&Stoppable::new(Infinite, stop), max_depth)
+        } else {
+            self.search_with_timer(&Stoppable::new(platform_timer!(duration), stop), max_depth)
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::time::Instant;
 
+    use whalecrab_lib::{movegen::moves::Move, square::Square};
+
     use crate::timers::elapsed::Elapsed;
 
     use super::*;
 
     #[track_caller]
-    fn assert_iterative_deepening_timing<T: MoveTimer, M: FnOnce(Duration) -> T>(make_timer: M) {
+    fn assert_iterative_deepening_timing<T: MoveTimer + Sync, M: FnOnce(Duration) -> T>(
+        make_timer: M,
+    ) {
         let mut engine = Engine::default();
 
         let duration = Duration::from_millis(1000);
@@ -106,4 +220,59 @@ mod tests {
         let best_move = engine.search(duration, u8::MAX).best_move;
         assert!(best_move.is_some());
     }
+
+    #[test]
+    fn stopped_search_still_finds_a_move() {
+        let mut engine = Engine::default();
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let stopper = {
+            let stop = stop.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(100));
+                stop.store(true, std::sync::atomic::Ordering::Relaxed);
+            })
+        };
+
+        let now = Instant::now();
+        let result = engine.search_with_stop(Duration::MAX, u8::MAX, stop);
+        stopper.join().unwrap();
+
+        assert!(now.elapsed() < Duration::from_secs(5));
+        assert!(result.best_move.is_some());
+    }
+
+    #[test]
+    fn search_stopped_before_starting_still_finds_a_move() {
+        let mut engine = Engine::default();
+        let stop = Arc::new(AtomicBool::new(true));
+        let result = engine.search_with_stop(Duration::MAX, u8::MAX, stop);
+        assert!(result.best_move.is_some());
+    }
+
+    #[test]
+    fn reports_each_depth() {
+        let mut engine = Engine::default();
+        let mut reports = Vec::new();
+        let result = engine.search_with_timer_and_progress(&Infinite, 3, |progress| {
+            reports.push((progress.depth, progress.nodes, progress.pv.to_vec()));
+        });
+
+        let depths: Vec<_> = reports.iter().map(|(depth, _, _)| *depth).collect();
+        assert_eq!(depths, [0, 1, 2, 3]);
+        assert!(reports.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+        assert_eq!(reports[3].2, result.pv);
+    }
+
+    #[test]
+    fn lazy_smp_finds_a_move() {
+        let fen = "r3r1k1/pbP2p1p/6pb/8/P1Q5/3B1qP1/2R2P1P/1R4K1 b - - 1 37";
+        let mut engine = Engine::from_fen(fen).unwrap();
+        engine.set_threads(4);
+        let before = engine.game.clone();
+        let expected = Move::infer(Square::F3, Square::H1, &engine.game);
+        let result = engine.search(Duration::MAX, 3);
+        assert_eq!(result.best_move, Some(expected), "\n{}", result);
+        assert_eq!(engine.game, before);
+    }
 }
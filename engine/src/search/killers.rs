@@ -0,0 +1,64 @@
+use whalecrab_lib::movegen::moves::Move;
+
+/// The deepest ply killer moves are tracked for
+const MAX_PLY: usize = u8::MAX as usize + 1;
+
+/// Quiet moves that recently caused a beta cutoff, two per ply
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct KillerMoves {
+    moves: Box<[[Option<Move>; 2]]>,
+}
+
+impl Default for KillerMoves {
+    fn default() -> Self {
+        Self {
+            moves: vec![[None; 2]; MAX_PLY].into_boxed_slice(),
+        }
+    }
+}
+
+impl KillerMoves {
+    /// The killer moves for a ply, most recent first
+    pub(crate) fn get(&self, ply: u8) -> &[Option<Move>; 2] {
+        &self.moves[ply as usize]
+    }
+
+    /// Remembers a quiet move that caused a cutoff, pushing out the oldest killer
+    pub(crate) fn insert(&mut self, ply: u8, m: Move) {
+        let killers = &mut self.moves[ply as usize];
+        if killers[0] != Some(m) {
+            killers[1] = killers[0];
+            killers[0] = Some(m);
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.moves.fill([None; 2]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use whalecrab_lib::square::Square;
+
+    use super::*;
+
+    #[test]
+    fn keeps_the_two_most_recent_killers() {
+        let mut killers = KillerMoves::default();
+        let [a, b, c] = [Square::A1, Square::B1, Square::C1].map(|to| Move::Normal {
+            from: Square::D1,
+            to,
+            capture: None,
+        });
+
+        killers.insert(3, a);
+        killers.insert(3, b);
+        killers.insert(3, b);
+        assert_eq!(killers.get(3), &[Some(b), Some(a)]);
+
+        killers.insert(3, c);
+        assert_eq!(killers.get(3), &[Some(c), Some(b)]);
+        assert_eq!(killers.get(4), &[None, None]);
+    }
+}
@@ -0,0 +1,105 @@
+pub use whalecrab_lib::engine::SearchLimits;
+
+use std::{
+    sync::{Arc, atomic::AtomicBool},
+    time::Duration,
+};
+
+use crate::{
+    engine::Engine,
+    move_result::SearchResult,
+    platform_timer,
+    search::progress::SearchProgress,
+    timers::{MoveTimer, infinite::Infinite, stoppable::Stoppable},
+};
+
+impl Engine {
+    /// Checks if the timer is over or the node limit was reached
+    #[inline(always)]
+    pub(crate) fn search_over<T: MoveTimer>(&self, timer: &T) -> bool {
+        self.stats.nodes >= self.node_limit || timer.over()
+    }
+
+    /// Searches for the best move until one of the limits is reached. With more than one thread,
+    /// the node limit applies to each thread separately.
+    pub fn search_with_limits(&mut self, limits: SearchLimits) -> SearchResult {
+        self.node_limit = limits.nodes;
+        let result = self.search(limits.duration, limits.depth);
+        self.node_limit = u64::MAX;
+        result
+    }
+
+    /// Same as `search_with_limits`, but calls `on_iteration` each time a depth is finished, see
+    /// `search_with_timer_and_progress`. The search also ends as soon as `stop` is set.
+    pub fn search_with_progress(
+        &mut self,
+        limits: SearchLimits,
+        stop: Arc<AtomicBool>,
+        on_iteration: impl FnMut(&SearchProgress),
+    ) -> SearchResult {
+        self.node_limit = limits.nodes;
+        let result = if limits.duration == Duration::MAX {
+            let timer = Stoppable::new(Infinite, stop);
+            self.search_with_timer_and_progress(&timer, limits.depth, on_iteration)
+        } else {
+            let timer = Stoppable::new(platform_timer!(limits.duration), stop);
+            self.search_with_timer_and_progress(&timer, limits.depth, on_iteration)
+        };
+        self.node_limit = u64::MAX;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEN: &str = "r1k2b1r/1p4p1/p1p4P/4B3/2p5/3P3P/NP2P1B1/2K2R2 w - - 0 29";
+
+    #[test]
+    fn node_limited_search_is_deterministic() {
+        let limits = SearchLimits::nodes(5000);
+        let first = Engine::from_fen(FEN).unwrap().search_with_limits(limits);
+        let second = Engine::from_fen(FEN).unwrap().search_with_limits(limits);
+        assert!(first.best_move.is_some());
+        assert_eq!(first, second);
+        assert_eq!(first.pv, second.pv);
+    }
+
+    #[test]
+    fn node_limit_stops_the_search() {
+        let mut engine = Engine::from_fen(FEN).unwrap();
+        let result = engine.search_with_limits(SearchLimits::nodes(1000));
+        assert!(result.best_move.is_some());
+        assert!(
+            engine.stats.nodes < 1100,
+            "Searched {} nodes with a limit of 1000",
+            engine.stats.nodes
+        );
+        assert_eq!(engine.node_limit, u64::MAX);
+    }
+
+    #[test]
+    fn progress_search_can_be_stopped() {
+        let mut engine = Engine::from_fen(FEN).unwrap();
+        let stop = Arc::new(AtomicBool::new(true));
+        let mut depths = Vec::new();
+        let result = engine.search_with_progress(SearchLimits::default(), stop, |progress| {
+            depths.push(progress.depth)
+        });
+        assert!(result.best_move.is_some());
+        assert!(depths.is_empty());
+    }
+
+    #[test]
+    fn depth_limited_search_stops_at_depth() {
+        let mut engine = Engine::from_fen(FEN).unwrap();
+        let result = engine.search_with_limits(SearchLimits::depth(2));
+        assert!(result.best_move.is_some());
+        assert!(result.pv.len() <= 3);
+        assert_eq!(
+            result,
+            Engine::from_fen(FEN).unwrap().search(Duration::MAX, 2)
+        );
+    }
+}
@@ -1,81 +1,112 @@
-use whalecrab_lib::movegen::pieces::piece::PieceColor;
+use whalecrab_lib::{
+    movegen::{moves::Move, packed::PackedMove},
+    position::game::State,
+};
 
 use crate::engine::Engine;
 use crate::score::Score;
-use crate::search::move_ordering::order_moves;
-use crate::transposition_table::{NodeType, TranspositionTableEntry};
+use crate::search::{
+    futility::is_quiet, move_ordering::order_moves, move_picker::MovePicker, trace::PruneReason,
+};
+use crate::transposition_table::{Bound, TranspositionTableEntry};
 use crate::{
     move_result::{SearchInfo, SearchResult},
     timers::MoveTimer,
 };
 
-/// Plays a move, gets the score from the given method, and then unplays the move and returns that
-/// score. Also does expensive validity checks in debug builds.
-macro_rules! search_move {
-    ($self:expr, $move:expr, $method:ident($($args:expr),*)) => {{
-        #[cfg(debug_assertions)]
-        let before = $self.game.clone();
-
-        $self.game.play(&$move);
-
-        #[cfg(debug_assertions)]
-        let during = $self.game.clone();
-
-        let score = $self.$method($($args),*);
-        $self.game.unplay($move);
-
-        #[cfg(debug_assertions)]
-        assert_eq!(
-            $self.game, before,
-            "State changed after playing and unplaying {}\n  Before: {:?}\n  During: {:?}\n   After: {:?}\n",
-            $move, before, during, $self.game
-        );
-
-        score
-    }};
-}
-
 impl Engine {
-    fn maxi<T: MoveTimer>(
+    /// Grades the position for the player to move, preferring checkmates that are fewer plies
+    /// away from the root
+    pub(crate) fn grade_finished_position(&mut self) -> Score {
+        let score = self.grade_position_relative();
+        if score == Score::MIN {
+            Score::mated_in(self.ply)
+        } else if score == Score::MAX {
+            Score::mate_in(self.ply)
+        } else {
+            score
+        }
+    }
+
+    /// Searches the position with principal variation search. Scores are relative to the player
+    /// to move.
+    fn negamax<T: MoveTimer>(
         &mut self,
         mut alpha: Score,
         beta: Score,
         depth: u8,
         timer: &T,
     ) -> SearchInfo {
-        if depth == 0 || timer.over() {
+        self.trace_window(alpha, beta, depth);
+
+        if self.search_over(timer) || self.game.state != State::InProgress {
             return SearchInfo {
-                score: self.grade_position(),
+                score: self.grade_finished_position(),
                 depth,
                 nodes: 1,
             };
         }
 
+        // The opponent can keep repeating the position, so it is as good as a draw
+        if self.game.is_repetition() {
+            self.trace_prune(PruneReason::Repetition);
+            return SearchInfo {
+                score: Score::default(),
+                depth,
+                nodes: 1,
+            };
+        }
+
+        if depth == 0 {
+            return self.quiesce(alpha, beta, timer);
+        }
+
         let existing = self.transposition_table.get(self.game.hash);
-        let better_than_existing = if let Some(entry) = existing {
-            if depth == entry.depth {
-                return SearchInfo {
-                    score: entry.score,
-                    depth,
-                    nodes: 1,
-                };
-            } else if depth > entry.depth {
-                if alpha > entry.score {
-                    alpha = entry.score;
-                }
-                false
-            } else {
-                true
-            }
-        } else {
-            true
-        };
+        if existing.is_some() {
+            self.stats.tt_hits += 1;
+        }
+        if let Some(score) = existing.and_then(|e| e.cutoff(alpha, beta, depth, self.ply)) {
+            self.stats.tt_cutoffs += 1;
+            self.trace_prune(PruneReason::TranspositionTable);
+            return SearchInfo {
+                score,
+                depth,
+                nodes: 1,
+            };
+        }
 
-        let mut node_type = NodeType::Exact;
+        let original_alpha = alpha;
         let mut result = SearchResult::new(Score::MIN, depth);
+        let futile = self.is_futile(alpha, depth);
+
+        let tt_move = existing
+            .as_ref()
+            .and_then(|e| e.best_move.unpack(&self.game));
+        let mut picker = MovePicker::new(tt_move, *self.killers.get(self.ply));
+        let mut first = true;
+        while let Some(m) = picker.next(&self.game) {
+            // Checks are kept, since quiescence does not look at them below the horizon
+            if futile && !first && is_quiet(&m) && !m.gives_check(&self.game) {
+                self.stats.futility_prunes += 1;
+                self.trace_skip(&m, PruneReason::Futility);
+                continue;
+            }
 
-        for m in order_moves(self.game.legal_moves(), &existing) {
-            let node = search_move!(self, &m, mini(alpha, beta, depth - 1, timer));
+            let mut node = if std::mem::take(&mut first) {
+                search_move!(self, &m, negamax(-beta, -alpha, depth - 1, timer))
+            } else {
+                // Later moves only need to prove that they are no better than the first
+                let zero_window =
+                    search_move!(self, &m, negamax(-alpha - 1, -alpha, depth - 1, timer));
+                if -zero_window.score > alpha && -zero_window.score < beta {
+                    self.stats.researches += 1;
+                    result += &zero_window;
+                    search_move!(self, &m, negamax(-beta, -alpha, depth - 1, timer))
+                } else {
+                    zero_window
+                }
+            };
+            node.score = -node.score;
             result += &node;
 
             if node.score > result.info.score {
@@ -86,193 +117,136 @@ impl Engine {
                 }
             }
 
-            if node.score >= beta {
-                node_type = NodeType::Cut;
+            if self.search_over(timer) {
+                break;
+            }
+
+            if alpha >= beta {
+                self.stats.beta_cutoffs += 1;
+                self.trace_prune(PruneReason::BetaCutoff);
+                if !m.is_capture() {
+                    self.killers.insert(self.ply, m);
+                }
                 break;
             }
         }
 
-        if better_than_existing {
+        if !self.search_over(timer) {
             let entry = TranspositionTableEntry {
-                best_move: result.best_move,
+                best_move: result
+                    .best_move
+                    .map_or(PackedMove::NONE, |m| m.pack(&self.game)),
                 depth,
-                score: result.info.score,
-                node_type,
+                score: result.info.score.to_tt(self.ply),
+                bound: Bound::from_window(result.info.score, original_alpha, beta),
             };
-            self.transposition_table.insert(self.game.hash, entry);
+            self.transposition_table.store(self.game.hash, entry);
         }
 
         result.info
     }
 
-    fn mini<T: MoveTimer>(
+    /// Continues searching at the given depth until the search finishes or the timer is over.
+    /// The score of the result is from white's perspective.
+    pub fn minimax<T: MoveTimer>(&mut self, timer: &T, depth: u8) -> SearchResult {
+        self.search_root(timer, depth, &[])
+    }
+
+    /// Same as `minimax`, but the `excluded` root moves are not searched
+    pub(crate) fn search_root<T: MoveTimer>(
         &mut self,
-        alpha: Score,
-        mut beta: Score,
-        depth: u8,
         timer: &T,
-    ) -> SearchInfo {
-        if depth == 0 || timer.over() {
-            return SearchInfo {
-                score: self.grade_position(),
-                depth,
-                nodes: 1,
-            };
-        }
+        depth: u8,
+        excluded: &[Move],
+    ) -> SearchResult {
+        let mut alpha = Score::MIN;
+        let beta = Score::MAX;
 
         let existing = self.transposition_table.get(self.game.hash);
-        let better_than_existing = if let Some(entry) = existing {
-            if depth == entry.depth {
-                return SearchInfo {
-                    score: entry.score,
-                    depth,
-                    nodes: 1,
-                };
-            } else if depth > entry.depth {
-                if beta < entry.score {
-                    beta = entry.score;
+        let killers = self.killers.get(self.ply);
+        let moves = order_moves(self.game.legal_moves(), &existing, killers, &self.game);
+
+        let mut result = SearchResult::new(Score::MIN, 0);
+
+        for m in moves.into_iter().filter(|m| !excluded.contains(m)) {
+            let mut node = search_move!(self, &m, negamax(-beta, -alpha, depth, timer));
+            if self.search_over(timer) {
+                // Still hand back a legal move if the search was stopped right away
+                if result.best_move.is_none() {
+                    result.best_move = Some(m);
                 }
-                false
-            } else {
-                true
+                break;
             }
-        } else {
-            true
-        };
-
-        let mut node_type = NodeType::Exact;
-        let mut result = SearchResult::new(Score::MAX, depth);
 
-        for m in order_moves(self.game.legal_moves(), &existing) {
-            let node = search_move!(self, &m, maxi(alpha, beta, depth - 1, timer));
+            node.score = -node.score;
             result += &node;
 
-            if node.score < result.info.score {
+            if node.score > result.info.score {
                 result.info.score = node.score;
                 result.best_move = Some(m);
-                if node.score < beta {
-                    beta = node.score;
+                if node.score > alpha {
+                    alpha = node.score;
                 }
             }
-
-            if node.score <= alpha {
-                node_type = NodeType::All;
-                break;
-            }
         }
 
-        if better_than_existing {
+        // The best move of the position might have been excluded
+        if !self.search_over(timer) && excluded.is_empty() {
             let entry = TranspositionTableEntry {
-                best_move: result.best_move,
+                best_move: result
+                    .best_move
+                    .map_or(PackedMove::NONE, |m| m.pack(&self.game)),
                 depth,
-                score: result.info.score,
-                node_type,
+                score: result.info.score.to_tt(self.ply),
+                bound: Bound::Exact,
             };
-            self.transposition_table.insert(self.game.hash, entry);
+            self.transposition_table.store(self.game.hash, entry);
         }
 
-        result.info
-    }
-
-    /// Continues searching at the given depth until the search finishes or the timer is over
-    pub fn minimax<T: MoveTimer>(&mut self, timer: &T, depth: u8) -> SearchResult {
-        let mut alpha = Score::MIN;
-        let mut beta = Score::MAX;
-
-        macro_rules! search_loop {
-            ($best_score:expr, $cmp:tt, $search:ident, $prune:expr) => {{
-                let existing = self.transposition_table.get(self.game.hash);
-                let better_than_existing = existing.is_none_or(|e| depth > e.depth);
-
-                let mut result = SearchResult::new($best_score, 0);
-
-                for m in order_moves(self.game.legal_moves(), &existing) {
-                    let node = search_move!(self, &m, $search(alpha, beta, depth, timer));
-                    if timer.over() {
-                        break;
-                    }
-
-                    result += &node;
-
-                    if node.score $cmp result.info.score {
-                        result.info.score = node.score;
-                        result.best_move = Some(m);
-                        if node.score $cmp $prune {
-                            $prune = node.score;
-                        }
-                    }
-                }
-
-                if better_than_existing {
-                    let entry = TranspositionTableEntry {
-                        best_move: result.best_move,
-                        depth,
-                        score: result.info.score,
-                        node_type: NodeType::Exact,
-                    };
-                    self.transposition_table.insert(self.game.hash, entry);
-                }
-
-                result
-            }};
-        }
-
-        match self.game.turn {
-            PieceColor::White => search_loop!(Score::MIN, >, mini, alpha),
-            PieceColor::Black => search_loop!(Score::MAX, <, maxi, beta),
-        }
+        result.info.score = result.info.score.for_color(self.game.turn);
+        result.pv = self.principal_variation(result.best_move, depth.saturating_add(1));
+        result
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use whalecrab_lib::{movegen::moves::Move, square::Square};
+    use whalecrab_lib::square::Square;
 
     use crate::timers::infinite::Infinite;
 
     use super::*;
 
     impl Engine {
-        fn maxi_without_pruning<T: MoveTimer>(&mut self, depth: u8, timer: &T) -> SearchInfo {
-            if depth == 0 || timer.over() {
+        fn negamax_without_pruning<T: MoveTimer>(&mut self, depth: u8, timer: &T) -> SearchInfo {
+            if timer.over() || self.game.state != State::InProgress {
                 return SearchInfo {
-                    score: self.grade_position(),
+                    score: self.grade_finished_position(),
                     depth,
                     nodes: 1,
                 };
             }
 
-            let mut result = SearchResult::new(Score::MIN, depth);
-
-            for m in self.game.legal_moves() {
-                let node = search_move!(self, &m, mini_without_pruning(depth - 1, timer));
-                result += &node;
-
-                if node.score > result.info.score {
-                    result.info.score = node.score;
-                    result.best_move = Some(m);
-                }
-            }
-
-            result.info
-        }
-
-        fn mini_without_pruning<T: MoveTimer>(&mut self, depth: u8, timer: &T) -> SearchInfo {
-            if depth == 0 || timer.over() {
+            if self.game.is_repetition() {
                 return SearchInfo {
-                    score: self.grade_position(),
+                    score: Score::default(),
                     depth,
                     nodes: 1,
                 };
             }
 
-            let mut result = SearchResult::new(Score::MAX, depth);
+            if depth == 0 {
+                return self.quiesce(Score::MIN, Score::MAX, timer);
+            }
+
+            let mut result = SearchResult::new(Score::MIN, depth);
 
             for m in self.game.legal_moves() {
-                let node = search_move!(self, &m, maxi_without_pruning(depth - 1, timer));
+                let mut node = search_move!(self, &m, negamax_without_pruning(depth - 1, timer));
+                node.score = -node.score;
                 result += &node;
 
-                if node.score < result.info.score {
+                if node.score > result.info.score {
                     result.info.score = node.score;
                     result.best_move = Some(m);
                 }
@@ -286,37 +260,32 @@ mod tests {
             timer: &T,
             depth: u8,
         ) -> SearchResult {
-            macro_rules! search_loop {
-            ($best_score:expr, $cmp:tt, $search:ident) => {{
-                let mut result = SearchResult::new($best_score, 0);
-
-                for m in self.game.legal_moves() {
-                    let node = search_move!(self, &m, $search(depth, timer));
-                    if timer.over() {
-                        break;
-                    }
-
-                    result += &node;
-
-                    if node.score $cmp result.info.score {
-                        result.info.score = node.score;
-                        result.best_move = Some(m);
-                    }
+            let mut result = SearchResult::new(Score::MIN, 0);
+
+            for m in self.game.legal_moves() {
+                let mut node = search_move!(self, &m, negamax_without_pruning(depth, timer));
+                if timer.over() {
+                    break;
                 }
 
-                result
-            }};
-        }
+                node.score = -node.score;
+                result += &node;
 
-            match self.game.turn {
-                PieceColor::White => search_loop!(Score::MIN, >, mini_without_pruning),
-                PieceColor::Black => search_loop!(Score::MAX, <, maxi_without_pruning),
+                if node.score > result.info.score {
+                    result.info.score = node.score;
+                    result.best_move = Some(m);
+                }
             }
+
+            result.info.score = result.info.score.for_color(self.game.turn);
+            result
         }
     }
 
     #[track_caller]
     fn assert_minimax_pruning_is_lossless(engine: &mut Engine, depth: u8) {
+        // Futility pruning is allowed to lose information
+        engine.futility_pruning = false;
         let actual = engine.minimax(&Infinite, depth);
         let expected = engine.minimax_without_pruning(&Infinite, depth);
         assert_eq!(
@@ -352,6 +321,39 @@ mod tests {
         assert_eq!(black_queens_before, engine.game.black_queens.popcnt());
     }
 
+    #[test]
+    fn futility_pruning_keeps_quiet_mates() {
+        // Capturing on e3 is searched first, leaving the back rank mate to the pruned moves
+        let fen = "6k1/5ppp/8/8/8/4p3/5PPP/R5K1 w - - 0 1";
+        let mut engine = Engine::from_fen(fen).unwrap();
+        let alpha = Score::new(1000);
+        assert!(engine.is_futile(alpha, 1));
+
+        let result = engine.negamax(alpha, Score::MAX, 1, &Infinite);
+        assert!(
+            result.score.is_mate() && result.score > alpha,
+            "{:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn repeated_positions_are_draws() {
+        let mut engine = Engine::default();
+        for (from, to) in [
+            (Square::G1, Square::F3),
+            (Square::B8, Square::C6),
+            (Square::F3, Square::G1),
+            (Square::C6, Square::B8),
+        ] {
+            let m = Move::infer(from, to, &engine.game);
+            engine.game.play(&m);
+        }
+
+        let result = engine.negamax(Score::MIN, Score::MAX, 2, &Infinite);
+        assert_eq!(result.score, Score::default());
+    }
+
     #[ignore]
     #[test]
     fn canary_minimax_pruning_should_be_lossless() {
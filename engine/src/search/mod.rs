@@ -1,3 +1,53 @@
+/// Plays a move, gets the score from the given method, and then takes the move back by copying the
+/// position from before it, and returns that score. Also does expensive validity checks in debug
+/// builds.
+macro_rules! search_move {
+    ($self:expr, $move:expr, $method:ident($($args:expr),*)) => {{
+        #[cfg(debug_assertions)]
+        let before = $self.game.clone();
+
+        $self.trace_enter($move);
+        let saved = *$self.game.position();
+        #[cfg(feature = "nnue")]
+        $self.nnue_sync();
+        $self.game.play(&$move);
+        #[cfg(feature = "nnue")]
+        $self.nnue_push();
+        $self.ply += 1;
+        $self.stats.nodes += 1;
+
+        #[cfg(debug_assertions)]
+        let during = $self.game.clone();
+
+        let score = $self.$method($($args),*);
+        $self.ply -= 1;
+        $self.game.take_back(saved);
+        #[cfg(feature = "nnue")]
+        $self.nnue_pop();
+        $self.trace_exit(&score);
+
+        #[cfg(debug_assertions)]
+        assert_eq!(
+            $self.game, before,
+            "State changed after playing and unplaying {}\n  Before: {:?}\n  During: {:?}\n   After: {:?}\n",
+            $move, before, during, $self.game
+        );
+
+        score
+    }};
+}
+
+mod futility;
 pub mod iterative_deepening;
+pub(crate) mod killers;
+pub mod limits;
 pub mod minimax;
 mod move_ordering;
+mod move_picker;
+pub mod multi_pv;
+mod principal_variation;
+pub mod progress;
+mod quiescence;
+pub mod skill;
+pub mod stats;
+pub mod trace;
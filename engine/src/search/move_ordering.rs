@@ -1,15 +1,36 @@
-use whalecrab_lib::movegen::{moves::Move, pieces::piece::PieceType};
+use whalecrab_lib::{
+    movegen::{move_list::MoveList, moves::Move, pieces::piece::PieceType},
+    position::game::Game,
+};
 
 use crate::{
     piece_eval::material_value, score::Score, transposition_table::TranspositionTableEntry,
 };
 
+/// Orders captures by most valuable victim, then by least valuable attacker
+fn mvv_lva(victim: PieceType, attacker: PieceType) -> Score {
+    Score::new(-2000) - material_value(victim) + material_value(attacker) / 10
+}
+
 /// Scores a move. This can be used for move ordering
-fn score_move(m: &Move, best: Option<&Move>) -> Score {
+pub(super) fn score_move(
+    m: &Move,
+    best: Option<&Move>,
+    killers: &[Option<Move>; 2],
+    game: &Game,
+) -> Score {
     if Some(m) == best {
         return Score::MIN;
     }
 
+    // Quiet killers are sorted right after captures
+    if killers[0] == Some(*m) {
+        return Score::new(-1100);
+    }
+    if killers[1] == Some(*m) {
+        return Score::new(-1000);
+    }
+
     match m {
         Move::Promotion {
             piece,
@@ -21,39 +42,81 @@ fn score_move(m: &Move, best: Option<&Move>) -> Score {
             capture: None,
             ..
         } => Score::new(-5000) - material_value(*piece),
-        Move::CaptureEnPassant { .. } => Score::new(-2000) - material_value(PieceType::Pawn),
+        Move::CaptureEnPassant { .. } => mvv_lva(PieceType::Pawn, PieceType::Pawn),
         Move::Normal {
+            from,
             capture: Some(capture),
             ..
-        } => Score::new(-2000) - material_value(*capture),
+        } => {
+            let attacker = game
+                .piece_lookup(*from)
+                .map_or(PieceType::Pawn, |(piece, _)| piece);
+            mvv_lva(*capture, attacker)
+        }
         Move::Castle { .. } => Score::new(-500),
         _ => Score::new(0),
     }
 }
 
 /// Orders the moves for better minimax pruning
-pub fn order_moves(mut moves: Vec<Move>, existing: &Option<&TranspositionTableEntry>) -> Vec<Move> {
-    let best_move = existing.and_then(|e| e.best_move.as_ref());
+pub fn order_moves(
+    mut moves: MoveList,
+    existing: &Option<TranspositionTableEntry>,
+    killers: &[Option<Move>; 2],
+    game: &Game,
+) -> MoveList {
+    let best_move = existing.as_ref().and_then(|e| e.best_move.unpack(game));
 
-    moves.sort_unstable_by_key(|m| score_move(m, best_move));
+    moves.sort_unstable_by_key(|m| score_move(m, best_move.as_ref(), killers, game));
 
     moves
 }
 
 #[cfg(test)]
 mod tests {
+    use whalecrab_lib::square::Square;
+
     use crate::engine::Engine;
 
     use super::*;
 
     #[test]
     fn sort_moves_keeps_all_moves() {
-        let mut engine = Engine::default();
+        let engine = Engine::default();
         let moves = engine.game.legal_moves();
-        let sorted = order_moves(moves.clone(), &None);
+        let sorted = order_moves(moves.clone(), &None, &[None; 2], &engine.game);
         for sortedm in &sorted {
             assert!(moves.contains(sortedm));
         }
         assert_eq!(sorted.len(), moves.len());
     }
+
+    #[test]
+    fn killers_are_sorted_after_captures() {
+        let fen = "4k3/8/8/3p4/8/8/8/3QK3 w - - 0 1";
+        let engine = Engine::from_fen(fen).unwrap();
+        let capture = Move::infer(Square::D1, Square::D5, &engine.game);
+        let killer = Move::infer(Square::D1, Square::H5, &engine.game);
+        let sorted = order_moves(
+            engine.game.legal_moves(),
+            &None,
+            &[Some(killer), None],
+            &engine.game,
+        );
+        assert_eq!(sorted[0], capture);
+        assert_eq!(sorted[1], killer);
+    }
+
+    #[test]
+    fn captures_are_sorted_by_mvv_lva() {
+        let fen = "4k3/8/2r1q3/1P4N1/8/8/4Q3/4K3 w - - 0 1";
+        let engine = Engine::from_fen(fen).unwrap();
+        let sorted = order_moves(engine.game.legal_moves(), &None, &[None; 2], &engine.game);
+        let expected = [
+            Move::infer(Square::G5, Square::E6, &engine.game),
+            Move::infer(Square::E2, Square::E6, &engine.game),
+            Move::infer(Square::B5, Square::C6, &engine.game),
+        ];
+        assert_eq!(sorted[..3], expected);
+    }
 }
@@ -0,0 +1,238 @@
+use whalecrab_lib::{
+    bitboard::EMPTY,
+    movegen::{
+        move_list::{MAX_MOVES, MoveList},
+        moves::Move,
+        pieces::piece::PieceType,
+    },
+    position::game::Game,
+};
+
+use crate::{
+    piece_eval::material_value,
+    score::Score,
+    search::{futility::is_quiet, move_ordering::score_move},
+};
+
+/// The order moves are handed out in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    TranspositionMove,
+    Generate,
+    GoodCaptures,
+    Killers,
+    Quiets,
+    BadCaptures,
+    Done,
+}
+
+/// Hands out the legal moves of a node one at a time, best guesses first. The transposition table
+/// move is tried before anything is generated, and the rest are only picked out of the move list
+/// as they are needed, so nodes that cut off early never pay for generating or sorting everything.
+pub(crate) struct MovePicker {
+    stage: Stage,
+    tt_move: Option<Move>,
+    killers: [Option<Move>; 2],
+    killer: usize,
+    /// Good captures, then quiets, then bad captures. Moves before `current` were handed out.
+    moves: MoveList,
+    scores: [Score; MAX_MOVES],
+    current: usize,
+    good_captures_end: usize,
+    quiets_end: usize,
+}
+
+impl MovePicker {
+    pub(crate) fn new(tt_move: Option<Move>, killers: [Option<Move>; 2]) -> Self {
+        Self {
+            stage: Stage::TranspositionMove,
+            tt_move,
+            killers,
+            killer: 0,
+            moves: MoveList::new(),
+            scores: [Score::default(); MAX_MOVES],
+            current: 0,
+            good_captures_end: 0,
+            quiets_end: 0,
+        }
+    }
+
+    /// Picks the next move to search. `game` has to be the same position every time this is
+    /// called.
+    pub(crate) fn next(&mut self, game: &Game) -> Option<Move> {
+        loop {
+            match self.stage {
+                Stage::TranspositionMove => {
+                    self.stage = Stage::Generate;
+                    if let Some(m) = self.tt_move {
+                        if game.is_legal(&m) {
+                            return Some(m);
+                        }
+                        self.tt_move = None;
+                    }
+                }
+                Stage::Generate => {
+                    self.generate(game);
+                    self.stage = Stage::GoodCaptures;
+                }
+                Stage::GoodCaptures => match self.pick_best(self.good_captures_end) {
+                    Some(m) => return Some(m),
+                    None => self.stage = Stage::Killers,
+                },
+                Stage::Killers => {
+                    let Some(&killer) = self.killers.get(self.killer) else {
+                        self.stage = Stage::Quiets;
+                        continue;
+                    };
+                    self.killer += 1;
+
+                    // A killer from a sibling node is only worth trying if it is legal here
+                    if let Some(killer) = killer
+                        && let Some(i) = self.moves[self.current..self.quiets_end]
+                            .iter()
+                            .position(|&m| m == killer)
+                    {
+                        return Some(self.take(self.current + i));
+                    }
+                }
+                Stage::Quiets => match self.pick_best(self.quiets_end) {
+                    Some(m) => return Some(m),
+                    None => self.stage = Stage::BadCaptures,
+                },
+                Stage::BadCaptures => match self.pick_best(self.moves.len()) {
+                    Some(m) => return Some(m),
+                    None => self.stage = Stage::Done,
+                },
+                Stage::Done => return None,
+            }
+        }
+    }
+
+    /// Sorts every legal move other than the transposition table move into its stage
+    fn generate(&mut self, game: &Game) {
+        self.moves = game.legal_moves();
+        if let Some(tt_move) = self.tt_move
+            && let Some(i) = self.moves.iter().position(|&m| m == tt_move)
+        {
+            self.moves.swap_remove(i);
+        }
+
+        self.good_captures_end = partition(&mut self.moves, 0, |m| {
+            !is_quiet(m) && !is_bad_capture(game, m)
+        });
+        self.quiets_end = partition(&mut self.moves, self.good_captures_end, is_quiet);
+
+        for (score, m) in self.scores.iter_mut().zip(self.moves.iter()) {
+            *score = score_move(m, None, &[None; 2], game);
+        }
+    }
+
+    /// Hands out the move at `index`, which has to be at or after `current`
+    fn take(&mut self, index: usize) -> Move {
+        self.moves.swap(self.current, index);
+        self.scores.swap(self.current, index);
+        self.current += 1;
+        self.moves[self.current - 1]
+    }
+
+    /// Hands out the best scoring move left before `end`, which is the one with the lowest score
+    fn pick_best(&mut self, end: usize) -> Option<Move> {
+        let best = (self.current..end).min_by_key(|&i| self.scores[i])?;
+        Some(self.take(best))
+    }
+}
+
+/// A capture that gives up more than it takes if the victim is defended
+fn is_bad_capture(game: &Game, m: &Move) -> bool {
+    let Move::Normal {
+        from,
+        to,
+        capture: Some(victim),
+    } = *m
+    else {
+        return false;
+    };
+
+    let attacker = game
+        .piece_lookup(from)
+        .map_or(PieceType::Pawn, |(piece, _)| piece);
+
+    material_value(attacker) > material_value(victim)
+        && game.attackers_to(to, game.turn.opponent()) != EMPTY
+}
+
+/// Moves every move from `start` onwards that matches `pred` to the front of that range, and
+/// returns where the matching moves end
+fn partition(moves: &mut MoveList, start: usize, mut pred: impl FnMut(&Move) -> bool) -> usize {
+    let mut end = start;
+    for i in start..moves.len() {
+        if pred(&moves[i]) {
+            moves.swap(end, i);
+            end += 1;
+        }
+    }
+    end
+}
+
+#[cfg(test)]
+mod tests {
+    use whalecrab_lib::square::Square;
+
+    use super::*;
+
+    fn pick_all(picker: &mut MovePicker, game: &Game) -> Vec<Move> {
+        let mut picked = Vec::new();
+        while let Some(m) = picker.next(game) {
+            picked.push(m);
+        }
+        picked
+    }
+
+    #[test]
+    fn picks_every_legal_move_once() {
+        let fen = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+        let game = Game::from_fen(fen).unwrap();
+        let tt_move = Move::infer(Square::E5, Square::F7, &game);
+        let killer = Move::infer(Square::A2, Square::A3, &game);
+
+        let mut picker = MovePicker::new(Some(tt_move), [Some(killer), None]);
+        let mut picked = pick_all(&mut picker, &game);
+        let mut legal = game.legal_moves().to_vec();
+
+        assert_eq!(picked[0], tt_move);
+        let key = |m: &Move| m.to_string();
+        picked.sort_by_key(key);
+        legal.sort_by_key(key);
+        assert_eq!(picked, legal);
+    }
+
+    #[test]
+    fn stages_come_in_order() {
+        let fen = "4k3/8/2r1q3/1P4N1/8/3p4/4Q3/6K1 w - - 0 1";
+        let game = Game::from_fen(fen).unwrap();
+        let tt_move = Move::infer(Square::G1, Square::H1, &game);
+        let killer = Move::infer(Square::E2, Square::H5, &game);
+        let mut picker = MovePicker::new(Some(tt_move), [None, Some(killer)]);
+        let picked = pick_all(&mut picker, &game);
+
+        let expected = [
+            tt_move,
+            Move::infer(Square::G5, Square::E6, &game),
+            Move::infer(Square::E2, Square::E6, &game),
+            Move::infer(Square::B5, Square::C6, &game),
+            Move::infer(Square::E2, Square::D3, &game),
+            killer,
+        ];
+        assert_eq!(picked[..expected.len()], expected);
+    }
+
+    #[test]
+    fn illegal_transposition_moves_are_skipped() {
+        let game = Game::default();
+        let impossible = Move::infer(Square::E2, Square::E5, &game);
+        let mut picker = MovePicker::new(Some(impossible), [None; 2]);
+        let picked = pick_all(&mut picker, &game);
+        assert!(!picked.contains(&impossible));
+        assert_eq!(picked.len(), 20);
+    }
+}
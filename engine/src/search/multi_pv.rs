@@ -0,0 +1,144 @@
+use std::{
+    sync::{Arc, atomic::AtomicBool},
+    time::Duration,
+};
+
+use whalecrab_lib::movegen::moves::Move;
+
+use crate::{
+    engine::Engine, move_result::SearchResult, platform_timer, search::stats::SearchStats,
+    timers::MoveTimer, timers::infinite::Infinite, timers::stoppable::Stoppable,
+};
+
+impl Engine {
+    /// Searches the best `lines` root moves at the given depth, each with its own score and
+    /// principal variation. The lines are sorted from best to worst, and each one is found by
+    /// searching the root again without the moves of the lines before it.
+    pub fn multi_pv_minimax<T: MoveTimer>(
+        &mut self,
+        timer: &T,
+        depth: u8,
+        lines: usize,
+    ) -> Vec<SearchResult> {
+        let mut results: Vec<SearchResult> = Vec::with_capacity(lines);
+        let mut excluded: Vec<Move> = Vec::with_capacity(lines);
+
+        while results.len() < lines {
+            let result = self.search_root(timer, depth, &excluded);
+            let Some(best_move) = result.best_move else {
+                break;
+            };
+
+            excluded.push(best_move);
+            results.push(result);
+
+            if self.search_over(timer) {
+                break;
+            }
+        }
+
+        results
+    }
+
+    /// Same as `search_with_timer`, but returns the best `lines` root moves instead of only the
+    /// best one. This always searches on a single thread.
+    pub fn multi_pv_with_timer<T: MoveTimer>(
+        &mut self,
+        timer: &T,
+        max_depth: u8,
+        lines: usize,
+    ) -> Vec<SearchResult> {
+        let mut depth = 0;
+        let mut results = Vec::new();
+        self.killers.clear();
+        self.stats = SearchStats::default();
+        self.clear_trace();
+
+        loop {
+            let iteration = self.multi_pv_minimax(timer, depth, lines);
+
+            if self.search_over(timer) {
+                // Fall back to the interrupted search if no depth was completed
+                if results.is_empty() {
+                    results = iteration;
+                }
+                break;
+            }
+
+            results = iteration;
+
+            if depth >= max_depth {
+                break;
+            }
+            depth += 1;
+        }
+
+        results
+    }
+
+    /// Searches for the best `lines` moves in the position until the depth is reached or the
+    /// duration is up
+    pub fn multi_pv(
+        &mut self,
+        duration: Duration,
+        max_depth: u8,
+        lines: usize,
+    ) -> Vec<SearchResult> {
+        if duration == Duration::MAX {
+            self.multi_pv_with_timer(&Infinite, max_depth, lines)
+        } else {
+            self.multi_pv_with_timer(&platform_timer!(duration), max_depth, lines)
+        }
+    }
+
+    /// Same as `multi_pv`, but the search also ends as soon as `stop` is set
+    pub fn multi_pv_with_stop(
+        &mut self,
+        duration: Duration,
+        max_depth: u8,
+        lines: usize,
+        stop: Arc<AtomicBool>,
+    ) -> Vec<SearchResult> {
+        if duration == Duration::MAX {
+            self.multi_pv_with_timer(&Stoppable::new(Infinite, stop), max_depth, lines)
+        } else {
+            let timer = Stoppable::new(platform_timer!(duration), stop);
+            self.multi_pv_with_timer(&timer, max_depth, lines)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multi_pv_finds_distinct_lines() {
+        let fen = "rnb1kbnr/pppp1ppp/8/4p1q1/3PP3/8/PPP2PPP/RNBQKBNR w KQkq - 1 3";
+        let mut engine = Engine::from_fen(fen).unwrap();
+        let lines = engine.multi_pv(Duration::MAX, 2, 3);
+        assert_eq!(lines.len(), 3);
+
+        let moves: Vec<Move> = lines.iter().filter_map(|line| line.best_move).collect();
+        assert_eq!(moves.len(), 3);
+        assert!(moves[0] != moves[1] && moves[0] != moves[2] && moves[1] != moves[2]);
+
+        for line in &lines {
+            assert_eq!(line.pv.first(), line.best_move.as_ref());
+        }
+        for pair in lines.windows(2) {
+            assert!(pair[0].info.score >= pair[1].info.score);
+        }
+
+        let best = Engine::from_fen(fen).unwrap().search(Duration::MAX, 2);
+        assert_eq!(lines[0].best_move, best.best_move);
+    }
+
+    #[test]
+    fn multi_pv_is_limited_by_legal_moves() {
+        let fen = "7k/8/8/8/8/8/8/KR6 w - - 0 1";
+        let mut engine = Engine::from_fen(fen).unwrap();
+        let lines = engine.multi_pv(Duration::MAX, 1, 50);
+        assert_eq!(lines.len(), engine.game.legal_moves().len());
+    }
+}
@@ -0,0 +1,64 @@
+use whalecrab_lib::{movegen::moves::Move, position::game::State};
+
+use crate::engine::Engine;
+
+impl Engine {
+    /// Follows the best moves stored in the transposition table, starting with `best_move`, to
+    /// reconstruct the line the engine expects to be played. The position is left unchanged.
+    pub(crate) fn principal_variation(
+        &mut self,
+        best_move: Option<Move>,
+        max_len: u8,
+    ) -> Vec<Move> {
+        let mut pv = Vec::new();
+        let mut next = best_move;
+
+        while let Some(m) = next
+            && pv.len() < max_len as usize
+            && self.game.state == State::InProgress
+            && self.game.legal_moves().contains(&m)
+        {
+            self.game.play(&m);
+            pv.push(m);
+            next = self
+                .transposition_table
+                .get(self.game.hash)
+                .and_then(|e| e.best_move.unpack(&self.game));
+        }
+
+        for m in pv.iter().rev() {
+            self.game.unplay(m);
+        }
+
+        pv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::timers::infinite::Infinite;
+
+    use super::*;
+
+    #[test]
+    fn pv_starts_with_best_move() {
+        let mut engine = Engine::default();
+        let result = engine.minimax(&Infinite, 2);
+        assert_eq!(result.pv.first(), result.best_move.as_ref());
+        assert!(result.pv.len() <= 3);
+    }
+
+    #[test]
+    fn pv_is_playable() {
+        let fen = "r3r1k1/pbP2p1p/6pb/8/P1Q5/3B1qP1/2R2P1P/1R4K1 b - - 1 37";
+        let mut engine = Engine::from_fen(fen).unwrap();
+        let before = engine.game.clone();
+        let result = engine.minimax(&Infinite, 2);
+        assert_eq!(engine.game, before);
+
+        for m in &result.pv {
+            assert!(engine.game.legal_moves().contains(m), "{m} is not legal");
+            engine.game.play(m);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use std::time::Duration;
+
+use whalecrab_lib::movegen::moves::Move;
+
+use crate::score::Score;
+
+/// What the search knows after finishing an iteration, such as for UCI info lines
+#[derive(Debug, Clone, Copy)]
+pub struct SearchProgress<'a> {
+    /// The depth that was just finished
+    pub depth: u8,
+    /// The score of the best move, from white's perspective
+    pub score: Score,
+    /// The nodes searched so far by the main thread
+    pub nodes: u64,
+    /// How long the search has been going
+    pub elapsed: Duration,
+    /// The line of play the engine expects, starting with the best move
+    pub pv: &'a [Move],
+}
+
+impl SearchProgress<'_> {
+    /// Nodes searched per second
+    pub fn nps(&self) -> u64 {
+        let micros = self.elapsed.as_micros().max(1);
+        (self.nodes as u128 * 1_000_000 / micros) as u64
+    }
+}
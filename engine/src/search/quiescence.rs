@@ -0,0 +1,119 @@
+use whalecrab_lib::{
+    movegen::{move_list::MoveList, moves::Move},
+    position::game::State,
+};
+
+use crate::{
+    engine::Engine,
+    move_result::SearchInfo,
+    score::Score,
+    search::{move_ordering::order_moves, trace::PruneReason},
+    timers::MoveTimer,
+};
+
+/// Keeps only the moves worth extending in quiescence search. Every evasion is kept when in check,
+/// otherwise only captures and promotions are kept.
+fn noisy_moves(mut moves: MoveList, in_check: bool) -> MoveList {
+    if !in_check {
+        moves.retain(|m| m.is_capture() || matches!(m, Move::Promotion { .. }));
+    }
+
+    moves
+}
+
+impl Engine {
+    /// Extends captures until the position is quiet. Scores are relative to the player to move.
+    pub(crate) fn quiesce<T: MoveTimer>(
+        &mut self,
+        mut alpha: Score,
+        beta: Score,
+        timer: &T,
+    ) -> SearchInfo {
+        self.trace_window(alpha, beta, 0);
+
+        if self.game.state != State::InProgress || self.search_over(timer) {
+            return SearchInfo::new(self.grade_finished_position(), 0);
+        }
+
+        self.stats.qnodes += 1;
+        let in_check = self.game.is_in_check(self.game.turn);
+        let mut result = if in_check {
+            SearchInfo::new(Score::MIN, 0)
+        } else {
+            // Standing pat, the player is not forced to capture
+            let stand_pat = SearchInfo::new(self.grade_position_lazy(alpha, beta), 0);
+            if stand_pat.score >= beta {
+                self.stats.beta_cutoffs += 1;
+                self.trace_prune(PruneReason::BetaCutoff);
+                return stand_pat;
+            }
+            if stand_pat.score > alpha {
+                alpha = stand_pat.score;
+            }
+            stand_pat
+        };
+
+        let moves = noisy_moves(self.game.legal_moves(), in_check);
+        for m in order_moves(moves, &None, &[None; 2], &self.game) {
+            let mut node = search_move!(self, &m, quiesce(-beta, -alpha, timer));
+            node.score = -node.score;
+            result += &node;
+
+            if node.score > result.score {
+                result.score = node.score;
+                if node.score > alpha {
+                    alpha = node.score;
+                }
+            }
+
+            if alpha >= beta {
+                self.stats.beta_cutoffs += 1;
+                self.trace_prune(PruneReason::BetaCutoff);
+                break;
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use whalecrab_lib::square::Square;
+
+    use crate::timers::infinite::Infinite;
+
+    use super::*;
+
+    #[test]
+    fn quiescence_sees_recapture() {
+        let fen = "4k3/8/4p3/3p4/8/8/8/3QK3 w - - 0 1";
+        let mut engine = Engine::from_fen(fen).unwrap();
+        let blunder = Move::infer(Square::D1, Square::D5, &engine.game);
+        let result = engine.minimax(&Infinite, 0);
+        assert_ne!(result.best_move, Some(blunder), "\n{}", result);
+    }
+
+    #[test]
+    fn quiescence_resolves_hanging_piece() {
+        let fen = "4k3/8/8/3q4/8/8/8/3RK3 w - - 0 1";
+        let mut engine = Engine::from_fen(fen).unwrap();
+        let static_score = engine.grade_position_relative();
+        let quiet_score = engine.quiesce(Score::MIN, Score::MAX, &Infinite).score;
+        assert!(
+            quiet_score > static_score,
+            "Quiescence should see Rxd5, static: {}, quiet: {}",
+            static_score,
+            quiet_score
+        );
+    }
+
+    #[test]
+    fn quiescence_should_not_mutate_position() {
+        let fen = "r1k2b1r/1p4p1/p1p4P/4B3/2p5/3P3P/NP2P1B1/2K2R2 w - - 0 29";
+        let mut engine = Engine::from_fen(fen).unwrap();
+        let before = engine.game.clone();
+        let _ = engine.quiesce(Score::MIN, Score::MAX, &Infinite);
+        assert_eq!(engine.game, before);
+    }
+}
@@ -0,0 +1,156 @@
+use rand::RngExt;
+
+use crate::{engine::Engine, move_result::SearchResult, score::Score, timers::MoveTimer};
+
+/// How many of the best root moves a weakened engine chooses between
+const CANDIDATES: usize = 4;
+
+/// Weakens the engine in a controlled way so that it can be a fair opponent for humans. Lower
+/// levels search shallower, add more noise to the scores of the root moves and are willing to
+/// play moves further below the best one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SkillLevel(u8);
+
+impl Default for SkillLevel {
+    fn default() -> Self {
+        Self::MAX
+    }
+}
+
+impl SkillLevel {
+    /// Plays at full strength
+    pub const MAX: SkillLevel = SkillLevel(20);
+    /// The weakest level
+    pub const MIN: SkillLevel = SkillLevel(0);
+
+    /// Creates a skill level, clamping it to `SkillLevel::MAX`
+    pub fn new(level: u8) -> Self {
+        Self(level.min(Self::MAX.0))
+    }
+
+    pub const fn to_int(self) -> u8 {
+        self.0
+    }
+
+    /// Checks if the engine should play without any weakening
+    pub fn is_full_strength(self) -> bool {
+        self == Self::MAX
+    }
+
+    /// The deepest iteration the engine may search
+    pub fn max_depth(self) -> u8 {
+        if self.is_full_strength() {
+            u8::MAX
+        } else {
+            1 + self.0 / 3
+        }
+    }
+
+    /// The most random noise that can be added to the score of a root move
+    pub fn noise(self) -> Score {
+        Score::new((Self::MAX.0 - self.0) as i16 * 10)
+    }
+
+    /// How far below the best move a move may score and still be played
+    pub fn margin(self) -> Score {
+        Score::new((Self::MAX.0 - self.0) as i16 * 15)
+    }
+}
+
+impl Engine {
+    /// Sets how strong the engine should play. See `SkillLevel`
+    pub fn set_skill_level(&mut self, level: SkillLevel) {
+        self.skill = level;
+    }
+
+    /// Searches the best few root moves and randomly picks one that is close enough to the best,
+    /// according to the skill level
+    pub(crate) fn search_weakened<T: MoveTimer>(
+        &mut self,
+        timer: &T,
+        max_depth: u8,
+    ) -> SearchResult {
+        let depth = max_depth.min(self.skill.max_depth());
+        let lines = self.multi_pv_with_timer(timer, depth, CANDIDATES);
+
+        let turn = self.game.turn;
+        let nodes: u64 = lines.iter().map(|line| line.info.nodes).sum();
+        let Some(best) = lines.first().map(|line| line.info.score.for_color(turn)) else {
+            return SearchResult::default();
+        };
+
+        let noise = self.skill.noise().to_int();
+        let margin = self.skill.margin();
+        let mut result = lines
+            .into_iter()
+            .filter(|line| line.info.score.for_color(turn) >= best - margin)
+            .max_by_key(|line| line.info.score.for_color(turn) + self.rng.random_range(0..=noise))
+            .unwrap_or_default();
+
+        result.info.nodes = nodes;
+        result.stats = self.stats;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use whalecrab_lib::{movegen::moves::Move, square::Square};
+
+    use super::*;
+
+    #[test]
+    fn levels_are_clamped() {
+        assert_eq!(SkillLevel::new(200), SkillLevel::MAX);
+        assert!(SkillLevel::MAX.is_full_strength());
+        assert_eq!(SkillLevel::MAX.noise(), Score::default());
+        assert!(SkillLevel::MIN.max_depth() < SkillLevel::new(19).max_depth());
+    }
+
+    #[test]
+    fn weakened_engine_plays_legal_moves() {
+        let mut engine = Engine::default();
+        engine.set_skill_level(SkillLevel::MIN);
+        for _ in 0..6 {
+            let m = engine
+                .search(Duration::MAX, u8::MAX)
+                .best_move
+                .expect("The weakened engine did not play a move");
+            assert!(engine.game.legal_moves().contains(&m));
+            engine.game.play(&m);
+        }
+    }
+
+    #[test]
+    fn seeded_weakened_engines_play_the_same_game() {
+        let play = || {
+            let mut engine = Engine::default();
+            engine.set_skill_level(SkillLevel::MIN);
+            engine.set_seed(42);
+            let mut moves = Vec::new();
+            for _ in 0..6 {
+                let m = engine.search(Duration::MAX, u8::MAX).best_move.unwrap();
+                engine.game.play(&m);
+                moves.push(m);
+            }
+            moves
+        };
+        assert_eq!(play(), play());
+    }
+
+    #[test]
+    fn weakened_engine_still_takes_free_queen() {
+        let fen = "rnb1kbnr/pppp1ppp/8/4p1q1/3PP3/8/PPP2PPP/RNBQKBNR w KQkq - 1 3";
+        let mut engine = Engine::from_fen(fen).unwrap();
+        engine.set_skill_level(SkillLevel::new(10));
+        let expected = Move::infer(Square::C1, Square::G5, &engine.game);
+        for _ in 0..5 {
+            assert_eq!(
+                engine.search(Duration::MAX, u8::MAX).best_move,
+                Some(expected)
+            );
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use std::{fmt, ops::AddAssign};
+
+/// Counters collected while searching, mostly useful for tuning the search
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchStats {
+    /// Moves played by the search, including quiescence search
+    pub nodes: u64,
+    /// Positions visited by quiescence search
+    pub qnodes: u64,
+    /// Positions that were found in the transposition table
+    pub tt_hits: u64,
+    /// Transposition table entries that ended the search of a position right away
+    pub tt_cutoffs: u64,
+    /// Moves that were good enough to stop searching their siblings
+    pub beta_cutoffs: u64,
+    /// Quiet moves skipped by futility pruning
+    pub futility_prunes: u64,
+    /// Zero window searches that had to be searched again with the full window
+    pub researches: u64,
+    /// Evaluations that skipped the expensive terms, since the score was far outside the window
+    pub lazy_evals: u64,
+}
+
+impl Default for SearchStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SearchStats {
+    pub const fn new() -> Self {
+        Self {
+            nodes: 0,
+            qnodes: 0,
+            tt_hits: 0,
+            tt_cutoffs: 0,
+            beta_cutoffs: 0,
+            futility_prunes: 0,
+            researches: 0,
+            lazy_evals: 0,
+        }
+    }
+}
+
+impl AddAssign<&SearchStats> for SearchStats {
+    fn add_assign(&mut self, rhs: &SearchStats) {
+        self.nodes += rhs.nodes;
+        self.qnodes += rhs.qnodes;
+        self.tt_hits += rhs.tt_hits;
+        self.tt_cutoffs += rhs.tt_cutoffs;
+        self.beta_cutoffs += rhs.beta_cutoffs;
+        self.futility_prunes += rhs.futility_prunes;
+        self.researches += rhs.researches;
+        self.lazy_evals += rhs.lazy_evals;
+    }
+}
+
+impl fmt::Display for SearchStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "nodes {} qnodes {} tthits {} ttcutoffs {} betacutoffs {} futilityprunes {} researches {} lazyevals {}",
+            self.nodes,
+            self.qnodes,
+            self.tt_hits,
+            self.tt_cutoffs,
+            self.beta_cutoffs,
+            self.futility_prunes,
+            self.researches,
+            self.lazy_evals
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::engine::Engine;
+
+    #[test]
+    fn search_collects_stats() {
+        let fen = "r1k2b1r/1p4p1/p1p4P/4B3/2p5/3P3P/NP2P1B1/2K2R2 w - - 0 29";
+        let mut engine = Engine::from_fen(fen).unwrap();
+        let stats = engine.search(Duration::MAX, 3).stats;
+        assert!(stats.nodes > 0);
+        assert!(stats.qnodes > 0);
+        assert!(stats.tt_hits > 0);
+        assert!(stats.beta_cutoffs > 0);
+        assert!(stats.qnodes <= stats.nodes + 1);
+    }
+
+    #[test]
+    fn stats_are_reset_between_searches() {
+        let mut engine = Engine::default();
+        let first = engine.search(Duration::MAX, 2).stats;
+        engine.clear_persistant_cache();
+        let second = engine.search(Duration::MAX, 2).stats;
+        assert_eq!(first, second);
+    }
+}
@@ -0,0 +1,283 @@
+use std::fmt;
+
+use whalecrab_lib::movegen::moves::Move;
+
+use crate::{engine::Engine, move_result::SearchInfo, score::Score};
+
+/// Why the search stopped looking at a position early
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PruneReason {
+    /// The position was repeated, so it was scored as a draw
+    Repetition,
+    /// A transposition table entry was good enough to return right away
+    TranspositionTable,
+    /// A move was good enough that the remaining moves were not searched
+    BetaCutoff,
+    /// The quiet move could not raise alpha, so it was never searched
+    Futility,
+}
+
+impl fmt::Display for PruneReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            PruneReason::Repetition => "repetition",
+            PruneReason::TranspositionTable => "tt cutoff",
+            PruneReason::BetaCutoff => "beta cutoff",
+            PruneReason::Futility => "futility",
+        };
+        write!(f, "{}", reason)
+    }
+}
+
+/// A single position visited by the search. Scores are relative to the player to move in the
+/// position, like the search itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceNode {
+    /// How many moves deep the position is from the root
+    pub ply: u8,
+    /// The move leading to the position in uci notation
+    pub m: String,
+    pub depth: u8,
+    pub alpha: Score,
+    pub beta: Score,
+    /// Moves that were pruned before being searched have no score
+    pub score: Option<Score>,
+    pub reason: Option<PruneReason>,
+}
+
+/// Records the searched tree in the order it was visited, up to a limit of nodes. Useful for
+/// finding out why the engine prefers a move.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchTrace {
+    pub nodes: Vec<TraceNode>,
+    limit: usize,
+    /// Indexes of the nodes from the root to the current position. Nodes past the limit are
+    /// `None`
+    path: Vec<Option<usize>>,
+}
+
+impl SearchTrace {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            nodes: Vec::new(),
+            limit,
+            path: Vec::new(),
+        }
+    }
+
+    /// Checks if nodes were left out because of the limit
+    pub fn is_full(&self) -> bool {
+        self.nodes.len() >= self.limit
+    }
+
+    fn clear(&mut self) {
+        self.nodes.clear();
+        self.path.clear();
+    }
+
+    fn current(&mut self) -> Option<&mut TraceNode> {
+        let index = (*self.path.last()?)?;
+        self.nodes.get_mut(index)
+    }
+
+    fn enter(&mut self, m: String, ply: u8) {
+        if self.is_full() {
+            self.path.push(None);
+            return;
+        }
+
+        self.nodes.push(TraceNode {
+            ply,
+            m,
+            depth: 0,
+            alpha: Score::MIN,
+            beta: Score::MAX,
+            score: None,
+            reason: None,
+        });
+        self.path.push(Some(self.nodes.len() - 1));
+    }
+
+    fn exit(&mut self, score: Option<Score>) {
+        if let Some(node) = self.current() {
+            node.score = score;
+        }
+        self.path.pop();
+    }
+
+    /// Dumps the tree as JSON, with one object per node in the order they were visited
+    pub fn to_json(&self) -> String {
+        let nodes: Vec<String> = self
+            .nodes
+            .iter()
+            .map(|node| {
+                format!(
+                    r#"{{"ply":{},"move":"{}","depth":{},"alpha":{},"beta":{},"score":{},"prune":{}}}"#,
+                    node.ply,
+                    node.m,
+                    node.depth,
+                    node.alpha.to_int(),
+                    node.beta.to_int(),
+                    node.score
+                        .map_or("null".to_string(), |score| score.to_int().to_string()),
+                    node.reason
+                        .map_or("null".to_string(), |reason| format!(r#""{}""#, reason)),
+                )
+            })
+            .collect();
+        format!("[{}]", nodes.join(","))
+    }
+}
+
+/// Dumps the tree as indented text, with one line per node
+impl fmt::Display for SearchTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for node in &self.nodes {
+            let indent = "  ".repeat(node.ply.saturating_sub(1) as usize);
+            write!(
+                f,
+                "{}{} depth {} window [{}, {}]",
+                indent, node.m, node.depth, node.alpha, node.beta
+            )?;
+            if let Some(score) = node.score {
+                write!(f, " score {}", score)?;
+            }
+            if let Some(reason) = node.reason {
+                write!(f, " ({})", reason)?;
+            }
+            writeln!(f)?;
+        }
+
+        if self.is_full() {
+            writeln!(f, "... trace limit of {} nodes reached", self.limit)?;
+        }
+        Ok(())
+    }
+}
+
+impl Engine {
+    /// Starts recording the searched tree, keeping at most `limit` nodes. The trace is cleared at
+    /// the start of every search
+    pub fn enable_trace(&mut self, limit: usize) {
+        self.trace = Some(SearchTrace::new(limit));
+    }
+
+    /// Stops recording the searched tree and returns what was recorded
+    pub fn take_trace(&mut self) -> Option<SearchTrace> {
+        self.trace.take()
+    }
+
+    pub(crate) fn clear_trace(&mut self) {
+        if let Some(trace) = &mut self.trace {
+            trace.clear();
+        }
+    }
+
+    /// Called right before a move is searched
+    pub(crate) fn trace_enter(&mut self, m: &Move) {
+        if let Some(trace) = &mut self.trace {
+            trace.enter(m.to_uci(&self.game), self.ply + 1);
+        }
+    }
+
+    /// Called right after a move was searched
+    pub(crate) fn trace_exit(&mut self, info: &SearchInfo) {
+        if let Some(trace) = &mut self.trace {
+            trace.exit(Some(info.score));
+        }
+    }
+
+    /// Records the window the current position is searched with
+    pub(crate) fn trace_window(&mut self, alpha: Score, beta: Score, depth: u8) {
+        if let Some(node) = self.trace.as_mut().and_then(SearchTrace::current) {
+            node.alpha = alpha;
+            node.beta = beta;
+            node.depth = depth;
+        }
+    }
+
+    /// Records why the search of the current position ended early
+    pub(crate) fn trace_prune(&mut self, reason: PruneReason) {
+        if let Some(node) = self.trace.as_mut().and_then(SearchTrace::current) {
+            node.reason = Some(reason);
+        }
+    }
+
+    /// Records a move that was pruned without being searched
+    pub(crate) fn trace_skip(&mut self, m: &Move, reason: PruneReason) {
+        if self.trace.is_none() {
+            return;
+        }
+
+        self.trace_enter(m);
+        self.trace_prune(reason);
+        if let Some(trace) = &mut self.trace {
+            trace.exit(None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn trace_records_the_tree() {
+        let fen = "r1k2b1r/1p4p1/p1p4P/4B3/2p5/3P3P/NP2P1B1/2K2R2 w - - 0 29";
+        let mut engine = Engine::from_fen(fen).unwrap();
+        engine.enable_trace(100_000);
+        let result = engine.search(Duration::MAX, 2);
+        let trace = engine.take_trace().unwrap();
+
+        assert!(!trace.nodes.is_empty());
+        assert!(trace.nodes.iter().all(|node| node.ply > 0));
+        assert!(trace.nodes.iter().any(|node| node.reason.is_some()));
+
+        // The best move was searched at the root
+        let best = result.best_move.unwrap().to_uci(&engine.game);
+        assert!(
+            trace
+                .nodes
+                .iter()
+                .any(|node| node.ply == 1 && node.m == best)
+        );
+    }
+
+    #[test]
+    fn trace_respects_the_limit() {
+        let mut engine = Engine::default();
+        engine.enable_trace(50);
+        engine.search(Duration::MAX, 3);
+        let trace = engine.take_trace().unwrap();
+        assert_eq!(trace.nodes.len(), 50);
+        assert!(trace.is_full());
+        assert!(
+            trace
+                .to_string()
+                .ends_with("trace limit of 50 nodes reached\n")
+        );
+    }
+
+    #[test]
+    fn trace_dumps_json() {
+        let mut engine = Engine::default();
+        engine.enable_trace(10);
+        engine.search(Duration::MAX, 1);
+        let json = engine.take_trace().unwrap().to_json();
+        assert!(json.starts_with(r#"[{"ply":1,"move":""#));
+        assert!(json.ends_with("}]"));
+        assert_eq!(json.matches("\"ply\"").count(), 10);
+    }
+
+    #[test]
+    fn tracing_does_not_change_the_search() {
+        let fen = "rnbqk1nr/ppp2pp1/7p/3pp3/1b1PP3/8/PPPB1PPP/RN1QKBNR w KQkq - 0 1";
+        let mut engine = Engine::from_fen(fen).unwrap();
+        let expected = engine.search(Duration::MAX, 3);
+        engine.clear_persistant_cache();
+        engine.enable_trace(1000);
+        assert_eq!(engine.search(Duration::MAX, 3), expected);
+    }
+}
@@ -0,0 +1,44 @@
+use whalecrab_lib::position::game::Game;
+
+use crate::engine::Engine;
+
+/// Asserts that the evaluation of a position is the exact opposite of the evaluation of the same
+/// position with the colors flipped. Any difference means a term favours one of the colors.
+#[track_caller]
+pub fn assert_symmetric_eval(engine: &mut Engine, fen: &str) {
+    let game = Game::from_fen(fen).expect("Invalid fen");
+    let flipped = game.flip_colors();
+
+    engine.with_new_game(game);
+    let original = engine.explain_eval();
+    engine.with_new_game(flipped);
+    let mirrored = engine.explain_eval();
+
+    assert_eq!(
+        original.total, -mirrored.total,
+        "The evaluation of {} is asymmetric\nOriginal:\n{}\nFlipped:\n{}",
+        fen, original, mirrored
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::BENCH_FENS;
+
+    use super::*;
+
+    #[test]
+    fn evaluation_is_symmetric() {
+        let mut engine = Engine::default();
+        for fen in BENCH_FENS.iter().chain(&[
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 2",
+            "4k3/1R3ppp/8/8/8/8/r4PPP/4K3 w - - 0 1",
+            "6k1/5ppp/3q4/6N1/8/3Q1R2/5PPP/6K1 b - - 0 1",
+            "8/8/8/4k3/8/8/8/R3K3 w - - 0 1",
+            "4k3/p6p/8/4N3/3P4/8/8/4K3 w - - 0 1",
+        ]) {
+            assert_symmetric_eval(&mut engine, fen);
+        }
+    }
+}
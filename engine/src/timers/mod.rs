@@ -2,6 +2,7 @@ pub mod elapsed;
 pub mod infinite;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub mod rdtsc;
+pub mod stoppable;
 
 /// Returns the high performance `Rdtsc` timer on supported platforms, otherwise returns an `Elapsed` timer
 #[macro_export]
@@ -28,6 +29,13 @@ pub trait MoveTimer {
     fn over(&self) -> bool;
 }
 
+impl<T: MoveTimer> MoveTimer for &T {
+    #[inline(always)]
+    fn over(&self) -> bool {
+        (**self).over()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::timers::infinite::Infinite;
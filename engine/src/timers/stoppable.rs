@@ -0,0 +1,42 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+use crate::timers::MoveTimer;
+
+/// Wraps another timer so that the search can also be stopped early by setting a shared flag,
+/// such as from a `stop` command on another thread
+pub struct Stoppable<T: MoveTimer> {
+    timer: T,
+    stop: Arc<AtomicBool>,
+}
+
+impl<T: MoveTimer> Stoppable<T> {
+    pub fn new(timer: T, stop: Arc<AtomicBool>) -> Stoppable<T> {
+        Stoppable { timer, stop }
+    }
+}
+
+impl<T: MoveTimer> MoveTimer for Stoppable<T> {
+    #[inline(always)]
+    fn over(&self) -> bool {
+        self.stop.load(Ordering::Relaxed) || self.timer.over()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::timers::infinite::Infinite;
+
+    use super::*;
+
+    #[test]
+    fn stops_when_flag_is_set() {
+        let stop = Arc::new(AtomicBool::new(false));
+        let timer = Stoppable::new(Infinite, stop.clone());
+        assert!(!timer.over());
+        stop.store(true, Ordering::Relaxed);
+        assert!(timer.over());
+    }
+}
@@ -0,0 +1,243 @@
+use std::{
+    fmt,
+    str::FromStr,
+    sync::{Arc, atomic::AtomicBool},
+};
+
+use rand::{SeedableRng, rngs::Xoshiro256PlusPlus, seq::IndexedRandom};
+use whalecrab_lib::{
+    engine::{Engine as Mover, SearchLimits},
+    movegen::{moves::Move, pieces::piece::PieceType},
+    position::game::{Game, State},
+};
+
+use crate::{engine::Engine, mcts::MctsMover, piece_eval::material_value, score::Score};
+
+impl Mover for Engine {
+    fn best_move(&mut self, game: &mut Game, limits: &SearchLimits) -> Option<Move> {
+        self.with_new_game(game.clone());
+        self.search_with_limits(*limits).best_move
+    }
+}
+
+/// Plays a random legal move
+#[derive(Debug, Clone)]
+pub struct RandomMover {
+    rng: Xoshiro256PlusPlus,
+}
+
+impl Default for RandomMover {
+    fn default() -> Self {
+        Self {
+            rng: rand::make_rng(),
+        }
+    }
+}
+
+impl RandomMover {
+    /// Creates a mover that always plays the same moves for the same seed
+    pub fn seeded(seed: u64) -> Self {
+        Self {
+            rng: Xoshiro256PlusPlus::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Mover for RandomMover {
+    fn best_move(&mut self, game: &mut Game, _limits: &SearchLimits) -> Option<Move> {
+        game.legal_moves().choose(&mut self.rng).copied()
+    }
+}
+
+/// Plays the move that wins the most material right away, without looking at replies. Checkmates
+/// are always played.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GreedyMover;
+
+impl GreedyMover {
+    /// How much material the move wins for the player making it
+    fn gain(m: &Move) -> Score {
+        match m {
+            Move::Normal {
+                capture: Some(capture),
+                ..
+            } => material_value(*capture),
+            Move::CaptureEnPassant { .. } => material_value(PieceType::Pawn),
+            Move::Promotion { piece, capture, .. } => {
+                material_value(*piece) - material_value(PieceType::Pawn)
+                    + capture.map_or(Score::default(), material_value)
+            }
+            _ => Score::default(),
+        }
+    }
+}
+
+impl Mover for GreedyMover {
+    fn best_move(&mut self, game: &mut Game, _limits: &SearchLimits) -> Option<Move> {
+        game.legal_moves().into_iter().max_by_key(|m| {
+            game.play(m);
+            let is_mate = game.state == State::Checkmate;
+            game.unplay(m);
+            if is_mate { Score::MAX } else { Self::gain(m) }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum EngineKindParseError {
+    UnknownEngine,
+}
+
+/// The engines a frontend can let the user pick from
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EngineKind {
+    /// The full search
+    #[default]
+    Minimax,
+    /// Monte-Carlo tree search
+    Mcts,
+    Greedy,
+    Random,
+}
+
+impl EngineKind {
+    /// Creates a fresh engine of this kind. Engines of the same kind and seed make the same
+    /// choices, see [`Engine::next_seed`] for getting one.
+    pub fn build(self, seed: u64) -> Box<dyn Mover> {
+        self.build_with_stop(seed, Arc::default())
+    }
+
+    /// Same as `build`, but the engine's searches end early once `stop` is set. Only the Monte-Carlo
+    /// tree search looks at it, since the other toys answer right away.
+    pub fn build_with_stop(self, seed: u64, stop: Arc<AtomicBool>) -> Box<dyn Mover> {
+        match self {
+            EngineKind::Minimax => {
+                let mut engine = Engine::default();
+                engine.set_seed(seed);
+                Box::new(engine)
+            }
+            EngineKind::Mcts => Box::new(MctsMover::with_stop(stop)),
+            EngineKind::Greedy => Box::new(GreedyMover),
+            EngineKind::Random => Box::new(RandomMover::seeded(seed)),
+        }
+    }
+
+    /// Switches to the next kind of engine
+    pub fn cycle(&mut self) {
+        *self = match self {
+            EngineKind::Minimax => EngineKind::Mcts,
+            EngineKind::Mcts => EngineKind::Greedy,
+            EngineKind::Greedy => EngineKind::Random,
+            EngineKind::Random => EngineKind::Minimax,
+        };
+    }
+}
+
+impl fmt::Display for EngineKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            EngineKind::Minimax => "Minimax",
+            EngineKind::Mcts => "Mcts",
+            EngineKind::Greedy => "Greedy",
+            EngineKind::Random => "Random",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for EngineKind {
+    type Err = EngineKindParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "minimax" => Ok(EngineKind::Minimax),
+            "mcts" => Ok(EngineKind::Mcts),
+            "greedy" => Ok(EngineKind::Greedy),
+            "random" => Ok(EngineKind::Random),
+            _ => Err(EngineKindParseError::UnknownEngine),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use whalecrab_lib::square::Square;
+
+    use super::*;
+
+    #[test]
+    fn every_kind_plays_a_legal_move() {
+        let mut game = Game::default();
+        let before = game.clone();
+        let limits = SearchLimits::depth(1);
+        let mut kind = EngineKind::default();
+        loop {
+            let m = kind.build(0).best_move(&mut game, &limits);
+            assert!(
+                m.is_some_and(|m| game.legal_moves().contains(&m)),
+                "{}",
+                kind
+            );
+            assert_eq!(game, before);
+
+            kind.cycle();
+            if kind == EngineKind::default() {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn greedy_takes_the_queen() {
+        let fen = "rnb1kbnr/pppp1ppp/8/4p1q1/3PP3/8/PPP2PPP/RNBQKBNR w KQkq - 1 3";
+        let mut game = Game::from_fen(fen).unwrap();
+        let expected = Move::infer(Square::C1, Square::G5, &game);
+        let m = GreedyMover.best_move(&mut game, &SearchLimits::default());
+        assert_eq!(m, Some(expected));
+    }
+
+    #[test]
+    fn seeded_random_movers_agree() {
+        let mut game = Game::default();
+        let mut first = RandomMover::seeded(7);
+        let mut second = RandomMover::seeded(7);
+        for _ in 0..10 {
+            let m = first.best_move(&mut game, &SearchLimits::default());
+            assert_eq!(m, second.best_move(&mut game, &SearchLimits::default()));
+            game.play(&m.unwrap());
+        }
+    }
+
+    #[test]
+    fn kinds_built_with_the_same_seed_agree() {
+        let play = |seed| {
+            let mut game = Game::default();
+            let mut mover = EngineKind::Random.build(seed);
+            for _ in 0..20 {
+                let m = mover.best_move(&mut game, &SearchLimits::default());
+                game.play(&m.unwrap());
+            }
+            game
+        };
+        assert_eq!(play(7), play(7));
+
+        let mut engine = Engine::default();
+        engine.set_seed(7);
+        let first = engine.next_seed();
+        engine.set_seed(7);
+        assert_eq!(engine.next_seed(), first);
+    }
+
+    #[test]
+    fn kinds_round_trip_through_strings() {
+        for kind in [
+            EngineKind::Minimax,
+            EngineKind::Mcts,
+            EngineKind::Greedy,
+            EngineKind::Random,
+        ] {
+            assert_eq!(kind.to_string().parse::<EngineKind>().unwrap(), kind);
+        }
+        assert!("stockfish".parse::<EngineKind>().is_err());
+    }
+}
@@ -1,33 +1,118 @@
-use whalecrab_lib::movegen::moves::Move;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use whalecrab_lib::movegen::packed::PackedMove;
 
 use crate::{engine::TRANSPOSITION_TABLE_MEMORY_BUDGET_IN_KILOBYTES, score::Score};
 
-#[derive(Default, Clone, Debug, PartialEq)]
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
 pub(crate) struct TranspositionTableEntry {
-    pub(crate) best_move: Option<Move>,
+    /// Packed to keep entries small. Unpack it with the position the entry belongs to.
+    pub(crate) best_move: PackedMove,
     pub(crate) depth: u8,
     pub(crate) score: Score,
-    pub(crate) node_type: NodeType,
+    pub(crate) bound: Bound,
 }
 
-#[derive(Default, Clone, Debug, PartialEq)]
-pub(crate) enum NodeType {
+/// How the stored score relates to the true score of the position, from white's perspective
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Bound {
+    /// The score was searched with a full window
     #[default]
     Exact,
-    /// A beta cutoff was performed in maxi
-    Cut,
-    /// An alpha cutoff was performed in mini
-    All,
+    /// A beta cutoff was performed, so the true score is at least this high
+    Lower,
+    /// No move raised alpha or an alpha cutoff was performed, so the true score is at most this high
+    Upper,
+}
+
+impl Bound {
+    /// Classifies a score that was searched with the window `alpha..beta`
+    pub(crate) fn from_window(score: Score, alpha: Score, beta: Score) -> Bound {
+        if score <= alpha {
+            Bound::Upper
+        } else if score >= beta {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        }
+    }
+}
+
+impl TranspositionTableEntry {
+    /// Returns the stored score if it is deep enough and its bound falls outside of the window.
+    /// Mate scores are stored relative to the node, so `ply` is needed to bring them back.
+    pub(crate) fn cutoff(&self, alpha: Score, beta: Score, depth: u8, ply: u8) -> Option<Score> {
+        if self.depth < depth {
+            return None;
+        }
+
+        let score = self.score.from_tt(ply);
+        match self.bound {
+            Bound::Exact => Some(score),
+            Bound::Lower if score >= beta => Some(score),
+            Bound::Upper if score <= alpha => Some(score),
+            _ => None,
+        }
+    }
+}
+
+/// Marks slots that hold an entry, so that an empty slot never passes for one
+const OCCUPIED: u64 = 1 << 63;
+
+impl TranspositionTableEntry {
+    /// Packs the entry into the 64 bits of a slot: the move, then the score, the depth and the
+    /// bound
+    fn pack(self) -> u64 {
+        let bound = match self.bound {
+            Bound::Exact => 0,
+            Bound::Lower => 1,
+            Bound::Upper => 2,
+        };
+        self.best_move.bits() as u64
+            | (self.score.to_int() as u16 as u64) << 16
+            | (self.depth as u64) << 32
+            | bound << 40
+            | OCCUPIED
+    }
+
+    fn unpack(data: u64) -> Self {
+        Self {
+            best_move: PackedMove::from_bits(data as u16),
+            score: Score::new((data >> 16) as u16 as i16),
+            depth: (data >> 32) as u8,
+            bound: match data >> 40 & 0b11 {
+                0 => Bound::Exact,
+                1 => Bound::Lower,
+                _ => Bound::Upper,
+            },
+        }
+    }
 }
 
-type FullEntry = Option<(TranspositionTableEntry, u64)>;
+/// A packed entry, stored along with its hash xored with the entry. A slot written by two
+/// threads at once has a key that matches neither hash, so torn entries are never read.
+#[derive(Debug, Default)]
+struct Slot {
+    key: AtomicU64,
+    data: AtomicU64,
+}
 
-#[derive(Clone, Debug, PartialEq)]
+impl Slot {
+    /// The packed entry if it belongs to `hash`
+    fn load(&self, hash: u64) -> Option<u64> {
+        let data = self.data.load(Ordering::Relaxed);
+        let key = self.key.load(Ordering::Relaxed);
+        (data & OCCUPIED != 0 && key ^ data == hash).then_some(data)
+    }
+}
+
+/// Shared between every search thread without locking, see [`Slot`]
+#[derive(Debug)]
 pub(crate) struct TranspositionTable {
-    entries: Box<[FullEntry]>,
+    entries: Box<[Slot]>,
     mask: usize,
     #[cfg(debug_assertions)]
-    pub(crate) num_collisions: std::cell::RefCell<usize>,
+    pub(crate) num_collisions: std::sync::atomic::AtomicUsize,
 }
 
 impl Default for TranspositionTable {
@@ -36,20 +121,32 @@ impl Default for TranspositionTable {
     }
 }
 
+impl PartialEq for TranspositionTable {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+            || self.mask == other.mask
+                && self.entries.iter().zip(other.entries.iter()).all(|(a, b)| {
+                    a.key.load(Ordering::Relaxed) == b.key.load(Ordering::Relaxed)
+                        && a.data.load(Ordering::Relaxed) == b.data.load(Ordering::Relaxed)
+                })
+    }
+}
+
 impl TranspositionTable {
-    fn from_size(kilobytes: usize) -> Self {
-        let entry_size = std::mem::size_of::<FullEntry>();
+    pub(crate) fn from_size(kilobytes: usize) -> Self {
+        let entry_size = std::mem::size_of::<Slot>();
         let count = (kilobytes * 1024 / entry_size).next_power_of_two();
         Self {
-            entries: vec![None; count].into_boxed_slice(),
+            entries: (0..count).map(|_| Slot::default()).collect(),
             mask: count - 1,
             #[cfg(debug_assertions)]
-            num_collisions: std::cell::RefCell::new(0),
+            num_collisions: std::sync::atomic::AtomicUsize::new(0),
         }
     }
 
-    pub(crate) fn new() -> Self {
-        let kilobytes = *TRANSPOSITION_TABLE_MEMORY_BUDGET_IN_KILOBYTES.get_or_init(|| {
+    /// The memory budget tables get unless told otherwise
+    pub(crate) fn default_kilobytes() -> usize {
+        *TRANSPOSITION_TABLE_MEMORY_BUDGET_IN_KILOBYTES.get_or_init(|| {
             (if cfg!(test) && cfg!(debug_assertions) {
                 128
             } else if cfg!(test) {
@@ -59,33 +156,138 @@ impl TranspositionTable {
             } else {
                 4096
             }) * 1024
-        });
+        })
+    }
 
-        Self::from_size(kilobytes)
+    pub(crate) fn new() -> Self {
+        Self::from_size(Self::default_kilobytes())
     }
 
-    pub(crate) fn get(&self, hash: u64) -> Option<&TranspositionTableEntry> {
-        let key = hash as usize & self.mask;
-        let (entry, checksum) = self.entries[key].as_ref()?;
-        if *checksum == hash {
-            Some(entry)
-        } else {
-            #[cfg(debug_assertions)]
-            {
-                *self.num_collisions.try_borrow_mut().ok()? += 1;
+    /// How much memory the entries take, in kilobytes
+    pub(crate) fn kilobytes(&self) -> usize {
+        self.entries.len() * std::mem::size_of::<Slot>() / 1024
+    }
+
+    fn slot(&self, hash: u64) -> &Slot {
+        &self.entries[hash as usize & self.mask]
+    }
+
+    pub(crate) fn get(&self, hash: u64) -> Option<TranspositionTableEntry> {
+        let slot = self.slot(hash);
+        match slot.load(hash) {
+            Some(data) => Some(TranspositionTableEntry::unpack(data)),
+            None => {
+                #[cfg(debug_assertions)]
+                if slot.data.load(Ordering::Relaxed) & OCCUPIED != 0 {
+                    self.num_collisions.fetch_add(1, Ordering::Relaxed);
+                }
+                None
             }
-            None
         }
     }
 
-    pub(crate) fn insert(&mut self, hash: u64, entry: TranspositionTableEntry) {
-        let key = hash as usize & self.mask;
-        self.entries[key] = Some((entry, hash));
+    /// Inserts the entry unless a deeper search of the same position is already stored
+    pub(crate) fn store(&self, hash: u64, entry: TranspositionTableEntry) {
+        let slot = self.slot(hash);
+        if slot
+            .load(hash)
+            .is_none_or(|existing| entry.depth >= TranspositionTableEntry::unpack(existing).depth)
+        {
+            let data = entry.pack();
+            slot.data.store(data, Ordering::Relaxed);
+            slot.key.store(hash ^ data, Ordering::Relaxed);
+        }
     }
 
-    pub(crate) fn clear(&mut self) {
-        for entry in self.entries.iter_mut() {
-            *entry = None;
+    pub(crate) fn clear(&self) {
+        for slot in self.entries.iter() {
+            slot.data.store(0, Ordering::Relaxed);
+            slot.key.store(0, Ordering::Relaxed);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(score: i16, depth: u8, bound: Bound) -> TranspositionTableEntry {
+        TranspositionTableEntry {
+            best_move: PackedMove::NONE,
+            depth,
+            score: Score::new(score),
+            bound,
+        }
+    }
+
+    #[test]
+    fn shallow_entries_do_not_cut_off() {
+        let e = entry(50, 2, Bound::Exact);
+        assert_eq!(e.cutoff(Score::MIN, Score::MAX, 3, 0), None);
+        assert_eq!(e.cutoff(Score::MIN, Score::MAX, 2, 0), Some(Score::new(50)));
+    }
+
+    #[test]
+    fn bounds_only_cut_off_outside_of_window() {
+        let lower = entry(50, 4, Bound::Lower);
+        assert_eq!(
+            lower.cutoff(Score::new(0), Score::new(40), 4, 0),
+            Some(Score::new(50))
+        );
+        assert_eq!(lower.cutoff(Score::new(0), Score::new(60), 4, 0), None);
+
+        let upper = entry(50, 4, Bound::Upper);
+        assert_eq!(
+            upper.cutoff(Score::new(60), Score::new(100), 4, 0),
+            Some(Score::new(50))
+        );
+        assert_eq!(upper.cutoff(Score::new(40), Score::new(100), 4, 0), None);
+    }
+
+    #[test]
+    fn mate_scores_are_relative_to_the_node() {
+        // Mate in 5 plies from the root, found 2 plies in, is mate in 3 from that node
+        let mut e = entry(0, 4, Bound::Exact);
+        e.score = Score::mate_in(5).to_tt(2);
+        assert_eq!(
+            e.cutoff(Score::MIN, Score::MAX, 4, 4),
+            Some(Score::mate_in(7))
+        );
+    }
+
+    #[test]
+    fn store_keeps_deeper_entries() {
+        let tt = TranspositionTable::from_size(1);
+        tt.store(7, entry(10, 5, Bound::Exact));
+        tt.store(7, entry(20, 3, Bound::Exact));
+        assert_eq!(tt.get(7).unwrap().score, Score::new(10));
+        tt.store(7, entry(30, 6, Bound::Exact));
+        assert_eq!(tt.get(7).unwrap().score, Score::new(30));
+    }
+
+    #[test]
+    fn entries_survive_packing() {
+        let mut e = entry(-32000, 255, Bound::Upper);
+        e.best_move = PackedMove::from_bits(0xbeef);
+        assert_eq!(TranspositionTableEntry::unpack(e.pack()), e);
+
+        let tt = TranspositionTable::from_size(1);
+        assert_eq!(tt.get(0), None);
+        tt.store(0, entry(0, 0, Bound::Exact));
+        assert_eq!(tt.get(0), Some(entry(0, 0, Bound::Exact)));
+        tt.clear();
+        assert_eq!(tt.get(0), None);
+    }
+
+    #[test]
+    fn entries_are_shared_between_threads() {
+        let tt = TranspositionTable::from_size(1);
+        std::thread::scope(|scope| {
+            for depth in 1..=4 {
+                let tt = &tt;
+                scope.spawn(move || tt.store(7, entry(depth as i16, depth, Bound::Exact)));
+            }
+        });
+        assert_eq!(tt.get(7).unwrap().depth, 4);
+    }
+}
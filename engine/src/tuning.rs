@@ -0,0 +1,250 @@
+use std::{fmt, fs, io, mem, path::Path};
+
+use whalecrab_lib::position::game::Game;
+
+use crate::{engine::Engine, eval_params::EvalParams};
+
+/// A position together with the result of the game it was taken from, from white's perspective.
+/// 1.0 is a win, 0.5 a draw and 0.0 a loss.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TuningPosition {
+    pub game: Game,
+    pub result: f64,
+}
+
+#[derive(Debug)]
+pub enum TuningError {
+    Io(io::Error),
+    /// The line number, starting at 1, and the line itself
+    Parse(usize, String),
+}
+
+impl fmt::Display for TuningError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "Failed to read the tuning positions: {}", e),
+            Self::Parse(line, text) => {
+                write!(f, "Invalid tuning position on line {}: {}", line, text)
+            }
+        }
+    }
+}
+
+fn parse_result(result: &str) -> Option<f64> {
+    let result = match result.trim().trim_matches('"') {
+        "1-0" => 1.0,
+        "0-1" => 0.0,
+        "1/2-1/2" => 0.5,
+        other => other.parse().ok()?,
+    };
+    (0.0..=1.0).contains(&result).then_some(result)
+}
+
+impl TuningPosition {
+    /// Parses a fen followed by the result, either in brackets or after a semicolon. Results can
+    /// be written as "1-0", "1/2-1/2" and "0-1", or as "1.0", "0.5" and "0.0".
+    ///
+    /// ```text
+    /// 4k3/8/8/8/8/8/4P3/4K3 w - - 0 1 [1-0]
+    /// 4k3/8/8/8/8/8/4P3/4K3 w - - 0 1; 0.5
+    /// ```
+    pub fn parse(line: &str) -> Option<Self> {
+        let (fen, result) = match line.split_once('[') {
+            Some((fen, result)) => (fen, result.trim_end().strip_suffix(']')?),
+            None => line.split_once(';')?,
+        };
+
+        Some(Self {
+            game: Game::from_fen(fen.trim()).ok()?,
+            result: parse_result(result)?,
+        })
+    }
+
+    /// Reads one position per line, skipping empty lines and lines starting with '#'
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Vec<Self>, TuningError> {
+        let text = fs::read_to_string(path).map_err(TuningError::Io)?;
+        text.lines()
+            .enumerate()
+            .map(|(i, line)| (i, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .map(|(i, line)| Self::parse(line).ok_or(TuningError::Parse(i + 1, line.to_string())))
+            .collect()
+    }
+}
+
+/// Maps a score in centipawns to the expected result for white, where `k` controls how quickly
+/// an advantage turns into a win
+pub fn sigmoid(score: f64, k: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf(-k * score / 400.0))
+}
+
+/// Fits the evaluation weights to the results of real games, using Texel's tuning method. The
+/// error of a set of params is the mean squared difference between the result of every position
+/// and the result its static evaluation predicts. The weights are then nudged one at a time,
+/// keeping every change that lowers the error, until none does.
+pub struct Tuner {
+    /// Only used to evaluate the positions
+    engine: Engine,
+    positions: Vec<TuningPosition>,
+    /// The scaling constant of the sigmoid. Find the best one with `fit_k` before tuning
+    pub k: f64,
+}
+
+impl Tuner {
+    pub fn new(positions: Vec<TuningPosition>) -> Self {
+        Self {
+            engine: Engine::default(),
+            positions,
+            k: 1.0,
+        }
+    }
+
+    /// The mean squared error of `params` over every position
+    pub fn error(&mut self, params: &EvalParams) -> f64 {
+        if self.positions.is_empty() {
+            return 0.0;
+        }
+
+        self.engine.set_eval_params(params.clone());
+
+        let mut total = 0.0;
+        for position in &mut self.positions {
+            // Swapping avoids cloning every game on every evaluation
+            mem::swap(&mut self.engine.game, &mut position.game);
+            let score = self.engine.grade_position().to_int() as f64;
+            mem::swap(&mut self.engine.game, &mut position.game);
+
+            total += (position.result - sigmoid(score, self.k)).powi(2);
+        }
+
+        total / self.positions.len() as f64
+    }
+
+    /// Finds the `k` that gives `params` the lowest error, by repeatedly narrowing down the range
+    /// around the best value found so far. Sets and returns it.
+    pub fn fit_k(&mut self, params: &EvalParams) -> f64 {
+        let mut best = self.k;
+        let mut best_error = self.error(params);
+        let mut step = 0.5;
+        let mut low = 0.0;
+
+        for _ in 0..4 {
+            for i in 0..=10 {
+                self.k = low + step * i as f64;
+                let error = self.error(params);
+                if error < best_error {
+                    best = self.k;
+                    best_error = error;
+                }
+            }
+
+            low = (best - step).max(0.0);
+            step /= 5.0;
+        }
+
+        self.k = best;
+        best
+    }
+
+    /// Runs up to `iterations` passes over every weight, starting from `params`, and returns the
+    /// best params found. Stops early once a pass no longer improves anything.
+    pub fn tune(&mut self, params: &EvalParams, iterations: usize) -> EvalParams {
+        let mut best = params.clone();
+        let mut best_error = self.error(&best);
+        let mut weights = best.weights();
+
+        for _ in 0..iterations {
+            let mut improved = false;
+
+            for i in 0..weights.len() {
+                for delta in [1, -1] {
+                    weights[i] += delta;
+                    let mut candidate = best.clone();
+                    candidate.set_weights(&weights);
+
+                    let error = self.error(&candidate);
+                    if error < best_error {
+                        best = candidate;
+                        best_error = error;
+                        improved = true;
+                        break;
+                    }
+
+                    weights[i] -= delta;
+                }
+            }
+
+            if !improved {
+                break;
+            }
+        }
+
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(fen: &str, result: f64) -> TuningPosition {
+        TuningPosition {
+            game: Game::from_fen(fen).unwrap(),
+            result,
+        }
+    }
+
+    #[test]
+    fn parses_result_formats() {
+        let fen = "4k3/8/8/8/8/8/4P3/4K3 w - - 0 1";
+        for (line, result) in [
+            (format!("{} [1-0]", fen), 1.0),
+            (format!("{} [0.5]", fen), 0.5),
+            (format!("{};0-1", fen), 0.0),
+            (format!("{}; \"1/2-1/2\"", fen), 0.5),
+        ] {
+            let position = TuningPosition::parse(&line).unwrap();
+            assert_eq!(position.game.to_fen(), fen);
+            assert_eq!(position.result, result);
+        }
+
+        assert!(TuningPosition::parse(fen).is_none());
+        assert!(TuningPosition::parse(&format!("{} [2-0]", fen)).is_none());
+    }
+
+    #[test]
+    fn sigmoid_is_centered() {
+        assert_eq!(sigmoid(0.0, 1.0), 0.5);
+        assert!(sigmoid(300.0, 1.0) > 0.8);
+        assert!((sigmoid(-300.0, 1.0) + sigmoid(300.0, 1.0) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn tuning_lowers_the_error() {
+        // The doubled pawns still win, so the tuner should learn to penalize them less
+        let positions = vec![
+            position("4k3/8/8/8/8/4P3/4P3/4K3 w - - 0 1", 1.0),
+            position("4k3/4p3/4p3/8/8/8/8/4K3 w - - 0 1", 0.0),
+            position("4k3/3ppp2/8/8/8/8/3PP3/4K3 w - - 0 1", 0.5),
+        ];
+        let mut tuner = Tuner::new(positions);
+        let params = EvalParams::default();
+        let before = tuner.error(&params);
+
+        let tuned = tuner.tune(&params, 1);
+        assert!(tuner.error(&tuned) < before);
+    }
+
+    #[test]
+    fn fitting_k_never_raises_the_error() {
+        let positions = vec![
+            position("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1", 1.0),
+            position("4k3/4p3/8/8/8/8/8/4K3 w - - 0 1", 0.0),
+        ];
+        let mut tuner = Tuner::new(positions);
+        let params = EvalParams::default();
+        let before = tuner.error(&params);
+        tuner.fit_k(&params);
+        assert!(tuner.error(&params) <= before);
+    }
+}
@@ -35,7 +35,7 @@ macro_rules! bench_piece_method {
 }
 
 fn bench(c: &mut Criterion) {
-    let mut game = common::midgame();
+    let game = common::midgame();
 
     macro_rules! bench_piece_methods {
         ($type:expr) => {
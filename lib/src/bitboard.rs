@@ -118,6 +118,42 @@ impl BitBoard {
             | Rank::Eighth.mask().to_int(),
     );
 
+    pub const FILE_A: BitBoard = File::A.mask();
+    pub const FILE_B: BitBoard = File::B.mask();
+    pub const FILE_C: BitBoard = File::C.mask();
+    pub const FILE_D: BitBoard = File::D.mask();
+    pub const FILE_E: BitBoard = File::E.mask();
+    pub const FILE_F: BitBoard = File::F.mask();
+    pub const FILE_G: BitBoard = File::G.mask();
+    pub const FILE_H: BitBoard = File::H.mask();
+
+    pub const RANK_1: BitBoard = Rank::First.mask();
+    pub const RANK_2: BitBoard = Rank::Second.mask();
+    pub const RANK_3: BitBoard = Rank::Third.mask();
+    pub const RANK_4: BitBoard = Rank::Fourth.mask();
+    pub const RANK_5: BitBoard = Rank::Fifth.mask();
+    pub const RANK_6: BitBoard = Rank::Sixth.mask();
+    pub const RANK_7: BitBoard = Rank::Seventh.mask();
+    pub const RANK_8: BitBoard = Rank::Eighth.mask();
+
+    /// D4, E4, D5 and E5
+    pub const CENTER: BitBoard =
+        BitBoard((Self::FILE_D.0 | Self::FILE_E.0) & (Self::RANK_4.0 | Self::RANK_5.0));
+    /// The 16 squares from C3 to F6
+    pub const EXTENDED_CENTER: BitBoard = BitBoard(
+        (Self::FILE_C.0 | Self::FILE_D.0 | Self::FILE_E.0 | Self::FILE_F.0)
+            & (Self::RANK_3.0 | Self::RANK_4.0 | Self::RANK_5.0 | Self::RANK_6.0),
+    );
+    /// The F, G and H files, where a king that castled kingside lives
+    pub const KINGSIDE: BitBoard = BitBoard(Self::FILE_F.0 | Self::FILE_G.0 | Self::FILE_H.0);
+    /// The A, B and C files, where a king that castled queenside lives
+    pub const QUEENSIDE: BitBoard = BitBoard(Self::FILE_A.0 | Self::FILE_B.0 | Self::FILE_C.0);
+
+    /// Every light square, such as B1 and A2
+    pub const LIGHT_SQUARES: BitBoard = BitBoard(0x55AA_55AA_55AA_55AA);
+    /// Every dark square, such as A1 and H8
+    pub const DARK_SQUARES: BitBoard = BitBoard(!0x55AA_55AA_55AA_55AA);
+
     /// Construct a new bitboard from a u64
     #[inline]
     pub const fn new(b: u64) -> BitBoard {
@@ -185,6 +221,19 @@ impl BitBoard {
         BitBoard(self.0.swap_bytes())
     }
 
+    /// Mirrors this `BitBoard` from left to right, so the A file swaps with the H file
+    #[inline]
+    pub const fn flip_files(self) -> BitBoard {
+        const K1: u64 = 0x5555555555555555;
+        const K2: u64 = 0x3333333333333333;
+        const K4: u64 = 0x0f0f0f0f0f0f0f0f;
+        let mut x = self.0;
+        x = (x >> 1 & K1) | (x & K1) << 1;
+        x = (x >> 2 & K2) | (x & K2) << 2;
+        x = (x >> 4 & K4) | (x & K4) << 4;
+        BitBoard(x)
+    }
+
     /// Convert this `BitBoard` to a `usize` (for table lookups)
     #[inline]
     pub fn to_size(&self, rightshift: u8) -> usize {
@@ -215,6 +264,110 @@ impl BitBoard {
     pub fn down_left(self) -> BitBoard {
         self >> 9
     }
+
+    // Unlike the shifts above, these never wrap around the edge of the board onto the other side
+    pub const fn north_one(self) -> BitBoard {
+        BitBoard(self.0 << 8)
+    }
+    pub const fn south_one(self) -> BitBoard {
+        BitBoard(self.0 >> 8)
+    }
+    pub const fn east_one(self) -> BitBoard {
+        BitBoard((self.0 << 1) & !Self::FILE_A.0)
+    }
+    pub const fn west_one(self) -> BitBoard {
+        BitBoard((self.0 >> 1) & !Self::FILE_H.0)
+    }
+    pub const fn north_east_one(self) -> BitBoard {
+        BitBoard((self.0 << 9) & !Self::FILE_A.0)
+    }
+    pub const fn north_west_one(self) -> BitBoard {
+        BitBoard((self.0 << 7) & !Self::FILE_H.0)
+    }
+    pub const fn south_east_one(self) -> BitBoard {
+        BitBoard((self.0 >> 7) & !Self::FILE_A.0)
+    }
+    pub const fn south_west_one(self) -> BitBoard {
+        BitBoard((self.0 >> 9) & !Self::FILE_H.0)
+    }
+
+    /// Every square set, along with every square north of one
+    pub const fn north_fill(self) -> BitBoard {
+        let mut b = self.0;
+        b |= b << 8;
+        b |= b << 16;
+        b |= b << 32;
+        BitBoard(b)
+    }
+
+    /// Every square set, along with every square south of one
+    pub const fn south_fill(self) -> BitBoard {
+        let mut b = self.0;
+        b |= b >> 8;
+        b |= b >> 16;
+        b |= b >> 32;
+        BitBoard(b)
+    }
+
+    /// Every file that has a square set
+    pub const fn file_fill(self) -> BitBoard {
+        BitBoard(self.north_fill().0 | self.south_fill().0)
+    }
+
+    /// Every rank that has a square set
+    pub const fn rank_fill(self) -> BitBoard {
+        let mut b = self.0;
+        b |= (b << 1) & !Self::FILE_A.0;
+        b |= (b << 2) & !(Self::FILE_A.0 | Self::FILE_B.0);
+        b |= (b << 4) & !(Self::FILE_A.0 | Self::FILE_B.0 | Self::FILE_C.0 | Self::FILE_D.0);
+        let east = b;
+        b = self.0;
+        b |= (b >> 1) & !Self::FILE_H.0;
+        b |= (b >> 2) & !(Self::FILE_G.0 | Self::FILE_H.0);
+        b |= (b >> 4) & !(Self::FILE_E.0 | Self::FILE_F.0 | Self::FILE_G.0 | Self::FILE_H.0);
+        BitBoard(east | b)
+    }
+
+    /// Every subset of the squares set, including the empty set and the whole set, such as every
+    /// combination of blockers a slider could run into
+    pub const fn subsets(self) -> Subsets {
+        Subsets {
+            set: self.0,
+            next: Some(0),
+        }
+    }
+
+    /// The squares set, and every square next to one of them
+    pub const fn neighbourhood(self) -> BitBoard {
+        let row = self.0 | self.east_one().0 | self.west_one().0;
+        BitBoard(row | row << 8 | row >> 8)
+    }
+}
+
+/// Every subset of a `BitBoard`, from the empty set up to the whole set, made with the
+/// carry-rippler trick. See [`BitBoard::subsets`].
+#[derive(Debug, Clone)]
+pub struct Subsets {
+    set: u64,
+    next: Option<u64>,
+}
+
+impl Iterator for Subsets {
+    type Item = BitBoard;
+
+    #[inline]
+    fn next(&mut self) -> Option<BitBoard> {
+        let subset = self.next?;
+        // Subtracting the set ripples a carry through the bits that are not part of it
+        let next = subset.wrapping_sub(self.set) & self.set;
+        self.next = (next != 0).then_some(next);
+        Some(BitBoard(subset))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let upper = 1usize.checked_shl(self.set.count_ones());
+        (usize::from(self.next.is_some()), upper)
+    }
 }
 
 /// For the `BitBoard`, iterate over every `Square` set.
@@ -275,4 +428,86 @@ A B C D E F G H"
         println!("{}", looking_for);
         assert_eq!(out, looking_for);
     }
+
+    #[test]
+    fn shifts_do_not_wrap() {
+        let h4 = BitBoard::from_square(Square::H4);
+        let a4 = BitBoard::from_square(Square::A4);
+        assert_eq!(h4.east_one(), EMPTY);
+        assert_eq!(h4.north_east_one(), EMPTY);
+        assert_eq!(h4.south_east_one(), EMPTY);
+        assert_eq!(a4.west_one(), EMPTY);
+        assert_eq!(a4.north_west_one(), EMPTY);
+        assert_eq!(a4.south_west_one(), EMPTY);
+
+        assert_eq!(h4.west_one(), BitBoard::from_square(Square::G4));
+        assert_eq!(h4.north_west_one(), BitBoard::from_square(Square::G5));
+        assert_eq!(a4.south_east_one(), BitBoard::from_square(Square::B3));
+        assert_eq!(a4.north_one(), BitBoard::from_square(Square::A5));
+        assert_eq!(BitBoard::RANK_8.north_one(), EMPTY);
+        assert_eq!(BitBoard::RANK_1.south_one(), EMPTY);
+    }
+
+    #[test]
+    fn fills() {
+        let c3 = BitBoard::from_square(Square::C3);
+        assert_eq!(c3.file_fill(), BitBoard::FILE_C);
+        assert_eq!(c3.rank_fill(), BitBoard::RANK_3);
+        assert_eq!(c3.north_fill().popcnt(), 6);
+        assert_eq!(c3.south_fill().popcnt(), 3);
+        assert!(
+            c3.north_fill()
+                .has_square(BitBoard::from_square(Square::C8))
+        );
+
+        let corners = BitBoard::from_square(Square::A1) | BitBoard::from_square(Square::H8);
+        assert_eq!(corners.rank_fill(), BitBoard::RANK_1 | BitBoard::RANK_8);
+        assert_eq!(corners.file_fill(), BitBoard::FILE_A | BitBoard::FILE_H);
+    }
+
+    #[test]
+    fn named_masks() {
+        assert_eq!(BitBoard::CENTER.popcnt(), 4);
+        assert!(BitBoard::CENTER.has_square(BitBoard::from_square(Square::E4)));
+        assert_eq!(BitBoard::EXTENDED_CENTER.popcnt(), 16);
+        assert_eq!(BitBoard::KINGSIDE & BitBoard::QUEENSIDE, EMPTY);
+        assert_eq!(
+            BitBoard::from_square(Square::A1).neighbourhood().popcnt(),
+            4
+        );
+        assert_eq!(
+            BitBoard::from_square(Square::E4).neighbourhood().popcnt(),
+            9
+        );
+    }
+
+    #[test]
+    fn every_subset_once() {
+        let set = BitBoard::from_square(Square::A1)
+            | BitBoard::from_square(Square::D4)
+            | BitBoard::from_square(Square::H8);
+        let subsets: Vec<_> = set.subsets().collect();
+        assert_eq!(subsets.len(), 8);
+        assert_eq!(subsets[0], EMPTY);
+        assert_eq!(subsets[7], set);
+        for (i, subset) in subsets.iter().enumerate() {
+            assert_eq!(*subset & !set, EMPTY);
+            assert!(!subsets[..i].contains(subset));
+        }
+
+        assert_eq!(EMPTY.subsets().collect::<Vec<_>>(), [EMPTY]);
+        assert_eq!(BitBoard::FILE_A.subsets().count(), 256);
+    }
+
+    #[test]
+    fn flip_files() {
+        assert_eq!(BitBoard::FILE_A.flip_files(), BitBoard::FILE_H);
+        assert_eq!(BitBoard::QUEENSIDE.flip_files(), BitBoard::KINGSIDE);
+        assert_eq!(BitBoard::RANK_3.flip_files(), BitBoard::RANK_3);
+        for sq in Square::ALL_SQUARES {
+            let bb = BitBoard::from_square(sq);
+            assert_eq!(bb.flip_files(), BitBoard::from_square(sq.flip_file()));
+            assert_eq!(bb.reverse_colors(), BitBoard::from_square(sq.flip_side()));
+        }
+    }
 }
@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use crate::{movegen::moves::Move, position::game::Game};
+
+/// Bounds a search by time, depth and nodes, whichever runs out first. Unlike time, the depth and
+/// node limits do not depend on the speed of the machine, so searches bounded only by them are
+/// deterministic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchLimits {
+    /// How long the search may take
+    pub duration: Duration,
+    /// The deepest iteration that will be searched
+    pub depth: u8,
+    /// How many nodes may be searched before the search stops
+    pub nodes: u64,
+}
+
+impl Default for SearchLimits {
+    fn default() -> Self {
+        Self {
+            duration: Duration::MAX,
+            depth: u8::MAX,
+            nodes: u64::MAX,
+        }
+    }
+}
+
+impl SearchLimits {
+    /// Only limits the search by time
+    pub fn duration(duration: Duration) -> Self {
+        Self {
+            duration,
+            ..Default::default()
+        }
+    }
+
+    /// Only limits the search by depth
+    pub fn depth(depth: u8) -> Self {
+        Self {
+            depth,
+            ..Default::default()
+        }
+    }
+
+    /// Only limits the search by nodes
+    pub fn nodes(nodes: u64) -> Self {
+        Self {
+            nodes,
+            ..Default::default()
+        }
+    }
+}
+
+/// Anything that can pick a move for the player to move, from a full search down to a random
+/// mover. Frontends can use this to let the user pick which engine they play against.
+pub trait Engine {
+    /// Picks a move for the player to move within the limits. Returns `None` if the game is over.
+    /// The game is left in the same position it was given in.
+    fn best_move(&mut self, game: &mut Game, limits: &SearchLimits) -> Option<Move>;
+}
@@ -1,6 +1,12 @@
 pub mod bitboard;
+pub mod engine;
 pub mod file;
+#[cfg(feature = "lichess")]
+pub mod lichess;
+pub mod lines;
 pub mod movegen;
+pub mod pgn;
+pub mod polyglot;
 pub mod position;
 pub mod rank;
 pub mod square;
@@ -8,3 +14,4 @@ pub mod square;
 pub mod test_utils;
 pub mod utils;
 pub mod vectors;
+pub mod zobrist;
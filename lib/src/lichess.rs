@@ -0,0 +1,326 @@
+//! Downloads games from the [Lichess API](https://lichess.org/api) and replays them into
+//! [`Game`]s, such as to feed the analysis and tuning tools with real games. Only standard chess
+//! is supported, including games started from a position.
+
+use std::fmt;
+
+use serde::Deserialize;
+
+use crate::{
+    movegen::{moves::Move, pieces::piece::PieceColor},
+    pgn::PgnTags,
+    position::{fen::FenError, game::Game},
+};
+
+/// Where the Lichess API is hosted
+pub const LICHESS_URL: &str = "https://lichess.org";
+
+/// Why a game could not be downloaded or replayed
+#[derive(Debug)]
+pub enum LichessError {
+    /// The request failed, or Lichess refused it
+    Request(ureq::Error),
+    /// Lichess answered with something that is not a game
+    Json(serde_json::Error),
+    /// The game is of a variant other than standard chess
+    UnsupportedVariant(String),
+    /// The game started from a fen that could not be read
+    BadFen(FenError),
+    /// A move in the game could not be played
+    BadMove { index: usize, san: String },
+}
+
+impl fmt::Display for LichessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LichessError::Request(e) => write!(f, "the request to lichess failed: {e}"),
+            LichessError::Json(e) => write!(f, "lichess did not send a game: {e}"),
+            LichessError::UnsupportedVariant(variant) => {
+                write!(f, "the {variant} variant is not supported")
+            }
+            LichessError::BadFen(e) => write!(f, "the starting position is bad: {e}"),
+            LichessError::BadMove { index, san } => {
+                write!(f, "move {} ({san}) could not be played", index + 1)
+            }
+        }
+    }
+}
+
+impl From<ureq::Error> for LichessError {
+    fn from(e: ureq::Error) -> Self {
+        LichessError::Request(e)
+    }
+}
+
+impl From<serde_json::Error> for LichessError {
+    fn from(e: serde_json::Error) -> Self {
+        LichessError::Json(e)
+    }
+}
+
+/// A game as exported by Lichess, see <https://lichess.org/api#tag/Games>
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GameJson {
+    id: String,
+    #[serde(default)]
+    rated: bool,
+    variant: String,
+    speed: String,
+    status: String,
+    players: PlayersJson,
+    winner: Option<String>,
+    #[serde(default)]
+    moves: String,
+    initial_fen: Option<String>,
+    created_at: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct PlayersJson {
+    white: PlayerJson,
+    black: PlayerJson,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PlayerJson {
+    user: Option<UserJson>,
+    ai_level: Option<u8>,
+}
+
+#[derive(Deserialize)]
+struct UserJson {
+    name: String,
+}
+
+impl PlayerJson {
+    fn name(&self) -> String {
+        match (&self.user, self.ai_level) {
+            (Some(user), _) => user.name.clone(),
+            (None, Some(level)) => format!("Stockfish level {level}"),
+            (None, None) => "Anonymous".to_string(),
+        }
+    }
+}
+
+/// A game downloaded from Lichess, replayed up to its last move
+#[derive(Debug)]
+pub struct LichessGame {
+    /// The id in the game's url, such as q7ZvsdUF
+    pub id: String,
+    pub white: String,
+    pub black: String,
+    pub rated: bool,
+    /// How fast the game was, such as blitz or classical
+    pub speed: String,
+    /// How the game ended, such as mate, resign or outoftime. Games that are still going are
+    /// started.
+    pub status: String,
+    pub winner: Option<PieceColor>,
+    /// When the game was created, in milliseconds since the unix epoch
+    pub created_at: Option<u64>,
+    pub game: Game,
+}
+
+impl LichessGame {
+    /// Reads a game in the JSON format of the Lichess API and replays its moves
+    pub fn from_json(json: &str) -> Result<Self, LichessError> {
+        let json: GameJson = serde_json::from_str(json)?;
+        if !matches!(json.variant.as_str(), "standard" | "fromPosition") {
+            return Err(LichessError::UnsupportedVariant(json.variant));
+        }
+
+        let mut game = match &json.initial_fen {
+            Some(fen) => Game::from_fen(fen).map_err(LichessError::BadFen)?,
+            None => Game::default(),
+        };
+        for (index, san) in json.moves.split_whitespace().enumerate() {
+            let m = Move::from_san(san, &game).ok_or_else(|| LichessError::BadMove {
+                index,
+                san: san.to_string(),
+            })?;
+            game.play(&m);
+        }
+
+        Ok(Self {
+            white: json.players.white.name(),
+            black: json.players.black.name(),
+            winner: match json.winner.as_deref() {
+                Some("white") => Some(PieceColor::White),
+                Some("black") => Some(PieceColor::Black),
+                _ => None,
+            },
+            id: json.id,
+            rated: json.rated,
+            speed: json.speed,
+            status: json.status,
+            created_at: json.created_at,
+            game,
+        })
+    }
+
+    /// Where the game can be found on Lichess
+    pub fn url(&self) -> String {
+        format!("{LICHESS_URL}/{}", self.id)
+    }
+
+    /// The PGN result token, taken from Lichess since games can end in ways the board does not
+    /// show, such as by resignation or on time
+    pub fn result(&self) -> &'static str {
+        match (self.winner, self.status.as_str()) {
+            (Some(PieceColor::White), _) => "1-0",
+            (Some(PieceColor::Black), _) => "0-1",
+            (None, "created" | "started" | "aborted" | "noStart" | "unknownFinish") => "*",
+            (None, _) => "1/2-1/2",
+        }
+    }
+
+    /// The tags Lichess itself would put in the game's PGN
+    pub fn pgn_tags(&self) -> PgnTags {
+        let rated = if self.rated { "Rated" } else { "Casual" };
+        PgnTags {
+            event: format!("{rated} {} game", self.speed),
+            site: self.url(),
+            date: self
+                .created_at
+                .map(pgn_date)
+                .unwrap_or_else(|| PgnTags::default().date),
+            white: self.white.clone(),
+            black: self.black.clone(),
+            result: Some(self.result().to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// The game as a PGN, see [`Game::to_pgn`]
+    pub fn to_pgn(&self) -> String {
+        self.game.to_pgn(&self.pgn_tags())
+    }
+}
+
+/// Formats milliseconds since the unix epoch as a PGN date such as 2017.12.28
+fn pgn_date(millis: u64) -> String {
+    // Converts days to a proleptic Gregorian date, see
+    // https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = millis / 86_400_000 + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    format!("{year:04}.{month:02}.{day:02}")
+}
+
+/// Downloads the game with `id`, such as q7ZvsdUF from <https://lichess.org/q7ZvsdUF>
+pub fn fetch_game(id: &str) -> Result<LichessGame, LichessError> {
+    let json = ureq::get(format!("{LICHESS_URL}/game/export/{id}"))
+        .header("Accept", "application/json")
+        .call()?
+        .body_mut()
+        .read_to_string()?;
+    LichessGame::from_json(&json)
+}
+
+/// Downloads the last `max` games of `user`, newest first. Stops at the first game that can not
+/// be replayed, so use [`fetch_user_games_json`] to skip those instead.
+pub fn fetch_user_games(user: &str, max: usize) -> Result<Vec<LichessGame>, LichessError> {
+    fetch_user_games_json(user, max)?
+        .iter()
+        .map(|json| LichessGame::from_json(json))
+        .collect()
+}
+
+/// Downloads the last `max` games of `user`, newest first, without reading them. Each one can be
+/// read with [`LichessGame::from_json`].
+pub fn fetch_user_games_json(user: &str, max: usize) -> Result<Vec<String>, LichessError> {
+    let ndjson = ureq::get(format!("{LICHESS_URL}/api/games/user/{user}"))
+        .query("max", max.to_string())
+        .header("Accept", "application/x-ndjson")
+        .call()?
+        .body_mut()
+        .with_config()
+        .limit(u64::MAX)
+        .read_to_string()?;
+    Ok(ndjson
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESIGNED: &str = r#"{
+        "id": "q7ZvsdUF",
+        "rated": true,
+        "variant": "standard",
+        "speed": "blitz",
+        "perf": "blitz",
+        "createdAt": 1514505150384,
+        "status": "resign",
+        "players": {
+            "white": { "user": { "name": "Lance5500", "id": "lance5500" }, "rating": 2389 },
+            "black": { "aiLevel": 8 }
+        },
+        "winner": "white",
+        "moves": "e4 e5 Nf3 Nc6 Bb5 a6 Bxc6 dxc6 O-O"
+    }"#;
+
+    #[test]
+    fn replays_games() {
+        let game = LichessGame::from_json(RESIGNED).unwrap();
+        assert_eq!(game.white, "Lance5500");
+        assert_eq!(game.black, "Stockfish level 8");
+        assert_eq!(game.winner, Some(PieceColor::White));
+        assert_eq!(game.game.history().len(), 9);
+        assert_eq!(
+            game.game.to_fen(),
+            "r1bqkbnr/1pp2ppp/p1p5/4p3/4P3/5N2/PPPP1PPP/RNBQ1RK1 b kq - 1 5"
+        );
+
+        let pgn = game.to_pgn();
+        assert!(pgn.starts_with(
+            "[Event \"Rated blitz game\"]\n[Site \"https://lichess.org/q7ZvsdUF\"]\n[Date \"2017.12.28\"]\n"
+        ));
+        assert!(pgn.contains("[Result \"1-0\"]\n"));
+        assert!(pgn.ends_with("5. O-O 1-0\n"));
+    }
+
+    #[test]
+    fn refuses_what_can_not_be_replayed() {
+        let variant = RESIGNED.replace("\"standard\"", "\"chess960\"");
+        assert!(matches!(
+            LichessGame::from_json(&variant),
+            Err(LichessError::UnsupportedVariant(v)) if v == "chess960"
+        ));
+
+        let illegal = RESIGNED.replace("Bxc6", "Bxc7");
+        assert!(matches!(
+            LichessGame::from_json(&illegal),
+            Err(LichessError::BadMove { index: 6, san }) if san == "Bxc7"
+        ));
+
+        assert!(matches!(
+            LichessGame::from_json("{}"),
+            Err(LichessError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn pgn_dates() {
+        assert_eq!(pgn_date(0), "1970.01.01");
+        assert_eq!(pgn_date(951_782_400_000), "2000.02.29");
+        assert_eq!(pgn_date(1_514_505_150_384), "2017.12.28");
+    }
+}
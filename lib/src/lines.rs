@@ -0,0 +1,162 @@
+use crate::{
+    bitboard::{BitBoard, EMPTY},
+    square::Square,
+};
+
+/// The squares strictly between two squares that share a rank, file or diagonal. Empty for squares
+/// that do not line up.
+pub static BETWEEN: [[BitBoard; 64]; 64] = {
+    let mut table = [[EMPTY; 64]; 64];
+    let mut from = 0;
+    while from < 64 {
+        let mut to = 0;
+        while to < 64 {
+            table[from][to] = between_squares(from as i8, to as i8);
+            to += 1;
+        }
+        from += 1;
+    }
+    table
+};
+
+/// The whole line running through two squares that share a rank, file or diagonal, edge to edge
+/// and including both squares. Empty for squares that do not line up.
+pub static LINE: [[BitBoard; 64]; 64] = {
+    let mut table = [[EMPTY; 64]; 64];
+    let mut from = 0;
+    while from < 64 {
+        let mut to = 0;
+        while to < 64 {
+            table[from][to] = line_through(from as i8, to as i8);
+            to += 1;
+        }
+        from += 1;
+    }
+    table
+};
+
+/// The file and rank step to walk from `from` towards `to`, if they line up
+const fn step(from: i8, to: i8) -> Option<(i8, i8)> {
+    if from == to {
+        return None;
+    }
+
+    let file_diff = (to & 7) - (from & 7);
+    let rank_diff = (to >> 3) - (from >> 3);
+
+    if file_diff != 0 && rank_diff != 0 && file_diff.abs() != rank_diff.abs() {
+        return None;
+    }
+
+    Some((file_diff.signum(), rank_diff.signum()))
+}
+
+const fn on_board(file: i8, rank: i8) -> bool {
+    file >= 0 && file < 8 && rank >= 0 && rank < 8
+}
+
+const fn between_squares(from: i8, to: i8) -> BitBoard {
+    let Some((file_step, rank_step)) = step(from, to) else {
+        return EMPTY;
+    };
+
+    let mut bb = 0u64;
+    let mut file = (from & 7) + file_step;
+    let mut rank = (from >> 3) + rank_step;
+    while rank * 8 + file != to {
+        bb |= 1 << (rank * 8 + file);
+        file += file_step;
+        rank += rank_step;
+    }
+
+    BitBoard::new(bb)
+}
+
+const fn line_through(from: i8, to: i8) -> BitBoard {
+    let Some((file_step, rank_step)) = step(from, to) else {
+        return EMPTY;
+    };
+
+    // Back up to the edge of the board, then walk all the way across
+    let mut file = from & 7;
+    let mut rank = from >> 3;
+    while on_board(file - file_step, rank - rank_step) {
+        file -= file_step;
+        rank -= rank_step;
+    }
+
+    let mut bb = 0u64;
+    while on_board(file, rank) {
+        bb |= 1 << (rank * 8 + file);
+        file += file_step;
+        rank += rank_step;
+    }
+
+    BitBoard::new(bb)
+}
+
+/// The squares strictly between `from` and `to`, or nothing if they do not share a rank, file or
+/// diagonal
+#[inline]
+pub fn between(from: Square, to: Square) -> BitBoard {
+    BETWEEN[from.index()][to.index()]
+}
+
+/// The edge to edge line through `from` and `to`, or nothing if they do not share a rank, file or
+/// diagonal
+#[inline]
+pub fn line(from: Square, to: Square) -> BitBoard {
+    LINE[from.index()][to.index()]
+}
+
+/// Whether the three squares are on one rank, file or diagonal
+#[inline]
+pub fn aligned(a: Square, b: Square, c: Square) -> bool {
+    line(a, b).has_square(BitBoard::from_square(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{file::File, rank::Rank};
+
+    use super::*;
+
+    #[test]
+    fn between_matches_walking() {
+        for from in 0..64 {
+            for to in 0..64 {
+                let (from, to) = (Square::new(from), Square::new(to));
+                let mut walked = EMPTY;
+                if let Some(direction) = from.direction_to(to) {
+                    let mut current = from;
+                    while let Some(next) = current.walk(&direction)
+                        && next != to
+                    {
+                        walked.set(next);
+                        current = next;
+                    }
+                }
+
+                assert_eq!(between(from, to), walked, "{} to {}", from, to);
+            }
+        }
+    }
+
+    #[test]
+    fn lines_span_the_board() {
+        assert_eq!(line(Square::C3, Square::C6), File::C.mask());
+        assert_eq!(line(Square::B4, Square::G4), Rank::Fourth.mask());
+        assert_eq!(line(Square::B2, Square::D4), line(Square::H8, Square::A1));
+        assert_eq!(line(Square::B2, Square::D4).popcnt(), 8);
+        assert_eq!(line(Square::A2, Square::B1).popcnt(), 2);
+        assert_eq!(line(Square::A1, Square::B3), EMPTY);
+        assert_eq!(line(Square::E4, Square::E4), EMPTY);
+    }
+
+    #[test]
+    fn alignment() {
+        assert!(aligned(Square::A1, Square::C3, Square::H8));
+        assert!(aligned(Square::E1, Square::E8, Square::E4));
+        assert!(!aligned(Square::E1, Square::E8, Square::D4));
+    }
+}
@@ -0,0 +1,419 @@
+use crate::{
+    bitboard::{BitBoard, EMPTY},
+    lines,
+    movegen::{
+        move_list::MoveList,
+        moves::{Move, push_attacks_to_moves_with_occupied},
+        pieces::{
+            bishop, king, knight,
+            pawn::{self, PawnTargets},
+            piece::{PieceColor, PieceType},
+            rook,
+        },
+    },
+    position::{
+        castling::{self, CastleSide},
+        game::Game,
+    },
+    square::Square,
+    vectors::Vector,
+};
+
+/// Generates strictly legal moves for the side to move. Unlike [`LegalMovesFilter`], nothing is
+/// generated and thrown away afterwards, and none of the cached attack or check ray boards on
+/// `Game` are trusted. Everything needed to decide legality is worked out up front from the piece
+/// bitboards alone.
+///
+/// [`LegalMovesFilter`]: crate::movegen::legal_moves::LegalMovesFilter
+pub struct LegalMoveGen<'a> {
+    game: &'a Game,
+    color: PieceColor,
+    king: Square,
+    /// Enemy pieces giving check
+    pub checkers: BitBoard,
+    /// Squares a move other than a king move has to land on. Every square when not in check.
+    pub check_mask: BitBoard,
+    /// Friendly pieces pinned to the king
+    pub pinned: BitBoard,
+    /// Enemy ray pieces pinning something to the king
+    pub pinners: BitBoard,
+    /// Squares the king can not step onto
+    pub king_danger: BitBoard,
+}
+
+impl<'a> LegalMoveGen<'a> {
+    pub fn new(game: &'a Game) -> Self {
+        let color = game.turn;
+        let enemy = color.opponent();
+        let kingbb = *game.get_pieces(&PieceType::King, &color);
+        let king = kingbb.to_square();
+
+        let diagonal = *game.get_pieces(&PieceType::Bishop, &enemy)
+            | *game.get_pieces(&PieceType::Queen, &enemy);
+        let straight = *game.get_pieces(&PieceType::Rook, &enemy)
+            | *game.get_pieces(&PieceType::Queen, &enemy);
+
+        let checkers = game.attackers_to(king, enemy);
+
+        let mut check_mask = !EMPTY;
+        if checkers.popcnt() == 1 {
+            let checker = checkers.to_square();
+            check_mask = checkers;
+            if (diagonal | straight).has_square(checkers) {
+                check_mask |= lines::between(king, checker);
+            }
+        }
+
+        let (pinned, pinners) = game.pins(color);
+
+        // The king can not hide behind itself, so it is taken off the board before looking at
+        // what the enemy sliders reach
+        let kingless = game.occupied ^ kingbb;
+        let mut king_danger = pawn::attacks(*game.get_pieces(&PieceType::Pawn, &enemy), enemy);
+        for sq in *game.get_pieces(&PieceType::Knight, &enemy) {
+            king_danger |= knight::attacks(sq);
+        }
+        for sq in *game.get_pieces(&PieceType::King, &enemy) {
+            king_danger |= king::attacks(sq);
+        }
+        for sq in diagonal {
+            king_danger |= bishop::magic_attacks(sq, kingless);
+        }
+        for sq in straight {
+            king_danger |= rook::magic_attacks(sq, kingless);
+        }
+
+        Self {
+            game,
+            color,
+            king,
+            checkers,
+            check_mask,
+            pinned,
+            pinners,
+            king_danger,
+        }
+    }
+
+    /// Every square a pinned piece on `sq` can move to without exposing the king, including the
+    /// capture of its pinner
+    fn pin_ray(&self, sq: Square) -> BitBoard {
+        for pinner in self.pinners {
+            if lines::between(self.king, pinner).has_square(BitBoard::from_square(sq)) {
+                return lines::between(self.king, pinner) | BitBoard::from_square(pinner);
+            }
+        }
+
+        EMPTY
+    }
+
+    /// Restricts the targets of the piece on `sq` to the ones that keep the king safe
+    fn legal_targets(&self, sq: Square, targets: BitBoard) -> BitBoard {
+        let mut targets = targets & self.check_mask;
+        if self.pinned.has_square(BitBoard::from_square(sq)) {
+            targets &= self.pin_ray(sq);
+        }
+        targets
+    }
+
+    /// Whether the side to move is in check
+    pub fn in_check(&self) -> bool {
+        self.checkers != EMPTY
+    }
+
+    fn push_king_moves<V: Vector<Move>>(&self, moves: &mut V) {
+        let game = self.game;
+        let friendly = *game.get_occupied(&self.color);
+        let enemy_occupied = *game.get_occupied(&self.color.opponent());
+
+        let king_targets = king::attacks(self.king) & !friendly & !self.king_danger;
+        push_attacks_to_moves_with_occupied(moves, king_targets, self.king, game, enemy_occupied);
+    }
+
+    pub fn push_moves<V: Vector<Move>>(&self, moves: &mut V) {
+        if self.in_check() {
+            self.push_evasions(moves);
+            return;
+        }
+
+        let game = self.game;
+        let friendly = *game.get_occupied(&self.color);
+        let enemy_occupied = *game.get_occupied(&self.color.opponent());
+
+        self.push_king_moves(moves);
+        self.push_pawn_moves(moves);
+
+        // A pinned knight can never stay on its pin ray
+        for sq in *game.get_pieces(&PieceType::Knight, &self.color) & !self.pinned {
+            let targets = knight::attacks(sq) & !friendly;
+            push_attacks_to_moves_with_occupied(moves, targets, sq, game, enemy_occupied);
+        }
+
+        for piece in [PieceType::Bishop, PieceType::Rook, PieceType::Queen] {
+            for sq in *game.get_pieces(&piece, &self.color) {
+                let targets = piece.magic_attacks(sq, game.occupied) & !friendly;
+                let targets = self.legal_targets(sq, targets);
+                push_attacks_to_moves_with_occupied(moves, targets, sq, game, enemy_occupied);
+            }
+        }
+
+        self.push_castling_moves(moves);
+    }
+
+    /// Pushes every legal way out of check. Rather than generating every move and keeping the ones
+    /// that land on the check mask, this looks up which pieces can reach the checker or the
+    /// squares in front of it directly.
+    pub fn push_evasions<V: Vector<Move>>(&self, moves: &mut V) {
+        self.push_king_moves(moves);
+
+        // Only the king can get out of double check
+        if self.checkers.popcnt() > 1 {
+            return;
+        }
+
+        let game = self.game;
+        let checker = self.checkers.to_square();
+        let captured = game.piece_lookup(checker).map(|(piece, _)| piece);
+
+        // Pawns capture differently than they move, so they are handled on their own
+        self.push_pawn_moves(moves);
+
+        // A pinned piece can never get in between a check, nor capture the checker, without
+        // leaving its pin ray
+        let pieces = *game.get_occupied(&self.color)
+            & !*game.get_pieces(&PieceType::Pawn, &self.color)
+            & !*game.get_pieces(&PieceType::King, &self.color)
+            & !self.pinned;
+
+        for from in game.attackers_to(checker, self.color) & pieces {
+            moves.push(Move::Normal {
+                from,
+                to: checker,
+                capture: captured,
+            });
+        }
+
+        for to in self.check_mask ^ self.checkers {
+            for from in game.attackers_to(to, self.color) & pieces {
+                moves.push(Move::Normal {
+                    from,
+                    to,
+                    capture: None,
+                });
+            }
+        }
+    }
+
+    fn push_pawn_moves<V: Vector<Move>>(&self, moves: &mut V) {
+        let game = self.game;
+        let pawns = *game.get_pieces(&PieceType::Pawn, &self.color);
+        let unoccupied = !game.occupied;
+        let enemy_occupied = *game.get_occupied(&self.color.opponent());
+
+        let free = PawnTargets::new(pawns & !self.pinned, self.color, unoccupied, enemy_occupied);
+        pawn::push_target_moves(moves, game, self.color, free.masked(self.check_mask));
+
+        for sq in pawns & self.pinned {
+            let sqbb = BitBoard::from_square(sq);
+            let targets = PawnTargets::new(sqbb, self.color, unoccupied, enemy_occupied);
+            let mask = self.check_mask & self.pin_ray(sq);
+            pawn::push_target_moves(moves, game, self.color, targets.masked(mask));
+        }
+
+        let Some(target) = game.en_passant_target else {
+            return;
+        };
+
+        // En passant removes two pieces from the same rank at once, so rather than reasoning about
+        // pins the position after the capture is checked directly
+        let targetbb = BitBoard::from_square(target);
+        let Some(captured) = target.backward(&self.color) else {
+            return;
+        };
+        let capturedbb = BitBoard::from_square(captured);
+
+        for from in pawn::attacks(targetbb, self.color.opponent()) & pawns {
+            let occupied = (game.occupied ^ BitBoard::from_square(from) ^ capturedbb) | targetbb;
+            let checkers =
+                game.attackers_to_with_occupied(self.king, self.color.opponent(), occupied);
+            if checkers == EMPTY {
+                moves.push(Move::CaptureEnPassant {
+                    from: from.get_file(),
+                });
+            }
+        }
+    }
+
+    fn push_castling_moves<V: Vector<Move>>(&self, moves: &mut V) {
+        for side in [CastleSide::Queenside, CastleSide::Kingside] {
+            if castling::is_castle_legal(self.game, side, self.color) {
+                moves.push(Move::Castle { side });
+            }
+        }
+    }
+}
+
+impl Game {
+    /// Pushes every strictly legal move for the current player
+    pub fn push_legal_moves<V: Vector<Move>>(&self, moves: &mut V) {
+        LegalMoveGen::new(self).push_moves(moves);
+    }
+
+    /// Generates every strictly legal move for the current player without filtering psuedo legal
+    /// moves or mutating the game
+    pub fn generate_legal_moves(&self) -> MoveList {
+        let mut moves = MoveList::new();
+        self.push_legal_moves(&mut moves);
+        moves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        file::File,
+        position::game::State,
+        test_utils::{assert_meq, should_generate, shouldnt_generate},
+    };
+
+    fn perft(game: &mut Game, depth: u32) -> u64 {
+        let moves = game.generate_legal_moves();
+        if depth == 1 {
+            return moves.len() as u64;
+        }
+
+        let mut nodes = 0;
+        for m in moves {
+            game.play(&m);
+            nodes += perft(game, depth - 1);
+            game.unplay(&m);
+        }
+        nodes
+    }
+
+    #[test]
+    fn perft_starting_position() {
+        let mut game = Game::default();
+        assert_eq!(perft(&mut game, 1), 20);
+        assert_eq!(perft(&mut game, 2), 400);
+        assert_eq!(perft(&mut game, 3), 8902);
+    }
+
+    #[test]
+    fn perft_kiwipete() {
+        let fen = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+        let mut game = Game::from_fen(fen).unwrap();
+        assert_eq!(perft(&mut game, 1), 48);
+        assert_eq!(perft(&mut game, 2), 2039);
+    }
+
+    #[test]
+    fn perft_en_passant_pins() {
+        let fen = "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1";
+        let mut game = Game::from_fen(fen).unwrap();
+        assert_eq!(perft(&mut game, 1), 14);
+        assert_eq!(perft(&mut game, 2), 191);
+        assert_eq!(perft(&mut game, 3), 2812);
+    }
+
+    #[test]
+    fn perft_promotions() {
+        let fen = "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8";
+        let mut game = Game::from_fen(fen).unwrap();
+        assert_eq!(perft(&mut game, 1), 44);
+        assert_eq!(perft(&mut game, 2), 1486);
+    }
+
+    #[test]
+    fn agrees_with_filtered_legal_moves() {
+        for fen in [
+            "4k3/4r3/8/8/2N5/8/4K3/8 w - - 0 1",
+            "4k3/4r3/8/8/1B6/3P1P2/3PKP2/3RRR2 w - - 0 1",
+            "4k3/4r3/8/8/3P1P2/4B3/3PK3/6P1 w - - 0 1",
+            "4k3/4r3/8/6Qb/8/2R5/4KP2/8 w - - 0 1",
+            "r2q1rk1/p2n1pp1/1p3n1p/2b5/8/1R3P1N/P2pP1PP/2BQKB1R w K - 0 14",
+            "rn5b/3K1k1r/p2p1p1p/Pb2q2P/p5P1/1P6/6PR/8 w - - 7 54",
+            "kQ6/p7/Pp6/1P6/4p3/4R3/4P1p1/6K1 b - - 0 1",
+            "8/8/8/2rPp1nK/8/8/8/k7 w - e6 0 38",
+            // Pinned by rooks on both sides of the king
+            "r3r3/8/4R3/8/4K3/8/4R3/k3r3 w - - 0 1",
+        ] {
+            let game = Game::from_fen(fen).unwrap();
+            assert_meq(game.generate_legal_moves(), game.legal_moves());
+        }
+    }
+
+    #[test]
+    fn pins_from_both_sides_of_the_king() {
+        let fen = "r3r3/8/4R3/8/4K3/8/4R3/k3r3 w - - 0 1";
+        let game = Game::from_fen(fen).unwrap();
+        let moves = game.generate_legal_moves();
+        assert_eq!(moves.len(), 13);
+        for to in [Square::E5, Square::E7, Square::E8] {
+            assert!(moves.contains(&Move::infer(Square::E6, to, &game)));
+        }
+    }
+
+    #[test]
+    fn evasions_block_capture_or_run() {
+        let fen = "4k3/8/8/b7/5R2/1N6/2P5/4K3 w - - 0 1";
+        let game = Game::from_fen(fen).unwrap();
+        let generator = LegalMoveGen::new(&game);
+        assert!(generator.in_check());
+
+        let mut evasions = Vec::new();
+        generator.push_evasions(&mut evasions);
+        let expected = [
+            (Square::E1, Square::D1),
+            (Square::E1, Square::F1),
+            (Square::E1, Square::E2),
+            (Square::E1, Square::F2),
+            (Square::B3, Square::A5),
+            (Square::B3, Square::D2),
+            (Square::F4, Square::B4),
+            (Square::C2, Square::C3),
+        ]
+        .into_iter()
+        .map(|(from, to)| Move::infer(from, to, &game))
+        .collect::<Vec<_>>();
+        assert_meq(evasions, expected);
+    }
+
+    #[test]
+    fn no_moves_when_checkmated() {
+        let fen = "1kb2b1r/1p1p1ppp/1Np5/8/4P1PP/1P3PK1/r6q/8 w - - 1 27";
+        let game = Game::from_fen(fen).unwrap();
+        assert!(game.generate_legal_moves().is_empty());
+    }
+
+    #[test]
+    fn can_not_castle_out_of_or_through_check() {
+        let fen = "4k3/8/8/8/8/8/8/R3K1r1 w Q - 0 1";
+        let game = Game::from_fen(fen).unwrap();
+        let castle = Move::Castle {
+            side: CastleSide::Queenside,
+        };
+        shouldnt_generate(&game.generate_legal_moves(), &castle);
+        assert!(!game.is_legal(&castle));
+
+        let fen = "4k3/8/8/8/8/8/3r4/R3K3 w Q - 0 1";
+        let game = Game::from_fen(fen).unwrap();
+        shouldnt_generate(&game.generate_legal_moves(), &castle);
+        assert!(!game.is_legal(&castle));
+
+        let fen = "4k3/8/8/8/8/8/1r6/R3K3 w Q - 0 1";
+        let game = Game::from_fen(fen).unwrap();
+        assert_eq!(game.state, State::InProgress);
+        should_generate(&game.generate_legal_moves(), &castle);
+    }
+
+    #[test]
+    fn en_passant_can_not_expose_the_king() {
+        let fen = "8/8/8/2rPp2K/8/8/8/k7 w - e6 0 38";
+        let game = Game::from_fen(fen).unwrap();
+        let m = Move::CaptureEnPassant { from: File::D };
+        shouldnt_generate(&game.generate_legal_moves(), &m);
+    }
+}
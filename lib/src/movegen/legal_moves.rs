@@ -1,7 +1,7 @@
 use crate::{
     bitboard::{BitBoard, EMPTY},
     movegen::{moves::Move, pieces::piece::PieceType},
-    position::game::Game,
+    position::{castling, game::Game},
     square::Square,
 };
 
@@ -11,7 +11,7 @@ pub struct LegalMovesFilter<'a> {
     kingbb: BitBoard,
     king_attackers: BitBoard,
     attack_board: BitBoard,
-    checks: BitBoard,
+    pinned: BitBoard,
 }
 
 impl<'a> LegalMovesFilter<'a> {
@@ -24,7 +24,7 @@ impl<'a> LegalMovesFilter<'a> {
             king_attackers: game
                 .attackers((*game.get_pieces(&PieceType::King, &game.turn)).to_square()),
             attack_board: *game.get_attacks(&enemy),
-            checks: *game.get_check_rays(&enemy),
+            pinned: game.pinned(game.turn),
         }
     }
 
@@ -34,8 +34,8 @@ impl<'a> LegalMovesFilter<'a> {
         let frombb = BitBoard::from_square(from);
         let tobb = BitBoard::from_square(to);
 
-        if !self.check_special(m, from, frombb, to) {
-            return false;
+        if let Some(legal) = self.check_special(m, from, to) {
+            return legal;
         }
 
         let is_moving_king = self.kingbb.has_square(frombb);
@@ -50,7 +50,11 @@ impl<'a> LegalMovesFilter<'a> {
                     && attacking_piece.is_ray_piece()
                     && attacker.path_to(self.king)
                         & attacking_piece
-                            .psuedo_legal_targets_fast(self.game, &attacker)
+                            .psuedo_legal_targets_for(
+                                self.game,
+                                &attacker,
+                                self.game.turn.opponent(),
+                            )
                             .targets
                         & tobb
                         != EMPTY;
@@ -62,10 +66,8 @@ impl<'a> LegalMovesFilter<'a> {
                     return false;
                 }
             }
-            2 => {
-                if !is_moving_king {
-                    return false;
-                }
+            2 if !is_moving_king => {
+                return false;
             }
             _ => {}
         }
@@ -76,19 +78,15 @@ impl<'a> LegalMovesFilter<'a> {
                 return false;
             }
         } else {
-            // Prevent moving a pinned piece
-            if self.checks.has_square(frombb) {
-                // TODO: there are surely faster ways to do this than computing `self.checkers` per pinned move,
-                // such as computing this once per pinned piece in `LegalMovesFilter::new` or tracking
-                // pinning pieces in `self.game`
-                let (checking_piecebb, check_ray) = self
+            // A pinned piece can only move along its pin ray, which includes capturing the pinner
+            if self.pinned.has_square(frombb) {
+                // TODO: there are surely faster ways to do this than finding the pin ray per pinned
+                // move, such as computing every pin ray once in `LegalMovesFilter::new`
+                let pin_ray = self
                     .game
-                    .checkers(frombb)
-                    .expect("Could not find the piece populating `self.checks`");
-                // Allow capturing the checking piece & moving within the check ray
-                if tobb != checking_piecebb
-                    && !(check_ray.has_square(frombb) && check_ray.has_square(tobb))
-                {
+                    .pin_ray(from)
+                    .expect("Could not find the pinner of a pinned piece");
+                if !pin_ray.has_square(tobb) {
                     return false;
                 }
             }
@@ -97,43 +95,31 @@ impl<'a> LegalMovesFilter<'a> {
         true
     }
 
-    fn check_special(&self, m: Move, from: Square, frombb: BitBoard, to: Square) -> bool {
-        if let Move::CaptureEnPassant { .. } = m {
-            let pawn_rank = from.get_rank();
-            let king_rank = self.king.get_rank();
-
-            if pawn_rank != king_rank {
-                return true;
+    /// Castling and en passant are decided on their own, since neither fits the pin and check ray
+    /// reasoning used for every other move
+    fn check_special(&self, m: Move, from: Square, to: Square) -> Option<bool> {
+        match m {
+            Move::Castle { side } => {
+                Some(castling::is_castle_legal(self.game, side, self.game.turn))
             }
-
-            let remaining_row =
-                self.game.occupied ^ frombb ^ to.get_file().mask() & pawn_rank.mask();
-
-            if remaining_row.popcnt() < 2 {
-                return true;
-            }
-
-            // Ensure that the en_passant_capture does not leave an enemy horizontal ray
-            // piece staring at our king
-            let mut was_king_or_horizontal_ray = false;
-            for sq in remaining_row {
-                let (piece, color) = unsafe { self.game.piece_lookup(sq).unwrap_unchecked() };
-                let is_king_or_horizontal_ray = (color == self.game.turn
-                    && piece == PieceType::King)
-                    || (color != self.game.turn
-                        && (piece == PieceType::Rook || piece == PieceType::Queen));
-
-                if is_king_or_horizontal_ray && was_king_or_horizontal_ray {
-                    return false;
-                }
-
-                was_king_or_horizontal_ray = is_king_or_horizontal_ray;
-            }
-
-            return true;
+            Move::CaptureEnPassant { .. } => Some(self.is_en_passant_safe(from, to)),
+            _ => None,
         }
+    }
 
-        true
+    /// En passant takes two pawns off the board at once, which can uncover the king along their
+    /// rank or along a diagonal through the captured pawn. Rather than looking for those pins, the
+    /// occupancy after the capture is x-rayed from the king. This also covers capturing a pawn
+    /// that just gave check.
+    fn is_en_passant_safe(&self, from: Square, to: Square) -> bool {
+        let captured = Square::make_square(from.get_rank(), to.get_file());
+        let occupied =
+            (self.game.occupied ^ BitBoard::from_square(from) ^ BitBoard::from_square(captured))
+                | BitBoard::from_square(to);
+
+        self.game
+            .attackers_to_with_occupied(self.king, self.game.turn.opponent(), occupied)
+            == EMPTY
     }
 }
 
@@ -141,7 +127,7 @@ impl<'a> LegalMovesFilter<'a> {
 mod tests {
     use super::*;
 
-    use crate::position::game::Game;
+    use crate::{file::File, position::game::Game};
 
     #[test]
     fn pawn_recapture_through_queen_ray_should_be_legal() {
@@ -170,4 +156,49 @@ mod tests {
         assert!(lmf.check(right));
         assert!(lmf.check(capture_attacker));
     }
+
+    #[test]
+    fn pinned_piece_cannot_capture_checking_piece() {
+        let fen = "1k2R3/1pn5/p7/8/3K1Q2/8/8/8 b - - 3 3";
+        let game = Game::from_fen(fen).unwrap();
+        let m = Move::infer(Square::C7, Square::E8, &game);
+        let lmf = LegalMovesFilter::new(&game);
+        assert!(!lmf.check(m));
+    }
+
+    #[test]
+    fn pinned_piece_can_slide_along_its_pin_ray() {
+        let fen = "4k3/8/8/1b6/8/3B4/4K3/8 w - - 0 1";
+        let game = Game::from_fen(fen).unwrap();
+        let lmf = LegalMovesFilter::new(&game);
+        assert!(lmf.check(Move::infer(Square::D3, Square::C4, &game)));
+        assert!(lmf.check(Move::infer(Square::D3, Square::B5, &game)));
+        assert!(!lmf.check(Move::infer(Square::D3, Square::E4, &game)));
+    }
+
+    #[test]
+    fn en_passant_can_not_uncover_the_king() {
+        let capture = Move::CaptureEnPassant { from: File::E };
+        for (fen, legal) in [
+            // Both pawns leave the rank between the rook and the king
+            ("8/8/8/K2pP2r/8/8/8/7k w - d6 0 1", false),
+            // The captured pawn was shielding the king from the bishop
+            ("k7/1b6/8/3pP3/8/5K2/8/8 w - d6 0 1", false),
+            // Capturing the pawn that gives check
+            ("k7/8/8/3pP3/4K3/8/8/8 w - d6 0 1", true),
+            // Pieces on other ranks do not matter
+            ("8/8/r7/3pP2K/8/8/8/k7 w - d6 0 1", true),
+            ("8/8/8/r2pP1NK/8/8/8/k7 w - d6 0 1", true),
+        ] {
+            let game = Game::from_fen(fen).unwrap();
+            let lmf = LegalMovesFilter::new(&game);
+            assert_eq!(lmf.check(capture), legal, "{fen}");
+            assert_eq!(game.is_legal(&capture), legal, "{fen}");
+            assert_eq!(
+                game.generate_legal_moves().contains(&capture),
+                legal,
+                "{fen}"
+            );
+        }
+    }
 }
@@ -1,19 +1,145 @@
+use std::{fmt, str::FromStr};
+
 use crate::{
     add_piece,
     bitboard::BitBoard,
     castle, get_pieces_mut,
     movegen::{
-        moves::Move,
+        moves::{Move, UciMoveError},
         pieces::piece::{PieceColor, PieceType},
     },
     position::castling::{self, CastleSide},
-    position::game::Game,
+    position::game::{Game, State},
     rank::Rank,
     remove_piece,
     square::Square,
+    zobrist,
 };
 
+/// Why [`Game::try_play`] refused a move
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IllegalMove {
+    /// The game is already over
+    GameOver(State),
+    /// The side to move has no piece on the square the move starts from
+    NoPieceToMove(Square),
+    /// The piece can not make this move
+    NotLegal(Move),
+}
+
+impl fmt::Display for IllegalMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IllegalMove::GameOver(state) => write!(f, "the game is over by {state:?}"),
+            IllegalMove::NoPieceToMove(sq) => write!(f, "there is no piece to move on {sq}"),
+            IllegalMove::NotLegal(m) => write!(f, "{m} is not a legal move"),
+        }
+    }
+}
+
+/// Why [`Game::from_moves`] or [`Game::apply_uci_moves`] stopped at a move
+#[derive(Debug, Clone, PartialEq)]
+pub struct BadMove {
+    /// Where the move is in the list, starting from 0
+    pub index: usize,
+    /// The move as it was written
+    pub notation: String,
+    pub reason: BadMoveReason,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BadMoveReason {
+    /// The move is not written in uci or coordinate notation
+    Unreadable(UciMoveError),
+    Illegal(IllegalMove),
+}
+
+impl fmt::Display for BadMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "move {} ({}) ", self.index + 1, self.notation)?;
+        match self.reason {
+            BadMoveReason::Unreadable(e) => write!(f, "could not be read: {e}"),
+            BadMoveReason::Illegal(e) => write!(f, "could not be played: {e}"),
+        }
+    }
+}
+
 impl Game {
+    /// Checks if `m` can be played, without generating every legal move. Only the moves of the
+    /// piece being moved are generated, so this is cheap enough for validating moves from the
+    /// outside, such as UCI input or clicks in a GUI.
+    pub fn is_legal(&self, m: &Move) -> bool {
+        self.check_legal(m).is_ok()
+    }
+
+    /// Plays `m` if it is legal, otherwise leaves the game untouched and says why it was refused
+    pub fn try_play(&mut self, m: &Move) -> Result<(), IllegalMove> {
+        self.check_legal(m)?;
+        self.play(m);
+        Ok(())
+    }
+
+    /// Builds a game by playing `moves` from `start`. See [`Game::apply_uci_moves`] for the
+    /// notation the moves can be written in.
+    pub fn from_moves<S: AsRef<str>>(
+        mut start: Game,
+        moves: impl IntoIterator<Item = S>,
+    ) -> Result<Self, BadMove> {
+        start.apply_uci_moves(moves)?;
+        Ok(start)
+    }
+
+    /// Plays each of `moves` in order. They can be in uci notation such as e2e4 and e7e8q, or in
+    /// coordinate notation such as e2-e4 and d7xc8=N. Stops at the first move that can not be read
+    /// or is not legal, leaving the game as it was after the move before it.
+    pub fn apply_uci_moves<S: AsRef<str>>(
+        &mut self,
+        moves: impl IntoIterator<Item = S>,
+    ) -> Result<(), BadMove> {
+        for (index, notation) in moves.into_iter().enumerate() {
+            let notation = notation.as_ref();
+            self.play_coordinates(notation).map_err(|reason| BadMove {
+                index,
+                notation: notation.to_string(),
+                reason,
+            })?;
+        }
+        Ok(())
+    }
+
+    fn play_coordinates(&mut self, notation: &str) -> Result<(), BadMoveReason> {
+        // Inferring a move needs a piece on the square it starts from
+        let from = Square::from_str(notation)
+            .map_err(|e| BadMoveReason::Unreadable(UciMoveError::BadSquare(e)))?;
+        if !self
+            .piece_lookup(from)
+            .is_some_and(|(_, color)| color == self.turn)
+        {
+            return Err(BadMoveReason::Illegal(IllegalMove::NoPieceToMove(from)));
+        }
+
+        let m = Move::from_coordinates(notation, self).map_err(BadMoveReason::Unreadable)?;
+        self.try_play(&m).map_err(BadMoveReason::Illegal)
+    }
+
+    fn check_legal(&self, m: &Move) -> Result<(), IllegalMove> {
+        if self.state != State::InProgress {
+            return Err(IllegalMove::GameOver(self.state));
+        }
+
+        let from = m.from(self.turn);
+        match self.piece_lookup(from) {
+            Some((piece, color)) if color == self.turn => {
+                if piece.legal_moves(self, &from).contains(m) {
+                    Ok(())
+                } else {
+                    Err(IllegalMove::NotLegal(*m))
+                }
+            }
+            _ => Err(IllegalMove::NoPieceToMove(from)),
+        }
+    }
+
     /// Plays a move on the board
     pub fn play(&mut self, m: &Move) {
         self.log(format!(
@@ -47,6 +173,7 @@ impl Game {
         }
 
         self.capture_position();
+        self.move_history.push(*m);
 
         // This is a macro to avoid borrow-checker shenanigans that a lambda would have
         macro_rules! revoke_castling_rights {
@@ -78,13 +205,13 @@ impl Game {
                     .expect("Couldn't find piece to move!");
 
                 if let Some(enemy) = capture {
-                    let pieces = get_pieces_mut!(self, enemy, &color.opponent());
-                    remove_piece!(self, pieces, tobb, *to);
+                    let pieces = get_pieces_mut!(self.position, enemy, &color.opponent());
+                    remove_piece!(self.position, pieces, tobb, *to);
                 }
 
-                let pieces = get_pieces_mut!(self, &piece, &color);
-                remove_piece!(self, pieces, frombb, *from);
-                add_piece!(self, pieces, tobb, *to, piece, color);
+                let pieces = get_pieces_mut!(self.position, &piece, &color);
+                remove_piece!(self.position, pieces, frombb, *from);
+                add_piece!(self.position, pieces, tobb, *to, piece, color);
 
                 // Revoking appropriate castling rights
                 match piece {
@@ -119,9 +246,9 @@ impl Game {
 
                 let frombb = BitBoard::from_square(from);
                 let tobb = BitBoard::from_square(to);
-                let pieces = get_pieces_mut!(self, &PieceType::Pawn, &color);
-                remove_piece!(self, pieces, frombb, from);
-                add_piece!(self, pieces, tobb, to, PieceType::Pawn, color);
+                let pieces = get_pieces_mut!(self.position, &PieceType::Pawn, &color);
+                remove_piece!(self.position, pieces, frombb, from);
+                add_piece!(self.position, pieces, tobb, to, PieceType::Pawn, color);
             }
             Move::CaptureEnPassant { from: from_file } => {
                 let color = self.turn;
@@ -144,14 +271,14 @@ impl Game {
                     .expect("Can't find pawn behind en_passant_target!");
                 let en_passant_bb = BitBoard::from_square(en_passant_sq);
 
-                let pieces = get_pieces_mut!(self, &PieceType::Pawn, &color.opponent());
-                remove_piece!(self, pieces, en_passant_bb, en_passant_sq);
+                let pieces = get_pieces_mut!(self.position, &PieceType::Pawn, &color.opponent());
+                remove_piece!(self.position, pieces, en_passant_bb, en_passant_sq);
 
                 let frombb = BitBoard::from_square(from);
                 let tobb = BitBoard::from_square(to);
-                let pieces = get_pieces_mut!(self, &PieceType::Pawn, &color);
-                remove_piece!(self, pieces, frombb, from);
-                add_piece!(self, pieces, tobb, to, PieceType::Pawn, color);
+                let pieces = get_pieces_mut!(self.position, &PieceType::Pawn, &color);
+                remove_piece!(self.position, pieces, frombb, from);
+                add_piece!(self.position, pieces, tobb, to, PieceType::Pawn, color);
             }
             Move::Promotion {
                 from: from_file,
@@ -175,17 +302,17 @@ impl Game {
                 let tobb = BitBoard::from_square(to);
 
                 if let Some(enemy) = capture {
-                    let pieces = get_pieces_mut!(self, enemy, &color.opponent());
-                    remove_piece!(self, pieces, tobb, to);
+                    let pieces = get_pieces_mut!(self.position, enemy, &color.opponent());
+                    remove_piece!(self.position, pieces, tobb, to);
                 }
 
                 // Remove pawn from original square
-                let pawns = get_pieces_mut!(self, &PieceType::Pawn, &color);
-                remove_piece!(self, pawns, frombb, from);
+                let pawns = get_pieces_mut!(self.position, &PieceType::Pawn, &color);
+                remove_piece!(self.position, pawns, frombb, from);
 
                 // Add promoted piece to new square
-                let promoted_pieces = get_pieces_mut!(self, piece, &color);
-                add_piece!(self, promoted_pieces, tobb, to, *piece, color);
+                let promoted_pieces = get_pieces_mut!(self.position, piece, &color);
+                add_piece!(self.position, promoted_pieces, tobb, to, *piece, color);
 
                 revoke_castling_rights!(to);
             }
@@ -195,7 +322,7 @@ impl Game {
 
                     match side {
                         CastleSide::Queenside => castle!(
-                            self,
+                            self.position,
                             &mut self.white_kings,
                             &mut self.white_rooks,
                             castling::WHITE_CASTLE_QUEENSIDE_KING_FROM_BB,
@@ -209,7 +336,7 @@ impl Game {
                             PieceColor::White
                         ),
                         CastleSide::Kingside => castle!(
-                            self,
+                            self.position,
                             &mut self.white_kings,
                             &mut self.white_rooks,
                             castling::WHITE_CASTLE_KINGSIDE_KING_FROM_BB,
@@ -229,7 +356,7 @@ impl Game {
 
                     match side {
                         CastleSide::Queenside => castle!(
-                            self,
+                            self.position,
                             &mut self.black_kings,
                             &mut self.black_rooks,
                             castling::BLACK_CASTLE_QUEENSIDE_KING_FROM_BB,
@@ -243,7 +370,7 @@ impl Game {
                             PieceColor::Black
                         ),
                         CastleSide::Kingside => castle!(
-                            self,
+                            self.position,
                             &mut self.black_kings,
                             &mut self.black_rooks,
                             castling::BLACK_CASTLE_KINGSIDE_KING_FROM_BB,
@@ -262,6 +389,28 @@ impl Game {
         }
 
         self.next_turn(m);
+        self.emit_played(*m);
+    }
+
+    /// Passes the turn to the opponent without moving a piece. The en passant target is cleared
+    /// and the hash is updated incrementally. Undo it with `Game::unmake_null`.
+    ///
+    /// The half move timeout starts over, so positions from before the null move are never
+    /// counted as repetitions. Passing while in check leaves the king capturable, so callers
+    /// should avoid that.
+    pub fn make_null(&mut self) {
+        self.log(format!("Playing null move on {:?}", self));
+        self.capture_position();
+
+        self.en_passant_target = None;
+        self.half_move_timeout = 0;
+        self.turn = self.turn.opponent();
+        if self.turn == PieceColor::White {
+            self.full_move_clock += 1;
+        }
+        self.hash ^= self.unrestoreable_hash() ^ zobrist::KEYS.black_to_move();
+        self.refresh();
+        self.state = self.determine_state();
     }
 }
 
@@ -271,7 +420,104 @@ mod tests {
     use crate::file::File;
     use crate::position::castling::CastleSide;
     use crate::position::game::Game;
+    use crate::square::SquareParseError;
     use crate::test_utils::{compare_to_fen, format_pretty_list, should_generate};
+    use crate::zobrist;
+
+    #[test]
+    fn is_legal_matches_legal_moves() {
+        let fens = [
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R b KQkq - 0 1",
+            "4k3/8/8/2KpP2r/8/8/8/8 w - d6 0 1",
+            "4k3/8/8/8/8/8/4q3/4K3 w - - 0 1",
+        ];
+
+        for fen in fens {
+            let game = Game::from_fen(fen).unwrap();
+            let legal = game.legal_moves();
+            for m in game.generate_all_psuedo_legal_moves() {
+                assert_eq!(game.is_legal(&m), legal.contains(&m), "{m} in {fen}");
+            }
+        }
+    }
+
+    #[test]
+    fn from_moves_plays_uci_and_coordinates() {
+        let game =
+            Game::from_moves(Game::default(), ["e2e4", "e7-e5", "g1f3", "b8-c6", "f1c4"]).unwrap();
+        assert_eq!(
+            game.to_fen(),
+            "r1bqkbnr/pppp1ppp/2n5/4p3/2B1P3/5N2/PPPP1PPP/RNBQK2R b KQkq - 3 3"
+        );
+
+        let mut castled = game.clone();
+        castled.apply_uci_moves(["g8f6", "e1g1"]).unwrap();
+        assert_eq!(
+            castled.history().last(),
+            Some(&Move::Castle {
+                side: CastleSide::Kingside
+            })
+        );
+    }
+
+    #[test]
+    fn apply_uci_moves_reports_the_bad_move() {
+        let mut game = Game::default();
+        let err = game.apply_uci_moves(["e2e4", "e7e5", "e4e5"]).unwrap_err();
+        assert_eq!(err.index, 2);
+        assert_eq!(err.notation, "e4e5");
+        assert!(matches!(
+            err.reason,
+            BadMoveReason::Illegal(IllegalMove::NotLegal(_))
+        ));
+        // The moves before it were still played
+        assert_eq!(game.history().len(), 2);
+
+        let err = Game::from_moves(Game::default(), ["e2e4", "e4e5"]).unwrap_err();
+        assert_eq!(
+            err.reason,
+            BadMoveReason::Illegal(IllegalMove::NoPieceToMove(Square::E4))
+        );
+
+        let err = Game::from_moves(Game::default(), ["z2z4"]).unwrap_err();
+        assert_eq!(
+            err.reason,
+            BadMoveReason::Unreadable(UciMoveError::BadSquare(SquareParseError::InvalidFile('z')))
+        );
+        assert_eq!(
+            err.to_string(),
+            "move 1 (z2z4) could not be read: invalid file 'z', expected a-h"
+        );
+    }
+
+    #[test]
+    fn try_play_refuses_illegal_moves() {
+        let mut game = Game::default();
+        let e5 = Move::infer(Square::E2, Square::E5, &game);
+        assert_eq!(game.try_play(&e5), Err(IllegalMove::NotLegal(e5)));
+
+        let black = Move::infer(Square::G8, Square::F6, &game);
+        assert_eq!(
+            game.try_play(&black),
+            Err(IllegalMove::NoPieceToMove(Square::G8))
+        );
+        compare_to_fen(&game, crate::position::game::STARTING_FEN);
+
+        let e4 = Move::infer(Square::E2, Square::E4, &game);
+        assert_eq!(game.try_play(&e4), Ok(()));
+        assert_eq!(game.history(), [e4]);
+
+        let mut mated = Game::from_fen("4k3/8/8/8/8/r7/5PPP/6K1 b - - 0 1").unwrap();
+        let mate = Move::infer(Square::A3, Square::A1, &mated);
+        assert_eq!(mated.try_play(&mate), Ok(()));
+        let h3 = Move::infer(Square::H2, Square::H3, &mated);
+        assert!(!mated.is_legal(&h3));
+        assert_eq!(
+            mated.try_play(&h3),
+            Err(IllegalMove::GameOver(State::Checkmate))
+        );
+    }
 
     #[test]
     fn both_lose_castling_rights_by_moving_kings() {
@@ -637,4 +883,35 @@ mod tests {
         game.play(&m);
         assert!(!game.castling_rights.white_kingside());
     }
+
+    /// Plays and unplays every move to `depth`, checking the incremental hash at every step
+    #[track_caller]
+    fn assert_incremental_hash(game: &mut Game, depth: u8) {
+        assert_eq!(game.hash, zobrist::hash(game), "{:?}", game);
+        if depth == 0 {
+            return;
+        }
+
+        for m in game.legal_moves() {
+            game.play(&m);
+            assert_eq!(game.hash, zobrist::hash(game), "After playing {m}");
+            assert_incremental_hash(game, depth - 1);
+            game.unplay(&m);
+            assert_eq!(game.hash, zobrist::hash(game), "After unplaying {m}");
+        }
+    }
+
+    #[test]
+    fn incremental_hash_matches_full_hash() {
+        for fen in [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1",
+            "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 2",
+            "4k3/2p1r3/r1n2p2/pq6/NPPpPBp1/1P1P3P/1Q1N2p1/1R2KB1R b K - 0 28",
+            "5q2/6P1/8/8/8/6rr/RR6/KN4nk w - - 0 1",
+        ] {
+            let mut game = Game::from_fen(fen).unwrap();
+            assert_incremental_hash(&mut game, 2);
+        }
+    }
 }
@@ -1,6 +1,9 @@
+pub mod legal;
 pub mod legal_moves;
 pub mod make;
+pub mod move_list;
 pub mod moves;
+pub mod packed;
 pub mod pieces;
 pub mod unmake;
 /// Shared stuff that shouldn't be public can go in utils.rs instead of mod.rs
@@ -0,0 +1,210 @@
+use std::{
+    fmt,
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{movegen::moves::Move, vectors::Vector};
+
+/// More than the most moves any reachable position has, which is 218
+pub const MAX_MOVES: usize = 256;
+
+/// A fixed capacity list of moves that lives on the stack, so generating the moves of a node never
+/// touches the heap. Derefs to a slice of moves for everything other than pushing.
+#[derive(Clone)]
+pub struct MoveList {
+    moves: [MaybeUninit<Move>; MAX_MOVES],
+    len: usize,
+}
+
+impl MoveList {
+    pub const fn new() -> Self {
+        Self {
+            moves: [MaybeUninit::uninit(); MAX_MOVES],
+            len: 0,
+        }
+    }
+
+    pub fn as_slice(&self) -> &[Move] {
+        // SAFETY: every move below `len` has been written by `push`
+        unsafe { std::slice::from_raw_parts(self.moves.as_ptr().cast(), self.len) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [Move] {
+        // SAFETY: every move below `len` has been written by `push`
+        unsafe { std::slice::from_raw_parts_mut(self.moves.as_mut_ptr().cast(), self.len) }
+    }
+
+    /// Removes and returns the move at `index`, replacing it with the last move
+    pub fn swap_remove(&mut self, index: usize) -> Move {
+        let m = self[index];
+        self.len -= 1;
+        self.moves[index] = self.moves[self.len];
+        m
+    }
+
+    /// Keeps only the moves that `keep` returns true for, in their original order
+    pub fn retain(&mut self, mut keep: impl FnMut(&Move) -> bool) {
+        let mut kept = 0;
+        for i in 0..self.len {
+            let m = self[i];
+            if keep(&m) {
+                self.moves[kept].write(m);
+                kept += 1;
+            }
+        }
+        self.len = kept;
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl Vector<Move> for MoveList {
+    fn push(&mut self, item: Move) {
+        assert!(
+            self.len < MAX_MOVES,
+            "MoveList overflow: capacity is {MAX_MOVES}"
+        );
+        self.moves[self.len].write(item);
+        self.len += 1;
+    }
+}
+
+impl Default for MoveList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for MoveList {
+    type Target = [Move];
+
+    fn deref(&self) -> &[Move] {
+        self.as_slice()
+    }
+}
+
+impl DerefMut for MoveList {
+    fn deref_mut(&mut self) -> &mut [Move] {
+        self.as_mut_slice()
+    }
+}
+
+impl fmt::Debug for MoveList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl PartialEq for MoveList {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl PartialEq<Vec<Move>> for MoveList {
+    fn eq(&self, other: &Vec<Move>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<const N: usize> PartialEq<[Move; N]> for MoveList {
+    fn eq(&self, other: &[Move; N]) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl FromIterator<Move> for MoveList {
+    fn from_iter<I: IntoIterator<Item = Move>>(iter: I) -> Self {
+        let mut moves = Self::new();
+        for m in iter {
+            moves.push(m);
+        }
+        moves
+    }
+}
+
+impl From<MoveList> for Vec<Move> {
+    fn from(moves: MoveList) -> Self {
+        moves.to_vec()
+    }
+}
+
+impl IntoIterator for MoveList {
+    type Item = Move;
+    type IntoIter = MoveListIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        MoveListIter {
+            list: self,
+            next: 0,
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a MoveList {
+    type Item = &'a Move;
+    type IntoIter = std::slice::Iter<'a, Move>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Hands out the moves of a `MoveList` in the order they were pushed
+pub struct MoveListIter {
+    list: MoveList,
+    next: usize,
+}
+
+impl Iterator for MoveListIter {
+    type Item = Move;
+
+    fn next(&mut self) -> Option<Move> {
+        let m = self.list.get(self.next).copied()?;
+        self.next += 1;
+        Some(m)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.list.len() - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for MoveListIter {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{position::game::Game, square::Square};
+
+    use super::*;
+
+    #[test]
+    fn keeps_push_order() {
+        let game = Game::default();
+        let expected = game.generate_all_psuedo_legal_moves().to_vec();
+        let mut moves = MoveList::new();
+        for &m in &expected {
+            moves.push(m);
+        }
+
+        assert_eq!(moves, expected);
+        assert_eq!(moves.into_iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn retain_and_swap_remove() {
+        let game = Game::default();
+        let mut moves = game.generate_all_psuedo_legal_moves();
+        let e4 = Move::infer(Square::E2, Square::E4, &game);
+
+        moves.retain(|m| m.from(game.turn) == Square::E2);
+        assert_eq!(moves.len(), 2);
+        let i = moves.iter().position(|&m| m == e4).unwrap();
+        assert_eq!(moves.swap_remove(i), e4);
+        assert_eq!(moves.len(), 1);
+    }
+}
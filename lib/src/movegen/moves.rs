@@ -3,7 +3,11 @@ use std::{fmt, str::FromStr};
 use crate::{
     bitboard::{BitBoard, EMPTY},
     file::File,
-    movegen::pieces::piece::{PieceColor, PieceType},
+    movegen::pieces::{
+        bishop, king, knight, pawn,
+        piece::{PieceColor, PieceType},
+        rook,
+    },
     position::{
         castling::{self, CastleSide},
         game::{Game, State},
@@ -17,6 +21,10 @@ use crate::{
 /// which is 7 bytes.
 const MAX_SHORTHAND_NOTATION_EXPECTED_BYTES: usize = 7;
 
+/// The long algebraic version of [`MAX_SHORTHAND_NOTATION_EXPECTED_BYTES`], from something
+/// like "e7xd8=Q#"
+const MAX_LONG_NOTATION_EXPECTED_BYTES: usize = 8;
+
 /// Converts a vector of moves to a vector of targets
 pub fn moves_to_targets_vec(moves: &[Move], game: &Game) -> Vec<Square> {
     moves.iter().map(|m| m.to(game)).collect()
@@ -134,6 +142,133 @@ pub fn targets_to_moves(targets: BitBoard, from: Square, game: &Game) -> Vec<Mov
     moves
 }
 
+/// Why a [`UciMove`] could not be read or played
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UciMoveError {
+    /// A uci move is two squares and an optional promotion piece, so 4 or 5 characters
+    WrongLength(usize),
+    BadSquare(SquareParseError),
+    /// The promotion suffix is not one of `nbrq`
+    BadPromotion(char),
+    /// There is a promotion suffix, but the move is not a pawn reaching the last rank
+    NotAPromotion,
+}
+
+impl fmt::Display for UciMoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UciMoveError::WrongLength(length) => {
+                write!(f, "expected 4 or 5 characters, found {length}")
+            }
+            UciMoveError::BadSquare(e) => write!(f, "{e}"),
+            UciMoveError::BadPromotion(c) => {
+                write!(f, "invalid promotion '{c}', expected n, b, r or q")
+            }
+            UciMoveError::NotAPromotion => write!(f, "only a pawn reaching the last rank promotes"),
+        }
+    }
+}
+
+/// Why [`Move::from_lan`] could not read a move
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LanError {
+    /// The squares or promotion could not be read
+    Unreadable(UciMoveError),
+    /// An uppercase letter in front of the squares that is not a piece
+    BadPiece(char),
+    /// The piece named in the notation is not the one on the source square
+    WrongPiece { square: Square, piece: PieceType },
+}
+
+impl fmt::Display for LanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LanError::Unreadable(e) => write!(f, "{e}"),
+            LanError::BadPiece(c) => write!(f, "invalid piece '{c}', expected N, B, R, Q or K"),
+            LanError::WrongPiece { square, piece } => {
+                write!(f, "there is no {piece:?} on {square} to move")
+            }
+        }
+    }
+}
+
+/// A move in uci notation such as e2e4 or e7e8n. Reading one only needs the notation, unlike a
+/// [`Move`], which knows what kind of move it is and so depends on the position. Use
+/// [`UciMove::resolve`] to turn it into a [`Move`] once the position is known.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UciMove {
+    pub from: Square,
+    pub to: Square,
+    pub promotion: Option<PieceType>,
+}
+
+impl FromStr for UciMove {
+    type Err = UciMoveError;
+
+    fn from_str(uci: &str) -> Result<Self, UciMoveError> {
+        let length = uci.chars().count();
+        if !(4..=5).contains(&length) {
+            return Err(UciMoveError::WrongLength(length));
+        }
+
+        let mut chars = uci.chars();
+        let mut square = || {
+            let square: String = chars.by_ref().take(2).collect();
+            Square::from_str(&square).map_err(UciMoveError::BadSquare)
+        };
+        let from = square()?;
+        let to = square()?;
+
+        let promotion = match chars.next() {
+            None => None,
+            Some(suffix) => match PieceType::from_notation(suffix) {
+                Some(
+                    piece @ (PieceType::Knight
+                    | PieceType::Bishop
+                    | PieceType::Rook
+                    | PieceType::Queen),
+                ) => Some(piece),
+                _ => return Err(UciMoveError::BadPromotion(suffix)),
+            },
+        };
+
+        Ok(Self {
+            from,
+            to,
+            promotion,
+        })
+    }
+}
+
+impl fmt::Display for UciMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}",
+            self.from.to_string().to_lowercase(),
+            self.to.to_string().to_lowercase()
+        )?;
+        if let Some(piece) = self.promotion {
+            write!(f, "{}", piece.notation().to_ascii_lowercase())?;
+        }
+        Ok(())
+    }
+}
+
+impl UciMove {
+    /// Works out which move this is in `game`. Promotions without a promotion piece are to a
+    /// queen.
+    pub fn resolve(self, game: &Game) -> Result<Move, UciMoveError> {
+        let Some(piece) = self.promotion else {
+            return Ok(Move::infer(self.from, self.to, game));
+        };
+        match Move::infer_promotion(self.from, self.to, piece, game) {
+            m @ Move::Promotion { .. } => Ok(m),
+            _ => Err(UciMoveError::NotAPromotion),
+        }
+    }
+}
+
 #[derive(PartialEq, Clone, Copy)]
 pub enum Move {
     Normal {
@@ -282,6 +417,22 @@ impl Move {
         }
     }
 
+    /// Infers the type of move like [`Move::infer`], but promotes to `piece` instead of a queen if
+    /// the move turns out to be a promotion
+    pub fn infer_promotion(from: Square, to: Square, piece: PieceType, game: &Game) -> Self {
+        match Move::infer(from, to, game) {
+            Move::Promotion {
+                from, to, capture, ..
+            } => Move::Promotion {
+                from,
+                to,
+                piece,
+                capture,
+            },
+            m => m,
+        }
+    }
+
     /// Returns the destination square of the move. Consumes self
     pub fn to(self, game: &Game) -> Square {
         match self {
@@ -355,105 +506,197 @@ impl Move {
         }
     }
 
-    /// Formats the move in Standard Algebraic Notation, such as Bxf7+.
-    /// `self` will be played and unplayed on the board to determine whether the move is a checkmate or check.
+    /// Checks if playing the move would put the opponent in check, either with the moved piece or
+    /// by uncovering a ray piece behind it. The move is not played, which makes this cheap enough
+    /// for move ordering and check extensions. `game` has to be the position the move is played
+    /// in.
+    pub fn gives_check(self, game: &Game) -> bool {
+        let us = game.turn;
+        let king = *game.get_pieces(&PieceType::King, &us.opponent());
+        if king == EMPTY {
+            return false;
+        }
+        let king = king.to_square();
+
+        let from = self.from(us);
+        let to = self.to(game);
+        let mut occupied = game.occupied & !BitBoard::from_square(from) | BitBoard::from_square(to);
+
+        // The piece that stands on `at` after the move, and might attack the king directly
+        let (piece, at) = match self {
+            Move::Castle { side } => {
+                let (rook_from, rook_to) = match (us, side) {
+                    (PieceColor::White, CastleSide::Kingside) => (
+                        castling::WHITE_CASTLE_KINGSIDE_ROOK_FROM,
+                        castling::WHITE_CASTLE_KINGSIDE_ROOK_TO,
+                    ),
+                    (PieceColor::White, CastleSide::Queenside) => (
+                        castling::WHITE_CASTLE_QUEENSIDE_ROOK_FROM,
+                        castling::WHITE_CASTLE_QUEENSIDE_ROOK_TO,
+                    ),
+                    (PieceColor::Black, CastleSide::Kingside) => (
+                        castling::BLACK_CASTLE_KINGSIDE_ROOK_FROM,
+                        castling::BLACK_CASTLE_KINGSIDE_ROOK_TO,
+                    ),
+                    (PieceColor::Black, CastleSide::Queenside) => (
+                        castling::BLACK_CASTLE_QUEENSIDE_ROOK_FROM,
+                        castling::BLACK_CASTLE_QUEENSIDE_ROOK_TO,
+                    ),
+                };
+                occupied =
+                    occupied & !BitBoard::from_square(rook_from) | BitBoard::from_square(rook_to);
+                (PieceType::Rook, rook_to)
+            }
+            Move::CaptureEnPassant { .. } => {
+                let captured = Square::make_square(from.get_rank(), to.get_file());
+                occupied &= !BitBoard::from_square(captured);
+                (PieceType::Pawn, to)
+            }
+            Move::Promotion { piece, .. } => (piece, to),
+            Move::Normal { .. } | Move::CreateEnPassant { .. } => match game.piece_lookup(from) {
+                Some((piece, _)) => (piece, to),
+                None => return false,
+            },
+        };
+
+        let direct = match piece {
+            PieceType::Pawn => pawn::square_attacks(at, us),
+            PieceType::Knight => knight::attacks(at),
+            PieceType::Bishop => bishop::magic_attacks(at, occupied),
+            PieceType::Rook => rook::magic_attacks(at, occupied),
+            PieceType::Queen => {
+                bishop::magic_attacks(at, occupied) | rook::magic_attacks(at, occupied)
+            }
+            PieceType::King => king::attacks(at),
+        };
+
+        // Whatever still attacks the king through the new occupancy was uncovered by the move,
+        // since the opponent can not already be in check
+        direct.has_square(BitBoard::from_square(king))
+            || game.attackers_to_with_occupied(king, us, occupied) != EMPTY
+    }
+
+    /// Formats the move in Standard Algebraic Notation, such as Bxf7+. `game` has to be the
+    /// position the move is played in. Pieces are only disambiguated from others that can legally
+    /// reach the same square.
     ///
     /// This was created following the specifications listed on the Chess Programming Wiki,
     /// and by verifying the notation against the lichess analysis board.
     /// Chess Programming Wiki: https://www.chessprogramming.org/Algebraic_Chess_Notation#Standard_Algebraic_Notation_.28SAN.29
     /// Lichess Analysis Board: https://lichess.org/analysis
-    pub fn to_san(self, game: &mut Game) -> String {
-        match self {
-            Move::Normal { from, to, capture } => {
-                let mut out = String::with_capacity(MAX_SHORTHAND_NOTATION_EXPECTED_BYTES);
+    pub fn to_san(self, game: &Game) -> String {
+        self.to_algebraic(game, false)
+    }
 
-                let is_capture = capture.is_some();
+    /// Formats the move in Long Algebraic Notation, such as Ng1-f3 or e7xd8=Q+. This is SAN with
+    /// the full source square and a `-` between the squares of moves that do not capture, so it
+    /// never needs disambiguating. `game` has to be the position the move is played in.
+    pub fn to_lan(self, game: &Game) -> String {
+        self.to_algebraic(game, true)
+    }
+
+    /// The notation shared by [`Move::to_san`] and, with `long`, [`Move::to_lan`]
+    fn to_algebraic(self, game: &Game, long: bool) -> String {
+        let mut out = String::with_capacity(if long {
+            MAX_LONG_NOTATION_EXPECTED_BYTES
+        } else {
+            MAX_SHORTHAND_NOTATION_EXPECTED_BYTES
+        });
 
-                if let Some((piece, color)) = game.piece_lookup(from) {
-                    if piece == PieceType::Pawn {
-                        if is_capture {
+        match self {
+            Move::Castle {
+                side: CastleSide::Queenside,
+            } => out.push_str("O-O-O"),
+            Move::Castle {
+                side: CastleSide::Kingside,
+            } => out.push_str("O-O"),
+            _ => {
+                let from = self.from(game.turn);
+                let to = self.to(game);
+                let is_capture = self.is_capture();
+
+                match game.piece_lookup(from) {
+                    Some((PieceType::Pawn, _)) | None => {
+                        if is_capture && !long {
                             out.push(from.get_file().notation());
                         }
-                    } else {
+                    }
+                    Some((piece, color)) => {
                         out.push(piece.notation());
-                        let attackers = game.attackers(to) & *game.get_pieces(&piece, &color);
-                        if attackers.popcnt() > 1 {
-                            let rank = from.get_rank();
-                            let file = from.get_file();
-                            let horizontal_attackers = attackers & rank.mask();
-                            let vertical_attackers = attackers & file.mask();
-                            if horizontal_attackers.popcnt() > 1 {
-                                out.push(file.notation());
-                            }
-                            if vertical_attackers.popcnt() > 1 {
-                                out.push(rank.notation());
-                            }
+                        if !long {
+                            self.push_san_disambiguation(&mut out, game, piece, color);
                         }
                     }
                 }
 
+                if long {
+                    out.push_str(&from.to_string().to_ascii_lowercase());
+                }
                 if is_capture {
                     out.push('x');
+                } else if long {
+                    out.push('-');
                 }
-
                 out.push_str(&to.to_string().to_ascii_lowercase());
 
-                game.play(&self);
-
-                if game.state == State::Checkmate {
-                    out.push('#');
-                } else if game.is_in_check(game.turn) {
-                    out.push('+');
+                if let Move::Promotion { piece, .. } = self {
+                    out.push('=');
+                    out.push(piece.notation());
                 }
-
-                game.unplay(&self);
-
-                out
             }
-            Move::CreateEnPassant { at } => {
-                format!("{}{}", at.notation(), game.turn.create_en_passant_rank())
-            }
-            Move::CaptureEnPassant { from } => {
-                format!("{}x{}",
-                    from.notation(),
-                    game.en_passant_target.expect(
-                        "Tried to create shorthand notation for capturing an en_passant, but there was no en_passant_target available"
-                    ).to_string().to_ascii_lowercase()
-                )
-            }
-            Move::Promotion {
-                from,
-                to: _,
-                piece,
-                capture: None,
-            } => {
-                format!(
-                    "{}{}={}",
-                    from.notation(),
-                    game.turn.final_rank(),
-                    piece.notation()
-                )
+        }
+
+        if self.gives_check(game) {
+            // Played on a copy without any history, so the hooks of `game` never see it
+            let mut after = Game::from_position(*game.position());
+            after.play(&self);
+            if after.state == State::Checkmate {
+                out.push('#');
+            } else {
+                out.push('+');
             }
-            Move::Promotion {
-                from,
-                to,
-                piece,
-                capture: Some(_),
-            } => {
-                format!(
-                    "{}x{}{}={}",
-                    from.notation(),
-                    to.notation(),
-                    game.turn.final_rank(),
-                    piece.notation()
-                )
+        }
+
+        out
+    }
+
+    /// Adds the file, rank or both of the source square when other pieces of the same kind can
+    /// legally move to the same square. The file is preferred, then the rank.
+    fn push_san_disambiguation(
+        self,
+        out: &mut String,
+        game: &Game,
+        piece: PieceType,
+        color: PieceColor,
+    ) {
+        if game.get_pieces(&piece, &color).popcnt() < 2 {
+            return;
+        }
+
+        let from = self.from(color);
+        let to = self.to(game);
+        let mut rivals = EMPTY;
+        for m in game.legal_moves().iter() {
+            let rival = m.from(color);
+            if rival != from && m.to(game) == to && game.piece_lookup(rival) == Some((piece, color))
+            {
+                rivals.set(rival);
             }
-            Move::Castle {
-                side: CastleSide::Queenside,
-            } => "O-O-O".to_string(),
-            Move::Castle {
-                side: CastleSide::Kingside,
-            } => "O-O".to_string(),
+        }
+
+        if rivals == EMPTY {
+            return;
+        }
+
+        let file = from.get_file();
+        let rank = from.get_rank();
+        if rivals & file.mask() == EMPTY {
+            out.push(file.notation());
+        } else if rivals & rank.mask() == EMPTY {
+            out.push(rank.notation());
+        } else {
+            out.push(file.notation());
+            out.push(rank.notation());
         }
     }
 
@@ -468,13 +711,125 @@ impl Move {
         out
     }
 
-    /// Returns a move from a uci string
-    pub fn from_uci(uci: &str, game: &Game) -> Result<Self, SquareParseError> {
-        Ok(Move::infer(
-            Square::from_str(&uci[..2])?,
-            Square::from_str(&uci[2..])?,
-            game,
-        ))
+    /// Returns a move from a uci string. A promotion suffix such as the n in e7e8n picks the
+    /// promotion piece, and promotions without one are to a queen. See [`UciMove`] for reading
+    /// the notation without a game.
+    pub fn from_uci(uci: &str, game: &Game) -> Result<Self, UciMoveError> {
+        uci.parse::<UciMove>()?.resolve(game)
+    }
+
+    /// Returns the legal move written as `san` in Standard Algebraic Notation. The check and
+    /// annotation marks at the end are ignored, castling may be written with zeros, and the origin
+    /// may be given even when it is not needed, such as in Ngf3. Returns `None` if no legal move
+    /// or more than one is written that way.
+    pub fn from_san(san: &str, game: &Game) -> Option<Self> {
+        let san = san.trim_end_matches(['+', '#', '!', '?']);
+        let legal = game.legal_moves();
+        let side = match san {
+            "O-O" | "0-0" => Some(CastleSide::Kingside),
+            "O-O-O" | "0-0-0" => Some(CastleSide::Queenside),
+            _ => None,
+        };
+        if let Some(side) = side {
+            let castle = Move::Castle { side };
+            return legal.contains(&castle).then_some(castle);
+        }
+
+        // The promotion piece comes last, such as in e8=Q, and the = is sometimes left out
+        let (san, promotion) = match san.char_indices().last()? {
+            (i, c) if i > 0 && c.is_ascii_uppercase() => (
+                san[..i].trim_end_matches('='),
+                Some(PieceType::from_notation(c)?),
+            ),
+            _ => (san, None),
+        };
+        let (piece, san) = match san.chars().next()? {
+            c if c.is_ascii_uppercase() => (
+                PieceType::from_notation(c).filter(|&piece| piece != PieceType::Pawn)?,
+                &san[1..],
+            ),
+            _ => (PieceType::Pawn, san),
+        };
+
+        let split = san.len().checked_sub(2)?;
+        let target = Square::from_str(san.get(split..)?).ok()?;
+        let (mut file, mut rank) = (None, None);
+        for c in san[..split].chars().filter(|&c| c != 'x') {
+            match c {
+                'a'..='h' => file = File::from_char(c),
+                '1'..='8' => rank = Rank::from_int(c as u8 - b'1'),
+                _ => return None,
+            }
+        }
+        // Pawns only leave their file when capturing, which names the file they came from
+        if piece == PieceType::Pawn && file.is_none() {
+            file = Some(target.get_file());
+        }
+
+        let mut matching = legal.into_iter().filter(|m| {
+            let from = m.from(game.turn);
+            let promotes_to = match m {
+                Move::Promotion { piece, .. } => Some(*piece),
+                _ => None,
+            };
+            !matches!(m, Move::Castle { .. })
+                && m.to(game) == target
+                && game
+                    .piece_lookup(from)
+                    .is_some_and(|(found, _)| found == piece)
+                && file.is_none_or(|file| from.get_file() == file)
+                && rank.is_none_or(|rank| from.get_rank() == rank)
+                && promotes_to == promotion
+        });
+        let m = matching.next()?;
+        // Leaving out an origin that is needed makes the move ambiguous
+        matching.next().is_none().then_some(m)
+    }
+
+    /// Returns a move from Long Algebraic Notation as written by [`Move::to_lan`]. The check and
+    /// annotation marks at the end are ignored, and pawn moves may leave out the `-`.
+    pub fn from_lan(lan: &str, game: &Game) -> Result<Self, LanError> {
+        let lan = lan.trim_end_matches(['+', '#', '!', '?']);
+        match lan {
+            "O-O" | "0-0" => {
+                return Ok(Move::Castle {
+                    side: CastleSide::Kingside,
+                });
+            }
+            "O-O-O" | "0-0-0" => {
+                return Ok(Move::Castle {
+                    side: CastleSide::Queenside,
+                });
+            }
+            _ => {}
+        }
+
+        let (piece, coordinates) = match lan.chars().next() {
+            Some(c) if c.is_ascii_uppercase() => (
+                PieceType::from_notation(c)
+                    .filter(|&piece| piece != PieceType::Pawn)
+                    .ok_or(LanError::BadPiece(c))?,
+                &lan[1..],
+            ),
+            _ => (PieceType::Pawn, lan),
+        };
+
+        let m = Move::from_coordinates(coordinates, game).map_err(LanError::Unreadable)?;
+        let square = m.from(game.turn);
+        match game.piece_lookup(square) {
+            Some((found, color)) if found == piece && color == game.turn => Ok(m),
+            _ => Err(LanError::WrongPiece { square, piece }),
+        }
+    }
+
+    /// Returns a move from coordinate notation, such as e2-e4, d4xe5 or e7e8=N. The separators are
+    /// optional, so anything [`Move::from_uci`] reads is read the same way here.
+    pub fn from_coordinates(notation: &str, game: &Game) -> Result<Self, UciMoveError> {
+        let uci: String = notation
+            .chars()
+            .filter(|c| !matches!(c, '-' | 'x' | ':' | '='))
+            .collect();
+        Move::from_uci(&uci, game)
     }
 }
 
@@ -532,10 +887,160 @@ mod tests {
         assert_eq!(Move::from_uci(uci, &game).unwrap(), m);
     }
 
+    #[test]
+    fn from_uci_underpromotion() {
+        let fen = "4k2r/6P1/8/8/8/8/8/4K3 w - - 0 1";
+        let game = Game::from_fen(fen).unwrap();
+
+        let capture = Move::from_uci("g7h8n", &game).unwrap();
+        let expected = Move::Promotion {
+            from: File::G,
+            to: File::H,
+            piece: PieceType::Knight,
+            capture: Some(PieceType::Rook),
+        };
+        assert_eq!(capture, expected);
+        assert_eq!(capture.to_uci(&game), "g7h8n");
+
+        let push = Move::from_uci("g7g8", &game).unwrap();
+        assert_eq!(push, Move::infer(Square::G7, Square::G8, &game));
+        assert!(matches!(
+            push,
+            Move::Promotion {
+                piece: PieceType::Queen,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn gives_check_matches_playing_the_move() {
+        let fens = [
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R b KQkq - 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+            "8/8/8/K2pP2k/8/8/8/8 w - d6 0 1",
+            "3k4/8/8/8/8/8/8/R3K2R w KQ - 0 1",
+            "4k3/1P6/8/8/8/8/8/3QK3 w - - 0 1",
+            "5k2/8/8/8/3B4/4N3/8/4R1K1 w - - 0 1",
+        ];
+
+        for fen in fens {
+            let mut game = Game::from_fen(fen).unwrap();
+            for m in game.legal_moves() {
+                let expected = {
+                    game.play(&m);
+                    let check = game.is_in_check(game.turn);
+                    game.unplay(&m);
+                    check
+                };
+                assert_eq!(m.gives_check(&game), expected, "{m} in {fen}");
+            }
+        }
+    }
+
+    #[test]
+    fn gives_discovered_and_castling_checks() {
+        // Moving the knight uncovers the rook on e1
+        let game = Game::from_fen("4k3/8/8/8/8/4N3/8/4R1K1 w - - 0 1").unwrap();
+        assert!(Move::infer(Square::E3, Square::C2, &game).gives_check(&game));
+
+        // The rook lands on f1 and checks the king on f8
+        let game = Game::from_fen("5k2/8/8/8/8/8/8/4K2R w K - 0 1").unwrap();
+        let castle = Move::Castle {
+            side: CastleSide::Kingside,
+        };
+        assert!(castle.gives_check(&game));
+
+        // Taking en passant opens the fifth rank for the rook
+        let game = Game::from_fen("8/8/8/R2pP2k/8/8/8/4K3 w - d6 0 1").unwrap();
+        let m = Move::CaptureEnPassant { from: File::E };
+        assert!(m.gives_check(&game));
+    }
+
+    #[test]
+    fn from_coordinates() {
+        let fen = "4k2r/6P1/8/8/8/8/8/4K3 w - - 0 1";
+        let game = Game::from_fen(fen).unwrap();
+
+        for notation in ["g7xh8=N", "g7-h8n", "g7h8N", "g7:h8=n"] {
+            assert_eq!(
+                Move::from_coordinates(notation, &game),
+                Move::from_uci("g7h8n", &game),
+                "{notation}"
+            );
+        }
+        assert_eq!(
+            Move::from_coordinates("e1-e2", &game),
+            Ok(Move::infer(Square::E1, Square::E2, &game))
+        );
+        assert_eq!(
+            Move::from_coordinates("e1", &game),
+            Err(UciMoveError::WrongLength(2))
+        );
+    }
+
+    #[test]
+    fn uci_moves_need_no_game() {
+        for uci in ["e2e4", "g7h8n", "a2a1q"] {
+            assert_eq!(uci.parse::<UciMove>().unwrap().to_string(), uci);
+        }
+        assert_eq!(
+            "e7e8N".parse(),
+            Ok(UciMove {
+                from: Square::E7,
+                to: Square::E8,
+                promotion: Some(PieceType::Knight),
+            })
+        );
+
+        let game = Game::default();
+        let m: UciMove = "g1f3".parse().unwrap();
+        assert_eq!(
+            m.resolve(&game),
+            Ok(Move::infer(Square::G1, Square::F3, &game))
+        );
+    }
+
+    #[test]
+    fn from_uci_errors() {
+        let fen = "4k2r/6P1/8/8/8/8/8/4K3 w - - 0 1";
+        let game = Game::from_fen(fen).unwrap();
+
+        for (uci, error) in [
+            ("", UciMoveError::WrongLength(0)),
+            ("g7", UciMoveError::WrongLength(2)),
+            ("g7h8qq", UciMoveError::WrongLength(6)),
+            (
+                "é7h8",
+                UciMoveError::BadSquare(SquareParseError::InvalidFile('é')),
+            ),
+            (
+                "g7h9",
+                UciMoveError::BadSquare(SquareParseError::InvalidRank('9')),
+            ),
+            (
+                "g0h8",
+                UciMoveError::BadSquare(SquareParseError::InvalidRank('0')),
+            ),
+            ("g7h8k", UciMoveError::BadPromotion('k')),
+            ("g7h8p", UciMoveError::BadPromotion('p')),
+            ("g7h8x", UciMoveError::BadPromotion('x')),
+            ("e1e2q", UciMoveError::NotAPromotion),
+        ] {
+            assert_eq!(Move::from_uci(uci, &game), Err(error), "{uci}");
+        }
+
+        assert_eq!(
+            UciMoveError::BadPromotion('k').to_string(),
+            "invalid promotion 'k', expected n, b, r or q"
+        );
+    }
+
     #[test]
     fn from_uci_capture() {
         let fen = "3qkbnr/1p3ppp/2n5/1ppbp3/8/r1pPBP1P/1P2P1P1/3QKBNR w Kk - 0 13";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
 
         let uci = "e3c5";
         let looking_for = Move::Normal {
@@ -579,31 +1084,31 @@ mod tests {
             capture: Some(PieceType::Pawn),
         };
 
-        assert_eq!(white_castles_queenside.to_san(&mut game), "O-O-O");
-        assert_eq!(white_promotes_to_queen.to_san(&mut game), "a8=Q");
+        assert_eq!(white_castles_queenside.to_san(&game), "O-O-O");
+        assert_eq!(white_promotes_to_queen.to_san(&game), "a8=Q");
 
         game.play(&white_castles_queenside);
-        assert_eq!(black_castles_kingside.to_san(&mut game), "O-O");
-        assert_eq!(black_promotes_to_knight.to_san(&mut game), "hxg1=N");
+        assert_eq!(black_castles_kingside.to_san(&game), "O-O");
+        assert_eq!(black_promotes_to_knight.to_san(&game), "hxg1=N");
 
         game.play(&black_castles_kingside);
-        assert_eq!(white_creates_en_passant.to_san(&mut game), "g4");
+        assert_eq!(white_creates_en_passant.to_san(&game), "g4");
 
         game.play(&white_creates_en_passant);
-        assert_eq!(black_captures_en_passant.to_san(&mut game), "fxg3");
+        assert_eq!(black_captures_en_passant.to_san(&game), "fxg3");
 
         game.play(&black_captures_en_passant);
         game.play(&white_promotes_to_queen);
-        assert_eq!(black_moves_rook.to_san(&mut game), "Rxf2");
+        assert_eq!(black_moves_rook.to_san(&game), "Rxf2");
     }
 
     #[test]
     fn to_shorthand_pawn_capture() {
         let fen = "k7/8/8/6p1/5R2/8/8/K7 b - - 0 1";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
         let m = Move::infer(Square::G5, Square::F4, &game);
         let expected = "gxf4";
-        let actual = m.to_san(&mut game);
+        let actual = m.to_san(&game);
         assert_ne!(actual, "xf4", "Pawn file is always included");
         assert_eq!(actual, expected);
     }
@@ -611,20 +1116,20 @@ mod tests {
     #[test]
     fn to_shorthand_bishop_capture() {
         let fen = "kp6/pp6/8/4r3/8/6B1/PP6/KP6 w - - 0 1";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
         let m = Move::infer(Square::G3, Square::E5, &game);
         let expected = "Bxe5";
-        let actual = m.to_san(&mut game);
+        let actual = m.to_san(&game);
         assert_eq!(actual, expected);
     }
 
     #[test]
     fn to_shorthand_bishop_capture_with_ambiguous_rank() {
         let fen = "kp6/pp4B1/8/4r3/8/6B1/PP6/KP6 w - - 0 1";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
         let m = Move::infer(Square::G3, Square::E5, &game);
         let expected = "B3xe5";
-        let actual = m.to_san(&mut game);
+        let actual = m.to_san(&game);
         assert_ne!(actual, "Bxe5", "Missing Rank specifier");
         assert_ne!(actual, "B7xe5", "Wrong bishop");
         assert_ne!(actual, "Bgxe5", "Unnecessary File specifier");
@@ -634,10 +1139,10 @@ mod tests {
     #[test]
     fn to_shorthand_bishop_capture_with_ambiguous_file() {
         let fen = "kp6/pp6/8/4r3/8/2B3B1/PP6/KP6 w - - 0 1";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
         let m = Move::infer(Square::C3, Square::E5, &game);
         let expected = "Bcxe5";
-        let actual = m.to_san(&mut game);
+        let actual = m.to_san(&game);
         assert_ne!(actual, "Bxe5", "Missing File specifier");
         assert_ne!(actual, "Bgxe5", "Wrong bishop");
         assert_ne!(actual, "B7xe5", "Unnecessary Rank specifier");
@@ -647,10 +1152,10 @@ mod tests {
     #[test]
     fn to_shorthand_bishop_capture_with_ambiguous_file_and_rank() {
         let fen = "k7/pp4B1/8/4r3/8/2B3B1/PP6/K7 w - - 0 1";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
         let m = Move::infer(Square::G3, Square::E5, &game);
         let expected = "Bg3xe5";
-        let actual = m.to_san(&mut game);
+        let actual = m.to_san(&game);
         assert_ne!(actual, "Bxe5", "Missing File and Rank specifiers");
         assert_ne!(actual, "Bgxe5", "Missing Rank specifier");
         assert_ne!(actual, "B3xe5", "Missing File specifier");
@@ -662,20 +1167,20 @@ mod tests {
     #[test]
     fn to_shorthand_slightly_ambiguous_en_passant_capture() {
         let fen = "kr6/pp6/8/5p2/5pP1/8/PP6/KR6 b - g3 0 1";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
         let noob = Move::infer(Square::F5, Square::G4, &game);
         let pro = Move::infer(Square::F4, Square::G3, &game);
-        assert_eq!(noob.to_san(&mut game), "fxg4");
-        assert_eq!(pro.to_san(&mut game), "fxg3");
+        assert_eq!(noob.to_san(&game), "fxg4");
+        assert_eq!(pro.to_san(&game), "fxg3");
     }
 
     #[test]
     fn to_shorthand_bishop_captures_with_check() {
         let fen = "r1bqk1nr/pppp1ppp/2n5/2b1p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 0 1";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
         let m = Move::infer(Square::C4, Square::F7, &game);
         let expected = "Bxf7+";
-        let actual = m.to_san(&mut game);
+        let actual = m.to_san(&game);
         assert_ne!(actual, "Bxf7", "Missing check specifier");
         assert_eq!(actual, expected);
     }
@@ -683,10 +1188,10 @@ mod tests {
     #[test]
     fn to_shorthand_queen_captures_with_checkmate() {
         let fen = "r1bqk1nr/pppp1ppp/2n5/2b1p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 0 1";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
         let m = Move::infer(Square::H5, Square::F7, &game);
         let expected = "Qxf7#";
-        let actual = m.to_san(&mut game);
+        let actual = m.to_san(&game);
         assert_ne!(actual, "Qxf7", "Missing checkmate specifier");
         assert!(
             !actual.contains('+'),
@@ -698,13 +1203,150 @@ mod tests {
     #[test]
     fn to_shorthand_stalemate() {
         let fen = "8/8/8/8/8/4k3/5p2/5K2 b - - 0 1";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
         let m = Move::infer(Square::E3, Square::F3, &game);
         let expected = "Kf3";
-        let actual = m.to_san(&mut game);
+        let actual = m.to_san(&game);
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn to_shorthand_knights_only_disambiguate_from_legal_rivals() {
+        let game = Game::from_fen("4k3/8/8/8/8/5N2/8/1N2K3 w - - 0 1").unwrap();
+        let m = Move::infer(Square::B1, Square::D2, &game);
+        assert_eq!(m.to_san(&game), "Nbd2");
+
+        let game = Game::from_fen("k7/4r3/8/8/8/8/4N3/1N2K3 w - - 0 1").unwrap();
+        let m = Move::infer(Square::B1, Square::C3, &game);
+        assert_eq!(m.to_san(&game), "Nc3", "The knight on e2 is pinned");
+    }
+
+    #[test]
+    fn to_shorthand_special_moves_with_check() {
+        let cases = [
+            (
+                "4k3/1P6/8/8/8/8/8/4K3 w - - 0 1",
+                Square::B7,
+                Square::B8,
+                "b8=Q+",
+            ),
+            (
+                "5k2/8/8/8/8/8/8/4K2R w K - 0 1",
+                Square::E1,
+                Square::G1,
+                "O-O+",
+            ),
+            (
+                "8/2k5/8/3pP3/8/8/8/4K3 w - d6 0 1",
+                Square::E5,
+                Square::D6,
+                "exd6+",
+            ),
+        ];
+
+        for (fen, from, to, expected) in cases {
+            let game = Game::from_fen(fen).unwrap();
+            let m = Move::infer(from, to, &game);
+            assert_eq!(m.to_san(&game), expected, "{fen}");
+        }
+    }
+
+    #[test]
+    fn from_san() {
+        let fen = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+        let game = Game::from_fen(fen).unwrap();
+        for m in game.legal_moves() {
+            assert_eq!(Move::from_san(&m.to_san(&game), &game), Some(m), "{m}");
+        }
+
+        assert_eq!(
+            Move::from_san("0-0-0!?", &game),
+            Some(Move::Castle {
+                side: CastleSide::Queenside
+            })
+        );
+        assert_eq!(
+            Move::from_san("Nxf7", &game),
+            Some(Move::infer(Square::E5, Square::F7, &game))
+        );
+        assert_eq!(Move::from_san("Qa8", &game), None);
+        assert_eq!(Move::from_san("e4", &game), None);
+        // The rooks on a1 and f1 can both go to d1
+        let ambiguous = Game::from_fen("4k3/8/8/8/8/8/8/R4RK1 w - - 0 1").unwrap();
+        assert_eq!(Move::from_san("Rd1", &ambiguous), None);
+        assert_eq!(
+            Move::from_san("Rfd1", &ambiguous),
+            Some(Move::infer(Square::F1, Square::D1, &ambiguous))
+        );
+
+        let game = Game::default();
+        let nf3 = Some(Move::infer(Square::G1, Square::F3, &game));
+        assert_eq!(Move::from_san("Ngf3", &game), nf3);
+        assert_eq!(Move::from_san("Ng1f3", &game), nf3);
+        assert_eq!(Move::from_san("Nxf3", &game), nf3);
+        assert_eq!(Move::from_san("Nhf3", &game), None);
+
+        let game = Game::from_fen("8/1P2k3/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        let knight = Some(Move::infer_promotion(
+            Square::B7,
+            Square::B8,
+            PieceType::Knight,
+            &game,
+        ));
+        assert_eq!(Move::from_san("b8=N", &game), knight);
+        assert_eq!(Move::from_san("b8N", &game), knight);
+        assert_eq!(Move::from_san("b8", &game), None);
+    }
+
+    #[test]
+    fn long_algebraic_notation() {
+        let fen = "r2qk2r/1P2P3/8/8/8/8/8/R3K1N1 w Qkq - 0 1";
+        let game = Game::from_fen(fen).unwrap();
+
+        for (from, to, lan) in [
+            (Square::G1, Square::F3, "Ng1-f3"),
+            (Square::E7, Square::D8, "e7xd8=Q+"),
+            (Square::A1, Square::A8, "Ra1xa8"),
+            (Square::E1, Square::C1, "O-O-O"),
+        ] {
+            let m = Move::infer(from, to, &game);
+            assert_eq!(m.to_lan(&game), lan);
+            assert_eq!(Move::from_lan(lan, &game), Ok(m), "{lan}");
+        }
+
+        let underpromotion =
+            Move::infer_promotion(Square::B7, Square::B8, PieceType::Knight, &game);
+        assert_eq!(underpromotion.to_lan(&game), "b7-b8=N");
+        assert_eq!(Move::from_lan("b7b8=N", &game), Ok(underpromotion));
+    }
+
+    #[test]
+    fn from_lan_errors() {
+        let game = Game::default();
+        assert_eq!(
+            Move::from_lan("Bg1-f3", &game),
+            Err(LanError::WrongPiece {
+                square: Square::G1,
+                piece: PieceType::Bishop
+            })
+        );
+        assert_eq!(
+            Move::from_lan("Ne2-e4", &game),
+            Err(LanError::WrongPiece {
+                square: Square::E2,
+                piece: PieceType::Knight
+            })
+        );
+        assert_eq!(
+            Move::from_lan("Pe2-e4", &game),
+            Err(LanError::BadPiece('P'))
+        );
+        assert_eq!(
+            Move::from_lan("Ng1", &game),
+            Err(LanError::Unreadable(UciMoveError::WrongLength(2)))
+        );
+    }
+
     #[test]
     fn max_shorthand_notation_expected_bytes_is_actually_max() {
         let max = MAX_SHORTHAND_NOTATION_EXPECTED_BYTES;
@@ -0,0 +1,180 @@
+use std::fmt;
+
+use crate::{
+    file::File,
+    movegen::{moves::Move, pieces::piece::PieceType},
+    position::{castling::CastleSide, game::Game},
+    square::Square,
+};
+
+const FROM_MASK: u16 = 0b111111;
+const TO_SHIFT: u16 = 6;
+const FLAG_SHIFT: u16 = 12;
+
+const NORMAL: u16 = 0;
+const DOUBLE_PUSH: u16 = 1;
+const EN_PASSANT: u16 = 2;
+const CASTLE: u16 = 3;
+const PROMOTE_KNIGHT: u16 = 4;
+const PROMOTE_BISHOP: u16 = 5;
+const PROMOTE_ROOK: u16 = 6;
+const PROMOTE_QUEEN: u16 = 7;
+
+/// A move squeezed into 16 bits, for when lots of moves need to be stored, such as in a
+/// transposition table. The low 6 bits are the source square, the next 6 bits are the destination
+/// square and the top 4 bits say what kind of move it is.
+///
+/// Captured pieces are not stored, so a packed move can only be turned back into a [`Move`] with
+/// the position it was packed in.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PackedMove(u16);
+
+impl PackedMove {
+    /// Stands in for no move. No real move goes from A1 to A1.
+    pub const NONE: PackedMove = PackedMove(0);
+
+    const fn new(from: Square, to: Square, flag: u16) -> Self {
+        Self(from.to_int() as u16 | (to.to_int() as u16) << TO_SHIFT | flag << FLAG_SHIFT)
+    }
+
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    pub const fn bits(self) -> u16 {
+        self.0
+    }
+
+    pub const fn is_none(self) -> bool {
+        self.0 == PackedMove::NONE.0
+    }
+
+    pub const fn from(self) -> Square {
+        Square::new((self.0 & FROM_MASK) as u8)
+    }
+
+    pub const fn to(self) -> Square {
+        Square::new((self.0 >> TO_SHIFT & FROM_MASK) as u8)
+    }
+
+    const fn flag(self) -> u16 {
+        self.0 >> FLAG_SHIFT
+    }
+
+    /// Turns the packed move back into a [`Move`]. `game` has to be the position the move was
+    /// packed in, or at least one with the same pieces on the source and destination squares.
+    /// Returns `None` for [`PackedMove::NONE`] or bits that are not a packed move.
+    pub fn unpack(self, game: &Game) -> Option<Move> {
+        if self.is_none() {
+            return None;
+        }
+
+        let (from, to) = (self.from(), self.to());
+        let capture = game.piece_lookup(to).map(|(piece, _)| piece);
+        let promotion = |piece| Move::Promotion {
+            from: from.get_file(),
+            to: to.get_file(),
+            piece,
+            capture,
+        };
+
+        Some(match self.flag() {
+            NORMAL => Move::Normal { from, to, capture },
+            DOUBLE_PUSH => Move::CreateEnPassant {
+                at: from.get_file(),
+            },
+            EN_PASSANT => Move::CaptureEnPassant {
+                from: from.get_file(),
+            },
+            CASTLE => Move::Castle {
+                side: if to.get_file() == File::G {
+                    CastleSide::Kingside
+                } else {
+                    CastleSide::Queenside
+                },
+            },
+            PROMOTE_KNIGHT => promotion(PieceType::Knight),
+            PROMOTE_BISHOP => promotion(PieceType::Bishop),
+            PROMOTE_ROOK => promotion(PieceType::Rook),
+            PROMOTE_QUEEN => promotion(PieceType::Queen),
+            _ => return None,
+        })
+    }
+}
+
+impl fmt::Debug for PackedMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_none() {
+            write!(f, "PackedMove::NONE")
+        } else {
+            write!(
+                f,
+                "PackedMove({}{}, {})",
+                self.from(),
+                self.to(),
+                self.flag()
+            )
+        }
+    }
+}
+
+impl Move {
+    /// Packs the move into 16 bits. `game` has to be the position the move is played in.
+    pub fn pack(self, game: &Game) -> PackedMove {
+        let flag = match self {
+            Move::Normal { .. } => NORMAL,
+            Move::CreateEnPassant { .. } => DOUBLE_PUSH,
+            Move::CaptureEnPassant { .. } => EN_PASSANT,
+            Move::Castle { .. } => CASTLE,
+            Move::Promotion { piece, .. } => match piece {
+                PieceType::Knight => PROMOTE_KNIGHT,
+                PieceType::Bishop => PROMOTE_BISHOP,
+                PieceType::Rook => PROMOTE_ROOK,
+                _ => PROMOTE_QUEEN,
+            },
+        };
+
+        PackedMove::new(self.from(game.turn), self.to(game), flag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_legal_move_survives_packing() {
+        let fens = [
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R b KQkq - 0 1",
+            "4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1",
+            "1n2k3/P7/8/8/8/8/7p/4K1N1 w - - 0 1",
+            "1n2k3/P7/8/8/8/8/7p/4K1N1 b - - 0 1",
+        ];
+
+        for fen in fens {
+            let game = Game::from_fen(fen).unwrap();
+            for m in game.legal_moves() {
+                let packed = m.pack(&game);
+                assert!(!packed.is_none());
+                assert_eq!(packed.unpack(&game), Some(m), "{fen}");
+                assert_eq!(PackedMove::from_bits(packed.bits()), packed);
+            }
+        }
+    }
+
+    #[test]
+    fn packed_moves_are_small() {
+        assert_eq!(std::mem::size_of::<PackedMove>(), 2);
+        assert_eq!(PackedMove::NONE.unpack(&Game::default()), None);
+    }
+
+    #[test]
+    fn squares_and_flags_are_packed() {
+        let game = Game::default();
+        let packed = Move::infer(Square::E2, Square::E4, &game).pack(&game);
+        assert_eq!(packed.from(), Square::E2);
+        assert_eq!(packed.to(), Square::E4);
+        assert_eq!(packed.flag(), DOUBLE_PUSH);
+    }
+}
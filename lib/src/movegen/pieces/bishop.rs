@@ -8,7 +8,7 @@ use crate::{
     vectors::Vector,
 };
 
-use super::piece::PieceMoveInfo;
+use super::piece::{PieceColor, PieceMoveInfo};
 
 pub const MAXIMUM_MOVE_COUNT: u32 = 13;
 
@@ -50,8 +50,8 @@ impl Square {
         attacks_to_moves(self.bishop_psuedo_legal_attacks(game), *self, game)
     }
 
-    pub fn bishop_psuedo_legal_targets(&self, game: &Game) -> PieceMoveInfo {
-        self.rays(&DIRECTIONS, game)
+    pub fn bishop_psuedo_legal_targets(&self, game: &Game, color: PieceColor) -> PieceMoveInfo {
+        self.rays(&DIRECTIONS, game, color)
     }
 
     /// Generates a list of rook targets considering blockers
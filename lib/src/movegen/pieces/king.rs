@@ -77,16 +77,21 @@ pub fn attacks(sq: Square) -> BitBoard {
     ATTACKS[sq.index()]
 }
 
+/// Every square attacked by `kings`, found for all of them at once with whole-bitboard shifts
+pub const fn all_attacks(kings: BitBoard) -> BitBoard {
+    psuedo_legal_attacks(kings)
+}
+
 impl Square {
     /// King safety not considered.
     pub fn king_psuedo_legal_moves(self, game: &Game) -> Vec<Move> {
         self.lazy_king_psuedo_legal_moves(game).collect()
     }
 
-    pub fn king_psuedo_legal_targets(self, game: &Game) -> PieceMoveInfo {
+    pub fn king_psuedo_legal_targets(self, game: &Game, color: PieceColor) -> PieceMoveInfo {
         let mut moveinfo = PieceMoveInfo::default();
 
-        let enemy_or_empty = !*game.get_occupied(&game.turn);
+        let enemy_or_empty = !*game.get_occupied(&color);
 
         let sqbb = BitBoard::from_square(self);
         let not_a_file = !File::A.mask();
@@ -102,7 +107,7 @@ impl Square {
         moveinfo.targets |= attacks & enemy_or_empty;
 
         let occupied = game.occupied;
-        match game.turn {
+        match color {
             PieceColor::White => {
                 if game.castling_rights.white_queenside()
                     && occupied & castling::WHITE_CASTLE_QUEENSIDE_NEEDS_CLEAR == EMPTY
@@ -192,6 +197,23 @@ mod tests {
         test_utils::{should_generate, shouldnt_generate},
     };
 
+    #[test]
+    fn all_attacks_match_attacks_of_each_square() {
+        let sets = [
+            BitBoard::from_square(Square::A1) | BitBoard::from_square(Square::H8),
+            Game::default().occupied,
+            !EMPTY,
+        ];
+
+        for pieces in sets {
+            let mut expected = EMPTY;
+            for sq in pieces {
+                expected |= attacks(sq);
+            }
+            assert_eq!(all_attacks(pieces), expected);
+        }
+    }
+
     #[test]
     fn white_sees_castling_kingside() {
         let fen = "r2qkbnr/pp1b1ppp/2n1p3/1BppP3/3P4/5N2/PPP2PPP/RNBQK2R w KQkq - 4 6";
@@ -244,7 +266,7 @@ mod tests {
         expected.attacks.set(Square::D7);
         expected.attacks.set(Square::E7);
 
-        let actual = Square::E8.king_psuedo_legal_targets(&game);
+        let actual = Square::E8.king_psuedo_legal_targets(&game, PieceColor::Black);
         assert_eq!(actual, expected);
     }
 
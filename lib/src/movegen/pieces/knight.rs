@@ -3,7 +3,7 @@ use crate::{
     file::File,
     movegen::{
         moves::{Move, attacks_to_moves, push_attacks_to_moves_with_occupied},
-        pieces::piece::PieceMoveInfo,
+        pieces::piece::{PieceColor, PieceMoveInfo},
     },
     position::game::Game,
     square::Square,
@@ -59,14 +59,19 @@ pub fn attacks(sq: Square) -> BitBoard {
     ATTACKS[sq.index()]
 }
 
+/// Every square attacked by `knights`, found for all of them at once with whole-bitboard shifts
+pub const fn all_attacks(knights: BitBoard) -> BitBoard {
+    psuedo_legal_attacks(knights)
+}
+
 impl Square {
     pub fn knight_psuedo_legal_moves(self, game: &Game) -> Vec<Move> {
         attacks_to_moves(attacks(self), self, game)
     }
 
-    pub fn knight_psuedo_legal_targets(self, game: &Game) -> PieceMoveInfo {
+    pub fn knight_psuedo_legal_targets(self, game: &Game, color: PieceColor) -> PieceMoveInfo {
         let mut moveinfo = PieceMoveInfo::default();
-        let enemy_or_empty = !*game.get_occupied(&game.turn);
+        let enemy_or_empty = !*game.get_occupied(&color);
         let attacks = attacks(self);
 
         moveinfo.attacks = attacks;
@@ -83,6 +88,23 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn all_attacks_match_attacks_of_each_square() {
+        let sets = [
+            BitBoard::from_square(Square::A1) | BitBoard::from_square(Square::H8),
+            Game::default().occupied,
+            !EMPTY,
+        ];
+
+        for pieces in sets {
+            let mut expected = EMPTY;
+            for sq in pieces {
+                expected |= attacks(sq);
+            }
+            assert_eq!(all_attacks(pieces), expected);
+        }
+    }
+
     #[test]
     fn knight_cant_capture_en_passant() {
         let mut game = Game::default();
@@ -1,154 +1,134 @@
 use crate::{
-    bitboard::BitBoard,
+    bitboard::{BitBoard, EMPTY},
     file::File,
     movegen::{
-        moves::{Move, targets_to_moves},
-        pieces::piece::{PieceColor, PieceMoveInfo, PieceType},
+        moves::Move,
+        pieces::piece::{PROMOTION_PIECES, PieceColor, PieceMoveInfo, PieceType},
     },
     position::game::Game,
-    rank::Rank,
     square::Square,
     vectors::Vector,
 };
 
-macro_rules! assert_shift {
-    ($shifted:expr, $regular:expr) => {
-        #[cfg(debug_assertions)]
-        {
-            let sqbb = match $regular {
-                Some(sq) => BitBoard::from_square(sq),
-                None => crate::bitboard::EMPTY,
-            };
-
-            assert_eq!($shifted, sqbb);
-        }
-    };
-}
+pub const MAXIMUM_MOVE_COUNT: u32 = 12;
 
-pub const MAXIMUM_MOVE_COUNT: u32 = 4;
+/// The squares a single pawn attacks, indexed by `PieceColor::to_int` and then by square
+static ATTACKS: [[BitBoard; 64]; 2] = {
+    let not_a = !File::A.mask().to_int();
+    let not_h = !File::H.mask().to_int();
 
-pub fn push_psuedo_legal_moves_white<V: Vector<Move>>(moves: &mut V, game: &Game) {
-    let twice_mask = Rank::Fourth.mask();
-    let promotion_mask = Rank::Eighth.mask();
-    let unoccupied = !game.occupied;
-
-    let once = game.white_pawns.up() & unoccupied;
-    let twice = once.up() & unoccupied & twice_mask;
-    let promotions = once & promotion_mask;
-
-    let capture_right = game.white_pawns.up_right() & (game.black_occupied & !File::A.mask());
-    let capture_left = game.white_pawns.up_left() & (game.black_occupied & !File::H.mask());
-
-    macro_rules! get_piece {
-        ($sq:expr) => {
-            Some(
-                if cfg!(debug_assertions) {
-                    game.piece_lookup($sq).unwrap()
-                } else {
-                    // Should be safe because with pawn move generation we know for sure
-                    // whether or not we can capture ahead of time using bit manipulation
-                    unsafe { game.piece_lookup($sq).unwrap_unchecked() }
-                }
-                .0,
-            )
-        };
-    }
-
-    for to in once ^ promotions {
-        let from = unsafe { to.down_unchecked() };
-        let m = Move::Normal {
-            from,
-            to,
-            capture: None,
-        };
-        moves.push(m);
-    }
-
-    for sq in twice {
-        let m = Move::CreateEnPassant { at: sq.get_file() };
-        moves.push(m);
+    let mut table = [[EMPTY; 64]; 2];
+    let mut n = 0;
+    while n < 64 {
+        let bb = BitBoard::from_square(Square::new(n)).to_int();
+        table[0][n as usize] = BitBoard::new((bb << 7) & not_h | (bb << 9) & not_a);
+        table[1][n as usize] = BitBoard::new((bb >> 9) & not_h | (bb >> 7) & not_a);
+        n += 1;
     }
+    table
+};
 
-    for sq in promotions {
-        let file = sq.get_file();
-        let m = Move::Promotion {
-            from: file,
-            to: file,
-            piece: PieceType::Queen,
-            capture: None,
-        };
-        moves.push(m);
-    }
+/// Every square a set of pawns of the same color can move to, found for all of them at once with
+/// whole-bitboard shifts
+#[derive(Default, PartialEq, Eq, Debug, Clone, Copy)]
+pub struct PawnTargets {
+    /// Single pushes, including the ones that promote
+    pub once: BitBoard,
+    /// Double pushes off the starting rank
+    pub twice: BitBoard,
+    /// Captures towards the A file
+    pub capture_left: BitBoard,
+    /// Captures towards the H file
+    pub capture_right: BitBoard,
+}
 
-    for to in capture_right & !promotion_mask {
-        let from = unsafe { to.dleft_unchecked() };
-        let m = Move::Normal {
-            from,
-            to,
-            capture: get_piece!(to),
-        };
-        moves.push(m);
+impl PawnTargets {
+    /// Finds the targets of `pawns`, which must all be of `color`. `capturable` is every square
+    /// the pawns are allowed to capture on.
+    pub fn new(
+        pawns: BitBoard,
+        color: PieceColor,
+        unoccupied: BitBoard,
+        capturable: BitBoard,
+    ) -> Self {
+        let twice_mask = color.create_en_passant_rank().mask();
+        let west = pawns & !File::A.mask();
+        let east = pawns & !File::H.mask();
+
+        match color {
+            PieceColor::White => {
+                let once = pawns.up() & unoccupied;
+                Self {
+                    once,
+                    twice: once.up() & unoccupied & twice_mask,
+                    capture_left: west.up_left() & capturable,
+                    capture_right: east.up_right() & capturable,
+                }
+            }
+            PieceColor::Black => {
+                let once = pawns.down() & unoccupied;
+                Self {
+                    once,
+                    twice: once.down() & unoccupied & twice_mask,
+                    capture_left: west.down_left() & capturable,
+                    capture_right: east.down_right() & capturable,
+                }
+            }
+        }
     }
 
-    for to in capture_left & !promotion_mask {
-        let from = unsafe { to.dright_unchecked() };
-        let m = Move::Normal {
-            from,
-            to,
-            capture: get_piece!(to),
-        };
-        moves.push(m);
+    /// Keeps only the targets inside of `mask`
+    pub fn masked(self, mask: BitBoard) -> Self {
+        Self {
+            once: self.once & mask,
+            twice: self.twice & mask,
+            capture_left: self.capture_left & mask,
+            capture_right: self.capture_right & mask,
+        }
     }
 
-    for to in capture_right & promotion_mask {
-        let from = unsafe { to.dleft_unchecked() };
-        let m = Move::Promotion {
-            from: from.get_file(),
-            to: to.get_file(),
-            piece: PieceType::Queen,
-            capture: get_piece!(to),
-        };
-        moves.push(m);
+    /// Every target, whether it is a push or a capture
+    pub fn all(&self) -> BitBoard {
+        self.once | self.twice | self.capture_left | self.capture_right
     }
+}
 
-    for to in capture_left & promotion_mask {
-        let from = unsafe { to.dright_unchecked() };
-        let m = Move::Promotion {
-            from: from.get_file(),
-            to: to.get_file(),
-            piece: PieceType::Queen,
-            capture: get_piece!(to),
-        };
-        moves.push(m);
-    }
+/// Every square attacked by `pawns`, which must all be of `color`
+pub fn attacks(pawns: BitBoard, color: PieceColor) -> BitBoard {
+    let west = pawns & !File::A.mask();
+    let east = pawns & !File::H.mask();
 
-    if let Some(target) = game.en_passant_target {
-        let mut process_target = |sq: Option<Square>| {
-            if let Some(sq) = sq
-                && game.white_pawns.has_square(BitBoard::from_square(sq))
-            {
-                let m = Move::CaptureEnPassant {
-                    from: sq.get_file(),
-                };
-                moves.push(m);
-            }
-        };
-        process_target(target.dleft());
-        process_target(target.dright());
+    match color {
+        PieceColor::White => west.up_left() | east.up_right(),
+        PieceColor::Black => west.down_left() | east.down_right(),
     }
 }
 
-pub fn push_psuedo_legal_moves_black<V: Vector<Move>>(moves: &mut V, game: &Game) {
-    let twice_mask = Rank::Fifth.mask();
-    let promotion_mask = Rank::First.mask();
-    let unoccupied = !game.occupied;
+/// Every square attacked by a single pawn of `color` on `sq`
+pub fn square_attacks(sq: Square, color: PieceColor) -> BitBoard {
+    ATTACKS[color.to_int() as usize][sq.index()]
+}
 
-    let once = game.black_pawns.down() & unoccupied;
-    let twice = once.down() & unoccupied & twice_mask;
-    let promotions = once & promotion_mask;
+/// Expands setwise pawn targets of `color` into moves. En passant is left to the caller. Every
+/// promotion is generated once for each piece in [`PROMOTION_PIECES`].
+pub(crate) fn push_target_moves<V: Vector<Move>>(
+    moves: &mut V,
+    game: &Game,
+    color: PieceColor,
+    targets: PawnTargets,
+) {
+    let promotion_mask = color.final_rank().mask();
+
+    // How far each kind of target is from the square the pawn started on
+    let (push, left, right): (i8, i8, i8) = match color {
+        PieceColor::White => (8, 7, 9),
+        PieceColor::Black => (-8, -9, -7),
+    };
 
-    let capture_right = game.black_pawns.down_left() & (game.white_occupied & !File::H.mask());
-    let capture_left = game.black_pawns.down_right() & (game.white_occupied & !File::A.mask());
+    // Should be safe because every target was shifted out of a square holding one of our pawns
+    let origin = |to: Square, offset: i8| unsafe {
+        Square::new_unchecked((to.to_int() as i8 - offset) as u8)
+    };
 
     macro_rules! get_piece {
         ($sq:expr) => {
@@ -156,6 +136,8 @@ pub fn push_psuedo_legal_moves_black<V: Vector<Move>>(moves: &mut V, game: &Game
                 if cfg!(debug_assertions) {
                     game.piece_lookup($sq).unwrap()
                 } else {
+                    // Should be safe because with pawn move generation we know for sure
+                    // whether or not we can capture ahead of time using bit manipulation
                     unsafe { game.piece_lookup($sq).unwrap_unchecked() }
                 }
                 .0,
@@ -163,202 +145,132 @@ pub fn push_psuedo_legal_moves_black<V: Vector<Move>>(moves: &mut V, game: &Game
         };
     }
 
-    for to in once ^ promotions {
-        let from = unsafe { to.up_unchecked() };
+    for to in targets.once & !promotion_mask {
         let m = Move::Normal {
-            from,
+            from: origin(to, push),
             to,
             capture: None,
         };
         moves.push(m);
     }
 
-    for sq in twice {
+    for sq in targets.twice {
         let m = Move::CreateEnPassant { at: sq.get_file() };
         moves.push(m);
     }
 
-    for sq in promotions {
+    for sq in targets.once & promotion_mask {
         let file = sq.get_file();
-        let m = Move::Promotion {
-            from: file,
-            to: file,
-            piece: PieceType::Queen,
-            capture: None,
-        };
-        moves.push(m);
+        for piece in PROMOTION_PIECES {
+            let m = Move::Promotion {
+                from: file,
+                to: file,
+                piece,
+                capture: None,
+            };
+            moves.push(m);
+        }
     }
 
-    for to in capture_right & !promotion_mask {
-        let from = unsafe { to.uright_unchecked() };
-        let m = Move::Normal {
-            from,
-            to,
-            capture: get_piece!(to),
-        };
-        moves.push(m);
-    }
+    let captures = match color {
+        PieceColor::White => [(targets.capture_right, right), (targets.capture_left, left)],
+        PieceColor::Black => [(targets.capture_left, left), (targets.capture_right, right)],
+    };
 
-    for to in capture_left & !promotion_mask {
-        let from = unsafe { to.uleft_unchecked() };
-        let m = Move::Normal {
-            from,
-            to,
-            capture: get_piece!(to),
-        };
-        moves.push(m);
+    for (capturing, offset) in captures {
+        for to in capturing & !promotion_mask {
+            let m = Move::Normal {
+                from: origin(to, offset),
+                to,
+                capture: get_piece!(to),
+            };
+            moves.push(m);
+        }
     }
 
-    for to in capture_right & promotion_mask {
-        let from = unsafe { to.uright_unchecked() };
-        let m = Move::Promotion {
-            from: from.get_file(),
-            to: to.get_file(),
-            piece: PieceType::Queen,
-            capture: get_piece!(to),
-        };
-        moves.push(m);
+    for (capturing, offset) in captures {
+        for to in capturing & promotion_mask {
+            let from = origin(to, offset).get_file();
+            let capture = get_piece!(to);
+            for piece in PROMOTION_PIECES {
+                let m = Move::Promotion {
+                    from,
+                    to: to.get_file(),
+                    piece,
+                    capture,
+                };
+                moves.push(m);
+            }
+        }
     }
+}
 
-    for to in capture_left & promotion_mask {
-        let from = unsafe { to.uleft_unchecked() };
-        let m = Move::Promotion {
-            from: from.get_file(),
-            to: to.get_file(),
-            piece: PieceType::Queen,
-            capture: get_piece!(to),
-        };
-        moves.push(m);
-    }
+/// Expands the setwise targets of every pawn of `color` into moves
+fn push_psuedo_legal_moves<V: Vector<Move>>(moves: &mut V, game: &Game, color: PieceColor) {
+    let pawns = *game.get_pieces(&PieceType::Pawn, &color);
+    let enemy_occupied = *game.get_occupied(&color.opponent());
+    let targets = PawnTargets::new(pawns, color, !game.occupied, enemy_occupied);
+    push_target_moves(moves, game, color, targets);
 
     if let Some(target) = game.en_passant_target {
-        let mut process_target = |sq: Option<Square>| {
-            if let Some(sq) = sq
-                && game.black_pawns.has_square(BitBoard::from_square(sq))
-            {
-                let m = Move::CaptureEnPassant {
-                    from: sq.get_file(),
-                };
-                moves.push(m);
-            }
-        };
-        process_target(target.uleft());
-        process_target(target.uright());
+        // Our pawns that could capture on the target are the ones an enemy pawn standing on it
+        // would attack
+        for sq in attacks(BitBoard::from_square(target), color.opponent()) & pawns {
+            let m = Move::CaptureEnPassant {
+                from: sq.get_file(),
+            };
+            moves.push(m);
+        }
     }
 }
 
+pub fn push_psuedo_legal_moves_white<V: Vector<Move>>(moves: &mut V, game: &Game) {
+    push_psuedo_legal_moves(moves, game, PieceColor::White);
+}
+
+pub fn push_psuedo_legal_moves_black<V: Vector<Move>>(moves: &mut V, game: &Game) {
+    push_psuedo_legal_moves(moves, game, PieceColor::Black);
+}
+
 impl Square {
     /// Generates all psuedo legal moves for a single pawn
     /// En_Passant is considered
-    /// Promotion is considered (only for queen)
+    /// Promotion is considered for every promotion piece
     /// King safety not considered
     pub fn pawn_psuedo_legal_moves(self, game: &Game) -> Vec<Move> {
-        targets_to_moves(self.pawn_psuedo_legal_targets(game).targets, self, game)
-    }
-
-    pub fn pawn_psuedo_legal_targets(self, game: &Game) -> PieceMoveInfo {
-        let mut moveinfo = PieceMoveInfo::default();
-
         let sqbb = BitBoard::from_square(self);
-        let friendly = game
-            .determine_color(BitBoard::from_square(self))
+        let color = game
+            .determine_color(sqbb)
             .expect("Tried to move non existent pawn");
+        let enemy_occupied = *game.get_occupied(&color.opponent());
+        let targets = PawnTargets::new(sqbb, color, !game.occupied, enemy_occupied);
 
-        let file = self.get_file();
-
-        match friendly {
-            PieceColor::White => {
-                let oncebb = sqbb << 8;
-                assert_shift!(oncebb, self.up());
-
-                if !game.occupied.has_square(oncebb) {
-                    moveinfo.targets |= oncebb;
-                    if self.get_rank() == Rank::Second {
-                        let twicebb = oncebb << 8;
-                        assert_shift!(twicebb, self.up().and_then(|s| s.up()));
-
-                        if !game.occupied.has_square(twicebb) {
-                            moveinfo.targets |= twicebb;
-                        }
-                    }
-                }
-
-                if file > File::A {
-                    let uleft = sqbb << 7;
-                    assert_shift!(uleft, self.uleft());
-
-                    moveinfo.attacks |= uleft;
-                    if game.black_occupied.has_square(uleft) {
-                        moveinfo.targets |= uleft;
-                    } else if let Some(target) = game.en_passant_target
-                        && uleft == BitBoard::from_square(target)
-                    {
-                        moveinfo.targets |= uleft;
-                    }
-                }
-
-                if file < File::H {
-                    let uright = sqbb << 9;
-                    assert_shift!(uright, self.uright());
-
-                    moveinfo.attacks |= uright;
-                    if game.black_occupied.has_square(uright) {
-                        moveinfo.targets |= uright;
-                    } else if let Some(target) = game.en_passant_target
-                        && uright == BitBoard::from_square(target)
-                    {
-                        moveinfo.targets |= uright;
-                    }
-                }
-            }
+        let mut moves = Vec::new();
+        push_target_moves(&mut moves, game, color, targets);
+        if let Some(target) = game.en_passant_target
+            && attacks(sqbb, color).has_square(BitBoard::from_square(target))
+        {
+            moves.push(Move::CaptureEnPassant {
+                from: self.get_file(),
+            });
+        }
+        moves
+    }
 
-            PieceColor::Black => {
-                let oncebb = sqbb >> 8;
-                assert_shift!(oncebb, self.down());
-
-                if !game.occupied.has_square(oncebb) {
-                    moveinfo.targets |= oncebb;
-                    if self.get_rank() == Rank::Seventh {
-                        let twicebb = oncebb >> 8;
-                        assert_shift!(twicebb, self.down().and_then(|s| s.down()));
-
-                        if !game.occupied.has_square(twicebb) {
-                            moveinfo.targets |= twicebb;
-                        }
-                    }
-                }
+    pub fn pawn_psuedo_legal_targets(self, game: &Game, color: PieceColor) -> PieceMoveInfo {
+        let sqbb = BitBoard::from_square(self);
+        let mut capturable = *game.get_occupied(&color.opponent());
+        if let Some(target) = game.en_passant_target {
+            capturable.set(target);
+        }
 
-                if file > File::A {
-                    let dleft = sqbb >> 9;
-                    assert_shift!(dleft, self.dleft());
-
-                    moveinfo.attacks |= dleft;
-                    if game.white_occupied.has_square(dleft) {
-                        moveinfo.targets |= dleft;
-                    } else if let Some(target) = game.en_passant_target
-                        && dleft == BitBoard::from_square(target)
-                    {
-                        moveinfo.targets |= dleft;
-                    }
-                }
+        let targets = PawnTargets::new(sqbb, color, !game.occupied, capturable);
 
-                if file < File::H {
-                    let dright = sqbb >> 7;
-                    assert_shift!(dright, self.dright());
-
-                    moveinfo.attacks |= dright;
-                    if game.white_occupied.has_square(dright) {
-                        moveinfo.targets |= dright;
-                    } else if let Some(target) = game.en_passant_target
-                        && dright == BitBoard::from_square(target)
-                    {
-                        moveinfo.targets |= dright;
-                    }
-                }
-            }
+        PieceMoveInfo {
+            targets: targets.all(),
+            attacks: attacks(sqbb, color),
+            ..Default::default()
         }
-
-        moveinfo
     }
 }
 
@@ -368,6 +280,19 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn square_attacks_match_setwise_attacks() {
+        for color in [PieceColor::White, PieceColor::Black] {
+            for sq in Square::ALL_SQUARES {
+                assert_eq!(
+                    square_attacks(sq, color),
+                    attacks(BitBoard::from_square(sq), color),
+                    "{color:?} {sq}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn white_pawn_sees_black_target() {
         let mut game = Game::default();
@@ -469,4 +394,62 @@ mod tests {
             moves
         );
     }
+
+    #[test]
+    fn every_promotion_piece_is_generated() {
+        let fen = "4k2r/6P1/8/8/8/8/8/4K3 w - - 0 1";
+        let game = Game::from_fen(fen).unwrap();
+        let moves = Square::G7.pawn_psuedo_legal_moves(&game);
+
+        for piece in PROMOTION_PIECES {
+            for (to, capture) in [(File::G, None), (File::H, Some(PieceType::Rook))] {
+                let m = Move::Promotion {
+                    from: File::G,
+                    to,
+                    piece,
+                    capture,
+                };
+                assert!(moves.contains(&m), "{m} was not generated");
+            }
+        }
+        assert_eq!(moves.len(), 8);
+    }
+
+    #[test]
+    fn setwise_targets_match_each_pawn() {
+        let fen = "r3k2r/1P4p1/8/2pP4/5p2/4P2P/P5P1/R3K2R w KQkq c6 0 1";
+        let game = Game::from_fen(fen).unwrap();
+
+        for color in [PieceColor::White, PieceColor::Black] {
+            let pawns = *game.get_pieces(&PieceType::Pawn, &color);
+            let mut capturable = *game.get_occupied(&color.opponent());
+            capturable.set(game.en_passant_target.unwrap());
+
+            let mut targets = BitBoard::default();
+            let mut attacked = BitBoard::default();
+            for sq in pawns {
+                let moveinfo = sq.pawn_psuedo_legal_targets(&game, color);
+                targets |= moveinfo.targets;
+                attacked |= moveinfo.attacks;
+            }
+
+            let setwise = PawnTargets::new(pawns, color, !game.occupied, capturable);
+            assert_eq!(setwise.all(), targets, "{:?}", color);
+            assert_eq!(attacks(pawns, color), attacked, "{:?}", color);
+        }
+    }
+
+    #[test]
+    fn captures_do_not_wrap_around_the_board() {
+        let pawns = BitBoard::from_square(Square::A4) | BitBoard::from_square(Square::H4);
+        let everything = !BitBoard::default();
+
+        let white = PawnTargets::new(pawns, PieceColor::White, everything, everything);
+        assert_eq!(white.capture_left, BitBoard::from_square(Square::G5));
+        assert_eq!(white.capture_right, BitBoard::from_square(Square::B5));
+
+        let black = PawnTargets::new(pawns, PieceColor::Black, everything, everything);
+        assert_eq!(black.capture_left, BitBoard::from_square(Square::G3));
+        assert_eq!(black.capture_right, BitBoard::from_square(Square::B3));
+    }
 }
@@ -2,6 +2,7 @@ use crate::{
     bitboard::{BitBoard, EMPTY},
     movegen::{
         legal_moves::LegalMovesFilter,
+        move_list::MoveList,
         moves::Move,
         pieces::{bishop, queen, rook},
     },
@@ -73,6 +74,14 @@ pub const ALL_PIECE_TYPES: [PieceType; 6] = [
 
 pub const ALL_RAY_PIECES: [PieceType; 3] = [PieceType::Bishop, PieceType::Rook, PieceType::Queen];
 
+/// Every piece a pawn can promote to, best first
+pub const PROMOTION_PIECES: [PieceType; 4] = [
+    PieceType::Queen,
+    PieceType::Rook,
+    PieceType::Bishop,
+    PieceType::Knight,
+];
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum PieceType {
     Pawn,
@@ -118,19 +127,34 @@ impl PieceType {
         }
     }
 
+    /// Generates the targets and attacks of the piece on `square`, for the color of that piece
     pub fn psuedo_legal_targets_fast(&self, game: &Game, square: &Square) -> PieceMoveInfo {
+        let color = game
+            .piece_lookup(*square)
+            .map_or(game.turn, |(_, color)| color);
+        self.psuedo_legal_targets_for(game, square, color)
+    }
+
+    /// Generates the targets and attacks of a piece of `color` on `square`, whichever side is to
+    /// move
+    pub fn psuedo_legal_targets_for(
+        &self,
+        game: &Game,
+        square: &Square,
+        color: PieceColor,
+    ) -> PieceMoveInfo {
         match self {
-            PieceType::Pawn => square.pawn_psuedo_legal_targets(game),
-            PieceType::Knight => square.knight_psuedo_legal_targets(game),
-            PieceType::Bishop => square.bishop_psuedo_legal_targets(game),
-            PieceType::Rook => square.rook_psuedo_legal_targets(game),
-            PieceType::Queen => square.queen_psuedo_legal_targets(game),
-            PieceType::King => square.king_psuedo_legal_targets(game),
+            PieceType::Pawn => square.pawn_psuedo_legal_targets(game, color),
+            PieceType::Knight => square.knight_psuedo_legal_targets(game, color),
+            PieceType::Bishop => square.bishop_psuedo_legal_targets(game, color),
+            PieceType::Rook => square.rook_psuedo_legal_targets(game, color),
+            PieceType::Queen => square.queen_psuedo_legal_targets(game, color),
+            PieceType::King => square.king_psuedo_legal_targets(game, color),
         }
     }
 
-    pub fn legal_moves(&self, game: &Game, square: &Square) -> Vec<Move> {
-        game.legal_moves_filter(self.psuedo_legal_moves(game, square))
+    pub fn legal_moves(&self, game: &Game, square: &Square) -> MoveList {
+        game.legal_moves_filter(self.psuedo_legal_moves(game, square).into_iter().collect())
     }
 
     pub fn is_ray_piece(&self) -> bool {
@@ -160,11 +184,42 @@ impl PieceType {
         }
     }
 
+    /// Parses a piece letter in either case, such as the promotion suffix of a uci move
+    pub fn from_notation(c: char) -> Option<PieceType> {
+        match c.to_ascii_uppercase() {
+            'P' => Some(PieceType::Pawn),
+            'N' => Some(PieceType::Knight),
+            'B' => Some(PieceType::Bishop),
+            'R' => Some(PieceType::Rook),
+            'Q' => Some(PieceType::Queen),
+            'K' => Some(PieceType::King),
+            _ => None,
+        }
+    }
+
     /// Returns the letter representing the piece type in standard notation.
     /// The letter is uppercase for White and lowercase for Black.
     pub fn colored_notation(self, color: PieceColor) -> char {
         color.color_notation(self.notation())
     }
+
+    /// Returns the unicode chess symbol of the piece, such as ♘ for a white knight
+    pub fn unicode(self, color: PieceColor) -> char {
+        match (color, self) {
+            (PieceColor::White, PieceType::Pawn) => '♙',
+            (PieceColor::White, PieceType::Knight) => '♘',
+            (PieceColor::White, PieceType::Bishop) => '♗',
+            (PieceColor::White, PieceType::Rook) => '♖',
+            (PieceColor::White, PieceType::Queen) => '♕',
+            (PieceColor::White, PieceType::King) => '♔',
+            (PieceColor::Black, PieceType::Pawn) => '♟',
+            (PieceColor::Black, PieceType::Knight) => '♞',
+            (PieceColor::Black, PieceType::Bishop) => '♝',
+            (PieceColor::Black, PieceType::Rook) => '♜',
+            (PieceColor::Black, PieceType::Queen) => '♛',
+            (PieceColor::Black, PieceType::King) => '♚',
+        }
+    }
 }
 
 /// Stores where a piece could move to and what squares it currently defends
@@ -180,15 +235,11 @@ pub struct PieceMoveInfo {
 
 impl Game {
     /// Filters out psuedo_legal moves that are found to be illegal
-    pub fn legal_moves_filter(&self, psuedo_legal: Vec<Move>) -> Vec<Move> {
-        let mut legal = Vec::with_capacity(psuedo_legal.len());
+    pub fn legal_moves_filter(&self, mut moves: MoveList) -> MoveList {
         let lmf = LegalMovesFilter::new(self);
+        moves.retain(|&m| lmf.check(m));
 
-        for m in psuedo_legal {
-            if !lmf.check(m) {
-                continue;
-            }
-
+        for m in &moves {
             debug_assert!(
                 !matches!(
                     m,
@@ -201,18 +252,16 @@ impl Game {
                 m,
                 self
             );
-
-            legal.push(m);
         }
 
         debug_assert!(
             self.white_kings != EMPTY && self.black_kings != EMPTY,
             "There is no king! {:#?} {:?}",
-            legal,
+            moves,
             self
         );
 
-        legal
+        moves
     }
 }
 
@@ -233,7 +282,7 @@ mod tests {
     #[test]
     fn cant_move_into_check() {
         let fen = "1k6/1r6/8/8/8/8/8/K7 w - - 0 1";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
         let psuedo_legal = game.generate_all_psuedo_legal_moves();
         let legal = game.legal_moves();
 
@@ -258,7 +307,7 @@ mod tests {
     #[test]
     fn block_check_with_piece() {
         let fen = "4k3/4r3/8/8/2N5/8/4K3/8 w - - 0 1";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
 
         let legal_moves = game.legal_moves();
         let looking_for = Move::infer(Square::C4, Square::E3, &game);
@@ -269,7 +318,7 @@ mod tests {
     #[test]
     fn must_move_out_of_check() {
         let fen = "4k3/4r3/8/8/8/3P1P2/4KP2/3RRR2 w - - 0 1";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
 
         let legal_moves = game.legal_moves();
         let looking_for = [Move::infer(Square::E2, Square::D2, &game)];
@@ -280,7 +329,7 @@ mod tests {
     #[test]
     fn capture_checking_piece() {
         let fen = "4k3/4r3/8/8/1B6/3P1P2/3PKP2/3RRR2 w - - 0 1";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
 
         let legal_moves = game.legal_moves();
         let looking_for = [Move::infer(Square::B4, Square::E7, &game)];
@@ -291,7 +340,7 @@ mod tests {
     #[test]
     fn pinned_piece_cannot_move() {
         let fen = "4k3/4r3/8/8/3P1P2/4B3/3PK3/6P1 w - - 0 1";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
 
         let legal_moves = game.legal_moves();
         let looking_for = Move::infer(Square::E3, Square::F2, &game);
@@ -302,7 +351,7 @@ mod tests {
     #[test]
     fn cant_move_king_within_check_ray() {
         let fen = "4K3/4R3/8/8/8/8/4k3/8 b - - 0 1";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
 
         let legal_moves = game.legal_moves();
         let looking_for = [
@@ -318,7 +367,7 @@ mod tests {
     #[test]
     fn must_move_out_of_double_check() {
         let fen = "4k3/4r3/8/6Qb/8/2R5/4KP2/8 w - - 0 1";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
         let legal_moves = game.legal_moves();
         let king = Square::E2;
 
@@ -575,7 +624,7 @@ Available moves: {}
     #[test]
     fn not_checkmate() {
         let fen = "r2q1rk1/p2n1pp1/1p3n1p/2b5/8/1R3P1N/P2pP1PP/2BQKB1R w K - 0 14";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
         let moves = game.legal_moves();
         let possible_moves = [
             Move::Normal {
@@ -598,7 +647,7 @@ Available moves: {}
     #[test]
     fn shouldnt_have_moves() {
         let fen = "1kb2b1r/1p1p1ppp/1Np5/8/4P1PP/1P3PK1/r6q/8 w - - 1 27";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
         let moves = game.legal_moves();
         assert!(
             moves.is_empty(),
@@ -626,7 +675,7 @@ Available moves: {}
     #[test]
     fn does_not_try_to_block_wrong_piece() {
         let fen = "4k3/3R4/q7/pP2Pb1p/1P4P1/6n1/4K1p1/2q5 w - - 9 74";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
         let moves = game.legal_moves();
         let m = Move::infer(Square::D7, Square::D3, &game);
         shouldnt_generate(&moves, &m);
@@ -635,7 +684,7 @@ Available moves: {}
     #[test]
     fn white_must_move_king_after_discovered() {
         let fen = "rn5b/3K1k1r/p2p1p1p/Pb2q2P/p5P1/1P6/6PR/8 w - - 7 54";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
         let actual = game.legal_moves();
         let expected = vec![
             Move::infer(Square::D7, Square::C7, &game),
@@ -650,7 +699,7 @@ Available moves: {}
     #[test]
     fn black_king_can_recapture_attacker() {
         let fen = "kQ6/p7/Pp6/1P6/4p3/4R3/4P1p1/6K1 b - - 0 1";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
         let expected = vec![Move::Normal {
             from: Square::A8,
             to: Square::B8,
@@ -663,7 +712,7 @@ Available moves: {}
     #[test]
     fn can_not_capture_en_passant_while_removing_both_pawns_blocking_check() {
         let fen = "8/8/8/2rPp2K/8/8/8/k7 w - e6 0 38";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
         let m = Move::CaptureEnPassant { from: File::D };
         let moves = game.legal_moves();
         shouldnt_generate(&moves, &m);
@@ -672,7 +721,7 @@ Available moves: {}
     #[test]
     fn can_capture_en_passant_because_there_is_an_extra_blocker() {
         let fen = "8/8/8/2rPp1nK/8/8/8/k7 w - e6 0 38";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
         let m = Move::CaptureEnPassant { from: File::D };
         let moves = game.legal_moves();
         should_generate(&moves, &m);
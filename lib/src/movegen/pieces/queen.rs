@@ -2,7 +2,11 @@ use crate::{
     bitboard::BitBoard,
     movegen::{
         moves::{Move, attacks_to_moves, push_attacks_to_moves_with_occupied},
-        pieces::{bishop, piece::PieceMoveInfo, rook},
+        pieces::{
+            bishop,
+            piece::{PieceColor, PieceMoveInfo},
+            rook,
+        },
     },
     position::game::Game,
     square::{ALL_DIRECTIONS, Square},
@@ -39,8 +43,8 @@ impl Square {
         attacks_to_moves(self.queen_psuedo_legal_attacks(game), *self, game)
     }
 
-    pub fn queen_psuedo_legal_targets(&self, game: &Game) -> PieceMoveInfo {
-        self.rays(&ALL_DIRECTIONS, game)
+    pub fn queen_psuedo_legal_targets(&self, game: &Game, color: PieceColor) -> PieceMoveInfo {
+        self.rays(&ALL_DIRECTIONS, game, color)
     }
 }
 
@@ -7,7 +7,7 @@ use crate::{
         pieces::piece::{PieceColor, PieceType},
     },
     position::castling::{self, CastleSide},
-    position::game::Game,
+    position::{events::GameEvent, game::Game},
     rank::Rank,
     remove_piece,
     square::Square,
@@ -17,6 +17,7 @@ impl Game {
     /// Unplays a move on the board.
     pub fn unplay(&mut self, m: &Move) {
         self.restore_position();
+        self.move_history.pop();
 
         match m {
             Move::Normal { from, to, capture } => {
@@ -28,15 +29,15 @@ impl Game {
                     .piece_lookup(to)
                     .expect("Couldn't find piece to unmove!");
 
-                let pieces = get_pieces_mut!(self, &piece, &color);
-                remove_piece!(self, pieces, tobb, to);
+                let pieces = get_pieces_mut!(self.position, &piece, &color);
+                remove_piece!(self.position, pieces, tobb, to);
                 // *pieces ^= tobb;
-                add_piece!(self, pieces, frombb, from, piece, color);
+                add_piece!(self.position, pieces, frombb, from, piece, color);
                 // *pieces |= frombb;
 
                 if let Some(enemy) = capture {
-                    let pieces = get_pieces_mut!(self, enemy, &color.opponent());
-                    add_piece!(self, pieces, tobb, to, *enemy, color.opponent());
+                    let pieces = get_pieces_mut!(self.position, enemy, &color.opponent());
+                    add_piece!(self.position, pieces, tobb, to, *enemy, color.opponent());
                     // *pieces |= tobb;
                 }
             }
@@ -55,10 +56,10 @@ impl Game {
 
                 let frombb = BitBoard::from_square(from);
                 let tobb = BitBoard::from_square(to);
-                let pawns = get_pieces_mut!(self, &PieceType::Pawn, &color);
-                remove_piece!(self, pawns, tobb, to);
+                let pawns = get_pieces_mut!(self.position, &PieceType::Pawn, &color);
+                remove_piece!(self.position, pawns, tobb, to);
                 // *pawns ^= tobb;
-                add_piece!(self, pawns, frombb, from, PieceType::Pawn, color);
+                add_piece!(self.position, pawns, frombb, from, PieceType::Pawn, color);
                 // *pawns |= frombb;
             }
             Move::CaptureEnPassant { from: from_file } => {
@@ -80,10 +81,10 @@ impl Game {
                 let frombb = BitBoard::from_square(from);
                 let tobb = BitBoard::from_square(to);
 
-                let pawns = get_pieces_mut!(self, &PieceType::Pawn, &color);
-                remove_piece!(self, pawns, tobb, to);
+                let pawns = get_pieces_mut!(self.position, &PieceType::Pawn, &color);
+                remove_piece!(self.position, pawns, tobb, to);
                 // *pawns ^= tobb;
-                add_piece!(self, pawns, frombb, from, PieceType::Pawn, color);
+                add_piece!(self.position, pawns, frombb, from, PieceType::Pawn, color);
                 // *pawns |= frombb;
 
                 // Restore the captured pawn
@@ -91,9 +92,9 @@ impl Game {
                     .backward(&color)
                     .expect("Can't find pawn behind en_passant_target!");
                 let en_passant_bb = BitBoard::from_square(en_passant_sq);
-                let enemy_pawns = get_pieces_mut!(self, &PieceType::Pawn, &enemy_color);
+                let enemy_pawns = get_pieces_mut!(self.position, &PieceType::Pawn, &enemy_color);
                 add_piece!(
-                    self,
+                    self.position,
                     enemy_pawns,
                     en_passant_bb,
                     en_passant_sq,
@@ -124,18 +125,18 @@ impl Game {
                 let tobb = BitBoard::from_square(to);
 
                 // Remove promoted piece from destination square
-                let promoted_pieces = get_pieces_mut!(self, piece, &color);
-                remove_piece!(self, promoted_pieces, tobb, to);
+                let promoted_pieces = get_pieces_mut!(self.position, piece, &color);
+                remove_piece!(self.position, promoted_pieces, tobb, to);
                 // *promoted_pieces ^= tobb;
 
                 // Restore original pawn
-                let pawns = get_pieces_mut!(self, &PieceType::Pawn, &color);
-                add_piece!(self, pawns, frombb, from, PieceType::Pawn, color);
+                let pawns = get_pieces_mut!(self.position, &PieceType::Pawn, &color);
+                add_piece!(self.position, pawns, frombb, from, PieceType::Pawn, color);
                 // *pawns |= frombb;
 
                 if let Some(enemy) = capture {
-                    let pieces = get_pieces_mut!(self, enemy, &color.opponent());
-                    add_piece!(self, pieces, tobb, to, *enemy, color.opponent());
+                    let pieces = get_pieces_mut!(self.position, enemy, &color.opponent());
+                    add_piece!(self.position, pieces, tobb, to, *enemy, color.opponent());
                     // *pieces |= tobb;
                 }
             }
@@ -144,7 +145,7 @@ impl Game {
                 match color {
                     PieceColor::White => match side {
                         CastleSide::Queenside => castle!(
-                            self,
+                            self.position,
                             &mut self.white_kings,
                             &mut self.white_rooks,
                             castling::WHITE_CASTLE_QUEENSIDE_KING_TO_BB,
@@ -158,7 +159,7 @@ impl Game {
                             PieceColor::White
                         ),
                         CastleSide::Kingside => castle!(
-                            self,
+                            self.position,
                             &mut self.white_kings,
                             &mut self.white_rooks,
                             castling::WHITE_CASTLE_KINGSIDE_KING_TO_BB,
@@ -174,7 +175,7 @@ impl Game {
                     },
                     PieceColor::Black => match side {
                         CastleSide::Queenside => castle!(
-                            self,
+                            self.position,
                             &mut self.black_kings,
                             &mut self.black_rooks,
                             castling::BLACK_CASTLE_QUEENSIDE_KING_TO_BB,
@@ -188,7 +189,7 @@ impl Game {
                             PieceColor::Black
                         ),
                         CastleSide::Kingside => castle!(
-                            self,
+                            self.position,
                             &mut self.black_kings,
                             &mut self.black_rooks,
                             castling::BLACK_CASTLE_KINGSIDE_KING_TO_BB,
@@ -207,6 +208,13 @@ impl Game {
         }
 
         self.previous_turn();
+        self.emit(GameEvent::MoveUndone(*m));
+    }
+
+    /// Takes back a null move played with `Game::make_null`
+    pub fn unmake_null(&mut self) {
+        self.restore_position();
+        self.previous_turn();
     }
 }
 
@@ -216,7 +224,8 @@ mod tests {
     use crate::file::File;
     use crate::position::game::State;
     use crate::square::Square;
-    use crate::test_utils::compare_games;
+    use crate::test_utils::{assert_caches_are_fresh, compare_games};
+    use crate::zobrist;
 
     macro_rules! play_unplay_with_game {
         ($game:expr, $sequence:expr) => {{
@@ -264,6 +273,47 @@ mod tests {
         };
     }
 
+    #[test]
+    fn unmake_null_restores_everything() {
+        let fen = "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 2";
+        let mut game = Game::from_fen(fen).unwrap();
+        let before = game.clone();
+
+        game.make_null();
+        assert_eq!(game.turn, PieceColor::Black);
+        assert_eq!(game.en_passant_target, None);
+        assert_eq!(game.hash, zobrist::hash(&game));
+        assert_ne!(game.hash, before.hash);
+        assert_caches_are_fresh(&game);
+
+        game.make_null();
+        assert_eq!(game.turn, PieceColor::White);
+        assert_eq!(game.hash, zobrist::hash(&game));
+
+        game.unmake_null();
+        game.unmake_null();
+        compare_games(&before, &game);
+        assert_eq!(game.hash, before.hash);
+    }
+
+    #[test]
+    fn null_move_inside_a_line() {
+        let mut game = Game::default();
+        let before = game.clone();
+        let e4 = Move::infer(Square::E2, Square::E4, &game);
+        game.play(&e4);
+        game.make_null();
+        let d4 = Move::infer(Square::D2, Square::D4, &game);
+        game.play(&d4);
+        assert_eq!(game.hash, zobrist::hash(&game));
+        assert_eq!(game.repetitions(), 0);
+
+        game.unplay(&d4);
+        game.unmake_null();
+        game.unplay(&e4);
+        compare_games(&before, &game);
+    }
+
     test_play_unplay!(unplay_normal, [(Square::G1, Square::F3)]);
     test_play_unplay!(unplay_create_en_passant, [(Square::E2, Square::E4)]);
 
@@ -342,4 +392,56 @@ mod tests {
         game.unplay(&m);
         assert_eq!(game.en_passant_target, Some(Square::F6));
     }
+
+    /// A small xorshift generator, so random games are the same on every run
+    struct Xorshift(u64);
+
+    impl Xorshift {
+        fn below(&mut self, n: usize) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % n as u64) as usize
+        }
+    }
+
+    #[test]
+    fn random_games_unmake_exactly() {
+        let fens = [
+            crate::position::game::STARTING_FEN,
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+            "4k3/8/8/8/8/8/8/4K2R w K - 40 80",
+        ];
+        let mut rng = Xorshift(0x9E37_79B9_7F4A_7C15);
+
+        for fen in fens {
+            for _ in 0..20 {
+                let start = Game::from_fen(fen).unwrap();
+                let mut game = start.clone();
+                let mut snapshots = Vec::new();
+
+                // Long enough to walk past the end of a game now and then
+                for _ in 0..300 {
+                    let moves = game.legal_moves();
+                    if moves.is_empty() {
+                        break;
+                    }
+                    let m = moves[rng.below(moves.len())];
+                    snapshots.push(game.clone());
+                    game.play(&m);
+                    assert_caches_are_fresh(&game);
+                }
+
+                while let Some(before) = snapshots.pop() {
+                    game.undo().unwrap();
+                    assert_caches_are_fresh(&game);
+                    compare_games(&before, &game);
+                    assert_eq!(before.to_fen(), game.to_fen());
+                }
+                compare_games(&start, &game);
+            }
+        }
+    }
 }
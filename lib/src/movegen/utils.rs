@@ -2,6 +2,10 @@
 #[macro_export]
 macro_rules! remove_piece {
     ($game:expr, $pieces:expr, $sqbb:expr, $sq:expr) => {
+        if let Some((piece, color)) = $game.piece_table.get($sq) {
+            $game.hash ^= $crate::zobrist::KEYS.piece(piece, color, $sq);
+            $game.material.remove(piece, color);
+        }
         $game.piece_table.set($sq, None);
         *$pieces ^= $sqbb;
     };
@@ -11,6 +15,8 @@ macro_rules! remove_piece {
 #[macro_export]
 macro_rules! add_piece {
     ($game:expr, $pieces:expr, $sqbb:expr, $sq:expr, $piece:expr, $color:expr) => {
+        $game.hash ^= $crate::zobrist::KEYS.piece($piece, $color, $sq);
+        $game.material.add($piece, $color);
         $game.piece_table.set($sq, Some(($piece, $color)));
         *$pieces |= $sqbb;
     };
@@ -0,0 +1,315 @@
+use std::fmt::Write;
+
+use crate::{
+    movegen::pieces::piece::PieceColor,
+    position::{
+        game::{Game, Outcome, STARTING_FEN},
+        history::GameHistory,
+    },
+};
+
+pub mod reader;
+
+/// Movetext lines are wrapped so that none of them are longer than this
+pub const PGN_LINE_WIDTH: usize = 80;
+
+/// The tags written at the top of a PGN. The Seven Tag Roster is always written, with `?` standing
+/// in for anything unknown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgnTags {
+    pub event: String,
+    pub site: String,
+    /// Written as YYYY.MM.DD, with `?` for unknown digits
+    pub date: String,
+    pub round: String,
+    pub white: String,
+    pub black: String,
+    /// Overrides the result from [`Game::outcome`], for games that ended in a way the board does
+    /// not show, such as by resignation
+    pub result: Option<String>,
+    /// Any other tags, written after the Seven Tag Roster in order
+    pub extra: Vec<(String, String)>,
+}
+
+impl Default for PgnTags {
+    fn default() -> Self {
+        Self {
+            event: "?".to_string(),
+            site: "?".to_string(),
+            date: "????.??.??".to_string(),
+            round: "?".to_string(),
+            white: "?".to_string(),
+            black: "?".to_string(),
+            result: None,
+            extra: Vec::new(),
+        }
+    }
+}
+
+/// The PGN game termination marker for `outcome`, `*` if the game is still going
+pub fn result_token(outcome: Option<Outcome>) -> &'static str {
+    match outcome {
+        None => "*",
+        Some(Outcome::Decisive {
+            winner: PieceColor::White,
+        }) => "1-0",
+        Some(Outcome::Decisive {
+            winner: PieceColor::Black,
+        }) => "0-1",
+        Some(Outcome::Draw(_)) => "1/2-1/2",
+    }
+}
+
+/// The symbols that stand for Numeric Annotation Glyphs 1 to 6, such as `?!` for `$6`
+pub const NAG_SYMBOLS: [&str; 6] = ["!", "?", "!!", "??", "!?", "?!"];
+
+/// What is written after a move by [`Game::to_annotated_pgn`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MoveAnnotation {
+    /// A Numeric Annotation Glyph, written as its symbol from [`NAG_SYMBOLS`] if it has one
+    pub nag: Option<u8>,
+    /// Written in braces after the move, such as `[%eval 0.35]`
+    pub comment: Option<String>,
+}
+
+/// The symbol of a Numeric Annotation Glyph, if it has one
+pub fn nag_symbol(nag: u8) -> Option<&'static str> {
+    NAG_SYMBOLS.get(usize::from(nag).checked_sub(1)?).copied()
+}
+
+fn push_tag(out: &mut String, name: &str, value: &str) {
+    let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+    let _ = writeln!(out, "[{name} \"{value}\"]");
+}
+
+/// Adds words to movetext, starting a new line whenever the next word would not fit
+struct Movetext {
+    out: String,
+    line: usize,
+}
+
+impl Movetext {
+    fn push(&mut self, word: &str) {
+        if self.line > 0 && self.line + 1 + word.len() > PGN_LINE_WIDTH {
+            self.out.push('\n');
+            self.line = 0;
+        } else if self.line > 0 {
+            self.out.push(' ');
+            self.line += 1;
+        }
+
+        self.out.push_str(word);
+        self.line += word.len();
+    }
+
+    /// Pushes `comment` in braces, wrapping it between words like the rest of the movetext
+    fn push_comment(&mut self, comment: &str) {
+        let mut words = comment.split_whitespace().peekable();
+        let mut word = String::from("{");
+        while let Some(next) = words.next() {
+            word.push_str(next);
+            if words.peek().is_some() {
+                self.push(&word);
+                word.clear();
+            }
+        }
+        word.push('}');
+        self.push(&word);
+    }
+}
+
+impl Game {
+    /// Writes every move played so far as a PGN, along with `tags`. Games that did not start from
+    /// the standard position get SetUp and FEN tags, and the result comes from
+    /// [`Game::outcome`].
+    pub fn to_pgn(&self, tags: &PgnTags) -> String {
+        self.to_annotated_pgn(tags, &[])
+    }
+
+    /// Writes a PGN like [`Game::to_pgn`], with the annotation at each index of `annotations`
+    /// after the move of the same ply. Moves past the end of `annotations` are left bare.
+    pub fn to_annotated_pgn(&self, tags: &PgnTags, annotations: &[MoveAnnotation]) -> String {
+        let history = GameHistory::from_game(self);
+        let start = history.start();
+        let result = tags
+            .result
+            .as_deref()
+            .unwrap_or(result_token(self.outcome()));
+
+        let mut out = String::new();
+        push_tag(&mut out, "Event", &tags.event);
+        push_tag(&mut out, "Site", &tags.site);
+        push_tag(&mut out, "Date", &tags.date);
+        push_tag(&mut out, "Round", &tags.round);
+        push_tag(&mut out, "White", &tags.white);
+        push_tag(&mut out, "Black", &tags.black);
+        push_tag(&mut out, "Result", result);
+
+        let fen = start.to_fen();
+        if fen != STARTING_FEN {
+            push_tag(&mut out, "SetUp", "1");
+            push_tag(&mut out, "FEN", &fen);
+        }
+        for (name, value) in &tags.extra {
+            push_tag(&mut out, name, value);
+        }
+        out.push('\n');
+
+        let mut movetext = Movetext { out, line: 0 };
+        let mut number = start.full_move_clock.max(1);
+        let mut turn = start.turn;
+        let mut commented = false;
+        for (ply, entry) in history.entries().iter().enumerate() {
+            match turn {
+                PieceColor::White => movetext.push(&format!("{number}.")),
+                // Black moves are numbered again after anything that comes between them and
+                // white's move
+                PieceColor::Black if ply == 0 || commented => {
+                    movetext.push(&format!("{number}..."))
+                }
+                PieceColor::Black => {}
+            }
+
+            let annotation = annotations.get(ply);
+            let nag = annotation.and_then(|a| a.nag);
+            match nag.and_then(nag_symbol) {
+                Some(symbol) => movetext.push(&format!("{}{symbol}", entry.san)),
+                None => {
+                    movetext.push(&entry.san);
+                    if let Some(nag) = nag {
+                        movetext.push(&format!("${nag}"));
+                    }
+                }
+            }
+
+            let comment = annotation.and_then(|a| a.comment.as_deref());
+            if let Some(comment) = comment {
+                movetext.push_comment(comment);
+            }
+            commented = comment.is_some();
+
+            if turn == PieceColor::Black {
+                number += 1;
+            }
+            turn = turn.opponent();
+        }
+        movetext.push(result);
+
+        let mut out = movetext.out;
+        out.push('\n');
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scholars_mate() {
+        let mut game = Game::default();
+        game.apply_uci_moves(["e2e4", "e7e5", "f1c4", "b8c6", "d1h5", "g8f6", "h5f7"])
+            .unwrap();
+        let tags = PgnTags {
+            white: "Whalecrab".to_string(),
+            black: "Player \"2\"".to_string(),
+            extra: vec![("TimeControl".to_string(), "-".to_string())],
+            ..Default::default()
+        };
+
+        let expected = r#"[Event "?"]
+[Site "?"]
+[Date "????.??.??"]
+[Round "?"]
+[White "Whalecrab"]
+[Black "Player \"2\""]
+[Result "1-0"]
+[TimeControl "-"]
+
+1. e4 e5 2. Bc4 Nc6 3. Qh5 Nf6 4. Qxf7# 1-0
+"#;
+        assert_eq!(game.to_pgn(&tags), expected);
+    }
+
+    #[test]
+    fn games_from_a_position() {
+        let fen = "4k3/8/8/8/8/8/8/R3K3 b Q - 0 12";
+        let mut game = Game::from_fen(fen).unwrap();
+        game.apply_uci_moves(["e8d7", "a1a7"]).unwrap();
+
+        let pgn = game.to_pgn(&PgnTags::default());
+        assert!(pgn.contains(
+            "[Result \"*\"]\n[SetUp \"1\"]\n[FEN \"4k3/8/8/8/8/8/8/R3K3 b Q - 0 12\"]\n"
+        ));
+        assert!(pgn.ends_with("\n\n12... Kd7 13. Ra7+ *\n"));
+
+        let resigned = PgnTags {
+            result: Some("1-0".to_string()),
+            ..Default::default()
+        };
+        let pgn = game.to_pgn(&resigned);
+        assert!(pgn.contains("[Result \"1-0\"]\n"));
+        assert!(pgn.ends_with(" Ra7+ 1-0\n"));
+    }
+
+    #[test]
+    fn draws() {
+        let mut game = Game::default();
+        let shuffle = ["g1f3", "g8f6", "f3g1", "f6g8"];
+        game.apply_uci_moves(shuffle.iter().cycle().take(8))
+            .unwrap();
+        assert!(
+            game.to_pgn(&PgnTags::default())
+                .ends_with("4. Ng1 Ng8 1/2-1/2\n")
+        );
+    }
+
+    #[test]
+    fn annotations() {
+        let mut game = Game::default();
+        game.apply_uci_moves(["e2e4", "e7e5", "d1h5", "b8c6"])
+            .unwrap();
+        let annotations = [
+            MoveAnnotation::default(),
+            MoveAnnotation {
+                comment: Some("[%eval 0.3]".to_string()),
+                ..Default::default()
+            },
+            MoveAnnotation {
+                nag: Some(6),
+                comment: Some("Too early".to_string()),
+            },
+            MoveAnnotation {
+                nag: Some(10),
+                comment: None,
+            },
+        ];
+
+        let pgn = game.to_annotated_pgn(&PgnTags::default(), &annotations);
+        assert!(pgn.ends_with("\n\n1. e4 e5 {[%eval 0.3]} 2. Qh5?! {Too early} 2... Nc6 $10 *\n"));
+        assert_eq!(
+            game.to_annotated_pgn(&PgnTags::default(), &annotations[..1]),
+            game.to_pgn(&PgnTags::default())
+        );
+    }
+
+    #[test]
+    fn movetext_is_wrapped() {
+        let mut movetext = Movetext {
+            out: String::new(),
+            line: 0,
+        };
+        for number in 1..=60 {
+            movetext.push(&format!("{number}."));
+            movetext.push("Nxf7+");
+        }
+
+        movetext.push_comment(&"A long comment ".repeat(20));
+
+        assert!(movetext.out.lines().count() > 1);
+        for line in movetext.out.lines() {
+            assert!(line.len() <= PGN_LINE_WIDTH, "{line}");
+            assert!(!line.starts_with(' ') && !line.ends_with(' '));
+        }
+    }
+}
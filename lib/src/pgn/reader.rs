@@ -0,0 +1,657 @@
+//! Reads PGN files one game at a time, so that databases of millions of games can be gone through
+//! while holding no more than a line of them in memory. Games are handed to a [`Visitor`] piece
+//! by piece as they are read, and [`GameReplayer`] is the visitor for replaying them into
+//! [`Game`]s.
+
+use std::{
+    fmt,
+    io::{self, BufRead},
+};
+
+use crate::{
+    movegen::moves::Move,
+    pgn::{NAG_SYMBOLS, PgnTags},
+    position::{fen::FenError, game::Game},
+};
+
+/// Whether the reader should skip over part of a game instead of visiting it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Skip(pub bool);
+
+/// Receives each game from [`PgnReader::read_game`] as it is read. Everything but
+/// [`Visitor::end_game`] does nothing by default.
+pub trait Visitor {
+    /// What is made of each game
+    type Output;
+
+    /// Called before anything else of a game
+    fn begin_game(&mut self) {}
+
+    /// Called for each tag pair, with any escapes in the value undone
+    fn tag(&mut self, _name: &str, _value: &str) {}
+
+    /// Called after the last tag. Skipping the movetext still finds where the game ends, which
+    /// is much quicker for games that are not wanted.
+    fn end_tags(&mut self) -> Skip {
+        Skip(false)
+    }
+
+    /// Called for each move in Standard Algebraic Notation, without its move number. Check marks
+    /// are kept, while annotations such as `!?` are passed to [`Visitor::nag`].
+    fn san(&mut self, _san: &str) {}
+
+    /// Called for each Numeric Annotation Glyph, such as 1 for `$1` or `!`
+    fn nag(&mut self, _nag: u8) {}
+
+    /// Called for each comment, without its braces or the whitespace around it
+    fn comment(&mut self, _comment: &str) {}
+
+    /// Called when a variation starts. Variations are skipped unless this returns `Skip(false)`.
+    fn begin_variation(&mut self) -> Skip {
+        Skip(true)
+    }
+
+    /// Called when a variation that was not skipped ends
+    fn end_variation(&mut self) {}
+
+    /// Called with the game termination marker, such as `1-0` or `*`, if the game has one
+    fn outcome(&mut self, _result: &str) {}
+
+    /// Called after the last of a game
+    fn end_game(&mut self) -> Self::Output;
+}
+
+/// Reads games one by one from a PGN, see the [module documentation](self)
+pub struct PgnReader<R> {
+    reader: R,
+    /// The line being read, as bytes since not every PGN is UTF-8
+    line: Vec<u8>,
+    /// How much of `line` has been read
+    pos: usize,
+    /// Tag values and comments, which can not be borrowed straight from `line`
+    text: Vec<u8>,
+}
+
+const BYTE_ORDER_MARK: &[u8] = b"\xEF\xBB\xBF";
+
+/// Bytes that end a move or any other token in movetext
+fn ends_token(byte: u8) -> bool {
+    byte.is_ascii_whitespace() || b"{}();$[".contains(&byte)
+}
+
+/// The Numeric Annotation Glyph of a move suffix such as `!?`
+fn suffix_nag(suffix: &[u8]) -> Option<u8> {
+    NAG_SYMBOLS
+        .iter()
+        .position(|symbol| symbol.as_bytes() == suffix)
+        .map(|i| i as u8 + 1)
+}
+
+/// `token` without a move number in front of it, such as `e4` from `1.e4` or `Nf6` from `2...Nf6`
+fn strip_move_number(token: &[u8]) -> &[u8] {
+    let digits = token.iter().take_while(|b| b.is_ascii_digit()).count();
+    match token[digits..].first() {
+        None => &[],
+        Some(b'.') => {
+            let rest = &token[digits..];
+            let dots = rest.iter().take_while(|&&b| b == b'.').count();
+            &rest[dots..]
+        }
+        // Castling with zeros, such as 0-0
+        Some(_) => token,
+    }
+}
+
+impl<R: BufRead> PgnReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: Vec::new(),
+            pos: 0,
+            text: Vec::new(),
+        }
+    }
+
+    /// Makes sure some of the current line is left to read, moving on to the next line if not.
+    /// Lines starting with the `%` escape are skipped. Returns false at the end of the input.
+    fn fill(&mut self) -> io::Result<bool> {
+        while self.pos >= self.line.len() {
+            self.line.clear();
+            self.pos = 0;
+            if self.reader.read_until(b'\n', &mut self.line)? == 0 {
+                return Ok(false);
+            }
+
+            if self.line.starts_with(BYTE_ORDER_MARK) {
+                self.pos = BYTE_ORDER_MARK.len();
+            }
+            if self.line.first() == Some(&b'%') {
+                self.pos = self.line.len();
+            }
+        }
+        Ok(true)
+    }
+
+    /// Moves past any whitespace, returning false at the end of the input
+    fn skip_whitespace(&mut self) -> io::Result<bool> {
+        while self.fill()? {
+            match self.line[self.pos..]
+                .iter()
+                .position(|b| !b.is_ascii_whitespace())
+            {
+                Some(skipped) => {
+                    self.pos += skipped;
+                    return Ok(true);
+                }
+                None => self.pos = self.line.len(),
+            }
+        }
+        Ok(false)
+    }
+
+    fn at_line_start(&self) -> bool {
+        self.line[..self.pos].iter().all(u8::is_ascii_whitespace)
+    }
+
+    /// Reads the next game into `visitor`, returning what it made of it or `None` once there are
+    /// no games left. A game ends at its termination marker, or where the tags of the next game
+    /// start if it has none.
+    pub fn read_game<V: Visitor>(&mut self, visitor: &mut V) -> io::Result<Option<V::Output>> {
+        if !self.skip_whitespace()? {
+            return Ok(None);
+        }
+
+        visitor.begin_game();
+        self.read_tags(visitor)?;
+        let skip = visitor.end_tags();
+        self.read_movetext(visitor, skip)?;
+        Ok(Some(visitor.end_game()))
+    }
+
+    /// Skips the next game, returning false if there are no games left
+    pub fn skip_game(&mut self) -> io::Result<bool> {
+        Ok(self.read_game(&mut SkipGame)?.is_some())
+    }
+
+    /// Replays each of the remaining games, see [`GameReplayer`]
+    pub fn games(self) -> Games<R> {
+        Games {
+            reader: self,
+            replayer: GameReplayer::default(),
+            failed: false,
+        }
+    }
+
+    fn read_tags<V: Visitor>(&mut self, visitor: &mut V) -> io::Result<()> {
+        while self.skip_whitespace()? && self.line[self.pos] == b'[' {
+            let line = &self.line[self.pos + 1..];
+            let name_end = line
+                .iter()
+                .position(|&b| b.is_ascii_whitespace() || b == b'"' || b == b']')
+                .unwrap_or(line.len());
+
+            self.text.clear();
+            let mut i = name_end;
+            while i < line.len() && line[i] != b'"' && line[i] != b']' {
+                i += 1;
+            }
+            if line.get(i) == Some(&b'"') {
+                i += 1;
+                while i < line.len() && line[i] != b'"' {
+                    if line[i] == b'\\' && i + 1 < line.len() {
+                        i += 1;
+                    }
+                    self.text.push(line[i]);
+                    i += 1;
+                }
+            }
+
+            let name = String::from_utf8_lossy(&line[..name_end]);
+            visitor.tag(&name, &String::from_utf8_lossy(&self.text));
+            // Anything after the tag on the same line is dropped along with it
+            self.pos = self.line.len();
+        }
+        Ok(())
+    }
+
+    fn read_movetext<V: Visitor>(&mut self, visitor: &mut V, skip: Skip) -> io::Result<()> {
+        // How many variations are open
+        let mut depth = 0;
+        // How deep the variation being skipped is, 0 for skipping the whole movetext
+        let mut skipping = skip.0.then_some(0);
+
+        while self.skip_whitespace()? {
+            let visiting = skipping.is_none();
+            match self.line[self.pos] {
+                // The next game, as this one has no termination marker
+                b'[' if depth == 0 && self.at_line_start() => break,
+                b'{' => {
+                    self.pos += 1;
+                    self.text.clear();
+                    while self.fill()? {
+                        let rest = &self.line[self.pos..];
+                        match rest.iter().position(|&b| b == b'}') {
+                            Some(end) => {
+                                self.text.extend_from_slice(&rest[..end]);
+                                self.pos += end + 1;
+                                break;
+                            }
+                            None => {
+                                self.text.extend_from_slice(rest);
+                                self.pos = self.line.len();
+                            }
+                        }
+                    }
+                    if visiting {
+                        visitor.comment(String::from_utf8_lossy(&self.text).trim());
+                    }
+                }
+                b';' => {
+                    if visiting {
+                        let comment = String::from_utf8_lossy(&self.line[self.pos + 1..]);
+                        visitor.comment(comment.trim());
+                    }
+                    self.pos = self.line.len();
+                }
+                b'(' => {
+                    self.pos += 1;
+                    depth += 1;
+                    if visiting && visitor.begin_variation().0 {
+                        skipping = Some(depth);
+                    }
+                }
+                b')' => {
+                    self.pos += 1;
+                    // A stray bracket is ignored
+                    if depth == 0 {
+                        continue;
+                    }
+                    if skipping == Some(depth) {
+                        skipping = None;
+                    } else if visiting {
+                        visitor.end_variation();
+                    }
+                    depth -= 1;
+                }
+                b'$' => {
+                    let digits = self.line[self.pos + 1..]
+                        .iter()
+                        .take_while(|b| b.is_ascii_digit())
+                        .count();
+                    let nag = std::str::from_utf8(&self.line[self.pos + 1..][..digits])
+                        .ok()
+                        .and_then(|nag| nag.parse().ok());
+                    self.pos += 1 + digits;
+                    if let Some(nag) = nag
+                        && visiting
+                    {
+                        visitor.nag(nag);
+                    }
+                }
+                _ => {
+                    let start = self.pos;
+                    let len = self.line[start..]
+                        .iter()
+                        .position(|&b| ends_token(b))
+                        .unwrap_or(self.line.len() - start);
+                    // A lone `[` that is not the start of a tag is skipped over
+                    self.pos += len.max(1);
+                    let token = &self.line[start..start + len];
+
+                    if let b"1-0" | b"0-1" | b"1/2-1/2" | b"*" = token {
+                        if depth > 0 {
+                            continue;
+                        }
+                        if skipping.is_none() {
+                            visitor.outcome(&String::from_utf8_lossy(token));
+                        }
+                        break;
+                    }
+
+                    let token = strip_move_number(token);
+                    let annotation = token
+                        .iter()
+                        .rev()
+                        .take_while(|&&b| b == b'!' || b == b'?')
+                        .count();
+                    let (san, suffix) = token.split_at(token.len() - annotation);
+                    if visiting && !san.is_empty() {
+                        visitor.san(&String::from_utf8_lossy(san));
+                        if let Some(nag) = suffix_nag(suffix) {
+                            visitor.nag(nag);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Skips whole games for [`PgnReader::skip_game`]
+struct SkipGame;
+
+impl Visitor for SkipGame {
+    type Output = ();
+
+    fn end_tags(&mut self) -> Skip {
+        Skip(true)
+    }
+
+    fn end_game(&mut self) {}
+}
+
+/// Why a game could not be replayed
+#[derive(Debug)]
+pub enum PgnError {
+    /// The PGN could not be read
+    Io(io::Error),
+    /// The FEN tag could not be read
+    BadFen(FenError),
+    /// A move of the main line could not be played
+    BadMove { index: usize, san: String },
+}
+
+impl fmt::Display for PgnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PgnError::Io(e) => write!(f, "the pgn could not be read: {e}"),
+            PgnError::BadFen(e) => write!(f, "the starting position is bad: {e}"),
+            PgnError::BadMove { index, san } => {
+                write!(f, "move {} ({san}) could not be played", index + 1)
+            }
+        }
+    }
+}
+
+impl From<io::Error> for PgnError {
+    fn from(e: io::Error) -> Self {
+        PgnError::Io(e)
+    }
+}
+
+/// A game replayed from a PGN
+#[derive(Debug)]
+pub struct PgnGame {
+    /// The tags of the game. The result is taken from the termination marker if there is no
+    /// Result tag, and the SetUp and FEN tags are left out as they are part of the game.
+    pub tags: PgnTags,
+    /// The game after the last move of its main line
+    pub game: Game,
+}
+
+/// A [`Visitor`] that plays the main line of each game, starting from its FEN tag if it has
+/// one. Comments, annotations and variations are left out.
+#[derive(Default)]
+pub struct GameReplayer {
+    tags: PgnTags,
+    game: Game,
+    moves: usize,
+    error: Option<PgnError>,
+}
+
+impl Visitor for GameReplayer {
+    type Output = Result<PgnGame, PgnError>;
+
+    fn begin_game(&mut self) {
+        *self = Self::default();
+    }
+
+    fn tag(&mut self, name: &str, value: &str) {
+        let value = value.to_string();
+        match name {
+            "Event" => self.tags.event = value,
+            "Site" => self.tags.site = value,
+            "Date" => self.tags.date = value,
+            "Round" => self.tags.round = value,
+            "White" => self.tags.white = value,
+            "Black" => self.tags.black = value,
+            "Result" => self.tags.result = Some(value),
+            "SetUp" => {}
+            "FEN" => match Game::from_fen(&value) {
+                Ok(game) => self.game = game,
+                Err(e) => self.error = Some(PgnError::BadFen(e)),
+            },
+            _ => self.tags.extra.push((name.to_string(), value)),
+        }
+    }
+
+    fn end_tags(&mut self) -> Skip {
+        Skip(self.error.is_some())
+    }
+
+    fn san(&mut self, san: &str) {
+        if self.error.is_some() {
+            return;
+        }
+
+        match Move::from_san(san, &self.game) {
+            Some(m) => self.game.play(&m),
+            None => {
+                self.error = Some(PgnError::BadMove {
+                    index: self.moves,
+                    san: san.to_string(),
+                })
+            }
+        }
+        self.moves += 1;
+    }
+
+    fn outcome(&mut self, result: &str) {
+        self.tags.result.get_or_insert_with(|| result.to_string());
+    }
+
+    fn end_game(&mut self) -> Self::Output {
+        let replayed = std::mem::take(self);
+        match replayed.error {
+            Some(e) => Err(e),
+            None => Ok(PgnGame {
+                tags: replayed.tags,
+                game: replayed.game,
+            }),
+        }
+    }
+}
+
+/// Replays the games of a PGN one by one, see [`PgnReader::games`]
+pub struct Games<R> {
+    reader: PgnReader<R>,
+    replayer: GameReplayer,
+    failed: bool,
+}
+
+impl<R: BufRead> Iterator for Games<R> {
+    type Item = Result<PgnGame, PgnError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        match self.reader.read_game(&mut self.replayer) {
+            Ok(game) => game,
+            Err(e) => {
+                // Whatever broke the input will not fix itself
+                self.failed = true;
+                Some(Err(e.into()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GAMES: &str = r#"[Event "Casual game"]
+[White "Anderssen, Adolf"]
+[Black "Kieseritzky, Lionel"]
+[Annotator "Someone \"quoted\""]
+[Result "1-0"]
+
+1. e4 e5 2. f4 exf4 3. Bc4 Qh4+ 4. Kf1 b5?! {A gambit of
+its own} 5. Bxb5 (5. Bb3 $2 Nf6) 5...Nf6 ; The rest is skipped
+1-0
+
+% A line the reader ignores
+[Event "From a position"]
+[SetUp "1"]
+[FEN "4k3/8/8/8/8/8/8/R3K3 w Q - 0 1"]
+
+1.O-O-O Kf7 2.Rd7+ *
+"#;
+
+    #[test]
+    fn replays_games() {
+        let games: Vec<_> = PgnReader::new(GAMES.as_bytes())
+            .games()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(games.len(), 2);
+
+        let immortal = &games[0];
+        assert_eq!(immortal.tags.white, "Anderssen, Adolf");
+        assert_eq!(immortal.tags.result.as_deref(), Some("1-0"));
+        assert_eq!(
+            immortal.tags.extra,
+            vec![("Annotator".to_string(), "Someone \"quoted\"".to_string())]
+        );
+        assert_eq!(
+            immortal.game.to_fen(),
+            "rnb1kb1r/p1pp1ppp/5n2/1B6/4Pp1q/8/PPPP2PP/RNBQ1KNR w kq - 1 6"
+        );
+
+        let from_position = &games[1];
+        assert_eq!(from_position.tags.result.as_deref(), Some("*"));
+        assert!(from_position.tags.extra.is_empty());
+        assert_eq!(
+            from_position.game.to_fen(),
+            "8/3R1k2/8/8/8/8/8/2K5 b - - 3 2"
+        );
+    }
+
+    /// Writes down everything it is given
+    struct Recorder {
+        events: Vec<String>,
+        variations: bool,
+    }
+
+    impl Visitor for Recorder {
+        type Output = Vec<String>;
+
+        fn tag(&mut self, name: &str, value: &str) {
+            self.events.push(format!("[{name} {value}]"));
+        }
+
+        fn san(&mut self, san: &str) {
+            self.events.push(san.to_string());
+        }
+
+        fn nag(&mut self, nag: u8) {
+            self.events.push(format!("${nag}"));
+        }
+
+        fn comment(&mut self, comment: &str) {
+            self.events.push(format!("{{{comment}}}"));
+        }
+
+        fn begin_variation(&mut self) -> Skip {
+            self.events.push("(".to_string());
+            Skip(!self.variations)
+        }
+
+        fn end_variation(&mut self) {
+            self.events.push(")".to_string());
+        }
+
+        fn outcome(&mut self, result: &str) {
+            self.events.push(result.to_string());
+        }
+
+        fn end_game(&mut self) -> Self::Output {
+            std::mem::take(&mut self.events)
+        }
+    }
+
+    #[test]
+    fn visits_every_part() {
+        let mut reader = PgnReader::new(GAMES.as_bytes());
+        let mut recorder = Recorder {
+            events: Vec::new(),
+            variations: true,
+        };
+
+        let events = reader.read_game(&mut recorder).unwrap().unwrap();
+        assert_eq!(events[3], "[Annotator Someone \"quoted\"]");
+        assert_eq!(
+            events[12..].join(" "),
+            "b5 $6 {A gambit of\nits own} Bxb5 ( Bb3 $2 Nf6 ) Nf6 {The rest is skipped} 1-0"
+        );
+
+        recorder.variations = false;
+        let events = reader.read_game(&mut recorder).unwrap().unwrap();
+        assert_eq!(
+            events,
+            [
+                "[Event From a position]",
+                "[SetUp 1]",
+                "[FEN 4k3/8/8/8/8/8/8/R3K3 w Q - 0 1]",
+                "O-O-O",
+                "Kf7",
+                "Rd7+",
+                "*"
+            ]
+        );
+
+        assert!(reader.read_game(&mut recorder).unwrap().is_none());
+    }
+
+    #[test]
+    fn skips_variations_and_games() {
+        let mut reader = PgnReader::new(GAMES.as_bytes());
+        let mut recorder = Recorder {
+            events: Vec::new(),
+            variations: false,
+        };
+
+        let events = reader.read_game(&mut recorder).unwrap().unwrap();
+        assert!(events.contains(&"(".to_string()));
+        assert!(!events.contains(&"Bb3".to_string()));
+        assert!(!events.contains(&")".to_string()));
+
+        let mut reader = PgnReader::new(GAMES.as_bytes());
+        assert!(reader.skip_game().unwrap());
+        assert!(reader.skip_game().unwrap());
+        assert!(!reader.skip_game().unwrap());
+    }
+
+    #[test]
+    fn games_without_results_end_at_the_next_tags() {
+        let pgn = b"[Event \"Unfinished\"]\n\n1. d4 d5\n[Event \"Next\"]\n1. c4 {caf\xE9} 1/2-1/2";
+        let mut reader = PgnReader::new(&pgn[..]);
+        let mut recorder = Recorder {
+            events: Vec::new(),
+            variations: false,
+        };
+
+        let events = reader.read_game(&mut recorder).unwrap().unwrap();
+        assert_eq!(events, ["[Event Unfinished]", "d4", "d5"]);
+
+        // Bytes that are not UTF-8 are read as replacement characters
+        let events = reader.read_game(&mut recorder).unwrap().unwrap();
+        assert_eq!(events, ["[Event Next]", "c4", "{caf\u{FFFD}}", "1/2-1/2"]);
+    }
+
+    #[test]
+    fn reports_bad_games_and_carries_on() {
+        let pgn = "1. e4 e5 2. Ke3 *\n\n[FEN \"8/8/8 w - - 0 1\"]\n1. e4 *\n\n1. d4 *\n";
+        let games: Vec<_> = PgnReader::new(pgn.as_bytes()).games().collect();
+        assert_eq!(games.len(), 3);
+        assert!(matches!(
+            &games[0],
+            Err(PgnError::BadMove { index: 2, san }) if san == "Ke3"
+        ));
+        assert!(matches!(games[1], Err(PgnError::BadFen(_))));
+        assert!(games[2].is_ok());
+    }
+}
@@ -0,0 +1,272 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+    bitboard::{BitBoard, EMPTY},
+    file::{ALL_FILES, File},
+    movegen::pieces::piece::{PieceColor, PieceType},
+    position::{
+        castling::CastlingRights,
+        fen::FenError,
+        game::{Game, State},
+        material::MaterialCount,
+        piece_table::PieceTable,
+    },
+    rank::{ALL_RANKS, Rank},
+    square::Square,
+};
+
+/// Everything about a single position, without the history of how it was reached. It is small and
+/// `Copy`, so the search can save one before making a move and copy it back instead of unmaking
+/// the move piece by piece. [`Game`](crate::position::game::Game) wraps a `Position` along with
+/// the history needed for repetitions and undo, and derefs to it.
+#[derive(Clone, Copy)]
+pub struct Position {
+    pub white_pawns: BitBoard,
+    pub white_knights: BitBoard,
+    pub white_bishops: BitBoard,
+    pub white_rooks: BitBoard,
+    pub white_queens: BitBoard,
+    pub white_kings: BitBoard,
+
+    pub black_pawns: BitBoard,
+    pub black_knights: BitBoard,
+    pub black_bishops: BitBoard,
+    pub black_rooks: BitBoard,
+    pub black_queens: BitBoard,
+    pub black_kings: BitBoard,
+
+    pub castling_rights: CastlingRights,
+    pub en_passant_target: Option<Square>,
+    pub turn: PieceColor,
+
+    pub half_move_timeout: u8,
+    pub full_move_clock: u16,
+    pub state: State,
+    pub hash: u64,
+
+    // Cached game state
+    pub white_occupied: BitBoard,
+    pub black_occupied: BitBoard,
+    pub occupied: BitBoard,
+
+    pub white_attacks: BitBoard,
+    pub black_attacks: BitBoard,
+    pub white_check_rays: BitBoard,
+    pub black_check_rays: BitBoard,
+    pub(crate) piece_table: PieceTable,
+    pub(crate) material: MaterialCount,
+}
+
+impl Position {
+    /// A board without any pieces, castling rights or cached values
+    pub const fn empty() -> Self {
+        Self {
+            white_pawns: EMPTY,
+            white_knights: EMPTY,
+            white_bishops: EMPTY,
+            white_rooks: EMPTY,
+            white_queens: EMPTY,
+            white_kings: EMPTY,
+
+            black_pawns: EMPTY,
+            black_knights: EMPTY,
+            black_bishops: EMPTY,
+            black_rooks: EMPTY,
+            black_queens: EMPTY,
+            black_kings: EMPTY,
+
+            castling_rights: CastlingRights::empty(),
+            en_passant_target: None,
+            turn: PieceColor::White,
+
+            half_move_timeout: 0,
+            full_move_clock: 0,
+            state: State::InProgress,
+            hash: 0,
+
+            white_occupied: EMPTY,
+            black_occupied: EMPTY,
+            occupied: EMPTY,
+
+            white_attacks: EMPTY,
+            black_attacks: EMPTY,
+            white_check_rays: EMPTY,
+            black_check_rays: EMPTY,
+            piece_table: PieceTable::new(),
+            material: MaterialCount::empty(),
+        }
+    }
+
+    /// Attempts to generate a fen from the position
+    pub fn to_fen(&self) -> String {
+        let mut fen = String::new();
+
+        for rank in (0..8).rev() {
+            let mut empty_count: u8 = 0;
+
+            for file in 0..8 {
+                let sq = Square::make_square(Rank::from_index(rank), File::from_index(file));
+
+                if let Some((piece, color)) = self.piece_table.get(sq) {
+                    // If we had empty squares, add the count first
+                    if empty_count > 0 {
+                        fen.push_str(&empty_count.to_string());
+                        empty_count = 0;
+                    }
+
+                    let piece_char = match color {
+                        PieceColor::White => match piece {
+                            PieceType::Pawn => 'P',
+                            PieceType::Knight => 'N',
+                            PieceType::Bishop => 'B',
+                            PieceType::Rook => 'R',
+                            PieceType::Queen => 'Q',
+                            PieceType::King => 'K',
+                        },
+
+                        PieceColor::Black => match piece {
+                            PieceType::Pawn => 'p',
+                            PieceType::Knight => 'n',
+                            PieceType::Bishop => 'b',
+                            PieceType::Rook => 'r',
+                            PieceType::Queen => 'q',
+                            PieceType::King => 'k',
+                        },
+                    };
+
+                    fen.push(piece_char);
+                } else {
+                    empty_count += 1;
+                }
+            }
+
+            // Add any remaining empty squares at the end of the rank
+            if empty_count > 0 {
+                fen.push_str(&empty_count.to_string());
+            }
+
+            // Add rank separator (except for the last rank)
+            if rank > 0 {
+                fen.push('/');
+            }
+        }
+
+        fen.push(' ');
+        fen.push(match self.turn {
+            PieceColor::White => 'w',
+            PieceColor::Black => 'b',
+        });
+
+        fen.push(' ');
+        fen.push_str(&self.castling_rights.to_fen());
+
+        fen.push(' ');
+        if let Some(target) = self.en_passant_target {
+            fen.push_str(&target.to_string().to_lowercase());
+        } else {
+            fen.push('-');
+        }
+
+        fen.push_str(format!(" {} {}", self.half_move_timeout, self.full_move_clock).as_str());
+
+        fen
+    }
+
+    /// Draws the board with white at the bottom, using the fen letters of the pieces and `.` for
+    /// empty squares. The ranks and files are labeled along the left and bottom edges.
+    pub fn to_ascii(&self) -> String {
+        self.draw(PieceType::colored_notation)
+    }
+
+    /// Like [`Position::to_ascii`], but with unicode chess symbols such as ♞
+    pub fn to_unicode(&self) -> String {
+        self.draw(PieceType::unicode)
+    }
+
+    fn draw(&self, glyph: fn(PieceType, PieceColor) -> char) -> String {
+        let mut out = String::with_capacity(8 * 18 + 17);
+        for rank in ALL_RANKS.into_iter().rev() {
+            out.push(rank.notation());
+            for file in ALL_FILES {
+                out.push(' ');
+                out.push(
+                    match self.piece_table.get(Square::make_square(rank, file)) {
+                        Some((piece, color)) => glyph(piece, color),
+                        None => '.',
+                    },
+                );
+            }
+            out.push('\n');
+        }
+
+        out.push(' ');
+        for file in ALL_FILES {
+            out.push(' ');
+            out.push(file.notation());
+        }
+        out
+    }
+}
+
+impl fmt::Display for Position {
+    /// Writes the fen of the position, or draws the board like [`Position::to_ascii`] with `{:#}`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(&self.to_ascii())
+        } else {
+            f.write_str(&self.to_fen())
+        }
+    }
+}
+
+impl FromStr for Position {
+    type Err = FenError;
+
+    /// Reads a fen, refusing illegal positions like [`Game::from_fen`] does
+    fn from_str(fen: &str) -> Result<Self, FenError> {
+        Game::from_fen(fen).map(|game| *game.position())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fen_round_trips_through_the_std_traits() {
+        let fen = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R b KQkq - 3 17";
+        let position: Position = fen.parse().unwrap();
+        assert_eq!(position.to_string(), fen);
+
+        let game: Game = fen.parse().unwrap();
+        assert_eq!(game.to_string(), fen);
+
+        assert!(matches!(
+            "8/8/8/8 w - - 0 1".parse::<Position>(),
+            Err(FenError::WrongRankCount { ranks: 4 })
+        ));
+    }
+
+    #[test]
+    fn draws_the_board() {
+        let game =
+            Game::from_fen("r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3")
+                .unwrap();
+        let expected = "\
+8 r . b q k b n r
+7 p p p p . p p p
+6 . . n . . . . .
+5 . . . . p . . .
+4 . . . . P . . .
+3 . . . . . N . .
+2 P P P P . P P P
+1 R N B Q K B . R
+  a b c d e f g h";
+        assert_eq!(game.to_ascii(), expected);
+        assert_eq!(format!("{game:#}"), expected);
+
+        let unicode = game.to_unicode();
+        assert_eq!(unicode.lines().next(), Some("8 ♜ . ♝ ♛ ♚ ♝ ♞ ♜"));
+        assert_eq!(unicode.lines().nth(5), Some("3 . . . . . ♘ . ."));
+    }
+}
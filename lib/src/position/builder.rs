@@ -0,0 +1,189 @@
+use crate::{
+    bitboard::BitBoard,
+    movegen::pieces::piece::{PieceColor, PieceType},
+    position::{castling::CastlingRights, game::Game, legality::Ruleset},
+    square::Square,
+};
+
+/// Sets up a position piece by piece instead of through a fen string.
+///
+/// ```
+/// use whalecrab_lib::movegen::pieces::piece::{PieceColor, PieceType};
+/// use whalecrab_lib::position::builder::GameBuilder;
+/// use whalecrab_lib::square::Square;
+///
+/// let game = GameBuilder::new()
+///     .piece(Square::E1, PieceType::King, PieceColor::White)
+///     .piece(Square::E8, PieceType::King, PieceColor::Black)
+///     .piece(Square::E4, PieceType::Queen, PieceColor::White)
+///     .turn(PieceColor::Black)
+///     .build()
+///     .unwrap();
+/// assert_eq!(game.to_fen(), "4k3/8/8/8/4Q3/8/8/4K3 b - - 0 1");
+/// ```
+#[derive(Debug, Clone)]
+pub struct GameBuilder {
+    squares: [Option<(PieceType, PieceColor)>; 64],
+    turn: PieceColor,
+    castling_rights: CastlingRights,
+    en_passant_target: Option<Square>,
+    half_move_timeout: u8,
+    full_move_clock: u16,
+}
+
+impl Default for GameBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameBuilder {
+    /// An empty board with white to move and no castling rights
+    pub fn new() -> Self {
+        Self {
+            squares: [None; 64],
+            turn: PieceColor::White,
+            castling_rights: CastlingRights::empty(),
+            en_passant_target: None,
+            half_move_timeout: 0,
+            full_move_clock: 1,
+        }
+    }
+
+    /// Starts from the current position of `game`, such as to edit it
+    pub fn from_game(game: &Game) -> Self {
+        let mut builder = Self {
+            turn: game.turn,
+            castling_rights: game.castling_rights,
+            en_passant_target: game.en_passant_target,
+            half_move_timeout: game.half_move_timeout,
+            full_move_clock: game.full_move_clock,
+            ..Self::new()
+        };
+        for (sq, piece, color) in game.pieces() {
+            builder.squares[sq.index()] = Some((piece, color));
+        }
+        builder
+    }
+
+    /// Puts a piece on `sq`, replacing whatever was there
+    pub fn piece(mut self, sq: Square, piece: PieceType, color: PieceColor) -> Self {
+        self.squares[sq.index()] = Some((piece, color));
+        self
+    }
+
+    /// Removes the piece on `sq`, if any
+    pub fn clear(mut self, sq: Square) -> Self {
+        self.squares[sq.index()] = None;
+        self
+    }
+
+    pub fn turn(mut self, turn: PieceColor) -> Self {
+        self.turn = turn;
+        self
+    }
+
+    pub fn castling(mut self, castling_rights: CastlingRights) -> Self {
+        self.castling_rights = castling_rights;
+        self
+    }
+
+    pub fn en_passant(mut self, en_passant_target: Option<Square>) -> Self {
+        self.en_passant_target = en_passant_target;
+        self
+    }
+
+    pub fn half_move_timeout(mut self, half_move_timeout: u8) -> Self {
+        self.half_move_timeout = half_move_timeout;
+        self
+    }
+
+    pub fn full_move_clock(mut self, full_move_clock: u16) -> Self {
+        self.full_move_clock = full_move_clock;
+        self
+    }
+
+    /// The piece on `sq` so far
+    pub fn piece_on(&self, sq: Square) -> Option<(PieceType, PieceColor)> {
+        self.squares[sq.index()]
+    }
+
+    /// Builds the game, refusing positions that break the [`Ruleset::essential`] laws like
+    /// [`Game::from_fen`] does. See [`Game::validate`] for why.
+    pub fn build(&self) -> Result<Game, Ruleset> {
+        let game = self.build_unchecked();
+        game.validate()?;
+        Ok(game)
+    }
+
+    /// Builds the game without checking if the position makes sense
+    pub fn build_unchecked(&self) -> Game {
+        let mut game = Game::empty();
+        for (sq, colored_piece) in Square::ALL_SQUARES.into_iter().zip(self.squares) {
+            if let Some((piece, color)) = colored_piece {
+                *game.get_pieces_mut(&piece, &color) |= BitBoard::from_square(sq);
+            }
+        }
+
+        game.turn = self.turn;
+        game.castling_rights = self.castling_rights;
+        game.en_passant_target = self.en_passant_target;
+        game.half_move_timeout = self.half_move_timeout;
+        game.full_move_clock = self.full_move_clock;
+        game.initialize();
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::compare_games;
+
+    #[test]
+    fn builds_the_same_game_as_the_fen() {
+        let fens = [
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 b - - 3 40",
+        ];
+
+        for fen in fens {
+            let game = Game::from_fen(fen).unwrap();
+            let built = GameBuilder::from_game(&game).build().unwrap();
+            assert_eq!(built.to_fen(), fen);
+            compare_games(&built, &game);
+        }
+    }
+
+    #[test]
+    fn pieces_can_be_moved_around() {
+        let builder = GameBuilder::from_game(&Game::default())
+            .clear(Square::E2)
+            .piece(Square::E4, PieceType::Pawn, PieceColor::White)
+            .piece(Square::D8, PieceType::Knight, PieceColor::Black)
+            .turn(PieceColor::Black)
+            .castling(CastlingRights::from_fen("KQk"))
+            .en_passant(Some(Square::E3));
+        assert_eq!(builder.piece_on(Square::E2), None);
+        assert_eq!(
+            builder.piece_on(Square::D8),
+            Some((PieceType::Knight, PieceColor::Black))
+        );
+        assert_eq!(
+            builder.build().unwrap().to_fen(),
+            "rnbnkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQk e3 0 1"
+        );
+    }
+
+    #[test]
+    fn refuses_broken_positions() {
+        let builder = GameBuilder::new().piece(Square::E1, PieceType::King, PieceColor::White);
+        let verdict = builder.build().unwrap_err();
+        assert!(verdict.exactly_one_king_per_side);
+        assert!(!verdict.opponent_must_not_be_in_check);
+
+        let game = builder.build_unchecked();
+        assert_eq!(game.white_kings, BitBoard::from_square(Square::E1));
+    }
+}
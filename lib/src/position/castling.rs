@@ -1,6 +1,11 @@
 use std::fmt;
 
-use crate::{bitboard::BitBoard, square::Square};
+use crate::{
+    bitboard::{BitBoard, EMPTY},
+    movegen::pieces::piece::PieceColor,
+    position::game::Game,
+    square::Square,
+};
 
 pub const BLACK_CASTLE_KINGSIDE_NEEDS_CLEAR: BitBoard =
     BitBoard::new(0b01100000_00000000_00000000_00000000_00000000_00000000_00000000_00000000);
@@ -73,6 +78,50 @@ pub enum CastleSide {
     Kingside,
 }
 
+/// Checks if `color` can castle towards `side` in `game`. The right to castle has to be kept, every
+/// square between the king and the rook has to be empty, and the king may not start on, walk over
+/// or land on a square the opponent attacks. This is the one place castling legality is decided,
+/// for move generation as well as for validating moves from the outside.
+pub fn is_castle_legal(game: &Game, side: CastleSide, color: PieceColor) -> bool {
+    let (right, needs_clear, king_from, king_to) = match (color, side) {
+        (PieceColor::White, CastleSide::Queenside) => (
+            game.castling_rights.white_queenside(),
+            WHITE_CASTLE_QUEENSIDE_NEEDS_CLEAR,
+            WHITE_CASTLE_QUEENSIDE_KING_FROM,
+            WHITE_CASTLE_QUEENSIDE_KING_TO,
+        ),
+        (PieceColor::White, CastleSide::Kingside) => (
+            game.castling_rights.white_kingside(),
+            WHITE_CASTLE_KINGSIDE_NEEDS_CLEAR,
+            WHITE_CASTLE_KINGSIDE_KING_FROM,
+            WHITE_CASTLE_KINGSIDE_KING_TO,
+        ),
+        (PieceColor::Black, CastleSide::Queenside) => (
+            game.castling_rights.black_queenside(),
+            BLACK_CASTLE_QUEENSIDE_NEEDS_CLEAR,
+            BLACK_CASTLE_QUEENSIDE_KING_FROM,
+            BLACK_CASTLE_QUEENSIDE_KING_TO,
+        ),
+        (PieceColor::Black, CastleSide::Kingside) => (
+            game.castling_rights.black_kingside(),
+            BLACK_CASTLE_KINGSIDE_NEEDS_CLEAR,
+            BLACK_CASTLE_KINGSIDE_KING_FROM,
+            BLACK_CASTLE_KINGSIDE_KING_TO,
+        ),
+    };
+
+    if !right || game.occupied & needs_clear != EMPTY {
+        return false;
+    }
+
+    // The king only walks on its own rank, so the square it passes is right between the two
+    let passed = Square::new((king_from.to_int() + king_to.to_int()) / 2);
+    let enemy = color.opponent();
+    [king_from, passed, king_to]
+        .into_iter()
+        .all(|sq| game.attackers_to(sq, enemy) == EMPTY)
+}
+
 #[derive(Clone, Copy, PartialEq, Hash)]
 pub struct CastlingRights(u8);
 
@@ -111,7 +160,7 @@ impl CastlingRights {
     const BLACK_QUEENSIDE: u8 = 0b0100;
     const BLACK_KINGSIDE: u8 = 0b1000;
 
-    pub fn empty() -> Self {
+    pub const fn empty() -> Self {
         Self(0)
     }
 
@@ -123,18 +172,20 @@ impl CastlingRights {
         self.0
     }
 
+    /// Reads the castling field of a fen. Besides `KQkq`, the Shredder-FEN and X-FEN file letters
+    /// of the rooks castling is supported with are understood, so `HAha` means the same as `KQkq`.
     pub fn from_fen(castling_fen: &str) -> Self {
         let mut out = 0;
-        if castling_fen.contains('Q') {
+        if castling_fen.contains(['Q', 'A']) {
             out |= Self::WHITE_QUEENSIDE;
         }
-        if castling_fen.contains('K') {
+        if castling_fen.contains(['K', 'H']) {
             out |= Self::WHITE_KINGSIDE;
         }
-        if castling_fen.contains('q') {
+        if castling_fen.contains(['q', 'a']) {
             out |= Self::BLACK_QUEENSIDE;
         }
-        if castling_fen.contains('k') {
+        if castling_fen.contains(['k', 'h']) {
             out |= Self::BLACK_KINGSIDE;
         }
         Self(out)
@@ -160,6 +211,21 @@ impl CastlingRights {
         out
     }
 
+    /// The castling field of a Shredder-FEN, which names the file of each castling rook instead
+    /// of the side, such as `HAha`
+    pub fn to_shredder_fen(&self) -> String {
+        self.to_fen()
+            .chars()
+            .map(|c| match c {
+                'K' => 'H',
+                'Q' => 'A',
+                'k' => 'h',
+                'q' => 'a',
+                c => c,
+            })
+            .collect()
+    }
+
     pub fn white_queenside(self) -> bool {
         self.0 & Self::WHITE_QUEENSIDE != 0
     }
@@ -200,3 +266,102 @@ impl CastlingRights {
         self.0 &= !Self::BLACK_KINGSIDE;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn castling_needs_the_right_and_an_empty_path() {
+        let game = Game::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+        for color in [PieceColor::White, PieceColor::Black] {
+            for side in [CastleSide::Queenside, CastleSide::Kingside] {
+                assert!(is_castle_legal(&game, side, color), "{color:?} {side:?}");
+            }
+        }
+
+        let game = Game::from_fen("r3k2r/8/8/8/8/8/8/RN2K2R w Kq - 0 1").unwrap();
+        assert!(!is_castle_legal(
+            &game,
+            CastleSide::Queenside,
+            PieceColor::White
+        ));
+        assert!(is_castle_legal(
+            &game,
+            CastleSide::Kingside,
+            PieceColor::White
+        ));
+        assert!(!is_castle_legal(
+            &game,
+            CastleSide::Kingside,
+            PieceColor::Black
+        ));
+    }
+
+    #[test]
+    fn shredder_fen_castling_rights() {
+        for (shredder, fen) in [("HAha", "KQkq"), ("Ha", "Kq"), ("A", "Q"), ("-", "-")] {
+            let rights = CastlingRights::from_fen(shredder);
+            assert_eq!(rights.to_fen(), fen);
+            assert_eq!(rights.to_shredder_fen(), shredder);
+            assert_eq!(CastlingRights::from_fen(fen), rights);
+        }
+
+        let fen = "r3k2r/8/8/8/8/8/8/R3K2R w HAha - 0 1";
+        let game = Game::from_fen(fen).unwrap();
+        assert_eq!(game.to_fen(), "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1");
+        assert_eq!(game.to_shredder_fen(), fen);
+
+        // Castling with a rook that does not start in the corner is not supported
+        assert!(Game::from_fen("1r2k2r/8/8/8/8/8/8/1R2K2R w HBhb - 0 1").is_err());
+    }
+
+    #[test]
+    fn king_may_not_castle_out_of_through_or_into_check() {
+        // In check from the e8 rook
+        let game = Game::from_fen("4r3/8/8/3k4/8/8/8/R3K2R w KQ - 0 1").unwrap();
+        assert!(!is_castle_legal(
+            &game,
+            CastleSide::Kingside,
+            PieceColor::White
+        ));
+        assert!(!is_castle_legal(
+            &game,
+            CastleSide::Queenside,
+            PieceColor::White
+        ));
+
+        // f1 and c1 are attacked, but b1 does not matter
+        let game = Game::from_fen("5r2/8/8/3k4/8/8/8/R3K2R w KQ - 0 1").unwrap();
+        assert!(!is_castle_legal(
+            &game,
+            CastleSide::Kingside,
+            PieceColor::White
+        ));
+        let game = Game::from_fen("2r5/8/8/3k4/8/8/8/R3K2R w KQ - 0 1").unwrap();
+        assert!(!is_castle_legal(
+            &game,
+            CastleSide::Queenside,
+            PieceColor::White
+        ));
+        let game = Game::from_fen("1r6/8/8/3k4/8/8/8/R3K2R w KQ - 0 1").unwrap();
+        assert!(is_castle_legal(
+            &game,
+            CastleSide::Queenside,
+            PieceColor::White
+        ));
+
+        // The knight on h3 covers g1
+        let game = Game::from_fen("8/8/8/3k4/8/7n/8/R3K2R w KQ - 0 1").unwrap();
+        assert!(!is_castle_legal(
+            &game,
+            CastleSide::Kingside,
+            PieceColor::White
+        ));
+        assert!(is_castle_legal(
+            &game,
+            CastleSide::Queenside,
+            PieceColor::White
+        ));
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{
+    movegen::pieces::piece::{PieceColor, PieceType},
+    position::game::Game,
+};
+
+/// How many Chess960 start positions there are
+pub const CHESS960_POSITIONS: u16 = 960;
+
+/// The Scharnagl number of the standard start position
+pub const STANDARD_CHESS960_NUMBER: u16 = 518;
+
+/// Where the knights go among the five squares left after placing the bishops and the queen,
+/// indexed by what is left of the Scharnagl number
+const KNIGHT_PLACEMENTS: [(usize, usize); 10] = [
+    (0, 1),
+    (0, 2),
+    (0, 3),
+    (0, 4),
+    (1, 2),
+    (1, 3),
+    (1, 4),
+    (2, 3),
+    (2, 4),
+    (3, 4),
+];
+
+/// The back rank of the Chess960 start position with Scharnagl number `number`, from the A file
+/// to the H file. Returns `None` if `number` is not below [`CHESS960_POSITIONS`].
+pub fn back_rank(number: u16) -> Option<[PieceType; 8]> {
+    if number >= CHESS960_POSITIONS {
+        return None;
+    }
+
+    let mut rank = [None; 8];
+    let number = number as usize;
+
+    // The light squared bishop goes on b, d, f or h and the dark squared one on a, c, e or g
+    rank[number % 4 * 2 + 1] = Some(PieceType::Bishop);
+    rank[number / 4 % 4 * 2] = Some(PieceType::Bishop);
+
+    let mut place = |nth: usize, piece: PieceType| {
+        let file = rank
+            .iter()
+            .enumerate()
+            .filter(|(_, sq)| sq.is_none())
+            .nth(nth)
+            .map(|(file, _)| file)
+            .expect("There are always enough empty squares left");
+        rank[file] = Some(piece);
+    };
+
+    let number = number / 16;
+    place(number % 6, PieceType::Queen);
+
+    // Placing the first knight shifts the squares after it down by one
+    let (first, second) = KNIGHT_PLACEMENTS[number / 6];
+    place(first, PieceType::Knight);
+    place(second - 1, PieceType::Knight);
+
+    // The king always ends up between the rooks
+    for piece in [PieceType::Rook, PieceType::King, PieceType::Rook] {
+        place(0, piece);
+    }
+
+    Some(rank.map(|piece| piece.expect("Every square has been filled")))
+}
+
+/// The castling rights `color` keeps with `rank` as its back rank. Castling is only understood
+/// from the standard squares, so a side can only castle towards a rook on the A or H file while
+/// its king stands on the E file.
+fn castling_fen(rank: &[PieceType; 8], color: PieceColor) -> String {
+    let mut fen = String::new();
+    if rank[4] == PieceType::King {
+        if rank[7] == PieceType::Rook {
+            fen.push(color.color_notation('K'));
+        }
+        if rank[0] == PieceType::Rook {
+            fen.push(color.color_notation('Q'));
+        }
+    }
+    fen
+}
+
+impl Game {
+    /// The Chess960 start position with Scharnagl number `number`, see [`back_rank`]. Returns
+    /// `None` if `number` is not below [`CHESS960_POSITIONS`].
+    ///
+    /// Castling is only allowed where the king and rook start on their standard squares, since
+    /// castling from anywhere else is not supported yet.
+    pub fn from_chess960(number: u16) -> Option<Self> {
+        Self::from_double_chess960(number, number)
+    }
+
+    /// A Double Fischer Random start position, where white and black each get their own
+    /// Chess960 back rank. See [`Game::from_chess960`].
+    pub fn from_double_chess960(white: u16, black: u16) -> Option<Self> {
+        let white = back_rank(white)?;
+        let black = back_rank(black)?;
+        let row = |rank: &[PieceType; 8], color| {
+            rank.iter()
+                .map(|piece| piece.colored_notation(color))
+                .collect::<String>()
+        };
+
+        let castling =
+            castling_fen(&white, PieceColor::White) + &castling_fen(&black, PieceColor::Black);
+        let fen = format!(
+            "{}/pppppppp/8/8/8/8/PPPPPPPP/{} w {} - 0 1",
+            row(&black, PieceColor::Black),
+            row(&white, PieceColor::White),
+            if castling.is_empty() { "-" } else { &castling },
+        );
+
+        Some(Self::from_fen(&fen).expect("Chess960 start positions are always legal"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn every_start_position_follows_the_rules() {
+        let mut seen = HashSet::new();
+        for number in 0..CHESS960_POSITIONS {
+            let rank = back_rank(number).unwrap();
+            assert!(
+                seen.insert(rank.map(PieceType::notation)),
+                "{number} is a duplicate"
+            );
+
+            let files = |piece| (0..8).filter(move |&file| rank[file] == piece);
+            let bishops: Vec<_> = files(PieceType::Bishop).collect();
+            let rooks: Vec<_> = files(PieceType::Rook).collect();
+            let king = files(PieceType::King).next().unwrap();
+            assert_eq!(bishops.len(), 2);
+            assert_ne!(bishops[0] % 2, bishops[1] % 2, "{number}");
+            assert!(rooks[0] < king && king < rooks[1], "{number}");
+            assert_eq!(files(PieceType::Knight).count(), 2);
+            assert_eq!(files(PieceType::Queen).count(), 1);
+
+            assert!(Game::from_chess960(number).is_some(), "{number}");
+        }
+        assert_eq!(back_rank(CHESS960_POSITIONS), None);
+    }
+
+    #[test]
+    fn known_start_positions() {
+        let standard = Game::from_chess960(STANDARD_CHESS960_NUMBER).unwrap();
+        assert_eq!(standard.to_fen(), Game::default().to_fen());
+
+        assert_eq!(
+            Game::from_chess960(0).unwrap().to_fen(),
+            "bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w - - 0 1"
+        );
+        assert_eq!(
+            Game::from_chess960(959).unwrap().to_fen(),
+            "rkrnnqbb/pppppppp/8/8/8/8/PPPPPPPP/RKRNNQBB w - - 0 1"
+        );
+    }
+
+    #[test]
+    fn double_start_positions() {
+        let game = Game::from_double_chess960(STANDARD_CHESS960_NUMBER, 0).unwrap();
+        assert_eq!(
+            game.to_fen(),
+            "bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQ - 0 1"
+        );
+        assert!(Game::from_double_chess960(0, CHESS960_POSITIONS).is_none());
+    }
+}
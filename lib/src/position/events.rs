@@ -0,0 +1,139 @@
+use std::fmt;
+
+use crate::{
+    movegen::{moves::Move, pieces::piece::PieceColor},
+    position::game::{Game, State},
+};
+
+/// Something that happened to a [`Game`], handed to every callback added with [`Game::on_event`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameEvent {
+    MovePlayed(Move),
+    /// A move was taken back with [`Game::unplay`], [`Game::undo`] or [`Game::take_back`]
+    MoveUndone(Move),
+    /// The side to move is in check after a move, and the game goes on
+    Check(PieceColor),
+    /// A move ended the game
+    GameEnded(State),
+}
+
+type Hook = Box<dyn FnMut(&GameEvent) + Send>;
+
+/// The callbacks of a game. Clones start out without any, so that a copy handed to a search or a
+/// worker thread never reports the moves it tries out.
+#[derive(Default)]
+pub(crate) struct EventHooks(Vec<Hook>);
+
+impl Clone for EventHooks {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl fmt::Debug for EventHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "EventHooks({})", self.0.len())
+    }
+}
+
+impl Game {
+    /// Calls `hook` with every [`GameEvent`] from now on. Clones of the game do not keep their
+    /// hooks.
+    pub fn on_event(&mut self, hook: impl FnMut(&GameEvent) + Send + 'static) {
+        self.hooks.0.push(Box::new(hook));
+    }
+
+    /// Removes every hook added with [`Game::on_event`]
+    pub fn clear_event_hooks(&mut self) {
+        self.hooks.0.clear();
+    }
+
+    pub(crate) fn emit(&mut self, event: GameEvent) {
+        for hook in &mut self.hooks.0 {
+            hook(&event);
+        }
+    }
+
+    /// Emits the events that follow playing `m`
+    pub(crate) fn emit_played(&mut self, m: Move) {
+        if self.hooks.0.is_empty() {
+            return;
+        }
+
+        self.emit(GameEvent::MovePlayed(m));
+        if self.state != State::InProgress {
+            self.emit(GameEvent::GameEnded(self.state));
+        } else if self.is_in_check(self.turn) {
+            self.emit(GameEvent::Check(self.turn));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::square::Square;
+
+    fn record(game: &mut Game) -> Arc<Mutex<Vec<GameEvent>>> {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        game.on_event(move |event| sink.lock().unwrap().push(*event));
+        events
+    }
+
+    #[test]
+    fn reports_moves_checks_and_the_end() {
+        let mut game = Game::default();
+        let events = record(&mut game);
+
+        game.apply_uci_moves(["f2f3", "e7e5", "g2g4"]).unwrap();
+        events.lock().unwrap().clear();
+
+        let mate = Move::infer(Square::D8, Square::H4, &game);
+        game.play(&mate);
+        assert_eq!(
+            *events.lock().unwrap(),
+            [
+                GameEvent::MovePlayed(mate),
+                GameEvent::GameEnded(State::Checkmate)
+            ]
+        );
+
+        events.lock().unwrap().clear();
+        game.undo();
+        let check = Move::infer(Square::F8, Square::B4, &game);
+        game.play(&check);
+        assert_eq!(
+            *events.lock().unwrap(),
+            [GameEvent::MoveUndone(mate), GameEvent::MovePlayed(check)]
+        );
+
+        let mut game = Game::from_fen("4k3/8/8/8/8/8/8/R3K3 w Q - 0 1").unwrap();
+        let events = record(&mut game);
+        let m = Move::infer(Square::A1, Square::A8, &game);
+        game.play(&m);
+        assert_eq!(
+            *events.lock().unwrap(),
+            [
+                GameEvent::MovePlayed(m),
+                GameEvent::Check(PieceColor::Black)
+            ]
+        );
+    }
+
+    #[test]
+    fn clones_do_not_report() {
+        let mut game = Game::default();
+        let events = record(&mut game);
+
+        let mut clone = game.clone();
+        clone.apply_uci_moves(["e2e4"]).unwrap();
+        assert!(events.lock().unwrap().is_empty());
+
+        game.clear_event_hooks();
+        game.apply_uci_moves(["e2e4"]).unwrap();
+        assert!(events.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,139 @@
+use std::fmt;
+
+use crate::position::legality::Ruleset;
+
+/// Why a fen was refused by [`Game::from_fen`](crate::position::game::Game::from_fen). Every
+/// `index` is the byte offset in the fen where the problem starts.
+#[derive(Debug, Clone)]
+pub enum FenError {
+    /// The fen ended before this field
+    MissingField(&'static str),
+    /// A character in the piece placement that is neither a piece nor a count of empty squares
+    BadPiece { index: usize, found: char },
+    /// A rank that does not add up to eight squares
+    BadRankLength { index: usize, files: usize },
+    /// The piece placement does not have eight ranks
+    WrongRankCount { ranks: usize },
+    /// The side to move is neither `w` nor `b`
+    BadTurn { index: usize, found: String },
+    /// A character in the castling rights other than `KQkq`, or the file letter of a rook
+    /// castling is not supported with
+    BadCastling { index: usize, found: char },
+    /// The en passant target is neither `-` nor a square on the third or sixth rank
+    BadEnPassant { index: usize, found: String },
+    /// The halfmove clock or fullmove number is not a number, or is too large
+    BadClock { index: usize, found: String },
+    /// The fen could be read, but the position breaks the laws in the [`Ruleset`]
+    Illegal(Ruleset),
+}
+
+impl fmt::Display for FenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FenError::MissingField(field) => write!(f, "the {field} is missing"),
+            FenError::BadPiece { index, found } => {
+                write!(f, "'{found}' at index {index} is not a piece")
+            }
+            FenError::BadRankLength { index, files } => {
+                write!(
+                    f,
+                    "the rank at index {index} has {files} squares instead of 8"
+                )
+            }
+            FenError::WrongRankCount { ranks } => {
+                write!(f, "the board has {ranks} ranks instead of 8")
+            }
+            FenError::BadTurn { index, found } => {
+                write!(f, "\"{found}\" at index {index} is not w or b")
+            }
+            FenError::BadCastling { index, found } => {
+                write!(f, "'{found}' at index {index} is not a castling right")
+            }
+            FenError::BadEnPassant { index, found } => {
+                write!(
+                    f,
+                    "\"{found}\" at index {index} is not an en passant square"
+                )
+            }
+            FenError::BadClock { index, found } => {
+                write!(f, "\"{found}\" at index {index} is not a move count")
+            }
+            FenError::Illegal(verdict) => {
+                let verdict = verdict.verdict();
+                let laws: Vec<_> = verdict.lines().collect();
+                write!(f, "the position is illegal: {}", laws.join(" "))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::position::game::Game;
+
+    fn error(fen: &str) -> FenError {
+        Game::from_fen(fen).expect_err(fen)
+    }
+
+    #[test]
+    fn says_what_is_wrong_and_where() {
+        assert!(matches!(
+            error("4k3/8/8/8/8/8/8/4K3 w - -"),
+            FenError::MissingField("halfmove clock")
+        ));
+        assert!(matches!(
+            error("4k3/8/8/8/8/8/8/4X3 w - - 0 1"),
+            FenError::BadPiece {
+                index: 17,
+                found: 'X'
+            }
+        ));
+        assert!(matches!(
+            error("4k3/8/8/8/8/8/8/4K4 w - - 0 1"),
+            FenError::BadRankLength {
+                index: 16,
+                files: 9
+            }
+        ));
+        assert!(matches!(
+            error("4k3/8/8/8/8/8/4K3 w - - 0 1"),
+            FenError::WrongRankCount { ranks: 7 }
+        ));
+        assert!(matches!(
+            error("4k3/8/8/8/8/8/8/4K3 white - - 0 1"),
+            FenError::BadTurn { index: 20, .. }
+        ));
+        assert!(matches!(
+            error("4k3/8/8/8/8/8/8/4K3 w Kx - 0 1"),
+            FenError::BadCastling {
+                index: 23,
+                found: 'x'
+            }
+        ));
+        assert!(matches!(
+            error("4k3/8/8/8/8/8/8/4K3 w - e4 0 1"),
+            FenError::BadEnPassant { index: 24, .. }
+        ));
+        assert!(matches!(
+            error("4k3/8/8/8/8/8/8/4K3 w - - 0 -1"),
+            FenError::BadClock { index: 28, .. }
+        ));
+        assert!(matches!(
+            error("4k3/8/8/8/8/8/8/4R1K1 w - - 0 1"),
+            FenError::Illegal(verdict) if verdict.opponent_must_not_be_in_check
+        ));
+    }
+
+    #[test]
+    fn messages_point_at_the_problem() {
+        assert_eq!(
+            error("4k3/8/8/8/8/8/8/4X3 w - - 0 1").to_string(),
+            "'X' at index 17 is not a piece"
+        );
+        assert_eq!(
+            error("4k3/8/8/8/8/8/8/4R1K1 w - - 0 1").to_string(),
+            "the position is illegal: The opponent is in check!"
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use std::{
-    collections::HashMap,
     fmt,
-    hash::{DefaultHasher, Hash, Hasher},
+    hash::Hash,
+    ops::{Deref, DerefMut},
     str::FromStr,
 };
 
@@ -14,82 +14,125 @@ use crate::{
     bitboard::{BitBoard, EMPTY},
     file::File,
     get_attacks, get_attacks_mut, get_check_rays, get_check_rays_mut, get_occupied,
-    get_occupied_mut, get_pieces, get_pieces_mut,
+    get_occupied_mut, get_pieces, get_pieces_mut, lines,
     movegen::{
+        legal::LegalMoveGen,
         legal_moves::LegalMovesFilter,
+        move_list::MoveList,
         moves::{Move, lazy_attacks_to_moves_with_occupied},
         pieces::{
             self,
             bishop::{self},
             king, knight, pawn,
-            piece::{ALL_PIECE_TYPES, ALL_RAY_PIECES, PieceColor, PieceType},
+            piece::{ALL_RAY_PIECES, PieceColor, PieceType},
             queen::{self},
             rook::{self},
         },
     },
     position::{
+        board::Position,
         castling::{self, CastleSide, CastlingRights},
+        events::{EventHooks, GameEvent},
+        fen::FenError,
+        legality::Ruleset,
+        material::MaterialCount,
         piece_table::PieceTable,
         previous::{PositionHistory, UnRestoreable},
     },
     rank::Rank,
     square::Square,
-    vectors::{ArrayVec, UnsafeVec, Vector},
+    vectors::Vector,
+    zobrist,
 };
 
 pub const STARTING_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum State {
+    #[default]
     InProgress,
     Checkmate,
     Stalemate,
     Timeout,
     Repetition,
+    /// Neither player has enough pieces left to checkmate
+    InsufficientMaterial,
+}
+
+/// How a finished game ended
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outcome {
+    /// `winner` checkmated their opponent
+    Decisive {
+        winner: PieceColor,
+    },
+    Draw(DrawReason),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DrawReason {
+    Stalemate,
+    /// Fifty moves went by without a capture or pawn move
+    FiftyMoveRule,
+    Repetition,
+    InsufficientMaterial,
+}
+
+impl Outcome {
+    /// The color that won, or `None` for a draw
+    pub fn winner(self) -> Option<PieceColor> {
+        match self {
+            Outcome::Decisive { winner } => Some(winner),
+            Outcome::Draw(_) => None,
+        }
+    }
+}
+
+impl State {
+    /// How the game ended, or `None` if it is still going. `turn` is the side to move.
+    pub fn outcome(self, turn: PieceColor) -> Option<Outcome> {
+        Some(match self {
+            State::InProgress => return None,
+            State::Checkmate => Outcome::Decisive {
+                winner: turn.opponent(),
+            },
+            State::Stalemate => Outcome::Draw(DrawReason::Stalemate),
+            State::Timeout => Outcome::Draw(DrawReason::FiftyMoveRule),
+            State::Repetition => Outcome::Draw(DrawReason::Repetition),
+            State::InsufficientMaterial => Outcome::Draw(DrawReason::InsufficientMaterial),
+        })
+    }
 }
 
 #[derive(Clone)]
 pub struct Game {
-    // Piece bitboards (formerly Board fields)
-    pub white_pawns: BitBoard,
-    pub white_knights: BitBoard,
-    pub white_bishops: BitBoard,
-    pub white_rooks: BitBoard,
-    pub white_queens: BitBoard,
-    pub white_kings: BitBoard,
-
-    pub black_pawns: BitBoard,
-    pub black_knights: BitBoard,
-    pub black_bishops: BitBoard,
-    pub black_rooks: BitBoard,
-    pub black_queens: BitBoard,
-    pub black_kings: BitBoard,
-
-    pub castling_rights: CastlingRights,
-    pub en_passant_target: Option<Square>,
-    pub turn: PieceColor,
-
-    pub half_move_timeout: u8,
-    pub full_move_clock: u16,
-    pub state: State,
-    pub seen_positions: HashMap<u64, u8>,
-    pub hash: u64,
-
-    // Cached game state
-    pub white_occupied: BitBoard,
-    pub black_occupied: BitBoard,
-    pub occupied: BitBoard,
-
+    pub(crate) position: Position,
+    /// The hash of every position before this one, oldest first. Used to detect repetitions. This
+    /// is a stack that make pushes to and unmake pops from, and only the part since the last
+    /// irreversible move is ever searched.
+    pub key_history: Vec<u64>,
+    /// Every move played since the game was set up, oldest first
+    pub(crate) move_history: Vec<Move>,
     position_history: PositionHistory,
-    pub white_attacks: BitBoard,
-    pub black_attacks: BitBoard,
-    pub white_check_rays: BitBoard,
-    pub black_check_rays: BitBoard,
-    pub(crate) piece_table: PieceTable,
+    pub(crate) hooks: EventHooks,
     #[cfg(feature = "panic_logger")]
     panic_logger: RefCell<BufLogger>,
 }
 
+impl Deref for Game {
+    type Target = Position;
+
+    fn deref(&self) -> &Position {
+        &self.position
+    }
+}
+
+impl DerefMut for Game {
+    fn deref_mut(&mut self) -> &mut Position {
+        &mut self.position
+    }
+}
+
 impl PartialEq for Game {
     fn eq(&self, other: &Self) -> bool {
         self.hash == other.hash
@@ -118,7 +161,7 @@ impl Hash for Game {
 
 impl Default for Game {
     fn default() -> Self {
-        let mut game = Self {
+        let mut game = Self::from_position(Position {
             white_pawns: BitBoard::INITIAL_WHITE_PAWNS,
             white_knights: BitBoard::INITIAL_WHITE_KNIGHTS,
             white_bishops: BitBoard::INITIAL_WHITE_BISHOPS,
@@ -134,33 +177,31 @@ impl Default for Game {
             black_kings: BitBoard::INITIAL_BLACK_KINGS,
 
             castling_rights: CastlingRights::default(),
-            en_passant_target: None,
-            turn: PieceColor::White,
-
-            half_move_timeout: 0,
             full_move_clock: 1,
-            state: State::InProgress,
-            seen_positions: HashMap::new(),
-            hash: 0,
-
-            white_attacks: EMPTY,
-            black_attacks: EMPTY,
-            white_check_rays: EMPTY,
-            black_check_rays: EMPTY,
-            white_occupied: EMPTY,
-            black_occupied: EMPTY,
-            occupied: EMPTY,
-            position_history: PositionHistory::new(),
-            piece_table: PieceTable::new(),
-            #[cfg(feature = "panic_logger")]
-            panic_logger: RefCell::new(BufLogger::new()),
-        };
+            ..Position::empty()
+        });
 
         game.initialize();
         game
     }
 }
 
+impl fmt::Display for Game {
+    /// Writes the fen of the current position, or draws it with `{:#}`, see [`Position`]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.position(), f)
+    }
+}
+
+impl FromStr for Game {
+    type Err = FenError;
+
+    /// Reads a fen, see [`Game::from_fen`]
+    fn from_str(fen: &str) -> Result<Self, FenError> {
+        Self::from_fen(fen)
+    }
+}
+
 impl fmt::Debug for Game {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if !f.alternate() {
@@ -277,90 +318,88 @@ impl Game {
         }
     }
 
-    /// Whether white can legally castle queenside
+    /// Whether white can legally castle queenside, see [`castling::is_castle_legal`]
     pub fn can_white_castle_queenside(&self) -> bool {
-        self.castling_rights.white_queenside()
-            && self.occupied & castling::WHITE_CASTLE_QUEENSIDE_NEEDS_CLEAR == EMPTY
+        castling::is_castle_legal(self, CastleSide::Queenside, PieceColor::White)
     }
 
-    /// Whether white can legally castle kingside
+    /// Whether white can legally castle kingside, see [`castling::is_castle_legal`]
     pub fn can_white_castle_kingside(&self) -> bool {
-        self.castling_rights.white_kingside()
-            && self.occupied & castling::WHITE_CASTLE_KINGSIDE_NEEDS_CLEAR == EMPTY
+        castling::is_castle_legal(self, CastleSide::Kingside, PieceColor::White)
     }
 
-    /// Whether black can legally castle queenside
+    /// Whether black can legally castle queenside, see [`castling::is_castle_legal`]
     pub fn can_black_castle_queenside(&self) -> bool {
-        self.castling_rights.black_queenside()
-            && self.occupied & castling::BLACK_CASTLE_QUEENSIDE_NEEDS_CLEAR == EMPTY
+        castling::is_castle_legal(self, CastleSide::Queenside, PieceColor::Black)
     }
 
-    /// Whether black can legally castle kingside
+    /// Whether black can legally castle kingside, see [`castling::is_castle_legal`]
     pub fn can_black_castle_kingside(&self) -> bool {
-        self.castling_rights.black_kingside()
-            && self.occupied & castling::BLACK_CASTLE_KINGSIDE_NEEDS_CLEAR == EMPTY
+        castling::is_castle_legal(self, CastleSide::Kingside, PieceColor::Black)
     }
 
     // Constructors
     pub fn empty() -> Self {
+        Self::from_position(Position::empty())
+    }
+
+    /// Wraps `position` in a game without any history. The cached values of `position` are used
+    /// as they are.
+    pub(crate) fn from_position(position: Position) -> Self {
         Self {
-            white_pawns: EMPTY,
-            white_knights: EMPTY,
-            white_bishops: EMPTY,
-            white_rooks: EMPTY,
-            white_queens: EMPTY,
-            white_kings: EMPTY,
-
-            black_pawns: EMPTY,
-            black_knights: EMPTY,
-            black_bishops: EMPTY,
-            black_rooks: EMPTY,
-            black_queens: EMPTY,
-            black_kings: EMPTY,
-
-            castling_rights: CastlingRights::empty(),
-            en_passant_target: None,
-            turn: PieceColor::White,
-
-            half_move_timeout: 0,
-            full_move_clock: 0,
-            state: State::InProgress,
-            seen_positions: HashMap::new(),
-            hash: 0,
-
-            white_attacks: EMPTY,
-            black_attacks: EMPTY,
-            white_check_rays: EMPTY,
-            black_check_rays: EMPTY,
-            white_occupied: EMPTY,
-            black_occupied: EMPTY,
-            occupied: EMPTY,
+            position,
+            key_history: Vec::new(),
+            move_history: Vec::new(),
             position_history: PositionHistory::new(),
-            piece_table: PieceTable::new(),
+            hooks: EventHooks::default(),
             #[cfg(feature = "panic_logger")]
             panic_logger: RefCell::new(BufLogger::new()),
         }
     }
 
-    /// Takes a fen string, parses and converts it into a game.
-    pub fn from_fen(fen: &str) -> Option<Self> {
+    /// The current position, without the history of how it was reached
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+
+    /// Takes a fen string, parses and converts it into a game. Positions that break the
+    /// [`Ruleset::essential`] laws are refused with [`FenError::Illegal`], see [`Game::validate`]
+    /// for why.
+    pub fn from_fen(fen: &str) -> Result<Self, FenError> {
+        let game = Self::from_fen_unchecked(fen)?;
+        game.validate().map_err(FenError::Illegal)?;
+        Ok(game)
+    }
+
+    /// Takes a fen string, parses and converts it into a game without checking if the position
+    /// makes sense.
+    pub fn from_fen_unchecked(fen: &str) -> Result<Self, FenError> {
         // Example Fen:
         // r1bqkbnr/ppp1pppp/2n5/1B1P4/8/8/PPPP1PPP/RNBQK1NR b KQkq - 2 3
-        let mut split_fen = fen.split(' ');
-        let body_fen = split_fen.next()?;
-        let turn_fen = split_fen.next()?;
-        let castling_fen = split_fen.next()?;
-        let en_passant_fen = split_fen.next()?;
-        let half_move_fen = split_fen.next()?;
-        let full_move_fen = split_fen.next()?;
-
-        let rows = body_fen.split('/');
-        let mut game = Game::empty();
+        let mut split_fen = fen.split(' ').scan(0, |index, field: &str| {
+            let start = *index;
+            *index += field.len() + 1;
+            Some((start, field))
+        });
+        let mut next_field = |name| split_fen.next().ok_or(FenError::MissingField(name));
+        let (_, body_fen) = next_field("piece placement")?;
+        let (turn_index, turn_fen) = next_field("side to move")?;
+        let (castling_index, castling_fen) = next_field("castling rights")?;
+        let (en_passant_index, en_passant_fen) = next_field("en passant target")?;
+        let (half_move_index, half_move_fen) = next_field("halfmove clock")?;
+        let (full_move_index, full_move_fen) = next_field("fullmove number")?;
+
+        let ranks = body_fen.split('/').count();
+        if ranks != 8 {
+            return Err(FenError::WrongRankCount { ranks });
+        }
 
-        for (rank, row) in rows.rev().enumerate() {
+        let mut game = Game::empty();
+        let mut row_index = 0;
+        for (rank, row) in body_fen.split('/').enumerate() {
+            let rank = Rank::from_index(7 - rank);
             let mut file = 0;
-            for c in row.chars() {
-                let sqbb = BitBoard::from_rank_file(Rank::from_index(rank), File::from_index(file));
+            for (i, c) in row.char_indices() {
                 let colored_piece = match c {
                     'p' => Some((PieceType::Pawn, PieceColor::Black)),
                     'n' => Some((PieceType::Knight, PieceColor::Black)),
@@ -378,139 +417,202 @@ impl Game {
                 };
 
                 if let Some((piece, color)) = colored_piece {
-                    let pieces = game.get_pieces_mut(&piece, &color);
-                    *pieces |= sqbb;
+                    if file < 8 {
+                        let sqbb = BitBoard::from_rank_file(rank, File::from_index(file));
+                        *game.get_pieces_mut(&piece, &color) |= sqbb;
+                    }
                     file += 1;
                 } else {
-                    file += c.to_digit(10)? as usize;
+                    match c.to_digit(10) {
+                        Some(empty @ 1..=8) => file += empty as usize,
+                        _ => {
+                            return Err(FenError::BadPiece {
+                                index: row_index + i,
+                                found: c,
+                            });
+                        }
+                    }
                 }
             }
+
+            if file != 8 {
+                return Err(FenError::BadRankLength {
+                    index: row_index,
+                    files: file,
+                });
+            }
+            row_index += row.len() + 1;
         }
 
-        game.turn = if turn_fen == "b" {
-            PieceColor::Black
-        } else {
-            PieceColor::White
+        game.turn = match turn_fen {
+            "w" => PieceColor::White,
+            "b" => PieceColor::Black,
+            _ => {
+                return Err(FenError::BadTurn {
+                    index: turn_index,
+                    found: turn_fen.to_string(),
+                });
+            }
         };
 
         if castling_fen != "-" {
+            if let Some((i, c)) = castling_fen
+                .char_indices()
+                .find(|(_, c)| !matches!(c, 'K' | 'Q' | 'k' | 'q' | 'H' | 'A' | 'h' | 'a'))
+            {
+                return Err(FenError::BadCastling {
+                    index: castling_index + i,
+                    found: c,
+                });
+            }
             game.castling_rights = CastlingRights::from_fen(castling_fen);
         }
 
-        if let Ok(sq) = Square::from_str(en_passant_fen) {
-            game.en_passant_target = Some(sq);
-        }
-
-        if let Ok(half_moves) = half_move_fen.parse() {
-            game.half_move_timeout = half_moves;
+        if en_passant_fen != "-" {
+            let bad_en_passant = || FenError::BadEnPassant {
+                index: en_passant_index,
+                found: en_passant_fen.to_string(),
+            };
+            if en_passant_fen.len() != 2 || !en_passant_fen.ends_with(['3', '6']) {
+                return Err(bad_en_passant());
+            }
+            game.en_passant_target =
+                Some(Square::from_str(en_passant_fen).map_err(|_| bad_en_passant())?);
         }
 
-        if let Ok(full_moves) = full_move_fen.parse() {
-            game.full_move_clock = full_moves;
-        }
+        game.half_move_timeout = half_move_fen.parse().map_err(|_| FenError::BadClock {
+            index: half_move_index,
+            found: half_move_fen.to_string(),
+        })?;
+        game.full_move_clock = full_move_fen.parse().map_err(|_| FenError::BadClock {
+            index: full_move_index,
+            found: full_move_fen.to_string(),
+        })?;
 
         game.initialize();
 
-        Some(game)
+        Ok(game)
     }
 
-    /// Attempts to generate a fen from the current game state
-    pub fn to_fen(&self) -> String {
-        let mut fen = String::new();
-
-        for rank in (0..8).rev() {
-            let mut empty_count: u8 = 0;
-
-            for file in 0..8 {
-                let sq = Square::make_square(Rank::from_index(rank), File::from_index(file));
+    /// Like [`Position::to_fen`], but writes the castling rights as Shredder-FEN rook files, which is
+    /// what Chess960 GUIs expect
+    pub fn to_shredder_fen(&self) -> String {
+        let fen = self.to_fen();
+        let mut fields: Vec<_> = fen.split(' ').collect();
+        let castling = self.castling_rights.to_shredder_fen();
+        fields[2] = &castling;
+        fields.join(" ")
+    }
 
-                if let Some((piece, color)) = self.piece_lookup(sq) {
-                    // If we had empty squares, add the count first
-                    if empty_count > 0 {
-                        fen.push_str(&empty_count.to_string());
-                        empty_count = 0;
+    /// Mirrors the board vertically and swaps the colors of every piece, the castling rights and
+    /// the side to move. The result is the same position seen from the other side, so a correct
+    /// evaluation scores it as the exact opposite. The move history is not kept.
+    pub fn flip_colors(&self) -> Game {
+        let swap_case = |s: &str| -> String {
+            s.chars()
+                .map(|c| {
+                    if c.is_ascii_uppercase() {
+                        c.to_ascii_lowercase()
+                    } else {
+                        c.to_ascii_uppercase()
                     }
+                })
+                .collect()
+        };
 
-                    let piece_char = match color {
-                        PieceColor::White => match piece {
-                            PieceType::Pawn => 'P',
-                            PieceType::Knight => 'N',
-                            PieceType::Bishop => 'B',
-                            PieceType::Rook => 'R',
-                            PieceType::Queen => 'Q',
-                            PieceType::King => 'K',
-                        },
-
-                        PieceColor::Black => match piece {
-                            PieceType::Pawn => 'p',
-                            PieceType::Knight => 'n',
-                            PieceType::Bishop => 'b',
-                            PieceType::Rook => 'r',
-                            PieceType::Queen => 'q',
-                            PieceType::King => 'k',
-                        },
-                    };
-
-                    fen.push(piece_char);
-                } else {
-                    empty_count += 1;
-                }
-            }
-
-            // Add any remaining empty squares at the end of the rank
-            if empty_count > 0 {
-                fen.push_str(&empty_count.to_string());
-            }
-
-            // Add rank separator (except for the last rank)
-            if rank > 0 {
-                fen.push('/');
-            }
-        }
-
-        fen.push(' ');
-        fen.push(match self.turn {
-            PieceColor::White => 'w',
-            PieceColor::Black => 'b',
-        });
-
-        fen.push(' ');
-        fen.push_str(&self.castling_rights.to_fen());
-
-        fen.push(' ');
-        if let Some(target) = self.en_passant_target {
-            fen.push_str(&target.to_string().to_lowercase());
-        } else {
-            fen.push('-');
-        }
+        let fen = self.to_fen();
+        let fields: Vec<&str> = fen.split(' ').collect();
+
+        let body = fields[0]
+            .split('/')
+            .rev()
+            .map(swap_case)
+            .collect::<Vec<_>>()
+            .join("/");
+        let turn = match self.turn {
+            PieceColor::White => "b",
+            PieceColor::Black => "w",
+        };
+        let en_passant = match self.en_passant_target {
+            Some(target) => target.flip_side().to_string().to_lowercase(),
+            None => "-".to_string(),
+        };
 
-        fen.push_str(format!(" {} {}", self.half_move_timeout, self.full_move_clock).as_str());
+        let flipped = format!(
+            "{} {} {} {} {} {}",
+            body,
+            turn,
+            swap_case(fields[2]),
+            en_passant,
+            fields[4],
+            fields[5]
+        );
+        Game::from_fen(&flipped).expect("A flipped position is always valid")
+    }
+
+    /// Mirrors the board in the vertical line between the D and E files, so a piece on A2 ends up
+    /// on H2. Nothing about chess changes by doing this except castling, so the castling rights
+    /// are dropped. Useful for augmenting training data and for checking that an evaluation
+    /// treats both wings alike. The move history is not kept.
+    pub fn mirror_vertical(&self) -> Game {
+        let fen = self.to_fen();
+        let fields: Vec<&str> = fen.split(' ').collect();
+
+        let body = fields[0]
+            .split('/')
+            .map(|rank| rank.chars().rev().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("/");
+        let en_passant = match self.en_passant_target {
+            Some(target) => target.flip_file().to_string().to_lowercase(),
+            None => "-".to_string(),
+        };
 
-        fen
+        let mirrored = format!(
+            "{} {} - {} {} {}",
+            body, fields[1], en_passant, fields[4], fields[5]
+        );
+        Game::from_fen(&mirrored).expect("A mirrored position is always valid")
     }
 
     // Move generation related
     /// Restores the essential data from the previous position
     pub(crate) fn restore_position(&mut self) {
+        self.key_history.pop();
+
         let last_position = self
             .position_history
             .pop(self.turn)
             .expect("Tried to unmake a move, but the required information is not present");
+        self.hash ^= self.unrestoreable_hash();
         self.castling_rights = last_position.castling_rights;
         self.half_move_timeout = last_position.half_move_timeout;
         self.en_passant_target = last_position.en_passant_target;
-        // We can assume that this position was reached from a non-terminal state
-        self.state = State::InProgress;
+        self.hash ^= self.unrestoreable_hash();
+        self.state = last_position.state;
     }
 
     /// Captures essential position information to be restored later
     pub(crate) fn capture_position(&mut self) {
+        self.key_history.push(self.hash);
         let last_position = UnRestoreable {
             castling_rights: self.castling_rights,
             half_move_timeout: self.half_move_timeout,
             en_passant_target: self.en_passant_target,
+            state: self.state,
         };
         self.position_history.push(last_position);
+        // Rehashed in `next_turn` once the new rights and target are known
+        self.hash ^= self.unrestoreable_hash();
+    }
+
+    /// The part of the hash covering castling rights and the en passant target
+    pub(crate) fn unrestoreable_hash(&self) -> u64 {
+        let mut hash = zobrist::KEYS.castling(self.castling_rights);
+        if let Some(target) = self.en_passant_target {
+            hash ^= zobrist::KEYS.en_passant(target.get_file());
+        }
+        hash
     }
 
     /// Finishes a turn and determines game state is possible
@@ -529,6 +631,7 @@ impl Game {
         if self.turn == PieceColor::White {
             self.full_move_clock += 1;
         }
+        self.hash ^= self.unrestoreable_hash() ^ zobrist::KEYS.black_to_move();
         self.refresh();
 
         // Half move timeout
@@ -548,16 +651,10 @@ impl Game {
             self.half_move_timeout += 1;
         }
 
-        // Repetition
-        if let Some(times_seen) = self.seen_positions.get_mut(&self.hash) {
-            *times_seen += 1;
-            if *times_seen == 3 {
-                self.state = State::Repetition;
-                // Skip the below state determination
-                return;
-            }
-        } else {
-            self.seen_positions.insert(self.hash, 1);
+        if self.repetitions() >= 2 {
+            self.state = State::Repetition;
+            // Skip the below state determination
+            return;
         }
 
         self.state = self.determine_state();
@@ -565,16 +662,8 @@ impl Game {
 
     /// Reverses turn color and full_move_clock to the last turn
     pub(crate) fn previous_turn(&mut self) {
-        // Repetition
-        if let Some(times_seen) = self.seen_positions.get_mut(&self.hash) {
-            if *times_seen == 1 {
-                self.seen_positions.remove(&self.hash);
-            } else {
-                *times_seen -= 1;
-            }
-        }
-
         self.turn = self.turn.opponent();
+        self.hash ^= zobrist::KEYS.black_to_move();
 
         self.refresh();
         if self.turn == PieceColor::Black {
@@ -586,13 +675,69 @@ impl Game {
     /// Initalizes the game. This should only be called inside of constructors
     pub(crate) fn initialize(&mut self) {
         self.populate_piece_table();
+        self.material = MaterialCount::from_position(&self.position);
         self.refresh();
-        self.seen_positions.insert(self.hash, 1);
+        self.hash = zobrist::hash(self);
+        if self.has_insufficient_material() {
+            self.state = State::InsufficientMaterial;
+        }
+    }
+
+    /// Every move played so far, oldest first. Null moves are not included.
+    pub fn history(&self) -> &[Move] {
+        &self.move_history
+    }
+
+    /// Takes back the last move played and returns it, or returns `None` if no moves were played.
+    /// A null move has to be taken back with `Game::unmake_null` before this is called.
+    pub fn undo(&mut self) -> Option<Move> {
+        let m = *self.move_history.last()?;
+        self.unplay(&m);
+        Some(m)
+    }
+
+    /// Counts how many times the current position was reached before. Only positions since the
+    /// last irreversible move are checked, since earlier ones can not repeat.
+    pub fn repetitions(&self) -> usize {
+        self.key_history
+            .iter()
+            .rev()
+            .take(self.half_move_timeout as usize)
+            // Positions with the other player to move can never match
+            .skip(1)
+            .step_by(2)
+            .filter(|&&hash| hash == self.hash)
+            .count()
+    }
+
+    /// Checks if the current position was reached before. A search can treat this as a draw,
+    /// since whatever was best the first time can be repeated.
+    pub fn is_repetition(&self) -> bool {
+        self.repetitions() > 0
+    }
+
+    /// Takes back the last move played with [`Game::play`] by copying back `saved`, the position
+    /// from right before it. Nothing has to be recalculated, so this is cheaper than
+    /// [`Game::unplay`], which is why the search saves a copy before every move. Null moves still
+    /// have to be taken back with [`Game::unmake_null`].
+    pub fn take_back(&mut self, saved: Position) {
+        let key = self.key_history.pop();
+        debug_assert_eq!(
+            key,
+            Some(saved.hash),
+            "Took back to a position that was not played"
+        );
+        let m = self.move_history.pop();
+        self.position_history.pop(saved.turn);
+        self.position = saved;
+        if let Some(m) = m {
+            self.emit(GameEvent::MoveUndone(m));
+        }
     }
 
     /// Recalculates certain cached values regarding the position
     /// Should be called on Self initialization and position updates
-    fn refresh(&mut self) {
+    pub(crate) fn refresh(&mut self) {
         let white_pieces = self.white_pawns
             | self.white_knights
             | self.white_bishops
@@ -617,26 +762,49 @@ impl Game {
         self.black_occupied = black_pieces;
         self.occupied = pieces;
 
-        let mut hasher = DefaultHasher::new();
-        self.hash(&mut hasher);
-        self.hash = hasher.finish();
-
         self.update_attacks();
     }
 
-    /// This method will check for all states aside from `State::Repetition`
-    fn determine_state(&self) -> State {
-        let has_moves = match self.turn {
+    /// Checks if the side to move has a legal move, stopping at the first one found
+    fn has_legal_moves(&self) -> bool {
+        match self.turn {
             PieceColor::White => self.lazy_legal_moves_white().next().is_some(),
             PieceColor::Black => self.lazy_legal_moves_black().next().is_some(),
+        }
+    }
+
+    /// Checks if the side to move is checkmated. Unlike `state`, this is worked out from the
+    /// position itself, so it is also right for games loaded from a fen.
+    pub fn is_checkmate(&self) -> bool {
+        self.is_in_check(self.turn) && !self.has_legal_moves()
+    }
+
+    /// Checks if the side to move has no legal moves while not in check
+    pub fn is_stalemate(&self) -> bool {
+        !self.is_in_check(self.turn) && !self.has_legal_moves()
+    }
+
+    /// How the game ended, or `None` if it is still going. Works the outcome out from the
+    /// position and its history without touching `state`.
+    pub fn outcome(&self) -> Option<Outcome> {
+        let state = if self.repetitions() >= 2 {
+            State::Repetition
+        } else {
+            self.determine_state()
         };
+        state.outcome(self.turn)
+    }
 
-        if !has_moves {
+    /// This method will check for all states aside from `State::Repetition`
+    pub(crate) fn determine_state(&self) -> State {
+        if !self.has_legal_moves() {
             if self.is_in_check(self.turn) {
                 State::Checkmate
             } else {
                 State::Stalemate
             }
+        } else if self.has_insufficient_material() {
+            State::InsufficientMaterial
         } else if self.half_move_timeout == 50 {
             State::Timeout
         } else {
@@ -644,12 +812,20 @@ impl Game {
         }
     }
 
-    /// Calculates the attack bitboard for the given player
-    fn calculate_attacks(&self, color: &PieceColor) -> (BitBoard, BitBoard) {
-        let mut attacks = EMPTY;
+    /// Calculates the attacks and check rays of `color` from scratch, whichever side is to move.
+    /// The cached [`Game::get_attacks`] and [`Game::get_check_rays`] are kept up to date with this.
+    pub fn calculate_attacks(&self, color: &PieceColor) -> (BitBoard, BitBoard) {
+        // Pawns, knights and kings never have check rays, so all of their attacks are found at
+        // once. Only the ray pieces are visited one by one.
+        let mut attacks = pawn::attacks(*self.get_pieces(&PieceType::Pawn, color), *color)
+            | knight::all_attacks(*self.get_pieces(&PieceType::Knight, color))
+            | king::all_attacks(*self.get_pieces(&PieceType::King, color));
         let mut check_rays = EMPTY;
 
-        for sq in *self.get_occupied(color) {
+        let ray_pieces = *self.get_pieces(&PieceType::Bishop, color)
+            | *self.get_pieces(&PieceType::Rook, color)
+            | *self.get_pieces(&PieceType::Queen, color);
+        for sq in ray_pieces {
             let Some((piece, _)) = self.piece_lookup(sq) else {
                 panic!(
                     "The piece lookup table has a fake piece! {:?}\n{:?}",
@@ -657,7 +833,7 @@ impl Game {
                     self.get_occupied(color)
                 )
             };
-            let moveinfo = piece.psuedo_legal_targets_fast(self, &sq);
+            let moveinfo = piece.psuedo_legal_targets_for(self, &sq, *color);
             attacks |= moveinfo.attacks;
             check_rays |= moveinfo.check_rays;
         }
@@ -737,6 +913,36 @@ impl Game {
     }
 
     // Game/state queries
+    /// Checks if neither player can possibly checkmate. This is the case with only kings and a
+    /// single minor piece left, or with only bishops that all stand on the same square color.
+    pub fn has_insufficient_material(&self) -> bool {
+        let material = self.material;
+        if material.has_heavy_pieces_or_pawns() {
+            return false;
+        }
+
+        if material.minor_pieces() <= 1 {
+            return true;
+        }
+
+        let bishops = self.white_bishops | self.black_bishops;
+        material.count(PieceType::Knight, PieceColor::White) == 0
+            && material.count(PieceType::Knight, PieceColor::Black) == 0
+            && (bishops & BitBoard::LIGHT_SQUARES == EMPTY
+                || bishops & BitBoard::DARK_SQUARES == EMPTY)
+    }
+
+    /// Checks that the position follows the [`Ruleset::essential`] laws. On failure, the laws that
+    /// were broken are returned, and their [`std::fmt::Display`] says what is wrong.
+    pub fn validate(&self) -> Result<(), Ruleset> {
+        let verdict = Ruleset::essential().judge(self);
+        if verdict.guilty() {
+            Err(verdict)
+        } else {
+            Ok(())
+        }
+    }
+
     /// Checks if the player's king is in check
     pub fn is_in_check(&self, color: PieceColor) -> bool {
         match color {
@@ -745,28 +951,117 @@ impl Game {
         }
     }
 
-    /// Returns a bitboard of every piece attacking the given square
+    /// Returns a bitboard of every piece of `color` attacking `sq`. Each attack pattern is looked
+    /// up from `sq` outwards, so this works for empty squares and does not rely on the cached
+    /// attack boards.
+    pub fn attackers_to(&self, sq: Square, color: PieceColor) -> BitBoard {
+        self.attackers_to_with_occupied(sq, color, self.occupied)
+    }
+
+    /// Same as [`Game::attackers_to`], but ray pieces look through `occupied` instead of the real
+    /// board. Pieces missing from `occupied` are treated as captured.
+    pub fn attackers_to_with_occupied(
+        &self,
+        sq: Square,
+        color: PieceColor,
+        occupied: BitBoard,
+    ) -> BitBoard {
+        let queens = *self.get_pieces(&PieceType::Queen, &color);
+        let diagonal = *self.get_pieces(&PieceType::Bishop, &color) | queens;
+        let straight = *self.get_pieces(&PieceType::Rook, &color) | queens;
+
+        // A pawn of `color` attacks `sq` from wherever a pawn of the other color on `sq` would
+        // attack
+        let attackers = (pawn::square_attacks(sq, color.opponent())
+            & *self.get_pieces(&PieceType::Pawn, &color))
+            | (knight::attacks(sq) & *self.get_pieces(&PieceType::Knight, &color))
+            | (king::attacks(sq) & *self.get_pieces(&PieceType::King, &color))
+            | (bishop::magic_attacks(sq, occupied) & diagonal)
+            | (rook::magic_attacks(sq, occupied) & straight);
+
+        attackers & occupied
+    }
+
+    /// Returns a bitboard of every piece attacking the piece on the given square
     pub fn attackers(&self, sq: Square) -> BitBoard {
-        let mut attackers = EMPTY;
-        let sqbb = BitBoard::from_square(sq);
-        let color = if let Some(color) = self.determine_color(sqbb) {
-            color
-        } else {
-            return attackers;
-        };
+        match self.determine_color(BitBoard::from_square(sq)) {
+            Some(color) => self.attackers_to(sq, color.opponent()),
+            None => EMPTY,
+        }
+    }
+
+    /// Returns a bitboard of every friendly piece defending the piece on the given square, or in
+    /// other words, every piece that could recapture on it
+    pub fn defenders(&self, sq: Square) -> BitBoard {
+        match self.determine_color(BitBoard::from_square(sq)) {
+            Some(color) => self.attackers_to(sq, color),
+            None => EMPTY,
+        }
+    }
 
+    /// How many enemy pieces attack the piece on the given square
+    pub fn num_attackers(&self, sq: Square) -> u32 {
+        self.attackers(sq).popcnt()
+    }
+
+    /// How many friendly pieces defend the piece on the given square
+    pub fn num_defenders(&self, sq: Square) -> u32 {
+        self.defenders(sq).popcnt()
+    }
+
+    /// Returns the pieces of `color` that are pinned to their king, followed by the enemy ray
+    /// pieces pinning them. Looking from the king through nothing but enemy pieces finds every ray
+    /// piece that would be giving check if our own pieces were out of the way, and the ones with
+    /// exactly one of our pieces in between are pinning it.
+    pub(crate) fn pins(&self, color: PieceColor) -> (BitBoard, BitBoard) {
+        let kingbb = *self.get_king(color);
+        if kingbb == EMPTY {
+            return (EMPTY, EMPTY);
+        }
+
+        let king = kingbb.to_square();
         let enemy = color.opponent();
-        if !self.get_attacks(&enemy).has_square(sqbb) {
-            return attackers;
+        let enemy_occupied = *self.get_occupied(&enemy);
+        let queens = *self.get_pieces(&PieceType::Queen, &enemy);
+        let diagonal = *self.get_pieces(&PieceType::Bishop, &enemy) | queens;
+        let straight = *self.get_pieces(&PieceType::Rook, &enemy) | queens;
+
+        let mut pinned = EMPTY;
+        let mut pinners = EMPTY;
+        let xrays = (bishop::magic_attacks(king, enemy_occupied) & diagonal)
+            | (rook::magic_attacks(king, enemy_occupied) & straight);
+        for sq in xrays {
+            let between = lines::between(king, sq) & self.occupied;
+            if between.popcnt() == 1 && between & *self.get_occupied(&color) != EMPTY {
+                pinned |= between;
+                pinners.set(sq);
+            }
         }
 
-        for piece in ALL_PIECE_TYPES {
-            let moveinfo = piece.psuedo_legal_targets_fast(self, &sq);
-            let potential_enemy = self.get_pieces(&piece, &enemy);
-            attackers |= moveinfo.targets & *potential_enemy;
+        (pinned, pinners)
+    }
+
+    /// Returns every piece of `color` that is pinned to its own king
+    pub fn pinned(&self, color: PieceColor) -> BitBoard {
+        self.pins(color).0
+    }
+
+    /// Returns the squares the pinned piece on `sq` can move to without exposing its king, which is
+    /// the ray between the king and the pinner along with the pinner itself. Returns `None` if the
+    /// piece is not pinned.
+    pub fn pin_ray(&self, sq: Square) -> Option<BitBoard> {
+        let sqbb = BitBoard::from_square(sq);
+        let color = self.determine_color(sqbb)?;
+        let (pinned, pinners) = self.pins(color);
+        if !pinned.has_square(sqbb) {
+            return None;
         }
 
-        attackers
+        let king = self.get_king(color).to_square();
+        pinners
+            .into_iter()
+            .find(|&pinner| lines::between(king, pinner).has_square(sqbb))
+            .map(|pinner| lines::between(king, pinner) | BitBoard::from_square(pinner))
     }
 
     /// Returns the squarebb of the piece pinning `sqbb` to the king and a bitboard of its pin/check
@@ -782,11 +1077,13 @@ impl Game {
         let king = self.get_king(color).to_square();
         let occupied = self.occupied ^ sqbb;
         for piece in ALL_RAY_PIECES {
-            let attacks = piece.magic_attacks(king, occupied);
+            // Only pieces revealed by removing `sqbb` can be pinning it
+            let xray =
+                piece.magic_attacks(king, occupied) & !piece.magic_attacks(king, self.occupied);
             let potential_enemies = *self.get_pieces(&piece, &enemy);
-            let checker = attacks & potential_enemies;
+            let checker = xray & potential_enemies;
             if checker != EMPTY {
-                return Some((checker, attacks & checks));
+                return Some((checker, lines::between(king, checker.to_square()) & checks));
             }
         }
 
@@ -810,22 +1107,49 @@ impl Game {
         self.piece_table.get(sq)
     }
 
+    /// Every piece on the board along with its square, from A1 up to H8. Only occupied squares are
+    /// visited, so this is cheaper than calling [`Game::piece_lookup`] on all 64 of them.
+    pub fn pieces(&self) -> impl Iterator<Item = (Square, PieceType, PieceColor)> {
+        self.pieces_on(self.occupied)
+    }
+
+    /// Every piece of `color` along with its square, from A1 up to H8
+    pub fn pieces_of(
+        &self,
+        color: PieceColor,
+    ) -> impl Iterator<Item = (Square, PieceType, PieceColor)> {
+        self.pieces_on(*self.get_occupied(&color))
+    }
+
+    fn pieces_on(
+        &self,
+        squares: BitBoard,
+    ) -> impl Iterator<Item = (Square, PieceType, PieceColor)> {
+        squares.into_iter().map(|sq| {
+            let (piece, color) = self
+                .piece_table
+                .get(sq)
+                .expect("The piece table is missing an occupied square");
+            (sq, piece, color)
+        })
+    }
+
     pub const fn maximum_move_count_white(&self) -> u32 {
-        self.white_pawns.popcnt() * pieces::pawn::MAXIMUM_MOVE_COUNT
-            + self.white_knights.popcnt() * pieces::knight::MAXIMUM_MOVE_COUNT
-            + self.white_bishops.popcnt() * pieces::bishop::MAXIMUM_MOVE_COUNT
-            + self.white_rooks.popcnt() * pieces::rook::MAXIMUM_MOVE_COUNT
-            + self.white_queens.popcnt() * pieces::queen::MAXIMUM_MOVE_COUNT
-            + self.white_kings.popcnt() * pieces::king::MAXIMUM_MOVE_COUNT
+        self.position.white_pawns.popcnt() * pieces::pawn::MAXIMUM_MOVE_COUNT
+            + self.position.white_knights.popcnt() * pieces::knight::MAXIMUM_MOVE_COUNT
+            + self.position.white_bishops.popcnt() * pieces::bishop::MAXIMUM_MOVE_COUNT
+            + self.position.white_rooks.popcnt() * pieces::rook::MAXIMUM_MOVE_COUNT
+            + self.position.white_queens.popcnt() * pieces::queen::MAXIMUM_MOVE_COUNT
+            + self.position.white_kings.popcnt() * pieces::king::MAXIMUM_MOVE_COUNT
     }
 
     pub const fn maximum_move_count_black(&self) -> u32 {
-        self.black_pawns.popcnt() * pieces::pawn::MAXIMUM_MOVE_COUNT
-            + self.black_knights.popcnt() * pieces::knight::MAXIMUM_MOVE_COUNT
-            + self.black_bishops.popcnt() * pieces::bishop::MAXIMUM_MOVE_COUNT
-            + self.black_rooks.popcnt() * pieces::rook::MAXIMUM_MOVE_COUNT
-            + self.black_queens.popcnt() * pieces::queen::MAXIMUM_MOVE_COUNT
-            + self.black_kings.popcnt() * pieces::king::MAXIMUM_MOVE_COUNT
+        self.position.black_pawns.popcnt() * pieces::pawn::MAXIMUM_MOVE_COUNT
+            + self.position.black_knights.popcnt() * pieces::knight::MAXIMUM_MOVE_COUNT
+            + self.position.black_bishops.popcnt() * pieces::bishop::MAXIMUM_MOVE_COUNT
+            + self.position.black_rooks.popcnt() * pieces::rook::MAXIMUM_MOVE_COUNT
+            + self.position.black_queens.popcnt() * pieces::queen::MAXIMUM_MOVE_COUNT
+            + self.position.black_kings.popcnt() * pieces::king::MAXIMUM_MOVE_COUNT
     }
 
     pub fn lazy_psuedo_legal_moves_white(&self) -> impl Iterator<Item = Move> {
@@ -885,11 +1209,11 @@ impl Game {
             .chain(
                 std::iter::once_with(move || {
                     if self.white_pawns != EMPTY {
-                        let mut moves = ArrayVec::<Move, 32>::new();
+                        let mut moves = MoveList::new();
                         pawn::push_psuedo_legal_moves_white(&mut moves, self);
                         moves
                     } else {
-                        ArrayVec::new()
+                        MoveList::new()
                     }
                 })
                 .flatten(),
@@ -951,11 +1275,11 @@ impl Game {
             .chain(
                 std::iter::once_with(move || {
                     if self.black_pawns != EMPTY {
-                        let mut moves = ArrayVec::<Move, 32>::new();
+                        let mut moves = MoveList::new();
                         pawn::push_psuedo_legal_moves_black(&mut moves, self);
                         moves
                     } else {
-                        ArrayVec::new()
+                        MoveList::new()
                     }
                 })
                 .flatten(),
@@ -1064,9 +1388,9 @@ impl Game {
 
         // Avoid allocation if possible
         if self.white_pawns != EMPTY {
-            let mut moves = ArrayVec::<Move, 32>::new();
+            let mut moves = MoveList::new();
             pawn::push_psuedo_legal_moves_white(&mut moves, self);
-            lazy_return!(moves.first());
+            lazy_return!(moves.first().copied());
         }
 
         None
@@ -1120,35 +1444,52 @@ impl Game {
     }
 
     /// Generates all psuedo legal moves for the current player
-    pub fn generate_all_psuedo_legal_moves(&self) -> Vec<Move> {
-        match self.turn {
-            PieceColor::White => {
-                let mut moves = UnsafeVec::with_capacity(self.maximum_move_count_white() as usize);
-                self.push_psuedo_legal_moves_white(&mut moves);
-                moves.finish()
-            }
-            PieceColor::Black => {
-                let mut moves = UnsafeVec::with_capacity(self.maximum_move_count_black() as usize);
-                self.push_psuedo_legal_moves_black(&mut moves);
-                moves.finish()
-            }
-        }
+    pub fn generate_all_psuedo_legal_moves(&self) -> MoveList {
+        let mut moves = MoveList::new();
+        self.push_psuedo_legal_moves(&mut moves);
+        moves
     }
 
-    /// Hands over pregenerated legal moves on the first call, and generates legal moves
-    /// again for each subsequent call. If you want to call this method multiple times,
-    /// think about calling this method once and storing the output instead.
-    pub fn legal_moves(&mut self) -> Vec<Move> {
+    /// Generates the legal moves of the side to move, or none once the game is over. Nothing is
+    /// cached, so store the output instead of calling this again for the same position.
+    pub fn legal_moves(&self) -> MoveList {
         if self.state != State::InProgress {
-            return Vec::new();
+            return MoveList::new();
         }
 
         self.generate_all_legal_moves()
     }
 
-    /// Generates all legal moves for the current player. This also updates position state
-    /// for statemate or checkmate
-    fn generate_all_legal_moves(&self) -> Vec<Move> {
+    /// The legal moves of the piece on `sq`. Empty if the square is empty, holds a piece of the
+    /// side not to move, or the game is over.
+    pub fn moves_from(&self, sq: Square) -> MoveList {
+        match self.piece_lookup(sq) {
+            Some((piece, color)) if color == self.turn && self.state == State::InProgress => {
+                piece.legal_moves(self, &sq)
+            }
+            _ => MoveList::new(),
+        }
+    }
+
+    /// The legal moves that end on `sq`, including captures of the piece standing there
+    pub fn moves_to(&self, sq: Square) -> MoveList {
+        let mut moves = self.legal_moves();
+        moves.retain(|m| m.to(self) == sq);
+        moves
+    }
+
+    /// Generates all legal moves for the current player. The game is left untouched, checkmate and
+    /// stalemate are found with [`Game::outcome`] instead.
+    fn generate_all_legal_moves(&self) -> MoveList {
+        // Out of check, filtering is still the fastest way to get every move. In check, most
+        // psuedo legal moves would be thrown away, so the evasions are generated directly instead.
+        let generator = LegalMoveGen::new(self);
+        if generator.in_check() {
+            let mut moves = MoveList::new();
+            generator.push_evasions(&mut moves);
+            return moves;
+        }
+
         self.legal_moves_filter(self.generate_all_psuedo_legal_moves())
     }
 }
@@ -1160,11 +1501,60 @@ mod tests {
     use crate::movegen::pieces::pawn;
     use crate::movegen::pieces::piece::{PieceColor, PieceType};
     use crate::position::game::Game;
-    use crate::position::game::{STARTING_FEN, State};
+    use crate::position::game::{DrawReason, Outcome, STARTING_FEN, State};
     use crate::square::Square;
-    use crate::test_utils::{assert_meq, compare_to_fen, format_pretty_list, should_generate};
+    use crate::test_utils::{
+        assert_meq, compare_games, compare_to_fen, format_pretty_list, should_generate,
+    };
     use crate::vectors::UnsafeVec;
 
+    #[test]
+    fn flip_colors() {
+        let game =
+            Game::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w Kq - 3 7")
+                .unwrap();
+        compare_to_fen(
+            &game.flip_colors(),
+            "r3k2r/pppbbppp/2n2q1P/1P2p3/3pn3/BN2PNP1/P1PPQPB1/R3K2R b kQ - 3 7",
+        );
+        assert_eq!(game.flip_colors().flip_colors(), game);
+
+        let en_passant =
+            Game::from_fen("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 2")
+                .unwrap();
+        assert_eq!(en_passant.flip_colors().en_passant_target, Some(Square::F3));
+    }
+
+    #[test]
+    fn mirror_vertical() {
+        let game =
+            Game::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w Kq - 3 7")
+                .unwrap();
+        compare_to_fen(
+            &game.mirror_vertical(),
+            "r2k3r/1bpqpp1p/1pnp2nb/3NP3/3P2p1/p1Q2N2/PPPBBPPP/R2K3R w - - 3 7",
+        );
+        assert_eq!(
+            game.mirror_vertical().mirror_vertical().to_fen(),
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w - - 3 7"
+        );
+        assert_eq!(
+            game.legal_moves().len(),
+            game.mirror_vertical().legal_moves().len() + 1,
+            "Only castling kingside should be lost"
+        );
+
+        let en_passant =
+            Game::from_fen("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 2")
+                .unwrap();
+        let mirrored = en_passant.mirror_vertical();
+        assert_eq!(mirrored.en_passant_target, Some(Square::C6));
+        assert_eq!(
+            mirrored.flip_colors(),
+            en_passant.flip_colors().mirror_vertical()
+        );
+    }
+
     #[track_caller]
     fn assert_lazy_equals_push_black(game: &Game) {
         let lazy: Vec<Move> = game.lazy_psuedo_legal_moves_black().collect();
@@ -1229,6 +1619,93 @@ mod tests {
         assert_eq!(game.state, State::Timeout);
     }
 
+    #[test]
+    fn status_queries_do_not_need_a_move() {
+        let mated = Game::from_fen("4k3/8/8/8/8/8/5PPP/r5K1 w - - 0 1").unwrap();
+        assert_eq!(mated.state, State::InProgress);
+        assert!(mated.is_checkmate());
+        assert!(!mated.is_stalemate());
+        assert_eq!(
+            mated.outcome(),
+            Some(Outcome::Decisive {
+                winner: PieceColor::Black
+            })
+        );
+        assert_eq!(mated.outcome().unwrap().winner(), Some(PieceColor::Black));
+
+        let stalemate = Game::from_fen("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").unwrap();
+        assert!(stalemate.is_stalemate());
+        assert!(!stalemate.is_checkmate());
+        assert_eq!(
+            stalemate.outcome(),
+            Some(Outcome::Draw(DrawReason::Stalemate))
+        );
+
+        let game = Game::default();
+        assert!(!game.is_checkmate());
+        assert!(!game.is_stalemate());
+        assert_eq!(game.outcome(), None);
+    }
+
+    #[test]
+    fn outcome_matches_state() {
+        let mut game = Game::default();
+        for (from, to) in [
+            (Square::G1, Square::F3),
+            (Square::G8, Square::F6),
+            (Square::F3, Square::G1),
+            (Square::F6, Square::G8),
+            (Square::G1, Square::F3),
+            (Square::G8, Square::F6),
+            (Square::F3, Square::G1),
+            (Square::F6, Square::G8),
+        ] {
+            assert_eq!(game.outcome(), game.state.outcome(game.turn));
+            let m = Move::infer(from, to, &game);
+            game.play(&m);
+        }
+        assert_eq!(game.state, State::Repetition);
+        assert_eq!(game.outcome(), Some(Outcome::Draw(DrawReason::Repetition)));
+    }
+
+    #[test]
+    fn insufficient_material() {
+        for fen in [
+            "8/8/4k3/8/8/3K4/8/8 w - - 0 1",
+            "8/8/4k3/8/8/3K4/5B2/8 w - - 0 1",
+            "8/8/4k3/8/8/3K4/6n1/8 b - - 0 1",
+            "8/2b5/4k3/8/8/3K4/5B2/8 w - - 0 1",
+        ] {
+            let game = Game::from_fen(fen).unwrap();
+            assert!(game.has_insufficient_material(), "{}", fen);
+            assert_eq!(game.state, State::InsufficientMaterial, "{}", fen);
+            assert!(game.legal_moves().is_empty());
+        }
+
+        for fen in [
+            "8/8/4k3/8/8/3K4/4P3/8 w - - 0 1",
+            "8/8/4k3/8/8/3K4/4NN2/8 w - - 0 1",
+            "8/3b4/4k3/8/8/3K4/5B2/8 w - - 0 1",
+            "8/8/4k3/8/8/3K4/4BN2/8 w - - 0 1",
+        ] {
+            let game = Game::from_fen(fen).unwrap();
+            assert!(!game.has_insufficient_material(), "{}", fen);
+            assert_eq!(game.state, State::InProgress, "{}", fen);
+        }
+    }
+
+    #[test]
+    fn capturing_the_last_pawn_is_a_draw() {
+        let fen = "8/8/8/4p3/3K4/8/8/k7 w - - 0 1";
+        let mut game = Game::from_fen(fen).unwrap();
+        assert_eq!(game.state, State::InProgress);
+        let capture = Move::infer(Square::D4, Square::E5, &game);
+        game.play(&capture);
+        assert_eq!(game.state, State::InsufficientMaterial);
+        game.unplay(&capture);
+        assert_eq!(game.state, State::InProgress);
+    }
+
     #[test]
     fn draw_by_repetition() {
         let mut game = Game::default();
@@ -1254,6 +1731,137 @@ mod tests {
         assert_eq!(game.state, State::Repetition);
     }
 
+    #[test]
+    fn undo_takes_back_the_history() {
+        let mut game = Game::default();
+        let before = game.clone();
+        let mut played = Vec::new();
+        for (from, to) in [
+            (Square::E2, Square::E4),
+            (Square::D7, Square::D5),
+            (Square::E4, Square::D5),
+        ] {
+            let m = Move::infer(from, to, &game);
+            game.play(&m);
+            played.push(m);
+        }
+        assert_eq!(game.history(), played);
+
+        assert_eq!(game.undo(), played.pop());
+        assert_eq!(game.history(), played);
+        assert_eq!(game.undo(), played.pop());
+        assert_eq!(game.undo(), played.pop());
+        assert_eq!(game.undo(), None);
+        compare_games(&before, &game);
+    }
+
+    #[test]
+    fn twofold_repetition_is_undone_with_unplay() {
+        let mut game = Game::default();
+        let mut played = Vec::new();
+        for (from, to) in [
+            (Square::G1, Square::F3),
+            (Square::B8, Square::C6),
+            (Square::F3, Square::G1),
+            (Square::C6, Square::B8),
+        ] {
+            assert!(!game.is_repetition());
+            let m = Move::infer(from, to, &game);
+            game.play(&m);
+            played.push(m);
+        }
+
+        assert!(game.is_repetition());
+        assert_eq!(game.repetitions(), 1);
+        assert_eq!(game.state, State::InProgress);
+
+        game.unplay(&played.pop().unwrap());
+        assert!(!game.is_repetition());
+        assert_eq!(game.key_history.len(), 3);
+    }
+
+    #[test]
+    fn repetitions_follow_undo_across_irreversible_moves() {
+        let mut game = Game::default();
+        let shuffle = [
+            (Square::G1, Square::F3),
+            (Square::B8, Square::C6),
+            (Square::F3, Square::G1),
+            (Square::C6, Square::B8),
+        ];
+        for (from, to) in shuffle {
+            game.play(&Move::infer(from, to, &game));
+        }
+        assert_eq!(game.repetitions(), 1);
+
+        game.play(&Move::infer(Square::E2, Square::E3, &game));
+        assert_eq!(game.half_move_timeout, 0);
+        assert_eq!(game.repetitions(), 0);
+
+        for (from, to) in [
+            (Square::B8, Square::C6),
+            (Square::G1, Square::F3),
+            (Square::C6, Square::B8),
+        ] {
+            game.play(&Move::infer(from, to, &game));
+            assert_eq!(game.repetitions(), 0);
+        }
+        game.play(&Move::infer(Square::F3, Square::G1, &game));
+        assert_eq!(game.repetitions(), 1);
+
+        while game.history().len() > 4 {
+            game.undo();
+        }
+        assert_eq!(game.repetitions(), 1);
+        assert_eq!(game.key_history.len(), 4);
+    }
+
+    #[test]
+    fn take_back_copies_the_position_back() {
+        let fen = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+        let mut game = Game::from_fen(fen).unwrap();
+        let a4 = Move::infer(Square::A2, Square::A4, &game);
+        game.play(&a4);
+
+        let before = game.clone();
+        for m in game.legal_moves() {
+            let saved = *game.position();
+            game.play(&m);
+            game.take_back(saved);
+            compare_games(&before, &game);
+        }
+
+        // Moves taken back by copying can still be mixed with unmaking
+        let saved = *game.position();
+        let m = game.legal_moves()[0];
+        game.play(&m);
+        game.take_back(saved);
+        assert_eq!(game.undo(), Some(a4));
+        compare_to_fen(&game, fen);
+    }
+
+    #[test]
+    fn unplay_restores_a_finished_state() {
+        let mut game = Game::default();
+        for _ in 0..2 {
+            for (from, to) in [
+                (Square::G1, Square::F3),
+                (Square::B8, Square::C6),
+                (Square::F3, Square::G1),
+                (Square::C6, Square::B8),
+            ] {
+                game.play(&Move::infer(from, to, &game));
+            }
+        }
+        assert_eq!(game.state, State::Repetition);
+
+        // Nothing stops a move from being played after the game is over
+        let m = Move::infer(Square::E2, Square::E4, &game);
+        game.play(&m);
+        game.unplay(&m);
+        assert_eq!(game.state, State::Repetition);
+    }
+
     #[test]
     fn should_not_have_moves_after_draw_by_repetition() {
         let mut game = Game::default();
@@ -1287,7 +1895,163 @@ mod tests {
         let fen = "kr2r3/pp6/8/2N5/4pK2/8/2B1R1B1/8 w - - 0 1";
         let game = Game::from_fen(fen).unwrap();
         let black_pawnbb = Square::E4;
-        assert_eq!(game.attackers(black_pawnbb).popcnt(), 3);
+        // The king, rook, both bishops and the knight
+        assert_eq!(game.attackers(black_pawnbb).popcnt(), 5);
+        assert_eq!(game.num_attackers(black_pawnbb), 5);
+        assert_eq!(game.num_defenders(black_pawnbb), 1);
+    }
+
+    #[test]
+    fn moves_from_and_to_a_square() {
+        let fens = [
+            STARTING_FEN,
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1",
+            "4k3/8/8/8/8/8/3q4/4K3 w - - 0 1",
+        ];
+
+        for fen in fens {
+            let game = Game::from_fen(fen).unwrap();
+            let all = game.legal_moves();
+            for sq in Square::ALL_SQUARES {
+                let from: Vec<_> = all
+                    .iter()
+                    .filter(|m| m.from(game.turn) == sq)
+                    .copied()
+                    .collect();
+                let mut moves_from = game.moves_from(sq).to_vec();
+                moves_from.sort_by_key(|m| m.to(&game).to_int());
+                let mut expected = from.clone();
+                expected.sort_by_key(|m| m.to(&game).to_int());
+                assert_eq!(moves_from, expected, "{fen} {sq}");
+
+                let to: Vec<_> = all.iter().filter(|m| m.to(&game) == sq).copied().collect();
+                assert_eq!(game.moves_to(sq).to_vec(), to, "{fen} {sq}");
+            }
+        }
+
+        let game = Game::default();
+        assert_eq!(game.moves_from(Square::E7).len(), 0);
+        assert_eq!(game.moves_from(Square::E4).len(), 0);
+        assert_eq!(game.moves_to(Square::E4).len(), 1);
+    }
+
+    #[test]
+    fn pieces_visits_every_piece_once() {
+        let game = Game::default();
+        let pieces: Vec<_> = game.pieces().collect();
+        assert_eq!(pieces.len(), 32);
+        assert_eq!(pieces[0], (Square::A1, PieceType::Rook, PieceColor::White));
+        assert_eq!(pieces[31], (Square::H8, PieceType::Rook, PieceColor::Black));
+        for &(sq, piece, color) in &pieces {
+            assert_eq!(game.piece_lookup(sq), Some((piece, color)));
+        }
+
+        let black: Vec<_> = game.pieces_of(PieceColor::Black).collect();
+        assert_eq!(black, pieces[16..]);
+        assert_eq!(Game::empty().pieces().count(), 0);
+    }
+
+    #[test]
+    fn attacks_do_not_depend_on_the_turn() {
+        let placement = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R";
+        let white = Game::from_fen(&format!("{placement} w KQkq - 0 1")).unwrap();
+        let black = Game::from_fen(&format!("{placement} b KQkq - 0 1")).unwrap();
+
+        for color in [PieceColor::White, PieceColor::Black] {
+            assert_eq!(
+                white.calculate_attacks(&color),
+                black.calculate_attacks(&color)
+            );
+            for sq in *white.get_occupied(&color) {
+                let (piece, _) = white.piece_lookup(sq).unwrap();
+                assert_eq!(
+                    piece.psuedo_legal_targets_for(&white, &sq, color),
+                    piece.psuedo_legal_targets_for(&black, &sq, color),
+                    "{piece:?} on {sq}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn attackers_to_empty_squares() {
+        let fen = "kr2r3/pp6/8/2N5/4pK2/8/2B1R1B1/8 w - - 0 1";
+        let game = Game::from_fen(fen).unwrap();
+        assert_eq!(
+            game.attackers_to(Square::D3, PieceColor::White),
+            BitBoard::from_square(Square::C2) | BitBoard::from_square(Square::C5)
+        );
+        assert_eq!(
+            game.attackers_to(Square::D3, PieceColor::Black),
+            BitBoard::from_square(Square::E4)
+        );
+
+        // With the e4 pawn out of the way, the e8 rook reaches all the way down to e2
+        let occupied = game.occupied ^ BitBoard::from_square(Square::E4);
+        assert_eq!(
+            game.attackers_to_with_occupied(Square::E2, PieceColor::Black, occupied),
+            BitBoard::from_square(Square::E8)
+        );
+    }
+
+    #[test]
+    fn pinned_pieces_and_their_rays() {
+        let fen = "4k3/4r3/8/1b6/8/3PB3/4K3/8 w - - 0 1";
+        let game = Game::from_fen(fen).unwrap();
+        assert_eq!(
+            game.pinned(PieceColor::White),
+            BitBoard::from_square(Square::E3) | BitBoard::from_square(Square::D3)
+        );
+        assert_eq!(game.pinned(PieceColor::Black), EMPTY);
+
+        let e_file = [Square::E3, Square::E4, Square::E5, Square::E6, Square::E7]
+            .into_iter()
+            .fold(EMPTY, |acc, sq| acc | BitBoard::from_square(sq));
+        assert_eq!(game.pin_ray(Square::E3), Some(e_file));
+        assert_eq!(
+            game.pin_ray(Square::D3),
+            Some(
+                BitBoard::from_square(Square::D3)
+                    | BitBoard::from_square(Square::C4)
+                    | BitBoard::from_square(Square::B5)
+            )
+        );
+        assert_eq!(game.pin_ray(Square::E2), None);
+    }
+
+    #[test]
+    fn pins_from_both_sides_of_the_king() {
+        // The rooks on e6 and e2 are pinned by different rooks on the same file
+        let fen = "r3r3/8/4R3/8/4K3/8/4R3/k3r3 w - - 0 1";
+        let game = Game::from_fen(fen).unwrap();
+        let upper = [Square::E5, Square::E6, Square::E7, Square::E8]
+            .into_iter()
+            .fold(EMPTY, |acc, sq| acc | BitBoard::from_square(sq));
+        assert_eq!(game.pin_ray(Square::E6), Some(upper));
+        assert_eq!(game.legal_moves().len(), 13);
+    }
+
+    #[test]
+    fn num_defenders() {
+        let fen = "kr2r3/pp6/8/2N5/4pK2/8/2B1R1B1/8 w - - 0 1";
+        let game = Game::from_fen(fen).unwrap();
+        assert_eq!(game.defenders(Square::C5), EMPTY);
+        assert_eq!(
+            game.defenders(Square::A7),
+            BitBoard::from_square(Square::A8)
+        );
+        assert_eq!(
+            game.defenders(Square::E4),
+            BitBoard::from_square(Square::E8)
+        );
+        assert_eq!(game.defenders(Square::D4), EMPTY);
+
+        let pawns = Game::from_fen("4k3/8/8/3p4/4p3/8/8/4K3 w - - 0 1").unwrap();
+        assert_eq!(
+            pawns.defenders(Square::E4),
+            BitBoard::from_square(Square::D5)
+        );
     }
 
     #[test]
@@ -1320,7 +2084,7 @@ mod tests {
     #[test]
     fn can_capture_attacking_rook() {
         let fen = "rR1k3r/2p3p1/p1P2p1p/2Bpp3/8/6P1/P6P/1R4K1 b - - 3 33";
-        let mut game = Game::from_fen(fen).unwrap();
+        let game = Game::from_fen(fen).unwrap();
         let moves = game.legal_moves();
         should_generate(
             &moves,
@@ -1497,7 +2261,7 @@ mod tests {
 
     #[test]
     fn generating_legal_moves_should_not_mutate_position() {
-        let mut game = Game::default();
+        let game = Game::default();
         let mut last_moves = game.legal_moves();
         let mut last = game.clone();
         for _ in 1..20 {
@@ -0,0 +1,162 @@
+use crate::{movegen::moves::Move, position::game::Game};
+
+/// A move in a [`GameHistory`], along with how it is written
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryEntry {
+    pub m: Move,
+    /// The move in Standard Algebraic Notation, such as Nxf7+
+    pub san: String,
+}
+
+/// The record of a whole game, from its starting position through every move played since. Any
+/// ply can be revisited with [`GameHistory::seek`], which is what reviewing a game or writing it
+/// out move by move needs.
+#[derive(Debug, Clone)]
+pub struct GameHistory {
+    start: Game,
+    current: Game,
+    entries: Vec<HistoryEntry>,
+}
+
+impl GameHistory {
+    /// Starts an empty history from `start`
+    pub fn new(start: Game) -> Self {
+        Self {
+            current: start.clone(),
+            start,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Rebuilds the history of `game` by taking back every move it has played
+    pub fn from_game(game: &Game) -> Self {
+        let mut start = game.clone();
+        while start.undo().is_some() {}
+
+        let mut history = GameHistory::new(start);
+        for &m in game.history() {
+            history.play(m);
+        }
+        history
+    }
+
+    /// Plays `m` on the latest position and records it
+    pub fn play(&mut self, m: Move) {
+        let san = m.to_san(&self.current);
+        self.current.play(&m);
+        self.entries.push(HistoryEntry { m, san });
+    }
+
+    /// Forgets every move after `ply`, so a different line can be played from there
+    pub fn truncate(&mut self, ply: usize) {
+        while self.entries.len() > ply {
+            let entry = self
+                .entries
+                .pop()
+                .expect("Checked that there are entries left");
+            self.current.unplay(&entry.m);
+        }
+    }
+
+    /// A fresh game at `ply`, where 0 is the starting position. Returns `None` if fewer than
+    /// `ply` moves were played.
+    pub fn seek(&self, ply: usize) -> Option<Game> {
+        let mut game = self.start.clone();
+        for entry in self.entries.get(..ply)? {
+            game.play(&entry.m);
+        }
+        Some(game)
+    }
+
+    /// The position the game started from
+    pub fn start(&self) -> &Game {
+        &self.start
+    }
+
+    /// The position after the last move
+    pub fn current(&self) -> &Game {
+        &self.current
+    }
+
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    pub fn moves(&self) -> impl Iterator<Item = Move> {
+        self.entries.iter().map(|entry| entry.m)
+    }
+
+    /// The number of plies played
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{square::Square, test_utils::compare_games};
+
+    use super::*;
+
+    fn scholars_mate() -> GameHistory {
+        let mut history = GameHistory::new(Game::default());
+        for (from, to) in [
+            (Square::E2, Square::E4),
+            (Square::E7, Square::E5),
+            (Square::F1, Square::C4),
+            (Square::B8, Square::C6),
+            (Square::D1, Square::H5),
+            (Square::G8, Square::F6),
+            (Square::H5, Square::F7),
+        ] {
+            let m = Move::infer(from, to, history.current());
+            history.play(m);
+        }
+        history
+    }
+
+    #[test]
+    fn records_san() {
+        let history = scholars_mate();
+        let san: Vec<_> = history.entries().iter().map(|e| e.san.as_str()).collect();
+        assert_eq!(san, ["e4", "e5", "Bc4", "Nc6", "Qh5", "Nf6", "Qxf7#"]);
+    }
+
+    #[test]
+    fn seek_replays_from_the_start() {
+        let history = scholars_mate();
+        compare_games(&history.seek(0).unwrap(), history.start());
+        compare_games(&history.seek(history.len()).unwrap(), history.current());
+        assert!(history.seek(history.len() + 1).is_none());
+
+        let after_e5 = history.seek(2).unwrap();
+        assert_eq!(
+            after_e5.to_fen(),
+            "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2"
+        );
+    }
+
+    #[test]
+    fn truncate_allows_a_new_line() {
+        let mut history = scholars_mate();
+        history.truncate(4);
+        assert_eq!(history.len(), 4);
+        compare_games(history.current(), &history.seek(4).unwrap());
+
+        let m = Move::infer(Square::G1, Square::F3, history.current());
+        history.play(m);
+        assert_eq!(history.entries().last().unwrap().san, "Nf3");
+    }
+
+    #[test]
+    fn rebuilt_from_a_game() {
+        let original = scholars_mate();
+        let rebuilt = GameHistory::from_game(original.current());
+        assert_eq!(rebuilt.entries(), original.entries());
+        compare_games(rebuilt.start(), original.start());
+    }
+}
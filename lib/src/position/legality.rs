@@ -2,7 +2,8 @@ use std::fmt::{self, Debug, Display};
 
 use crate::{
     bitboard::{BitBoard, EMPTY},
-    position::game::Game,
+    movegen::pieces::piece::{PieceColor, PieceType},
+    position::{castling::*, game::Game},
     rank::Rank,
 };
 
@@ -61,6 +62,45 @@ fn no_pawns_on_promotion_ranks(game: &Game) -> bool {
     (game.white_pawns & Rank::Eighth.mask()) | (game.black_pawns & Rank::First.mask()) == EMPTY
 }
 
+fn en_passant_target_is_consistent(game: &Game) -> bool {
+    let Some(target) = game.en_passant_target else {
+        return true;
+    };
+
+    // The pawn that just moved two squares is in front of the target, and it came from behind it
+    let expected_rank = match game.turn {
+        PieceColor::White => Rank::Sixth,
+        PieceColor::Black => Rank::Third,
+    };
+    let pawn = target.backward(&game.turn);
+    let origin = target.forward(&game.turn);
+
+    target.get_rank() == expected_rank
+        && pawn.is_some_and(|sq| {
+            game.get_pieces(&PieceType::Pawn, &game.turn.opponent())
+                .has_square(BitBoard::from_square(sq))
+        })
+        && origin.is_some_and(|sq| !game.occupied.has_square(BitBoard::from_square(sq)))
+        && !game.occupied.has_square(BitBoard::from_square(target))
+}
+
+fn castling_rights_match_king_and_rooks(game: &Game) -> bool {
+    let rights = game.castling_rights;
+    let stands = |pieces: BitBoard, sqbb: BitBoard| pieces.has_square(sqbb);
+
+    let white_king = stands(game.white_kings, WHITE_CASTLE_KINGSIDE_KING_FROM_BB);
+    let black_king = stands(game.black_kings, BLACK_CASTLE_KINGSIDE_KING_FROM_BB);
+
+    (!rights.white_kingside()
+        || white_king && stands(game.white_rooks, WHITE_CASTLE_KINGSIDE_ROOK_FROM_BB))
+        && (!rights.white_queenside()
+            || white_king && stands(game.white_rooks, WHITE_CASTLE_QUEENSIDE_ROOK_FROM_BB))
+        && (!rights.black_kingside()
+            || black_king && stands(game.black_rooks, BLACK_CASTLE_KINGSIDE_ROOK_FROM_BB))
+        && (!rights.black_queenside()
+            || black_king && stands(game.black_rooks, BLACK_CASTLE_QUEENSIDE_ROOK_FROM_BB))
+}
+
 /// The set of rules used to determine position legality
 #[derive(Debug, Clone, Copy)]
 pub struct Ruleset {
@@ -69,6 +109,8 @@ pub struct Ruleset {
     pub no_more_than_16_pieces_per_side: bool,
     pub no_more_than_eight_pawns_plus_promoted_pieces_per_side: bool,
     pub no_pawns_on_promotion_ranks: bool,
+    pub en_passant_target_is_consistent: bool,
+    pub castling_rights_match_king_and_rooks: bool,
 }
 
 impl Default for Ruleset {
@@ -92,6 +134,19 @@ impl Ruleset {
             no_more_than_16_pieces_per_side: true,
             no_more_than_eight_pawns_plus_promoted_pieces_per_side: true,
             no_pawns_on_promotion_ranks: true,
+            en_passant_target_is_consistent: true,
+            castling_rights_match_king_and_rooks: true,
+        }
+    }
+
+    /// The laws every position has to follow for moves to be generated correctly in it. Unlike
+    /// [`Ruleset::complete`], pieces are not counted, so composed positions that could never come
+    /// up in a real game are still allowed.
+    pub fn essential() -> Self {
+        Self {
+            no_more_than_16_pieces_per_side: false,
+            no_more_than_eight_pawns_plus_promoted_pieces_per_side: false,
+            ..Self::complete()
         }
     }
 
@@ -103,6 +158,8 @@ impl Ruleset {
             no_more_than_16_pieces_per_side: false,
             no_more_than_eight_pawns_plus_promoted_pieces_per_side: false,
             no_pawns_on_promotion_ranks: false,
+            en_passant_target_is_consistent: false,
+            castling_rights_match_king_and_rooks: false,
         }
     }
 
@@ -113,6 +170,8 @@ impl Ruleset {
             || self.no_more_than_16_pieces_per_side
             || self.no_more_than_eight_pawns_plus_promoted_pieces_per_side
             || self.no_pawns_on_promotion_ranks
+            || self.en_passant_target_is_consistent
+            || self.castling_rights_match_king_and_rooks
     }
 
     /// Returns `true` if no laws were violated
@@ -139,6 +198,12 @@ impl Ruleset {
         if self.no_pawns_on_promotion_ranks {
             speech.push_str("There are pawns on the promotion ranks!\n");
         }
+        if self.en_passant_target_is_consistent {
+            speech.push_str("The en passant target does not follow a double pawn push!\n");
+        }
+        if self.castling_rights_match_king_and_rooks {
+            speech.push_str("A side can castle without its king and rook on their squares!\n");
+        }
 
         if speech.is_empty() {
             speech.push_str("Case dismissed!\n");
@@ -162,6 +227,10 @@ impl Ruleset {
                 && !no_more_than_eight_pawns_plus_promoted_pieces_per_side(game),
             no_pawns_on_promotion_ranks: self.no_pawns_on_promotion_ranks
                 && !no_pawns_on_promotion_ranks(game),
+            en_passant_target_is_consistent: self.en_passant_target_is_consistent
+                && !en_passant_target_is_consistent(game),
+            castling_rights_match_king_and_rooks: self.castling_rights_match_king_and_rooks
+                && !castling_rights_match_king_and_rooks(game),
         }
     }
 }
@@ -169,6 +238,7 @@ impl Ruleset {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::position::fen::FenError;
 
     #[test]
     fn no_more_than_eighth_pawns_plus_promoted_pieces_per_side() {
@@ -186,4 +256,56 @@ mod tests {
         let verdict = law.judge(&game);
         assert!(verdict.innocent(), "{}", verdict);
     }
+
+    #[test]
+    fn en_passant_target_needs_a_double_push() {
+        let law = Ruleset::essential();
+        let fine = Game::from_fen_unchecked("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1").unwrap();
+        assert!(law.judge(&fine).innocent());
+
+        for fen in [
+            "4k3/8/8/4P3/8/8/8/4K3 w - d6 0 1",
+            "4k3/8/8/3pP3/8/8/8/4K3 w - d3 0 1",
+            "4k3/3p4/8/3pP3/8/8/8/4K3 w - d6 0 1",
+        ] {
+            let game = Game::from_fen_unchecked(fen).unwrap();
+            let verdict = law.judge(&game);
+            assert!(verdict.en_passant_target_is_consistent, "{fen}");
+            assert!(Game::from_fen(fen).is_err(), "{fen}");
+        }
+    }
+
+    #[test]
+    fn castling_rights_need_the_king_and_rook() {
+        let law = Ruleset::essential();
+        for fen in [
+            "r3k2r/8/8/8/8/8/8/R4K1R w KQkq - 0 1",
+            "r3k3/8/8/8/8/8/8/R3K2R w KQkq - 0 1",
+            "1r2k2r/8/8/8/8/8/8/R3K2R b Qq - 0 1",
+        ] {
+            let game = Game::from_fen_unchecked(fen).unwrap();
+            let verdict = law.judge(&game);
+            assert!(verdict.castling_rights_match_king_and_rooks, "{fen}");
+            assert!(game.validate().is_err(), "{fen}");
+        }
+
+        let game = Game::from_fen("1r2k2r/8/8/8/8/8/8/R3K2R b KQk - 0 1").unwrap();
+        assert!(game.validate().is_ok());
+    }
+
+    #[test]
+    fn from_fen_refuses_broken_positions() {
+        for fen in [
+            "8/8/8/8/8/8/8/4K3 w - - 0 1",
+            "4k3/8/8/8/8/8/8/3KK3 w - - 0 1",
+            "4k2P/8/8/8/8/8/8/4K3 w - - 0 1",
+            "4k3/8/8/8/8/8/8/4R1K1 w - - 0 1",
+        ] {
+            assert!(Game::from_fen_unchecked(fen).is_ok(), "{fen}");
+            assert!(
+                matches!(Game::from_fen(fen), Err(FenError::Illegal(_))),
+                "{fen}"
+            );
+        }
+    }
 }
@@ -0,0 +1,155 @@
+use crate::{
+    get_pieces,
+    movegen::pieces::piece::{ALL_PIECE_TYPES, PieceColor, PieceType},
+    position::{board::Position, game::Game},
+};
+
+/// The value of a piece in centipawns, as counted by [`MaterialCount::value`]. Kings are worth
+/// nothing here, since both sides always have one.
+pub const fn piece_value(piece: PieceType) -> i16 {
+    match piece {
+        PieceType::Pawn => 100,
+        PieceType::Knight => 300,
+        PieceType::Bishop => 300,
+        PieceType::Rook => 500,
+        PieceType::Queen => 900,
+        PieceType::King => 0,
+    }
+}
+
+/// How many pieces of every type each side has, along with what they add up to. It is kept up to
+/// date while moves are made and unmade, so reading it never needs a popcount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MaterialCount {
+    /// Indexed by `PieceColor::to_int`, then by `PieceType::to_int`
+    counts: [[u8; 6]; 2],
+    /// Indexed by `PieceColor::to_int`
+    values: [i16; 2],
+}
+
+impl MaterialCount {
+    /// No pieces at all
+    pub const fn empty() -> Self {
+        Self {
+            counts: [[0; 6]; 2],
+            values: [0; 2],
+        }
+    }
+
+    /// Counts every piece on the board from scratch
+    pub fn from_position(position: &Position) -> Self {
+        let mut material = Self::empty();
+        for color in [PieceColor::White, PieceColor::Black] {
+            for piece in ALL_PIECE_TYPES {
+                for _ in *get_pieces!(position, &piece, &color) {
+                    material.add(piece, color);
+                }
+            }
+        }
+        material
+    }
+
+    pub const fn count(&self, piece: PieceType, color: PieceColor) -> u8 {
+        self.counts[color.to_int() as usize][piece.to_int() as usize]
+    }
+
+    /// The value of every piece `color` has, see [`piece_value`]
+    pub const fn value(&self, color: PieceColor) -> i16 {
+        self.values[color.to_int() as usize]
+    }
+
+    /// The value of every piece `color` has other than pawns
+    pub const fn non_pawn_value(&self, color: PieceColor) -> i16 {
+        self.value(color) - self.count(PieceType::Pawn, color) as i16 * piece_value(PieceType::Pawn)
+    }
+
+    /// The material `color` is ahead by, which is negative if it is behind
+    pub const fn balance(&self, color: PieceColor) -> i16 {
+        self.value(color) - self.value(color.opponent())
+    }
+
+    /// How many knights and bishops are left on the board
+    pub const fn minor_pieces(&self) -> u8 {
+        let mut total = 0;
+        let mut color = 0;
+        while color < 2 {
+            total += self.counts[color][PieceType::Knight.to_int() as usize]
+                + self.counts[color][PieceType::Bishop.to_int() as usize];
+            color += 1;
+        }
+        total
+    }
+
+    /// Whether any pawns, rooks or queens are left on the board
+    pub const fn has_heavy_pieces_or_pawns(&self) -> bool {
+        let mut color = 0;
+        while color < 2 {
+            let counts = self.counts[color];
+            if counts[PieceType::Pawn.to_int() as usize] > 0
+                || counts[PieceType::Rook.to_int() as usize] > 0
+                || counts[PieceType::Queen.to_int() as usize] > 0
+            {
+                return true;
+            }
+            color += 1;
+        }
+        false
+    }
+
+    pub(crate) const fn add(&mut self, piece: PieceType, color: PieceColor) {
+        self.counts[color.to_int() as usize][piece.to_int() as usize] += 1;
+        self.values[color.to_int() as usize] += piece_value(piece);
+    }
+
+    pub(crate) const fn remove(&mut self, piece: PieceType, color: PieceColor) {
+        self.counts[color.to_int() as usize][piece.to_int() as usize] -= 1;
+        self.values[color.to_int() as usize] -= piece_value(piece);
+    }
+}
+
+impl Game {
+    /// The pieces each side has, see [`MaterialCount`]
+    pub fn material(&self) -> MaterialCount {
+        self.material
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{movegen::moves::Move, square::Square, test_utils::assert_caches_are_fresh};
+
+    #[test]
+    fn starting_material() {
+        let material = Game::default().material();
+        for color in [PieceColor::White, PieceColor::Black] {
+            assert_eq!(material.count(PieceType::Pawn, color), 8);
+            assert_eq!(material.count(PieceType::Knight, color), 2);
+            assert_eq!(material.count(PieceType::King, color), 1);
+            assert_eq!(material.value(color), 3900);
+            assert_eq!(material.non_pawn_value(color), 3100);
+            assert_eq!(material.balance(color), 0);
+        }
+        assert_eq!(material.minor_pieces(), 8);
+        assert!(material.has_heavy_pieces_or_pawns());
+    }
+
+    #[test]
+    fn follows_captures_and_promotions() {
+        let fen = "1n2k3/P7/8/8/8/8/8/4K3 w - - 0 1";
+        let mut game = Game::from_fen(fen).unwrap();
+        let before = game.material();
+
+        let m = Move::infer(Square::A7, Square::B8, &game);
+        game.play(&m);
+        let material = game.material();
+        assert_eq!(material.count(PieceType::Pawn, PieceColor::White), 0);
+        assert_eq!(material.count(PieceType::Queen, PieceColor::White), 1);
+        assert_eq!(material.count(PieceType::Knight, PieceColor::Black), 0);
+        assert_eq!(material.balance(PieceColor::White), 900);
+        assert_caches_are_fresh(&game);
+
+        game.unplay(&m);
+        assert_eq!(game.material(), before);
+    }
+}
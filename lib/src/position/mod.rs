@@ -1,8 +1,16 @@
+pub mod board;
+pub mod builder;
 pub mod castling;
+pub mod chess960;
+pub mod events;
+pub mod fen;
 pub mod game;
 #[cfg(feature = "rand")]
 pub mod generator;
+pub mod history;
 pub mod legality;
+pub mod material;
+pub mod packed;
 pub mod piece_getters;
 mod piece_table;
 mod previous;
@@ -0,0 +1,201 @@
+use std::fmt;
+
+use crate::{
+    bitboard::BitBoard,
+    movegen::pieces::piece::{PieceColor, PieceType},
+    position::{builder::GameBuilder, castling::CastlingRights, game::Game, legality::Ruleset},
+    square::Square,
+};
+
+/// How many bytes a packed position takes
+pub const PACKED_POSITION_BYTES: usize = 32;
+
+/// A position packed into a fixed number of bytes, for storing many of them such as in datasets.
+/// The layout is:
+///
+/// | Bytes  | Contents                                                                  |
+/// |--------|---------------------------------------------------------------------------|
+/// | 0..8   | The occupied squares as a little endian bitboard                          |
+/// | 8..24  | A nibble for each occupied square from A1 up, low nibble first            |
+/// | 24     | The side to move in bit 0, the castling rights in bits 4 to 7             |
+/// | 25     | The en passant target square, or 255 if there is none                     |
+/// | 26     | The halfmove clock                                                        |
+/// | 27..29 | The fullmove number, little endian                                        |
+/// | 29..32 | Unused, always 0                                                          |
+///
+/// Each nibble is the piece type from [`PieceType::to_int`], plus 8 for black pieces. Only the
+/// position is kept, not the moves that led to it.
+pub type PackedPosition = [u8; PACKED_POSITION_BYTES];
+
+const PIECES_OFFSET: usize = 8;
+const FLAGS: usize = 24;
+const EN_PASSANT: usize = 25;
+const HALF_MOVE: usize = 26;
+const FULL_MOVE: usize = 27;
+const NO_EN_PASSANT: u8 = u8::MAX;
+const BLACK_PIECE: u8 = 8;
+
+/// Why [`Game::from_packed`] refused some bytes
+#[derive(Debug, Clone)]
+pub enum UnpackError {
+    /// More squares are occupied than there is room for pieces
+    TooManyPieces(u32),
+    /// A nibble that is not a piece
+    BadPiece { square: Square, nibble: u8 },
+    /// The en passant byte is not a square
+    BadEnPassant(u8),
+    /// The bytes could be read, but the position breaks the laws in the [`Ruleset`]
+    Illegal(Ruleset),
+}
+
+impl fmt::Display for UnpackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnpackError::TooManyPieces(count) => {
+                write!(f, "{count} squares are occupied, but at most 32 fit")
+            }
+            UnpackError::BadPiece { square, nibble } => {
+                write!(f, "{nibble} on {square} is not a piece")
+            }
+            UnpackError::BadEnPassant(byte) => write!(f, "{byte} is not an en passant square"),
+            UnpackError::Illegal(verdict) => {
+                let verdict = verdict.verdict();
+                let laws: Vec<_> = verdict.lines().collect();
+                write!(f, "the position is illegal: {}", laws.join(" "))
+            }
+        }
+    }
+}
+
+impl Game {
+    /// Packs the position into [`PACKED_POSITION_BYTES`] bytes, see [`PackedPosition`] for the
+    /// layout. Returns `None` if there are more than 32 pieces, which no legal game can reach.
+    pub fn to_packed(&self) -> Option<PackedPosition> {
+        if self.occupied.popcnt() > 32 {
+            return None;
+        }
+
+        let mut packed = [0; PACKED_POSITION_BYTES];
+        packed[..PIECES_OFFSET].copy_from_slice(&self.occupied.to_int().to_le_bytes());
+        for (i, (_, piece, color)) in self.pieces().enumerate() {
+            let nibble = piece.to_int() + color.to_int() * BLACK_PIECE;
+            packed[PIECES_OFFSET + i / 2] |= nibble << (i % 2 * 4);
+        }
+
+        packed[FLAGS] = self.turn.to_int() | self.castling_rights.to_int() << 4;
+        packed[EN_PASSANT] = self
+            .en_passant_target
+            .map_or(NO_EN_PASSANT, |sq| sq.to_int());
+        packed[HALF_MOVE] = self.half_move_timeout;
+        packed[FULL_MOVE..FULL_MOVE + 2].copy_from_slice(&self.full_move_clock.to_le_bytes());
+        Some(packed)
+    }
+
+    /// Unpacks a position written by [`Game::to_packed`], refusing positions that break the
+    /// [`Ruleset::essential`] laws like [`Game::from_fen`] does
+    pub fn from_packed(packed: &PackedPosition) -> Result<Self, UnpackError> {
+        let mut occupied = [0; 8];
+        occupied.copy_from_slice(&packed[..PIECES_OFFSET]);
+        let occupied = BitBoard::new(u64::from_le_bytes(occupied));
+        if occupied.popcnt() > 32 {
+            return Err(UnpackError::TooManyPieces(occupied.popcnt()));
+        }
+
+        let mut builder = GameBuilder::new();
+        for (i, square) in occupied.enumerate() {
+            let nibble = packed[PIECES_OFFSET + i / 2] >> (i % 2 * 4) & 0b1111;
+            let color = if nibble & BLACK_PIECE == 0 {
+                PieceColor::White
+            } else {
+                PieceColor::Black
+            };
+            let piece = PieceType::from_int(nibble & !BLACK_PIECE)
+                .ok_or(UnpackError::BadPiece { square, nibble })?;
+            builder = builder.piece(square, piece, color);
+        }
+
+        let flags = packed[FLAGS];
+        let turn = if flags & 1 == 0 {
+            PieceColor::White
+        } else {
+            PieceColor::Black
+        };
+        let en_passant = match packed[EN_PASSANT] {
+            NO_EN_PASSANT => None,
+            sq @ 0..64 => Some(Square::new(sq)),
+            byte => return Err(UnpackError::BadEnPassant(byte)),
+        };
+
+        builder
+            .turn(turn)
+            .castling(CastlingRights::from_int(flags >> 4))
+            .en_passant(en_passant)
+            .half_move_timeout(packed[HALF_MOVE])
+            .full_move_clock(u16::from_le_bytes([
+                packed[FULL_MOVE],
+                packed[FULL_MOVE + 1],
+            ]))
+            .build()
+            .map_err(UnpackError::Illegal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        let fens = [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R b Kq - 13 212",
+            "4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 b - - 99 65535",
+        ];
+
+        for fen in fens {
+            let game = Game::from_fen(fen).unwrap();
+            let packed = game.to_packed().unwrap();
+            assert_eq!(packed.len(), PACKED_POSITION_BYTES);
+            assert_eq!(Game::from_packed(&packed).unwrap().to_fen(), fen);
+        }
+    }
+
+    #[test]
+    fn refuses_bad_bytes() {
+        let game = Game::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        let packed = game.to_packed().unwrap();
+
+        let mut bad = packed;
+        bad[PIECES_OFFSET] = 0xd6;
+        assert!(matches!(
+            Game::from_packed(&bad),
+            Err(UnpackError::BadPiece {
+                square: Square::E1,
+                nibble: 6
+            })
+        ));
+
+        let mut bad = packed;
+        bad[EN_PASSANT] = 64;
+        assert!(matches!(
+            Game::from_packed(&bad),
+            Err(UnpackError::BadEnPassant(64))
+        ));
+
+        let mut bad = packed;
+        bad[..PIECES_OFFSET].fill(u8::MAX);
+        assert!(matches!(
+            Game::from_packed(&bad),
+            Err(UnpackError::TooManyPieces(64))
+        ));
+
+        // Both kings are white
+        let mut bad = packed;
+        bad[PIECES_OFFSET] = 0x55;
+        assert!(matches!(
+            Game::from_packed(&bad),
+            Err(UnpackError::Illegal(_))
+        ));
+    }
+}
@@ -3,103 +3,22 @@ use crate::{
     square::Square,
 };
 
-/// Bit-packed pair of squares.
-/// Low nibble (bits 0–3): even square. High nibble (bits 4–7): odd square.
-/// Nibble encoding:
-///   1111 = None
-///   0000 = white pawn   | 0110 = black pawn
-///   1000 = white knight | 1110 = black knight
-///   0100 = white bishop | 0001 = black bishop
-///   1100 = white rook   | 1001 = black rook
-///   0010 = white queen  | 0101 = black queen
-///   1010 = white king   | 1101 = black king
-#[derive(Clone)]
-struct PieceTableEntry(u8);
-
-impl PieceTableEntry {
-    const EMPTY: u8 = 0b1111_1111;
-
-    const fn encode(val: Option<(PieceType, PieceColor)>) -> u8 {
-        match val {
-            None => 0b1111,
-            Some((PieceType::Pawn, PieceColor::White)) => 0b0000,
-            Some((PieceType::Knight, PieceColor::White)) => 0b1000,
-            Some((PieceType::Bishop, PieceColor::White)) => 0b0100,
-            Some((PieceType::Rook, PieceColor::White)) => 0b1100,
-            Some((PieceType::Queen, PieceColor::White)) => 0b0010,
-            Some((PieceType::King, PieceColor::White)) => 0b1010,
-            Some((PieceType::Pawn, PieceColor::Black)) => 0b0110,
-            Some((PieceType::Knight, PieceColor::Black)) => 0b1110,
-            Some((PieceType::Bishop, PieceColor::Black)) => 0b0001,
-            Some((PieceType::Rook, PieceColor::Black)) => 0b1001,
-            Some((PieceType::Queen, PieceColor::Black)) => 0b0101,
-            Some((PieceType::King, PieceColor::Black)) => 0b1101,
-        }
-    }
-
-    const fn decode(nibble: u8) -> Option<(PieceType, PieceColor)> {
-        match nibble {
-            0b1111 => None,
-            0b0000 => Some((PieceType::Pawn, PieceColor::White)),
-            0b1000 => Some((PieceType::Knight, PieceColor::White)),
-            0b0100 => Some((PieceType::Bishop, PieceColor::White)),
-            0b1100 => Some((PieceType::Rook, PieceColor::White)),
-            0b0010 => Some((PieceType::Queen, PieceColor::White)),
-            0b1010 => Some((PieceType::King, PieceColor::White)),
-            0b0110 => Some((PieceType::Pawn, PieceColor::Black)),
-            0b1110 => Some((PieceType::Knight, PieceColor::Black)),
-            0b0001 => Some((PieceType::Bishop, PieceColor::Black)),
-            0b1001 => Some((PieceType::Rook, PieceColor::Black)),
-            0b0101 => Some((PieceType::Queen, PieceColor::Black)),
-            0b1101 => Some((PieceType::King, PieceColor::Black)),
-            _ => None,
-        }
-    }
-
-    const fn first(&self) -> Option<(PieceType, PieceColor)> {
-        Self::decode(self.0 & 0x0F)
-    }
-
-    const fn second(&self) -> Option<(PieceType, PieceColor)> {
-        Self::decode((self.0 >> 4) & 0x0F)
-    }
-
-    const fn set_first(&mut self, val: Option<(PieceType, PieceColor)>) {
-        self.0 = (self.0 & 0xF0) | Self::encode(val);
-    }
-
-    const fn set_second(&mut self, val: Option<(PieceType, PieceColor)>) {
-        self.0 = (self.0 & 0x0F) | (Self::encode(val) << 4);
-    }
-}
-
-#[derive(Clone)]
-pub struct PieceTable([PieceTableEntry; 32]);
+/// A mailbox of what stands on every square, kept up to date by make and unmake so looking up a
+/// piece is a single array read instead of testing every piece bitboard.
+#[derive(Clone, Copy)]
+pub struct PieceTable([Option<(PieceType, PieceColor)>; 64]);
 
 impl PieceTable {
     pub const fn new() -> Self {
-        const EMPTY: PieceTableEntry = PieceTableEntry(PieceTableEntry::EMPTY);
-        PieceTable([EMPTY; 32])
+        PieceTable([None; 64])
     }
 
     pub const fn get(&self, sq: Square) -> Option<(PieceType, PieceColor)> {
-        let index = sq.index();
-        let entry = &self.0[index >> 1];
-        if index & 1 == 0 {
-            entry.first()
-        } else {
-            entry.second()
-        }
+        self.0[sq.index()]
     }
 
     pub const fn set(&mut self, sq: Square, val: Option<(PieceType, PieceColor)>) {
-        let index = sq.index();
-        let entry = &mut self.0[index >> 1];
-        if index & 1 == 0 {
-            entry.set_first(val);
-        } else {
-            entry.set_second(val);
-        }
+        self.0[sq.index()] = val;
     }
 }
 
@@ -108,7 +27,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn roundtrip_all_pieces() {
+    fn every_piece_on_every_square() {
         let cases = [
             Some((PieceType::Pawn, PieceColor::White)),
             Some((PieceType::Knight, PieceColor::White)),
@@ -124,8 +43,14 @@ mod tests {
             Some((PieceType::King, PieceColor::Black)),
             None,
         ];
-        for val in cases {
-            assert_eq!(PieceTableEntry::decode(PieceTableEntry::encode(val)), val);
+        let mut table = PieceTable::new();
+        for i in 0..64 {
+            let sq = Square::new(i);
+            for val in cases {
+                table.set(sq, val);
+                assert_eq!(table.get(sq), val);
+            }
+            assert_eq!(table.get(sq), None);
         }
     }
 
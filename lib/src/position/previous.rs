@@ -1,5 +1,8 @@
 use crate::{
-    file::File, movegen::pieces::piece::PieceColor, position::castling::CastlingRights, rank::Rank,
+    file::File,
+    movegen::pieces::piece::PieceColor,
+    position::{castling::CastlingRights, game::State},
+    rank::Rank,
     square::Square,
 };
 
@@ -10,45 +13,51 @@ pub(crate) struct UnRestoreable {
     pub(crate) castling_rights: CastlingRights,
     pub(crate) en_passant_target: Option<Square>,
     pub(crate) half_move_timeout: u8,
+    pub(crate) state: State,
 }
 
 impl UnRestoreable {
     fn pack(self) -> PackedUnRestoreable {
         // Only the file is stored; rank is inferred from turn in unpack
-        let en_passant_bits: u16 = match self.en_passant_target {
-            Some(sq) => sq.get_file() as u16,
+        let en_passant_bits: u32 = match self.en_passant_target {
+            Some(sq) => sq.get_file() as u32,
             None => PackedUnRestoreable::EN_PASSANT_SENTINEL,
         };
 
         PackedUnRestoreable(
-            (self.castling_rights.to_int() as u16)
+            (self.castling_rights.to_int() as u32)
                 | en_passant_bits << PackedUnRestoreable::EN_PASSANT_OFFSET
-                | (self.half_move_timeout as u16) << PackedUnRestoreable::HALF_MOVE_OFFSET,
+                | (self.half_move_timeout as u32) << PackedUnRestoreable::HALF_MOVE_OFFSET
+                | (self.state as u32) << PackedUnRestoreable::STATE_OFFSET,
         )
     }
 }
 
 // TODO: do we really need self.half_move_timeout?
 /// Bit packed UnRestoreable. Call PackedUnRestoreable::unpack() to get back the UnRestoreable.
-/// Bit layout (16 bits total):
-/// [0..3]  castling_rights   (4 bits)
-/// [4..7]  en_passant_target (4 bits, 0-7 = File, 8 = None)
-/// [8..15] half_move_timeout (8 bits)
+/// Bit layout (19 bits used):
+/// [0..3]   castling_rights   (4 bits)
+/// [4..7]   en_passant_target (4 bits, 0-7 = File, 8 = None)
+/// [8..15]  half_move_timeout (8 bits)
+/// [16..18] state             (3 bits)
 #[derive(Clone, Copy, PartialEq, Debug)]
-struct PackedUnRestoreable(u16);
+struct PackedUnRestoreable(u32);
 
 impl PackedUnRestoreable {
-    const CASTLING_MASK: u16 = 0xF;
-    const EN_PASSANT_OFFSET: u16 = 4;
-    const EN_PASSANT_MASK: u16 = 0xF;
-    const EN_PASSANT_SENTINEL: u16 = 8;
-    const HALF_MOVE_OFFSET: u16 = 8;
-    const UNINITIALIZED: PackedUnRestoreable = PackedUnRestoreable(u16::MAX);
+    const CASTLING_MASK: u32 = 0xF;
+    const EN_PASSANT_OFFSET: u32 = 4;
+    const EN_PASSANT_MASK: u32 = 0xF;
+    const EN_PASSANT_SENTINEL: u32 = 8;
+    const HALF_MOVE_OFFSET: u32 = 8;
+    const HALF_MOVE_MASK: u32 = 0xFF;
+    const STATE_OFFSET: u32 = 16;
+    const STATE_MASK: u32 = 0x7;
 
     fn unpack(self, turn: PieceColor) -> UnRestoreable {
         let castling_rights =
             CastlingRights::from_int((self.0 & PackedUnRestoreable::CASTLING_MASK) as u8);
-        let half_move_timeout = (self.0 >> PackedUnRestoreable::HALF_MOVE_OFFSET) as u8;
+        let half_move_timeout = ((self.0 >> PackedUnRestoreable::HALF_MOVE_OFFSET)
+            & PackedUnRestoreable::HALF_MOVE_MASK) as u8;
 
         let en_passant_bits = (self.0 >> PackedUnRestoreable::EN_PASSANT_OFFSET)
             & PackedUnRestoreable::EN_PASSANT_MASK;
@@ -66,47 +75,48 @@ impl PackedUnRestoreable {
             None
         };
 
+        let state =
+            match (self.0 >> PackedUnRestoreable::STATE_OFFSET) & PackedUnRestoreable::STATE_MASK {
+                1 => State::Checkmate,
+                2 => State::Stalemate,
+                3 => State::Timeout,
+                4 => State::Repetition,
+                5 => State::InsufficientMaterial,
+                _ => State::InProgress,
+            };
+
         UnRestoreable {
             castling_rights,
             half_move_timeout,
             en_passant_target,
+            state,
         }
     }
 }
 
-#[derive(Clone)]
+/// Everything needed to unmake every move played so far, newest last. This grows with the game,
+/// so even very long games can be unmade all the way back to the start.
+#[derive(Clone, Default)]
 pub(crate) struct PositionHistory {
-    history: [PackedUnRestoreable; 256],
-    counter: u8,
-    len: u8,
+    history: Vec<PackedUnRestoreable>,
 }
 
 impl PositionHistory {
     pub(crate) const fn new() -> PositionHistory {
         PositionHistory {
-            history: [PackedUnRestoreable::UNINITIALIZED; 256],
-            counter: 0,
-            len: 0,
+            history: Vec::new(),
         }
     }
 
     /// Packs and stores `unrestoreable` for the given `turn`
     pub(crate) fn push(&mut self, unrestoreable: UnRestoreable) {
-        self.history[self.counter as usize] = unrestoreable.pack();
-        self.counter = self.counter.wrapping_add(1);
-        self.len = self.len.saturating_add(1);
+        self.history.push(unrestoreable.pack());
     }
 
     /// Pops and unpacks the last stored position for the given `turn`.
     /// `turn` must be the active player at the time the position was pushed.
     pub(crate) fn pop(&mut self, turn: PieceColor) -> Option<UnRestoreable> {
-        if self.len == 0 {
-            None
-        } else {
-            self.counter = self.counter.wrapping_sub(1);
-            self.len = self.len.saturating_sub(1);
-            Some(self.history[self.counter as usize].unpack(turn))
-        }
+        self.history.pop().map(|packed| packed.unpack(turn))
     }
 }
 
@@ -127,6 +137,7 @@ mod tests {
                 castling_rights: CastlingRights::from_int(0b1111),
                 half_move_timeout: 0,
                 en_passant_target: None,
+                ..Default::default()
             },
             PieceColor::White,
         );
@@ -139,6 +150,7 @@ mod tests {
                 castling_rights: CastlingRights::from_int(0b1010),
                 half_move_timeout: 10,
                 en_passant_target: Some(Square::make_square(Rank::Third, File::E)),
+                ..Default::default()
             },
             PieceColor::White,
         );
@@ -151,6 +163,7 @@ mod tests {
                 castling_rights: CastlingRights::from_int(0b0101),
                 half_move_timeout: 25,
                 en_passant_target: Some(Square::make_square(Rank::Sixth, File::D)),
+                ..Default::default()
             },
             PieceColor::Black,
         );
@@ -163,6 +176,7 @@ mod tests {
                 castling_rights: CastlingRights::from_int(0b0000),
                 half_move_timeout: 49,
                 en_passant_target: None,
+                ..Default::default()
             },
             PieceColor::Black,
         );
@@ -175,22 +189,52 @@ mod tests {
                 castling_rights: CastlingRights::from_int(0b1111),
                 half_move_timeout: u8::MAX,
                 en_passant_target: None,
+                ..Default::default()
             },
             PieceColor::White,
         );
     }
 
     #[test]
-    fn pop_after_wrap_returns_previous_entry() {
+    fn every_state_roundtrips() {
+        for state in [
+            State::InProgress,
+            State::Checkmate,
+            State::Stalemate,
+            State::Timeout,
+            State::Repetition,
+            State::InsufficientMaterial,
+        ] {
+            roundtrip(
+                UnRestoreable {
+                    castling_rights: CastlingRights::from_int(0b1111),
+                    half_move_timeout: u8::MAX,
+                    en_passant_target: Some(Square::make_square(Rank::Sixth, File::H)),
+                    state,
+                },
+                PieceColor::Black,
+            );
+        }
+    }
+
+    #[test]
+    fn long_histories_pop_in_order() {
         let mut history = PositionHistory::new();
-        for _ in 0..=256 {
-            history.push(UnRestoreable::default());
+        for half_move_timeout in 0..=u8::MAX {
+            for _ in 0..2 {
+                history.push(UnRestoreable {
+                    half_move_timeout,
+                    ..Default::default()
+                });
+            }
         }
 
-        assert!(history.pop(PieceColor::White).is_some());
-        assert!(
-            history.pop(PieceColor::White).is_some(),
-            "second pop after wrap is empty"
-        );
+        for half_move_timeout in (0..=u8::MAX).rev() {
+            for _ in 0..2 {
+                let popped = history.pop(PieceColor::White).unwrap();
+                assert_eq!(popped.half_move_timeout, half_move_timeout);
+            }
+        }
+        assert_eq!(history.pop(PieceColor::White), None);
     }
 }
@@ -3,6 +3,7 @@ use std::str::FromStr;
 
 use crate::bitboard::{BitBoard, EMPTY};
 use crate::file::File;
+use crate::lines;
 use crate::movegen::moves::Move;
 use crate::movegen::pieces::piece::{PieceColor, PieceMoveInfo, PieceType};
 use crate::position::game::Game;
@@ -33,7 +34,7 @@ pub const ALL_DIRECTIONS: [Direction; 8] = [
     Direction::SouthWest,
 ];
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SquareParseError {
     EmptyInput,
     MissingRank,
@@ -70,8 +71,11 @@ impl FromStr for Square {
         let mut chars = s.chars();
         let f = chars.next().ok_or(SquareParseError::EmptyInput)?;
         let r = chars.next().ok_or(SquareParseError::MissingRank)?;
-        let rank =
-            Rank::from_index(r.to_digit(10).ok_or(SquareParseError::InvalidRank(r))? as usize - 1);
+        let rank = r
+            .to_digit(10)
+            .filter(|r| (1..=8).contains(r))
+            .ok_or(SquareParseError::InvalidRank(r))?;
+        let rank = Rank::from_index(rank as usize - 1);
         let file = File::from_char(f).ok_or(SquareParseError::InvalidFile(f))?;
         Ok(Self::make_square(rank, file))
     }
@@ -188,11 +192,51 @@ impl Square {
         unsafe { Square::new_unchecked(((rank.to_int()) << 3) ^ (file.to_int())) }
     }
 
+    /// How many files apart two squares are
+    pub const fn file_distance(self, other: Square) -> u8 {
+        (self.0 & 7).abs_diff(other.0 & 7)
+    }
+
+    /// How many ranks apart two squares are
+    pub const fn rank_distance(self, other: Square) -> u8 {
+        (self.0 >> 3).abs_diff(other.0 >> 3)
+    }
+
+    /// Chebyshev distance, or how many king moves it takes to get from one square to another
+    pub const fn distance(self, other: Square) -> u8 {
+        let files = self.file_distance(other);
+        let ranks = self.rank_distance(other);
+        if files > ranks { files } else { ranks }
+    }
+
+    /// How many orthogonal steps it takes to get from one square to another
+    pub const fn manhattan_distance(self, other: Square) -> u8 {
+        self.file_distance(other) + self.rank_distance(other)
+    }
+
+    /// Checks if two squares are on the same diagonal or anti-diagonal
+    pub const fn same_diagonal(self, other: Square) -> bool {
+        self.file_distance(other) == self.rank_distance(other)
+    }
+
+    /// Checks if two squares share a rank, file or diagonal, so a queen could move between them on
+    /// an empty board. A square shares every line with itself.
+    pub const fn same_line(self, other: Square) -> bool {
+        self.file_distance(other) == 0
+            || self.rank_distance(other) == 0
+            || self.same_diagonal(other)
+    }
+
     /// Flips the side of the square for the opposite color
     pub const fn flip_side(&self) -> Square {
         unsafe { Square::new_unchecked(self.0 ^ 56) }
     }
 
+    /// Mirrors the square from left to right, so A3 becomes H3
+    pub const fn flip_file(&self) -> Square {
+        unsafe { Square::new_unchecked(self.0 ^ 7) }
+    }
+
     /// # Safety
     /// `self.get_file() > File::A && self.get_rank() < Rank::Eighth`
     pub const unsafe fn uleft_unchecked(&self) -> Square {
@@ -395,21 +439,7 @@ impl Square {
 
     /// Finds the straight exclusive path to another square if one exists
     pub fn path_to(self, to: Square) -> BitBoard {
-        let mut path = EMPTY;
-        let Some(direction) = self.direction_to(to) else {
-            return path;
-        };
-
-        let mut current = self;
-        while let Some(forward) = current.walk(&direction)
-            && forward != to
-        {
-            let forwardbb = BitBoard::from_square(forward);
-            path |= forwardbb;
-            current = forward;
-        }
-
-        path
+        lines::between(self, to)
     }
 
     /// Moves one square in a direction. Useful for ray pieces.
@@ -499,13 +529,11 @@ impl Square {
     }
 
     /// Generates a ray of squares until either the end of the board, right before a friendly piece,
-    /// or it ends right on an enemy piece. Used for ray pieces in move generation.
-    pub fn ray(&self, direction: &Direction, game: &Game) -> PieceMoveInfo {
+    /// or it ends right on an enemy piece. Used for ray pieces in move generation. `color` is the
+    /// side the ray piece belongs to, which does not have to be the side to move.
+    pub fn ray(&self, direction: &Direction, game: &Game, color: PieceColor) -> PieceMoveInfo {
         let mut moveinfo = PieceMoveInfo::default();
-        let selfbb = BitBoard::from_square(*self);
-
-        // Maybe I should take in color as a parameter?
-        let enemy = game.determine_color(selfbb).unwrap_or(game.turn).opponent();
+        let enemy = color.opponent();
 
         let mut current = *self;
         let mut second_blocker = false;
@@ -589,12 +617,12 @@ impl Square {
         }
     }
 
-    /// Generates moveinfo for ray pieces
-    pub fn rays(&self, directions: &[Direction], game: &Game) -> PieceMoveInfo {
+    /// Generates moveinfo for ray pieces of `color`
+    pub fn rays(&self, directions: &[Direction], game: &Game, color: PieceColor) -> PieceMoveInfo {
         let mut moveinfo = PieceMoveInfo::default();
 
         for direction in directions {
-            let raymoveinfo = self.ray(direction, game);
+            let raymoveinfo = self.ray(direction, game, color);
 
             moveinfo.targets |= raymoveinfo.targets;
             moveinfo.attacks |= raymoveinfo.attacks;
@@ -625,6 +653,35 @@ mod tests {
         assert!(!empty.in_bitboard(occupied));
     }
 
+    #[test]
+    fn distances() {
+        assert_eq!(Square::A1.distance(Square::H8), 7);
+        assert_eq!(Square::A1.manhattan_distance(Square::H8), 14);
+        assert_eq!(Square::E4.distance(Square::G5), 2);
+        assert_eq!(Square::E4.manhattan_distance(Square::G5), 3);
+        assert_eq!(Square::E4.file_distance(Square::B7), 3);
+        assert_eq!(Square::E4.rank_distance(Square::B7), 3);
+        assert_eq!(Square::C3.distance(Square::C3), 0);
+    }
+
+    #[test]
+    fn lines_through_squares() {
+        assert!(Square::A1.same_diagonal(Square::H8));
+        assert!(Square::B6.same_diagonal(Square::E3));
+        assert!(!Square::B6.same_diagonal(Square::E4));
+
+        assert!(Square::B6.same_line(Square::B1));
+        assert!(Square::B6.same_line(Square::H6));
+        assert!(Square::B6.same_line(Square::D8));
+        assert!(!Square::B6.same_line(Square::C8));
+
+        for a in Square::ALL_SQUARES {
+            for b in Square::ALL_SQUARES {
+                assert_eq!(a.same_line(b), a == b || lines::line(a, b) != EMPTY);
+            }
+        }
+    }
+
     #[test]
     fn uright_equals_up_right() {
         let sq = Square::E4;
@@ -657,6 +714,15 @@ mod tests {
         assert_eq!(Square::H3, Square::H3.flip_side().flip_side())
     }
 
+    #[test]
+    fn flip_file() {
+        assert_eq!(Square::H1, Square::A1.flip_file());
+        assert_eq!(Square::D5, Square::E5.flip_file());
+        assert_eq!(Square::B7, Square::G7.flip_file());
+        assert_eq!(Square::C3, Square::C3.flip_file().flip_file());
+        assert_eq!(Square::F6, Square::C3.flip_side().flip_file());
+    }
+
     #[test]
     fn ray() {
         let fen = "r1bq1r1k/1p4pp/1pnp4/2p1pNb1/2B1P3/P1PP4/1P3PPP/R1BQ1RK1 b - - 0 14";
@@ -672,10 +738,28 @@ mod tests {
         expected.attacks.set(Square::F6);
         expected.attacks.set(Square::F5);
 
-        let actual = rook.ray(&direction, &game);
+        let actual = rook.ray(&direction, &game, PieceColor::Black);
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn ray_for_the_side_not_to_move() {
+        // The same rook, seen with white to move instead
+        let fen = "r1bq1r1k/1p4pp/1pnp4/2p1pNb1/2B1P3/P1PP4/1P3PPP/R1BQ1RK1 w - - 0 14";
+        let game = Game::from_fen(fen).unwrap();
+
+        let mut expected = PieceMoveInfo::default();
+        for sq in [Square::F7, Square::F6, Square::F5] {
+            expected.attacks.set(sq);
+        }
+        expected.targets = expected.attacks;
+
+        assert_eq!(
+            Square::F8.ray(&Direction::South, &game, PieceColor::Black),
+            expected
+        );
+    }
+
     #[test]
     fn get_rank_file() {
         for rank in ALL_RANKS {
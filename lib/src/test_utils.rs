@@ -3,10 +3,11 @@ use std::fmt::Display;
 
 use crate::movegen::moves::Move;
 use crate::position::game::Game;
+use crate::square::Square;
 
 /// Formats the items in the vector neatly with their native display methods
 #[track_caller]
-pub fn format_pretty_list<T: Display>(v: &Vec<T>) -> String {
+pub fn format_pretty_list<T: Display>(v: &[T]) -> String {
     let mut lines = Vec::new();
     let title = type_name::<T>().to_owned();
     let start = " list [".to_owned();
@@ -53,6 +54,26 @@ macro_rules! assert_push {
 pub fn compare_games(before: &Game, after: &Game) {
     let mut differences = Vec::new();
 
+    assert_push!(differences, before, after, white_pawns);
+    assert_push!(differences, before, after, white_knights);
+    assert_push!(differences, before, after, white_bishops);
+    assert_push!(differences, before, after, white_rooks);
+    assert_push!(differences, before, after, white_queens);
+    assert_push!(differences, before, after, white_kings);
+    assert_push!(differences, before, after, black_pawns);
+    assert_push!(differences, before, after, black_knights);
+    assert_push!(differences, before, after, black_bishops);
+    assert_push!(differences, before, after, black_rooks);
+    assert_push!(differences, before, after, black_queens);
+    assert_push!(differences, before, after, black_kings);
+
+    assert_push!(differences, before, after, castling_rights, "{:?}");
+    assert_push!(differences, before, after, en_passant_target, "{:?}");
+    assert_push!(differences, before, after, turn, "{:?}");
+    assert_push!(differences, before, after, half_move_timeout);
+    assert_push!(differences, before, after, full_move_clock);
+    assert_push!(differences, before, after, state, "{:?}");
+
     assert_push!(differences, before, after, white_occupied);
     assert_push!(differences, before, after, black_occupied);
     assert_push!(differences, before, after, occupied);
@@ -62,7 +83,19 @@ pub fn compare_games(before: &Game, after: &Game) {
     assert_push!(differences, before, after, white_check_rays);
     assert_push!(differences, before, after, black_check_rays);
 
-    assert_push!(differences, before, after, seen_positions, "{:?}");
+    assert_push!(differences, before, after, key_history, "{:?}");
+    assert_push!(differences, before, after, move_history, "{:?}");
+    assert_push!(differences, before, after, hash, "{:#018x}");
+    assert_push!(differences, before, after, material, "{:?}");
+
+    for sq in Square::ALL_SQUARES {
+        let (expected, found) = (before.piece_lookup(sq), after.piece_lookup(sq));
+        if expected != found {
+            differences.push(format!(
+                "piece_table has changed at {sq}:\nExpected:\n{expected:?}\nFound:\n{found:?}\n"
+            ));
+        }
+    }
 
     if !differences.is_empty() {
         panic!(
@@ -73,6 +106,17 @@ pub fn compare_games(before: &Game, after: &Game) {
     }
 }
 
+/// Checks that the boards kept up to date by make and unmake, such as the attacks, check rays,
+/// occupancy, piece table and hash, match recalculating them from the pieces alone
+#[track_caller]
+pub fn assert_caches_are_fresh(game: &Game) {
+    let mut fresh = game.clone();
+    fresh.initialize();
+    // The state is part of the game's history rather than a cache
+    fresh.state = game.state;
+    compare_games(&fresh, game);
+}
+
 /// Compares and actual board to one generated from a fen
 #[track_caller]
 pub fn compare_to_fen(game: &Game, fen: &str) {
@@ -82,7 +126,7 @@ pub fn compare_to_fen(game: &Game, fen: &str) {
 
 /// Asserts that moves contains m
 #[track_caller]
-pub fn should_generate(moves: &Vec<Move>, m: &Move) {
+pub fn should_generate(moves: &[Move], m: &Move) {
     assert!(
         moves.contains(m),
         "The valid move {} was not generated! Available {}",
@@ -93,7 +137,7 @@ pub fn should_generate(moves: &Vec<Move>, m: &Move) {
 
 /// Asserts that moves doesn't contain m
 #[track_caller]
-pub fn shouldnt_generate(moves: &Vec<Move>, m: &Move) {
+pub fn shouldnt_generate(moves: &[Move], m: &Move) {
     assert!(
         !moves.contains(m),
         "The invalid move {} was generated! Available {}",
@@ -104,10 +148,12 @@ pub fn shouldnt_generate(moves: &Vec<Move>, m: &Move) {
 
 /// Assert that the two move lists are equal. The move lists do not need to be sorted
 #[track_caller]
-pub fn assert_meq(mut actual: Vec<Move>, mut expected: Vec<Move>) {
-    let key = |m: &Move| unsafe { std::mem::transmute::<Move, u32>(*m) };
-    actual.sort_by_key(key);
-    expected.sort_by_key(key);
+pub fn assert_meq(actual: impl Into<Vec<Move>>, expected: impl Into<Vec<Move>>) {
+    let (mut actual, mut expected) = (actual.into(), expected.into());
+    // Padding bytes of a move are not guaranteed to match, so moves are sorted by what they show
+    let key = |m: &Move| m.to_string();
+    actual.sort_by_cached_key(key);
+    expected.sort_by_cached_key(key);
     assert_eq!(
         actual,
         expected,
@@ -0,0 +1,198 @@
+use crate::{
+    file::File,
+    movegen::pieces::piece::{PieceColor, PieceType},
+    position::{castling::CastlingRights, game::Game},
+    square::Square,
+};
+
+/// Random keys used to build the Zobrist hash of a position
+pub struct ZobristKeys {
+    pieces: [[[u64; 64]; 6]; 2],
+    black_to_move: u64,
+    castling: [u64; 16],
+    en_passant: [u64; 8],
+}
+
+/// Seed for the key generator. Changing this changes every hash.
+const SEED: u64 = 0x5748_414C_4543_5241;
+
+/// A SplitMix64 step. Returns the next state and the generated value.
+const fn splitmix64(state: u64) -> (u64, u64) {
+    let state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    (state, z ^ (z >> 31))
+}
+
+pub static KEYS: ZobristKeys = {
+    let mut state = SEED;
+    let mut key;
+
+    let mut pieces = [[[0; 64]; 6]; 2];
+    let mut color = 0;
+    while color < 2 {
+        let mut piece = 0;
+        while piece < 6 {
+            let mut sq = 0;
+            while sq < 64 {
+                (state, key) = splitmix64(state);
+                pieces[color][piece][sq] = key;
+                sq += 1;
+            }
+            piece += 1;
+        }
+        color += 1;
+    }
+
+    (state, key) = splitmix64(state);
+    let black_to_move = key;
+
+    // Each castling right gets a key, and every combination is the xor of its rights
+    let mut rights = [0; 4];
+    let mut n = 0;
+    while n < 4 {
+        (state, key) = splitmix64(state);
+        rights[n] = key;
+        n += 1;
+    }
+
+    let mut castling = [0; 16];
+    let mut combination = 0;
+    while combination < 16 {
+        let mut bit = 0;
+        while bit < 4 {
+            if combination & (1 << bit) != 0 {
+                castling[combination] ^= rights[bit];
+            }
+            bit += 1;
+        }
+        combination += 1;
+    }
+
+    let mut en_passant = [0; 8];
+    let mut file = 0;
+    while file < 8 {
+        (state, key) = splitmix64(state);
+        en_passant[file] = key;
+        file += 1;
+    }
+
+    ZobristKeys {
+        pieces,
+        black_to_move,
+        castling,
+        en_passant,
+    }
+};
+
+impl ZobristKeys {
+    /// Key for a piece standing on a square
+    pub fn piece(&self, piece: PieceType, color: PieceColor, sq: Square) -> u64 {
+        self.pieces[color.to_int() as usize][piece.to_int() as usize][sq.index()]
+    }
+
+    /// Key xored in when it is black's turn
+    pub fn black_to_move(&self) -> u64 {
+        self.black_to_move
+    }
+
+    /// Key for a set of castling rights
+    pub fn castling(&self, rights: CastlingRights) -> u64 {
+        self.castling[rights.to_int() as usize]
+    }
+
+    /// Key for the file of an en passant target
+    pub fn en_passant(&self, file: File) -> u64 {
+        self.en_passant[file.index()]
+    }
+}
+
+/// Computes the Zobrist hash of a position from scratch
+pub fn hash(game: &Game) -> u64 {
+    let mut hash = 0;
+
+    for sq in game.occupied {
+        let (piece, color) = game
+            .piece_lookup(sq)
+            .expect("The occupied bitboard and the piece table disagree");
+        hash ^= KEYS.piece(piece, color, sq);
+    }
+
+    if game.turn == PieceColor::Black {
+        hash ^= KEYS.black_to_move();
+    }
+
+    hash ^= KEYS.castling(game.castling_rights);
+
+    if let Some(target) = game.en_passant_target {
+        hash ^= KEYS.en_passant(target.get_file());
+    }
+
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::{movegen::moves::Move, position::game::STARTING_FEN};
+
+    #[test]
+    fn piece_keys_are_unique() {
+        let mut seen = HashSet::new();
+        for color in KEYS.pieces {
+            for piece in color {
+                for key in piece {
+                    assert!(seen.insert(key), "Duplicate zobrist key {:#018x}", key);
+                }
+            }
+        }
+        assert!(seen.insert(KEYS.black_to_move));
+        for key in KEYS.en_passant {
+            assert!(seen.insert(key));
+        }
+    }
+
+    #[test]
+    fn empty_castling_rights_have_no_key() {
+        assert_eq!(KEYS.castling[0], 0);
+        assert_eq!(
+            KEYS.castling[15],
+            KEYS.castling[1] ^ KEYS.castling[2] ^ KEYS.castling[4] ^ KEYS.castling[8]
+        );
+    }
+
+    #[test]
+    fn hash_depends_on_turn() {
+        let white = Game::from_fen(STARTING_FEN).unwrap();
+        let black =
+            Game::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 0 1").unwrap();
+        assert_eq!(white.hash ^ black.hash, KEYS.black_to_move());
+    }
+
+    #[test]
+    fn transpositions_hash_the_same() {
+        let mut game = Game::default();
+        for (from, to) in [
+            (Square::G1, Square::F3),
+            (Square::G8, Square::F6),
+            (Square::B1, Square::C3),
+        ] {
+            game.play(&Move::infer(from, to, &game));
+        }
+
+        let mut transposed = Game::default();
+        for (from, to) in [
+            (Square::B1, Square::C3),
+            (Square::G8, Square::F6),
+            (Square::G1, Square::F3),
+        ] {
+            transposed.play(&Move::infer(from, to, &transposed));
+        }
+
+        assert_eq!(game.hash, transposed.hash);
+        assert_eq!(game.hash, hash(&game));
+    }
+}
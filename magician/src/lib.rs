@@ -13,16 +13,13 @@ pub fn generate_blockers_and_attackers<const N: usize>(
 ) -> ([(u64, u64); N], usize) {
     let mut baa = [(0, 0); N];
 
-    let mut subset = mask;
-    for i in 0..N {
-        baa[i] = (subset, attacks_fn(BitBoard::new(subset)).to_int());
-        if subset == 0 {
-            return (baa, i + 1);
-        }
-        subset = (subset - 1) & mask;
+    let mut count = 0;
+    for (entry, subset) in baa.iter_mut().zip(BitBoard::new(mask).subsets()) {
+        *entry = (subset.to_int(), attacks_fn(subset).to_int());
+        count += 1;
     }
 
-    unreachable!()
+    (baa, count)
 }
 
 pub fn next_magic<R: BitRange>(grng: &mut GameGenerator, range: R) -> u64 {
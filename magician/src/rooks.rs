@@ -88,44 +88,23 @@ impl MagicRook {
         BitBoard::new(self.attacks[key])
     }
 
+    /// Writes the magic number, the attack table is rebuilt from it by the build script of the
+    /// magics crate
     pub fn embed(&self, source: &mut String) {
-        source.push_str("MagicRook{attacks:[");
-        for pattern in self.attacks {
-            source.push_str(pattern.to_string().as_str());
-            source.push(',');
-        }
-        let _ = source.pop();
-
-        source.push_str("],mask:");
-        source.push_str(self.mask.to_string().as_str());
-        source.push_str(",magic:");
+        source.push_str("    ");
         source.push_str(self.magic.to_string().as_str());
-        source.push('}');
+        source.push(',');
     }
 }
 
+/// The source of `magics/src/rook_magics.rs`
 pub fn embedded_magic_rook_file(rooks: &MagicRooks) -> String {
-    let mut source = String::with_capacity(std::mem::size_of::<MagicRooks>().saturating_mul(4));
-    let types = format! {
-"pub const NUM_BITS: u8 = {};
-pub const NUM_SUBSETS: usize = 1 << NUM_BITS as usize;
-pub const SHIFT: usize = 64 - NUM_BITS as usize;
-
-pub type MagicRooks = [MagicRook; 64];
-
-pub struct MagicRook {{
-    pub attacks: [u64; NUM_SUBSETS],
-    pub mask: u64,
-    pub magic: u64,
-}}
-
-", MagicRook::NUM_BITS
-    };
-
-    source.push_str(types.as_str());
-    source.push_str("pub static ROOKS: MagicRooks = [");
+    let mut source = format!(
+        "// Generated by magician, see `magician --help`\n\npub const NUM_BITS: u8 = {};\n\npub const MAGICS: [u64; 64] = [\n",
+        MagicRook::NUM_BITS
+    );
     embed_magic_rooks(&mut source, rooks);
-    source.push_str("];");
+    source.push_str("];\n");
 
     source
 }
@@ -133,9 +112,8 @@ pub struct MagicRook {{
 pub fn embed_magic_rooks(source: &mut String, rooks: &MagicRooks) {
     for rook in rooks.iter() {
         rook.embed(source);
-        source.push(',')
+        source.push('\n');
     }
-    let _ = source.pop();
 }
 
 #[cfg(test)]
@@ -0,0 +1,109 @@
+//! Builds the magic attack tables from the magic numbers found by magician, so only the numbers
+//! need to be checked in and the tables can never disagree with them.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+#[path = "src/bishop_magics.rs"]
+mod bishop_magics;
+#[path = "src/rook_magics.rs"]
+mod rook_magics;
+
+const ROOK_DIRECTIONS: [(i8, i8); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const BISHOP_DIRECTIONS: [(i8, i8); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+/// Walks from `sq` in every direction until the edge of the board or a blocker, which is
+/// included. With `masked`, the last square before the edge is left out instead, since whether it
+/// is occupied never changes the attacks.
+fn slide(sq: usize, directions: &[(i8, i8); 4], blockers: u64, masked: bool) -> u64 {
+    let (rank, file) = ((sq / 8) as i8, (sq % 8) as i8);
+    let mut attacks = 0;
+
+    for (dr, df) in directions {
+        let (mut r, mut f) = (rank + dr, file + df);
+        while (0..8).contains(&r) && (0..8).contains(&f) {
+            if masked && !((0..8).contains(&(r + dr)) && (0..8).contains(&(f + df))) {
+                break;
+            }
+
+            let bb = 1 << (r * 8 + f);
+            attacks |= bb;
+            if blockers & bb != 0 {
+                break;
+            }
+            r += dr;
+            f += df;
+        }
+    }
+
+    attacks
+}
+
+/// Writes the static table of every square's attacks, mask and magic number
+fn embed(
+    name: &str,
+    ty: &str,
+    directions: &[(i8, i8); 4],
+    magics: &[u64; 64],
+    num_bits: u8,
+) -> String {
+    let shift = 64 - num_bits as u32;
+    let mut source = format!("pub static {name}: {ty}s = [");
+
+    for (sq, &magic) in magics.iter().enumerate() {
+        let mask = slide(sq, directions, 0, true);
+        let mut attacks = vec![0u64; 1 << num_bits];
+
+        // Visits every subset of the mask, starting and ending with the empty one
+        let mut blockers = 0u64;
+        loop {
+            let index = (blockers.wrapping_mul(magic) >> shift) as usize;
+            let expected = slide(sq, directions, blockers, false);
+            assert!(
+                attacks[index] == 0 || attacks[index] == expected,
+                "The magic number {magic} of {ty} {sq} does not work, generate a new one with magician"
+            );
+            attacks[index] = expected;
+
+            blockers = blockers.wrapping_sub(mask) & mask;
+            if blockers == 0 {
+                break;
+            }
+        }
+
+        write!(source, "{ty}{{attacks:[").unwrap();
+        for pattern in attacks {
+            write!(source, "{pattern},").unwrap();
+        }
+        write!(source, "],mask:{mask},magic:{magic}}},").unwrap();
+    }
+
+    source.push_str("];\n");
+    source
+}
+
+fn main() {
+    println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-changed=src/bishop_magics.rs");
+    println!("cargo::rerun-if-changed=src/rook_magics.rs");
+
+    let out_dir = env::var_os("OUT_DIR").expect("Cargo always sets OUT_DIR for build scripts");
+    let out_dir = Path::new(&out_dir);
+
+    let rooks = embed(
+        "ROOKS",
+        "MagicRook",
+        &ROOK_DIRECTIONS,
+        &rook_magics::MAGICS,
+        rook_magics::NUM_BITS,
+    );
+    fs::write(out_dir.join("rooks.rs"), rooks).expect("Failed to write the magic rooks");
+
+    let bishops = embed(
+        "BISHOPS",
+        "MagicBishop",
+        &BISHOP_DIRECTIONS,
+        &bishop_magics::MAGICS,
+        bishop_magics::NUM_BITS,
+    );
+    fs::write(out_dir.join("bishops.rs"), bishops).expect("Failed to write the magic bishops");
+}
@@ -0,0 +1,70 @@
+// Generated by magician, see `magician --help`
+
+pub const NUM_BITS: u8 = 9;
+
+pub const MAGICS: [u64; 64] = [
+    4679523702450178,
+    290519576675942432,
+    635586576777296,
+    9227928489278046208,
+    2261214449180992,
+    864699933148578016,
+    1153625271543300096,
+    2401359173274624,
+    72077395449888784,
+    1153345916364656657,
+    2612088892011069504,
+    92367779318988800,
+    144124058124943392,
+    6345571944760344960,
+    10376293576900872256,
+    9223389647428780291,
+    140742933611073,
+    1154328965928716544,
+    40568139365089312,
+    633353074664064,
+    4611897408198017152,
+    288388706031896832,
+    2305983747785296001,
+    10412331134607313152,
+    13511073783097856,
+    2306143193118294080,
+    855557552491520,
+    2309229505583120448,
+    9241676724167589888,
+    76632730917408769,
+    55239498558089216,
+    72691189777697856,
+    5764889281747290128,
+    53345642102848,
+    576568505520890884,
+    2882875541925530112,
+    15833243392934144,
+    2307540659730513986,
+    9042694206703616,
+    9016064276988420,
+    182422178023605248,
+    17798487084065,
+    288380201857976456,
+    4683814015710791680,
+    578748879017615488,
+    142959198602242,
+    52779007610894,
+    9579091330597280,
+    72626045894811648,
+    2306160364483510336,
+    576464138886185040,
+    36169554405138688,
+    2278190252838928,
+    615738324879360,
+    7066148094521378304,
+    1127343154267201,
+    1161728528228480,
+    18324928656,
+    6070887499314562048,
+    1130299036077568,
+    9077602425873152,
+    720580368499998736,
+    18142519362560,
+    594480717392527368,
+];
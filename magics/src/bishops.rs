@@ -1,4 +1,5 @@
-pub const NUM_BITS: u8 = 9;
+pub use crate::bishop_magics::{MAGICS, NUM_BITS};
+
 pub const NUM_SUBSETS: usize = 1 << NUM_BITS as usize;
 pub const SHIFT: usize = 64 - NUM_BITS as usize;
 
@@ -10,4 +11,5 @@ pub struct MagicBishop {
     pub magic: u64,
 }
 
-pub static BISHOPS: MagicBishops = [MagicBishop{attacks:[9241421688590303744,18049651735527936,0,0,0,0,0,0,134480384,134480384,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,35253226045952,35253226045952,0,0,0,0,0,0,134480384,134480384,0,0,0,0,0,0,0,0,0,0,0,0,0,0,512,512,0,0,0,0,0,0,512,512,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,512,512,0,0,0,0,0,0,512,512,0,0,0,0,0,0,0,0,0,0,68853957120,68853957120,0,0,0,0,0,0,134480384,134480384,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,68853957120,68853957120,0,0,0,0,0,0,134480384,134480384,0,0,0,0,0,0,0,0,0,0,0,0,0,0,512,512,0,0,0,0,0,0,512,512,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,512,512,0,0,0,0,0,0,512,512,0,0,0,0,0,0,0,0,0,0,262656,262656,0,0,0,0,0,0,262656,262656,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,262656,262656,0,0,0,0,0,0,262656,262656,0,0,0,0,0,0,0,0,0,0,0,0,0,0,512,512,0,0,0,0,0,0,512,512,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,512,512,0,0,0,0,0,0,512,512,0,0,0,0,0,0,0,0,0,0,262656,262656,0,0,0,0,0,0,262656,262656,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,262656,262656,0,0,0,0,0,0,262656,262656,0,0,0,0,0,0,0,0,0,0,0,0,0,0,512,512,0,0,0,0,0,0,512,512,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,512,512,0,0,0,0,0,0,512,512,0,0,0,0,0,0,0,0,0,0],mask:18049651735527936,magic:4679523702450178},MagicBishop{attacks:[36099303471056128,0,0,0,137707914496,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,525568,0,0,0,525568,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1280,0,0,0,1280,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1280,0,0,0,1280,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,70506452092160,268961024,0,0,137707914496,268961024,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,525568,525568,0,0,525568,525568,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1280,1280,0,0,1280,1280,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1280,1280,0,0,1280,1280,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,268961024,0,0,0,268961024,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,525568,0,0,0,525568,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1280,0,0,0,1280,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1280,0,0,0,1280,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:70506452091904,magic:290519576675942432},MagicBishop{attacks:[141012904249856,0,537987584,0,0,0,0,0,0,141012904184320,0,537922048,0,0,0,0,0,275415894528,0,537987584,0,0,0,0,0,0,275415828992,0,537922048,0,0,0,0,0,0,0,68096,0,68096,0,0,0,0,0,0,2560,0,2560,0,0,0,0,0,68096,0,68096,0,0,0,0,0,0,2560,0,2560,0,1116672,0,1116672,0,0,0,0,0,0,1051136,0,1051136,0,0,0,0,0,1116672,0,1116672,0,0,0,0,0,0,1051136,0,1051136,0,0,0,0,0,0,0,68096,0,68096,0,0,0,0,0,0,2560,0,2560,0,0,0,0,0,68096,0,68096,0,0,0,0,0,0,2560,0,2560,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:275415828992,magic:635586576777296},MagicBishop{attacks:[550848566272,0,0,0,19010560,0,16913408,0,0,0,16913408,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1075844096,0,0,0,0,2102272,5120,0,0,0,0,5120,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,550831657984,0,0,0,2102272,0,5120,0,0,0,5120,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,550831789056,0,0,0,2233344,0,136192,0,0,0,136192,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1092752384,0,0,0,19010560,0,16913408,0,0,0,16913408,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,550831657984,0,0,0,0,2102272,5120,0,0,0,0,5120,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1075844096,0,0,0,0,2102272,5120,0,0,0,0,5120,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1075975168,0,0,0,2233344,0,136192,0,0,0,136192,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:1075975168,magic:9227928489278046208},MagicBishop{attacks:[6480472064,0,4328794112,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2185504768,0,33826816,0,2151950336,0,272384,0,4332988416,0,4328794112,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2151688192,0,10240,0,2151950336,0,272384,0,38021120,0,33826816,0,4466688,0,272384,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2151688192,0,10240,0,0,2151688192,0,10240,4204544,0,10240,0,4466688,0,272384,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2151688192,0,10240,4204544,0,10240,0,0,4204544,0,10240,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4204544,0,10240,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:38021120,magic:2261214449180992},MagicBishop{attacks:[1108177604608,8409088,8665976832,8409088,1108169216000,20480,8657588224,20480,0,0,0,0,0,0,0,0,76042240,8409088,76042240,8409088,67653632,20480,67653632,20480,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,8933376,8409088,8933376,8409088,544768,20480,544768,20480,0,0,0,0,0,0,0,0,8933376,8409088,8933376,8409088,544768,20480,544768,20480,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:8657588224,magic:864699933148578016},MagicBishop{attacks:[283691315142656,0,1089536,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,17315176448,0,1089536,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,135307264,0,1089536,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,135307264,0,1089536,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,40960,0,40960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,40960,0,40960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,40960,0,40960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,40960,0,40960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2216338432000,0,1089536,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,17315176448,0,1089536,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,135307264,0,1089536,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,135307264,0,1089536,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,40960,0,40960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,40960,0,40960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,40960,0,40960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,40960,0,40960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:2216338399232,magic:1153625271543300096},MagicBishop{attacks:[72624976668147712,2113536,4432676798464,2113536,0,0,0,0,34630287360,2113536,34630287360,2113536,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,16384,16384,16384,16384,0,0,0,0,16384,16384,16384,16384,0,0,0,0,16384,16384,16384,16384,0,0,0,0,16384,16384,16384,16384,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,270548992,2113536,270548992,2113536,0,0,0,0,270548992,2113536,270548992,2113536,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,16384,16384,16384,16384,0,0,0,0,16384,16384,16384,16384,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,567382630219776,2113536,4432676798464,2113536,0,0,0,0,34630287360,2113536,34630287360,2113536,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,16384,16384,16384,16384,0,0,0,0,16384,16384,16384,16384,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,270548992,2113536,270548992,2113536,0,0,0,0,270548992,2113536,270548992,2113536,0,0,0,0],mask:567382630219776,magic:2401359173274624},MagicBishop{attacks:[4620710844295151618,0,34426978306,0,9024825867763714,0,34426978306,0,17626613022722,0,34426978306,0,17626613022722,0,34426978306,0,0,0,0,67239938,0,67239938,0,67239938,0,67239938,0,67239938,0,67239938,0,67239938,0,67239938,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,131074,0,131074,0,131074,0,131074,0,131074,0,131074,0,131074,0,131074,0,0,0,0,131074,0,131074,0,131074,0,131074,0,131074,0,131074,0,131074,0,131074,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:9024825867763712,magic:72077395449888784},MagicBishop{attacks:[9241421688590368773,134545413,68854022149,134545413,35253226110981,134545413,68854022149,134545413,18049651735592965,134545413,68854022149,134545413,35253226110981,134545413,68854022149,134545413,327685,327685,327685,327685,327685,327685,327685,327685,327685,327685,327685,327685,327685,327685,327685,327685,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:18049651735527424,magic:1153345916364656657},MagicBishop{attacks:[36099303487963146,0,0,0,36099303471185930,0,0,0,0,0,0,0,0,0,0,0,17432586,0,0,0,655370,0,0,0,0,0,0,0,0,0,0,0,70506468999178,0,0,0,70506452221962,0,0,0,0,0,0,0,0,0,0,0,17432586,0,0,0,655370,0,0,0,0,0,0,0,0,0,0,0,285868042,0,0,0,269090826,0,0,0,0,0,0,0,0,0,0,0,17432586,0,0,0,655370,0,0,0,0,0,0,0,0,0,0,0,285868042,0,0,0,269090826,0,0,0,0,0,0,0,0,0,0,0,17432586,0,0,0,655370,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,137724821514,0,0,0,137708044298,0,0,0,0,0,0,0,0,0,0,0,17432586,0,0,0,655370,0,0,0,0,0,0,0,0,0,0,0,137724821514,0,0,0,137708044298,0,0,0,0,0,0,0,0,0,0,0,17432586,0,0,0,655370,0,0,0,0,0,0,0,0,0,0,0,285868042,0,0,0,269090826,0,0,0,0,0,0,0,0,0,0,0,17432586,0,0,0,655370,0,0,0,0,0,0,0,0,0,0,0,285868042,0,0,0,269090826,0,0,0,0,0,0,0,0,0,0,0,17432586,0,0,0,655370,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:70506452221952,magic:2612088892011069504},MagicBishop{attacks:[141017232965652,0,0,0,0,0,141012937998356,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1310740,0,0,0,0,0,0,1310740,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1310740,0,0,0,0,0,1310740,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4866703380,0,0,0,0,0,571736084,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4329832468,0,0,0,0,0,34865172,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1310740,0,0,0,0,0,0,1310740,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,275416088596,0,0,0,0,0,275416088596,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4329832468,0,0,0,0,0,34865172,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4329832468,0,0,0,0,0,34865172,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,538181652,0,0,0,0,0,538181652,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,141012904443924,0,0,0,0,0,141012904443924,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4329832468,0,0,0,0,0,34865172,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,279744610324,0,0,0,0,0,275449643028,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,538181652,0,0,0,0,0,538181652,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1310740,0,0,0,0,0,1310740,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4866703380,0,0,0,0,0,571736084,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:275449643008,magic:92367779318988800},MagicBishop{attacks:[1659000848424,2621480,0,0,1108171292712,0,1143472168,0,0,0,69730344,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,550832177192,0,0,0,2621480,0,1076363304,0,550899286056,0,2621480,0,0,69730344,0,0,0,0,0,0,0,0,0,0,0,0,0,1109245034536,0,0,0,1108171292712,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,559489220648,0,0,0,8659664936,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,550832177192,0,0,0,0,2621480,0,0,0,0,0,0,0,0,0,0,0,0,0,1076363304,0,0,0,2621480,0,0,0,1143472168,0,0,0,0,69730344,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,550832177192,0,0,0,2621480,0,0,0,0,550899286056,0,0,0,69730344,0,0,0,0,0,0,0,0,0,0,0,0,0,9733406760,0,0,0,8659664936,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1076363304,0,0,0,0,2621480,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,550832177192,0,0,0,2621480,0,0,0,0,0,0,0,0,0,0,0,0,0,1076363304,0,0],mask:9733406720,magic:144124058124943392},MagicBishop{attacks:[283693466779728,19466813520,2152726608,2152726608,0,0,0,0,283691319296080,17319329872,5242960,5242960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2218490069072,19466813520,2152726608,2152726608,0,0,0,0,2216342585424,17319329872,5242960,5242960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2286944336,2286944336,2152726608,2152726608,0,0,0,0,139460688,139460688,5242960,5242960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2286944336,2286944336,2152726608,2152726608,0,0,0,0,139460688,139460688,5242960,5242960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:2216342585344,magic:6345571944760344960},MagicBishop{attacks:[72624976676520096,0,10485920,0,0,34638659744,0,10485920,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,567382638592160,0,10485920,0,0,34638659744,0,10485920,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4432685170848,0,10485920,0,0,34638659744,0,10485920,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4432685170848,0,10485920,0,0,34638659744,0,10485920,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,278921376,0,10485920,0,0,278921376,0,10485920,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,278921376,0,10485920,0,0,278921376,0,10485920,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,278921376,0,10485920,0,0,278921376,0,10485920,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,278921376,0,10485920,0,0,278921376,0,10485920,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:567382630203392,magic:10376293576900872256},MagicBishop{attacks:[145249953336262720,0,4194368,0,0,0,0,0,1134765260406848,0,4194368,0,0,0,0,0,0,0,541065280,0,4194368,0,0,0,0,0,541065280,0,4194368,0,0,0,8865353564224,0,4194368,0,0,0,0,0,8865353564224,0,4194368,0,0,0,0,0,0,0,541065280,0,4194368,0,0,0,0,0,541065280,0,4194368,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,69260542016,0,4194368,0,0,0,0,0,69260542016,0,4194368,0,0,0,0,0,0,0,541065280,0,4194368,0,0,0,0,0,541065280,0,4194368,0,0,0,69260542016,0,4194368,0,0,0,0,0,69260542016,0,4194368,0,0,0,0,0,0,0,541065280,0,4194368,0,0,0,0,0,541065280,0,4194368,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:1134765260406784,magic:9223389647428780291},MagicBishop{attacks:[2310355422147510788,0,2310355422147510784,0,0,33554948,0,33554944,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,17213424132,0,17213424128,0,0,33554948,0,33554944,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4512412933816836,0,4512412933816832,0,0,33554948,0,33554944,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,17213424132,0,17213424128,0,0,33554948,0,33554944,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,8813306446340,0,8813306446336,0,0,33554948,0,33554944,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,17213424132,0,17213424128,0,0,33554948,0,33554944,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,8813306446340,0,8813306446336,0,0,33554948,0,33554944,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,17213424132,0,17213424128,0,0,33554948,0,33554944,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:4512412933816832,magic:140742933611073},MagicBishop{attacks:[4620710844311799048,17626629670152,34443625736,34443625736,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4620710844311799040,17626629670144,34443625728,34443625728,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,9024825884411144,17626629670152,34443625736,34443625736,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,9024825884411136,17626629670144,34443625728,34443625728,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,83887368,83887368,83887368,83887368,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,83887360,83887360,83887360,83887360,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,83887368,83887368,83887368,83887368,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,83887360,83887360,83887360,83887360,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:9024825867633664,magic:1154328965928716544},MagicBishop{attacks:[9241421692918565393,73182218769,0,0,0,0,0,0,9241421688623598097,68887251473,0,0,0,0,0,0,18049656063789585,73182218769,0,0,0,0,0,0,18049651768822289,68887251473,0,0,0,0,0,0,4462742033,4462742033,0,0,0,0,0,0,167774737,167774737,0,0,0,0,0,0,4462742033,4462742033,0,0,0,0,0,0,167774737,167774737,0,0,0,0,0,0,9241421692918565392,0,73182218768,0,0,0,0,0,9241421688623598096,0,68887251472,0,0,0,0,0,18049656063789584,0,73182218768,0,0,0,0,0,18049651768822288,0,68887251472,0,0,0,0,0,4462742032,0,4462742032,0,0,0,0,0,167774736,0,167774736,0,0,0,0,0,4462742032,0,4462742032,0,0,0,0,0,167774736,0,167774736,0,0,0,0,0,0,0,35257554307585,73182218753,0,0,0,0,0,0,35253259340289,68887251457,0,0,0,0,0,0,35257554307585,73182218753,0,0,0,0,0,0,35253259340289,68887251457,0,0,0,0,0,0,4462742017,4462742017,0,0,0,0,0,0,167774721,167774721,0,0,0,0,0,0,4462742017,4462742017,0,0,0,0,0,0,167774721,167774721,0,0,0,0,0,0,35257554307584,0,73182218752,0,0,0,0,0,35253259340288,0,68887251456,0,0,0,0,0,35257554307584,0,73182218752,0,0,0,0,0,35253259340288,0,68887251456,0,0,0,0,0,4462742016,0,4462742016,0,0,0,0,0,167774720,0,167774720,0,0,0,0,0,4462742016,0,4462742016,0,0,0,0,0,167774720,0,167774720,0,0,0,0,0,9241421692918565377,73182218753,0,0,0,0,0,0,9241421688623598081,68887251457,0,0,0,0,0,0,18049656063789569,73182218753,0,0,0,0,0,0,18049651768822273,68887251457,0,0,0,0,0,0,4462742017,4462742017,0,0,0,0,0,0,167774721,167774721,0,0,0,0,0,0,4462742017,4462742017,0,0,0,0,0,0,167774721,167774721,0,0,0,0,0,0,9241421692918565376,0,73182218752,0,0,0,0,0,9241421688623598080,0,68887251456,0,0,0,0,0,18049656063789568,0,73182218752,0,0,0,0,0,18049651768822272,0,68887251456,0,0,0,0,0,4462742016,0,4462742016,0,0,0,0,0,167774720,0,167774720,0,0,0,0,0,4462742016,0,4462742016,0,0,0,0,0,167774720,0,167774720,0,0,0,35257554307601,73182218769,0,0,0,0,0,0,35253259340305,68887251473,0,0,0,0,0,0,35257554307601,73182218769,0,0,0,0,0,0,35253259340305,68887251473,0,0,0,0,0,0,4462742033,4462742033,0,0,0,0,0,0,167774737,167774737,0,0,0,0,0,0,4462742033,4462742033,0,0,0,0,0,0,167774737,167774737,0,0,0,0,0,0,35257554307600,0,73182218768,0,0,0,0,0,35253259340304,0,68887251472,0,0,0,0,0,35257554307600,0,73182218768,0,0,0,0,0,35253259340304,0,68887251472,0,0,0,0,0,4462742032,0,4462742032,0,0,0,0,0,167774736,0,167774736,0,0,0,0,0,4462742032,0,4462742032,0,0,0,0,0,167774736,0,167774736,0,0,0,0,0],mask:18049651768822272,magic:40568139365089312},MagicBishop{attacks:[36100411639206946,0,0,0,36099312127579170,0,0,0,0,0,0,0,0,335549440,0,0,0,335549440,36100411639206944,0,0,0,36099312127579168,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,71614620242978,0,0,0,70515108615202,0,0,0,0,0,0,0,0,0,0,36099303537644578,0,1245876065314,71614620242976,36099303537644578,0,146364437538,70515108615200,36100411639206914,0,0,0,36099312127579138,0,0,0,0,0,36099303537644576,0,1245876065312,0,36099303537644576,0,146364437536,0,36100411639206912,0,0,0,36099312127579136,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,70506518680610,0,1245876065314,0,70506518680610,0,146364437538,0,71614620242946,0,0,0,70515108615170,0,0,0,0,137774502946,70506518680608,0,1245876065312,137774502946,70506518680608,36099303537644546,146364437536,1245876065282,71614620242944,36099303537644546,0,146364437506,70515108615168,0,0,0,0,137774502944,0,0,0,137774502944,0,36099303537644544,0,1245876065280,0,36099303537644544,0,146364437504,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,137774502946,0,0,0,137774502946,0,70506518680578,0,1245876065282,0,70506518680578,0,146364437506,0,0,0,0,0,137774502944,0,0,0,137774502944,137774502914,70506518680576,0,1245876065280,137774502914,70506518680576,0,146364437504,0,0,0,0,0,0,0,0,0,0,137774502912,0,0,0,137774502912,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,137774502914,0,0,0,137774502914,1108437111842,0,0,0,8925484066,0,0,0,0,0,0,0,0,137774502912,0,0,0,137774502912,1108437111840,0,0,0,8925484064,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1108437111842,0,0,0,8925484066,0,0,0,0,0,0,0,0,0,0,335549474,0,1108437111842,1108437111840,335549474,0,8925484066,8925484064,1108437111810,0,0,0,8925484034,0,0,0,0,0,335549472,0,1108437111840,0,335549472,0,8925484064,0,1108437111808,0,0,0,8925484032,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,335549474,0,1108437111842,0,335549474,0,8925484066,0,1108437111810,0,0,0,8925484034,0,0,0,0,335549474,335549472,0,1108437111840,335549474,335549472,335549442,8925484064,1108437111810,1108437111808,335549442,0,8925484034,8925484032,0,0,0,0,335549472,0,0,0,335549472,0,335549440,0,1108437111808,0,335549440,0,8925484032,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,335549474,0,0,0,335549474,0,335549442,0,1108437111810,0,335549442,0,8925484034,0,0,0,0,0,335549472,0,0,0,335549472,335549442,335549440,0,1108437111808,335549442,335549440,0,8925484032,0,0,0,0,0,0,0,0,0,0,335549440,0,0,0,335549440,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,335549442,0,0,0,335549442],mask:70515108615168,magic:633353074664064},MagicBishop{attacks:[424704217196612,0,0,0,0,0,0,0,0,0,0,0,424704217196608,0,0,0,0,0,0,0,0,0,0,0,283966728841284,0,0,0,0,0,0,0,141013037361220,141030217230404,0,0,283966728841280,0,0,0,0,0,0,0,141013037361216,141030217230400,0,0,424704217196548,0,0,0,0,0,0,0,275549005892,292728875076,0,0,424704217196544,0,0,0,0,141013037361220,0,0,275549005888,292728875072,0,0,283966728841220,0,0,0,0,141013037361216,0,0,141013037361156,141030217230340,0,0,283966728841216,0,0,0,0,275549005892,0,0,141013037361152,141030217230336,0,0,0,0,0,0,0,275549005888,0,0,275549005828,292728875012,0,0,0,0,0,0,0,141013037361156,0,0,275549005824,292728875008,0,0,0,0,0,0,0,141013037361152,0,0,0,283691850934340,0,0,0,0,0,0,0,275549005828,0,0,0,283691850934336,0,0,0,0,0,0,0,275549005824,0,0,0,283691850934340,0,0,0,0,0,0,0,671098948,17850968132,0,0,283691850934336,0,0,0,0,0,0,0,671098944,17850968128,0,0,283691850934276,0,0,0,0,0,0,0,671098948,17850968132,0,0,283691850934272,0,0,143229240485956,0,671098948,0,0,671098944,17850968128,0,0,283691850934276,0,0,143229240485952,0,671098944,0,0,671098884,17850968068,0,0,283691850934272,0,0,2491752130628,0,671098948,0,0,671098880,17850968064,0,141013037361220,141030217230404,0,0,2491752130624,0,671098944,0,0,671098884,17850968068,0,141013037361216,141030217230400,0,0,143229240485892,0,671098884,0,0,671098880,17850968064,0,275549005892,292728875076,0,0,143229240485888,0,671098880,0,0,141013037361220,0,0,275549005888,292728875072,0,0,2491752130564,0,671098884,0,0,141013037361216,0,0,141013037361156,141030217230340,0,0,2491752130560,0,671098880,0,0,275549005892,0,0,141013037361152,141030217230336,0,0,0,0,0,0,0,275549005888,0,0,275549005828,292728875012,0,0,0,0,0,0,0,141013037361156,0,0,275549005824,292728875008,0,0,0,0,0,0,0,141013037361152,0,0,0,2216874223684,0,0,0,0,0,0,0,275549005828,0,0,0,2216874223680,0,0,0,0,0,0,0,275549005824,0,0,0,2216874223684,0,0,0,0,0,0,0,671098948,17850968132,0,0,2216874223680,0,0,0,0,0,0,0,671098944,17850968128,0,0,2216874223620,0,0,0,0,0,0,0,671098948,17850968132,0,0,2216874223616,0,0,0,0,671098948,0,0,671098944,17850968128,0,0,2216874223620,0,0,0,0,671098944,0,0,671098884,17850968068,0,0,2216874223616,0,0,0,0,671098948,0,0,671098880,17850968064,0,0,0,0,0,0,0,671098944,0,0,671098884,17850968068,0,0,0,0,0,0,0,671098884,0,0,671098880,17850968064,0,0,0,0,0,0,0,671098880,0,0,0,0,0,0,0,0,0,0,0,671098884,0,0,0,0,0,0,0,0,0,0,0,671098880,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:2491752130560,magic:4611897408198017152},MagicBishop{attacks:[72625527495610504,551098011784,0,0,0,0,72624977739796616,1342197896,0,0,0,0,0,0,0,0,0,0,72625527495610496,551098011776,0,0,0,0,72624977739796608,1342197888,0,0,0,0,0,0,4983504261256,551098011784,0,0,567933457682568,551098011784,4433748447368,1342197896,0,0,567383701868680,1342197896,0,0,0,0,0,0,4983504261248,551098011776,0,0,567933457682560,551098011776,4433748447360,1342197888,0,0,567383701868672,1342197888,0,0,0,0,0,0,4983504261256,551098011784,0,0,72625527495610376,551098011656,4433748447368,1342197896,0,0,72624977739796488,1342197768,0,0,0,0,0,0,4983504261248,551098011776,0,0,72625527495610368,551098011648,4433748447360,1342197888,0,0,72624977739796480,1342197760,0,0,0,0,0,0,4983504261128,551098011656,0,0,567933457682440,551098011656,4433748447240,1342197768,0,0,567383701868552,1342197768,0,0,0,0,0,0,4983504261120,551098011648,0,0,567933457682432,551098011648,4433748447232,1342197760,0,0,567383701868544,1342197760,0,0,0,0,0,0,4983504261128,551098011656,0,0,0,0,4433748447240,1342197768,0,0,0,0,0,0,0,0,0,0,4983504261120,551098011648,0,0,0,0,4433748447232,1342197760,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,585457750152,551098011784,0,0,0,0,35701936264,1342197896,0,0,0,0,0,0,0,0,0,0,585457750144,551098011776,0,0,0,0,35701936256,1342197888,0,0,0,0,0,0,585457750152,0,551098011784,0,585457750152,551098011784,35701936264,0,1342197896,0,35701936264,1342197896,0,0,0,0,0,0,585457750144,0,551098011776,0,585457750144,551098011776,35701936256,0,1342197888,0,35701936256,1342197888,0,0,0,0,0,0,585457750152,0,551098011784,0,585457750024,551098011656,35701936264,0,1342197896,0,35701936136,1342197768,0,0,0,0,0,0,585457750144,0,551098011776,0,585457750016,551098011648,35701936256,0,1342197888,0,35701936128,1342197760,0,0,0,0,0,0,585457750024,0,551098011656,0,585457750024,551098011656,35701936136,0,1342197768,0,35701936136,1342197768,0,0,0,0,0,0,585457750016,0,551098011648,0,585457750016,551098011648,35701936128,0,1342197760,0,35701936128,1342197760,0,0,0,0,0,0,585457750024,0,551098011656,0,0,0,35701936136,0,1342197768,0,0,0,0,0,0,0,0,0,585457750016,0,551098011648,0,0,0,35701936128,0,1342197760,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:567383701868544,magic:288388706031896832},MagicBishop{attacks:[145249955479592976,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2684395536,0,71403872272,0,0,0,0,0,0,0,0,0,0,0,0,0,145249955479592960,0,2684395536,0,0,0,0,0,0,0,0,0,0,0,0,0,2684395520,0,71403872256,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2684395520,0,1134767403737104,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2684395536,0,71403872272,0,0,0,0,0,0,0,0,0,0,0,0,0,1134767403737088,0,2684395536,0,0,0,0,0,0,0,0,0,0,0,0,0,2684395520,0,71403872256,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2684395520,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,8867496894480,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2684395536,0,71403872272,0,0,0,0,0,0,0,0,0,0,0,0,0,8867496894464,0,2684395536,0,0,0,0,0,0,0,0,0,0,0,0,0,2684395520,0,71403872256,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2684395520,0,8867496894480,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2684395536,0,71403872272,0,0,0,0,0,0,0,0,0,0,0,0,0,8867496894464,0,2684395536,0,0,0,0,0,0,0,0,0,0,0,0,0,2684395520,0,71403872256,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2684395520,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:1134765256220672,magic:2305983747785296001},MagicBishop{attacks:[290499906664153120,1073758240,0,0,290499906664153088,1073758208,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,17730698756128,1073758240,0,0,17730698756096,1073758208,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,138512711712,1073758240,0,0,138512711680,1073758208,0,0,0,0,0,0,0,0,0,0,2269530512441376,1073758240,0,0,2269530512441344,1073758208,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,138512711712,1073758240,0,0,138512711680,1073758208,0,0,0,0,0,0,0,0,0,0,17730698756128,1073758240,0,0,17730698756096,1073758208,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,138512711712,1073758240,0,0,138512711680,1073758208,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,138512711712,1073758240,0,0,138512711680,1073758208,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:2269530512441344,magic:10412331134607313152},MagicBishop{attacks:[1155177711057110024,1155177711057108992,0,0,0,8590066696,8590065664,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2256206450263040,2256206450262016,0,0,0,8590066688,8590065664,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4406636577792,4406636576768,0,0,0,8590066688,8590065664,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2256206450263048,2256206450262016,0,0,0,8590066696,8590065664,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4406636577800,4406636576768,0,0,0,8590066696,8590065664,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4406636577792,4406636576768,0,0,0,8590066688,8590065664,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1155177711057110016,1155177711057108992,0,0,0,8590066688,8590065664,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4406636577800,4406636576768,0,0,0,8590066696,8590065664,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:2256206450263040,magic:13511073783097856},MagicBishop{attacks:[2310355426409252880,0,0,0,8817568188432,0,0,0,4512417195558928,0,0,0,8817568188432,0,0,0,0,2310355426409252864,0,0,0,8817568188416,0,0,21475166224,4512417195558912,0,0,21475166224,8817568188416,0,0,21475166224,0,0,0,21475166224,0,0,0,0,21475166208,0,0,0,21475166208,0,0,0,21475166208,0,0,0,21475166208,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2310355426409250816,0,0,0,8817568186368,0,0,0,4512417195556864,0,0,0,8817568186368,0,0,0,0,2310355426409250816,0,0,0,8817568186368,0,0,21475164160,4512417195556864,0,0,21475164160,8817568186368,0,0,21475164160,0,0,0,21475164160,0,0,0,0,21475164160,0,0,0,21475164160,0,0,0,21475164160,0,0,0,21475164160,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:4512412900526080,magic:2306143193118294080},MagicBishop{attacks:[4620711952330133792,0,0,9025933902741760,0,0,0,0,0,18734648004896,1142461956096,0,18734648000768,0,0,0,4620710852818506016,0,0,9024834391113984,1142461956096,0,0,0,0,17635136377120,42950328320,0,17635136372992,0,0,0,0,0,0,0,42950328320,0,0,0,4620711952330133536,0,0,9025933902741504,0,0,0,0,0,0,18734648004640,0,0,18734648000512,0,0,4620710852818505760,0,0,9024834391113728,0,0,0,0,1142461960480,0,17635136376864,1142461956352,0,17635136372736,0,0,0,1142461960480,0,0,1142461956352,0,0,0,42950332704,0,0,42950328576,0,0,0,0,0,42950332704,0,0,42950328576,0,0,0,0,4620711952330133760,0,0,0,0,0,0,1142461960224,0,18734648004864,1142461956096,0,0,0,0,0,4620710852818505984,1142461960224,0,0,1142461956096,0,0,42950332448,0,17635136377088,42950328320,0,0,0,0,0,0,42950332448,0,0,42950328320,0,0,0,4620711952330133504,0,0,0,0,0,0,0,0,0,18734648004608,0,0,0,0,0,4620710852818505728,0,0,0,0,0,0,0,1142461960448,4620711952330129664,17635136376832,0,0,0,0,0,0,1142461960448,18734648000768,0,0,0,0,0,42950332672,4620710852818501888,0,0,0,0,0,0,0,42950332672,17635136372992,0,0,0,0,0,0,0,0,0,0,0,0,0,1142461960192,4620711952330129408,0,0,0,0,0,0,0,0,1142461960192,18734648000512,0,0,0,0,42950332416,4620710852818501632,0,0,0,0,0,0,0,1142461956352,42950332416,17635136372736,0,0,0,0,0,0,1142461956352,0,0,0,0,0,0,42950328576,0,0,0,0,0,0,0,0,42950328576,0,0,0,0,9025933902745888,0,0,4620711952330129664,0,0,0,0,0,18734648004896,1142461956096,0,18734648000768,0,0,0,9024834391118112,0,0,4620710852818501888,1142461956096,0,0,0,0,17635136377120,42950328320,0,17635136372992,0,0,0,0,0,0,0,42950328320,0,0,0,9025933902745632,0,0,4620711952330129408,0,0,0,0,0,0,18734648004640,0,0,18734648000512,0,0,9024834391117856,0,0,4620710852818501632,0,0,0,0,1142461960480,0,17635136376864,1142461956352,0,17635136372736,0,0,0,1142461960480,0,0,1142461956352,0,0,0,42950332704,0,0,42950328576,0,0,0,0,0,42950332704,0,0,42950328576,0,0,0,0,9025933902745856,0,0,0,0,0,0,1142461960224,0,18734648004864,1142461956096,0,0,0,0,0,9024834391118080,1142461960224,0,0,1142461956096,0,0,42950332448,0,17635136377088,42950328320,0,0,0,0,0,0,42950332448,0,0,42950328320,0,0,0,9025933902745600,0,0,0,0,0,0,0,0,0,18734648004608,0,0,0,0,0,9024834391117824,0,0,0,0,0,0,0,1142461960448,9025933902741760,17635136376832,0,0,0,0,0,0,1142461960448,18734648000768,0,0,0,0,0,42950332672,9024834391113984,0,0,0,0,0,0,0,42950332672,17635136372992,0,0,0,0,0,0,0,0,0,0,0,0,0,1142461960192,9025933902741504,0,0,0,0,0,0,0,0,1142461960192,18734648000512,0,0,0,0,42950332416,9024834391113728,0,0,0,0,0,0,0,1142461956352,42950332416,17635136372736,0,0,0,0,0,0,1142461956352,0,0,0,0,0,0,42950328576,0,0,0,0,0,0,0,0,42950328576,0,0,0,0],mask:9024834391117824,magic:855557552491520},MagicBishop{attacks:[9241705379636978241,9241421705637011520,9241705379636969985,9241421705637003264,283759900622848,85900665408,283759900631040,85900657152,37469296001537,9241421705637003777,37469296009729,9241421705637011969,2284923912192,85900664896,2284923920384,85900656640,318944272711680,35270272754241,318944272719872,35270272745985,283759900622848,85900664896,283759900631040,85900656640,18051867805483008,35270272753728,18051867805491200,35270272745472,2284923912192,85900664896,2284923920384,85900656640,18333342782202433,18049668782235712,18333342782194177,18049668782227456,283759900631617,85900664896,283759900623361,85900656640,37469296001537,18049668782227969,37469296009729,18049668782236161,2284923912705,85900657153,2284923920897,85900665345,9241705379636978240,35270272754241,9241705379636969984,35270272745985,283759900622848,85900665409,283759900631040,85900657153,37469296001536,9241421705637003776,37469296009728,9241421705637011968,2284923912192,85900664896,2284923920384,85900656640,9241705379636977728,35270272754240,9241705379636969472,35270272745984,283759900631617,85900664896,283759900623361,85900656640,37469296001024,9241421705637003264,37469296009216,9241421705637011456,2284923912705,85900657153,2284923920897,85900665345,18333342782202432,35270272753728,18333342782194176,35270272745472,283759900631616,85900665409,283759900623360,85900657153,37469296001536,18049668782227968,37469296009728,18049668782236160,2284923912704,85900657152,2284923920896,85900665344,18333342782201920,35270272754240,18333342782193664,35270272745984,283759900631104,85900665408,283759900622848,85900657152,37469296001024,18049668782227456,37469296009216,18049668782235648,2284923912192,85900656640,2284923920384,85900664832,9241705379636977728,35270272753728,9241705379636969472,35270272745472,283759900631616,85900664896,283759900623360,85900656640,37469296001024,9241421705637003264,37469296009216,9241421705637011456,2284923912704,85900657152,2284923920896,85900665344,318944272720449,35270272753728,318944272712193,35270272745472,283759900631104,85900665408,283759900622848,85900657152,9241423904660267585,35270272745985,9241423904660259329,35270272754177,2284923912192,85900656640,2284923920384,85900664832,18333342782201920,9241421705637003777,18333342782193664,9241421705637011969,283759900631104,85900664896,283759900622848,85900656640,37469296001024,18049668782227456,37469296009216,18049668782235648,2284923912192,85900656640,2284923920384,85900664832,318944272720449,35270272753728,318944272712193,35270272745472,283759900631617,85900664896,283759900623361,85900656640,18051867805491777,35270272745985,18051867805483521,35270272754177,2284923920961,85900657153,2284923912705,85900665345,318944272720448,18049668782227969,318944272712192,18049668782236161,283759900631104,85900657153,283759900622848,85900665345,9241423904660267584,35270272745984,9241423904660259328,35270272754176,2284923912192,85900656640,2284923920384,85900664832,318944272719936,9241421705637003776,318944272711680,9241421705637011968,283759900631617,85900664896,283759900623361,85900656640,9241423904660267072,35270272745472,9241423904660258816,35270272753664,2284923920961,85900657153,2284923912705,85900665345,318944272720448,9241421705637003264,318944272712192,9241421705637011456,283759900631616,85900657153,283759900623360,85900665345,18051867805491776,35270272745984,18051867805483520,35270272754176,2284923920960,85900657152,2284923912704,85900665344,318944272719936,18049668782227968,318944272711680,18049668782236160,283759900631104,85900657152,283759900622848,85900665344,18051867805491264,35270272745472,18051867805483008,35270272753664,2284923920448,85900656640,2284923912192,85900664832,318944272719936,18049668782227456,318944272711680,18049668782235648,283759900631616,85900656640,283759900623360,85900664832,9241423904660267072,35270272745472,9241423904660258816,35270272753664,2284923920960,85900657152,2284923912704,85900665344,9241705379636969985,9241421705637003264,9241705379636978177,9241421705637011456,283759900631104,85900657152,283759900622848,85900665344,37469296009793,9241421705637012033,37469296001537,9241421705637003777,2284923920448,85900656640,2284923912192,85900664832,318944272719936,35270272745985,318944272711680,35270272754177,283759900631104,85900656640,283759900622848,85900664832,18051867805491264,35270272745472,18051867805483008,35270272753664,2284923920448,85900656640,2284923912192,85900664832,18333342782194177,18049668782227456,18333342782202369,18049668782235648,283759900623361,85900656640,283759900631553,85900664832,37469296009793,18049668782236225,37469296001537,18049668782227969,2284923920961,85900665409,2284923912705,85900657153,9241705379636969984,35270272745985,9241705379636978176,35270272754177,283759900631104,85900657153,283759900622848,85900665345,37469296009792,9241421705637012032,37469296001536,9241421705637003776,2284923920448,85900656640,2284923912192,85900664832,9241705379636969472,35270272745984,9241705379636977664,35270272754176,283759900623361,85900656640,283759900631553,85900664832,37469296009280,9241421705637011520,37469296001024,9241421705637003264,2284923920961,85900665409,2284923912705,85900657153,18333342782194176,35270272745472,18333342782202368,35270272753664,283759900623360,85900657153,283759900631552,85900665345,37469296009792,18049668782236224,37469296001536,18049668782227968,2284923920960,85900665408,2284923912704,85900657152,18333342782193664,35270272745984,18333342782201856,35270272754176,283759900622848,85900657152,283759900631040,85900665344,37469296009280,18049668782235712,37469296001024,18049668782227456,2284923920448,85900664896,2284923912192,85900656640,9241705379636969472,35270272745472,9241705379636977664,35270272753664,283759900623360,85900656640,283759900631552,85900664832,37469296009280,9241421705637011520,37469296001024,9241421705637003264,2284923920960,85900665408,2284923912704,85900657152,318944272712193,35270272745472,318944272720385,35270272753664,283759900622848,85900657152,283759900631040,85900665344,9241423904660259329,35270272754241,9241423904660267521,35270272745985,2284923920448,85900664896,2284923912192,85900656640,18333342782193664,9241421705637012033,18333342782201856,9241421705637003777,283759900622848,85900656640,283759900631040,85900664832,37469296009280,18049668782235712,37469296001024,18049668782227456,2284923920448,85900664896,2284923912192,85900656640,318944272712193,35270272745472,318944272720385,35270272753664,283759900623361,85900656640,283759900631553,85900664832,18051867805483521,35270272754241,18051867805491713,35270272745985,2284923912705,85900665409,2284923920897,85900657153,318944272712192,18049668782236225,318944272720384,18049668782227969,283759900622848,85900665409,283759900631040,85900657153,9241423904660259328,35270272754240,9241423904660267520,35270272745984,2284923920448,85900664896,2284923912192,85900656640,318944272711680,9241421705637012032,318944272719872,9241421705637003776,283759900623361,85900656640,283759900631553,85900664832,9241423904660258816,35270272753728,9241423904660267008,35270272745472,2284923912705,85900665409,2284923920897,85900657153,318944272712192,9241421705637011520,318944272720384,9241421705637003264,283759900623360,85900665409,283759900631552,85900657153,18051867805483520,35270272754240,18051867805491712,35270272745984,2284923912704,85900665408,2284923920896,85900657152,318944272711680,18049668782236224,318944272719872,18049668782227968,283759900622848,85900665408,283759900631040,85900657152,18051867805483008,35270272753728,18051867805491200,35270272745472,2284923912192,85900664896,2284923920384,85900656640,318944272711680,18049668782235712,318944272719872,18049668782227456,283759900623360,85900664896,283759900631552,85900656640,9241423904660258816,35270272753728,9241423904660267008,35270272745472,2284923912704,85900665408,2284923920896,85900657152],mask:18051867805491712,magic:2309229505583120448},MagicBishop{attacks:[108724279602332802,108724279602316290,171801330816,171801314304,36666685564403712,36666685564387328,171801329664,171801313280,108724279602332800,108724279602316288,36103735610983554,36103735610967042,36666685564403712,36666685564387328,36103735610982400,36103735610966016,36099337564472450,36099337564455938,36103735610983552,36103735610967040,36099337564471296,36099337564454912,36103735610982400,36103735610966016,36099337564472448,36099337564455936,36099337564472450,36099337564455938,36099337564471296,36099337564454912,36099337564471296,36099337564454912,637888545439872,637888545423360,36099337564472448,36099337564455936,637888545440770,637888545424386,36099337564471296,36099337564454912,637888545439872,637888545423360,74938592018560,74938592002048,637888545440768,637888545424384,74938592019458,74938592003074,70540545507456,70540545490944,74938592018560,74938592002048,70540545508354,70540545491970,74938592019456,74938592003072,70540545507456,70540545490944,70540545507456,70540545490944,70540545508352,70540545491968,70540545508354,70540545491970,72625113839191170,72625113839174658,70540545507456,70540545490944,567519801262080,567519801245696,70540545508352,70540545491968,72625113839191168,72625113839174656,4569847841922,4569847825410,567519801262080,567519801245696,4569847840768,4569847824384,171801330818,171801314306,4569847841920,4569847825408,171801329664,171801313280,4569847840768,4569847824384,171801330816,171801314304,171801330818,171801314306,171801329664,171801313280,171801329664,171801313280,567519801262208,567519801245696,171801330816,171801314304,567519801263106,567519801246722,171801329664,171801313280,567519801262208,567519801245696,4569847840896,4569847824384,567519801263104,567519801246720,4569847841794,4569847825410,171801329792,171801313280,4569847840896,4569847824384,171801330690,171801314306,4569847841792,4569847825408,171801329792,171801313280,171801329792,171801313280,171801330688,171801314304,171801330690,171801314306,108724279602331776,108724279602315264,171801329792,171801313280,108724279602332674,108724279602316290,171801330688,171801314304,108724279602331776,108724279602315264,36103735610982528,36103735610966016,108724279602332672,108724279602316288,36103735610983426,36103735610967042,36099337564471424,36099337564454912,36103735610982528,36103735610966016,36099337564472322,36099337564455938,36103735610983424,36103735610967040,36099337564471424,36099337564454912,36099337564471424,36099337564454912,36099337564472320,36099337564455936,36099337564472322,36099337564455938,72695482583368834,72695482583352322,36099337564471424,36099337564454912,637888545439744,637888545423360,36099337564472320,36099337564455936,72695482583368832,72695482583352320,74938592019586,74938592003074,637888545439744,637888545423360,74938592018432,74938592002048,70540545508482,70540545491970,74938592019584,74938592003072,70540545507328,70540545490944,74938592018432,74938592002048,70540545508480,70540545491968,70540545508482,70540545491970,70540545507328,70540545490944,70540545507328,70540545490944,72625113839190144,72625113839173632,70540545508480,70540545491968,72625113839191042,72625113839174658,70540545507328,70540545490944,72625113839190144,72625113839173632,4569847840896,4569847824384,72625113839191040,72625113839174656,4569847841794,4569847825410,171801329792,171801313280,4569847840896,4569847824384,171801330690,171801314306,4569847841792,4569847825408,171801329792,171801313280,171801329792,171801313280,171801330688,171801314304,171801330690,171801314306,72625113839191170,72625113839174658,171801329792,171801313280,567519801262080,567519801245696,171801330688,171801314304,72625113839191168,72625113839174656,4569847841922,4569847825410,567519801262080,567519801245696,4569847840768,4569847824384,171801330818,171801314306,4569847841920,4569847825408,171801329664,171801313280,4569847840768,4569847824384,171801330816,171801314304,171801330818,171801314306,171801329664,171801313280,171801329664,171801313280,36666685564404866,36666685564388354,171801330816,171801314304,108724279602331648,108724279602315264,171801329664,171801313280,36666685564404864,36666685564388352,36103735610983554,36103735610967042,108724279602331648,108724279602315264,36103735610982400,36103735610966016,36099337564472450,36099337564455938,36103735610983552,36103735610967040,36099337564471296,36099337564454912,36103735610982400,36103735610966016,36099337564472448,36099337564455936,36099337564472450,36099337564455938,36099337564471296,36099337564454912,36099337564471296,36099337564454912,72695482583367808,72695482583351296,36099337564472448,36099337564455936,72695482583368706,72695482583352322,36099337564471296,36099337564454912,72695482583367808,72695482583351296,74938592018560,74938592002048,72695482583368704,72695482583352320,74938592019458,74938592003074,70540545507456,70540545490944,74938592018560,74938592002048,70540545508354,70540545491970,74938592019456,74938592003072,70540545507456,70540545490944,70540545507456,70540545490944,70540545508352,70540545491968,70540545508354,70540545491970,567519801263234,567519801246722,70540545507456,70540545490944,72625113839190016,72625113839173632,70540545508352,70540545491968,567519801263232,567519801246720,4569847841922,4569847825410,72625113839190016,72625113839173632,4569847840768,4569847824384,171801330818,171801314306,4569847841920,4569847825408,171801329664,171801313280,4569847840768,4569847824384,171801330816,171801314304,171801330818,171801314306,171801329664,171801313280,171801329664,171801313280,72625113839190144,72625113839173632,171801330816,171801314304,72625113839191042,72625113839174658,171801329664,171801313280,72625113839190144,72625113839173632,4569847840896,4569847824384,72625113839191040,72625113839174656,4569847841794,4569847825410,171801329792,171801313280,4569847840896,4569847824384,171801330690,171801314306,4569847841792,4569847825408,171801329792,171801313280,171801329792,171801313280,171801330688,171801314304,171801330690,171801314306,36666685564403840,36666685564387328,171801329792,171801313280,36666685564404738,36666685564388354,171801330688,171801314304,36666685564403840,36666685564387328,36103735610982528,36103735610966016,36666685564404736,36666685564388352,36103735610983426,36103735610967042,36099337564471424,36099337564454912,36103735610982528,36103735610966016,36099337564472322,36099337564455938,36103735610983424,36103735610967040,36099337564471424,36099337564454912,36099337564471424,36099337564454912,36099337564472320,36099337564455936,36099337564472322,36099337564455938,637888545440898,637888545424386,36099337564471424,36099337564454912,72695482583367680,72695482583351296,36099337564472320,36099337564455936,637888545440896,637888545424384,74938592019586,74938592003074,72695482583367680,72695482583351296,74938592018432,74938592002048,70540545508482,70540545491970,74938592019584,74938592003072,70540545507328,70540545490944,74938592018432,74938592002048,70540545508480,70540545491968,70540545508482,70540545491970,70540545507328,70540545490944,70540545507328,70540545490944,567519801262208,567519801245696,70540545508480,70540545491968,567519801263106,567519801246722,70540545507328,70540545490944,567519801262208,567519801245696,4569847840896,4569847824384,567519801263104,567519801246720,4569847841794,4569847825410,171801329792,171801313280,4569847840896,4569847824384,171801330690,171801314306,4569847841792,4569847825408,171801329792,171801313280,171801329792,171801313280,171801330688,171801314304,171801330690,171801314306,567519801263234,567519801246722,171801329792,171801313280,72625113839190016,72625113839173632,171801330688,171801314304,567519801263232,567519801246720,4569847841922,4569847825410,72625113839190016,72625113839173632,4569847840768,4569847824384,171801330818,171801314306,4569847841920,4569847825408,171801329664,171801313280,4569847840768,4569847824384,171801330816,171801314304,171801330818,171801314306,171801329664,171801313280,171801329664,171801313280],mask:637888545440768,magic:9241676724167589888},MagicBishop{attacks:[145390965166737412,149877184038916,0,0,0,0,0,0,0,0,0,0,1275777090848768,149877184006144,0,0,141081091016708,141081091016708,0,0,0,0,0,0,0,0,0,0,141081090983936,141081090983936,0,0,0,0,145390965166735360,149877184036864,0,0,0,0,0,0,0,0,0,0,1275777090846720,149877184004096,0,0,141081091014656,141081091014656,0,0,0,0,0,0,0,0,0,0,141081090981888,141081090981888,145250227678382084,9139695683588,0,0,0,0,0,0,0,0,0,0,1135039602493440,9139695650816,0,0,343602661380,343602661380,0,0,0,0,0,0,0,0,0,0,343602628608,343602628608,0,0,0,0,145250227678380032,9139695681536,0,0,0,0,0,0,0,0,0,0,1135039602491392,9139695648768,0,0,343602659328,343602659328,0,0,0,0,0,0,0,0,0,0,343602626560,343602626560,1275777090881540,149877184038916,0,0,0,0,0,0,145390965166704644,149877184006148,0,0,0,0,0,0,141081091016708,141081091016708,0,0,0,0,0,0,141081090983940,141081090983940,0,0,0,0,0,0,0,0,1275777090879488,149877184036864,0,0,0,0,0,0,145390965166702592,149877184004096,0,0,0,0,0,0,141081091014656,141081091014656,0,0,0,0,0,0,141081090981888,141081090981888,0,0,0,0,1135039602526212,9139695683588,0,0,0,0,0,0,145250227678349316,9139695650820,0,0,0,0,0,0,343602661380,343602661380,0,0,0,0,0,0,343602628612,343602628612,0,0,0,0,0,0,0,0,1135039602524160,9139695681536,0,0,0,0,0,0,145250227678347264,9139695648768,0,0,0,0,0,0,343602659328,343602659328,0,0,0,0,0,0,343602626560,343602626560,0,0,0,0,0,0,0,0,145390965166737408,149877184038912,0,0,1275777090848772,149877184006148,0,0,0,0,0,0,0,0,0,0,141081091016704,141081091016704,0,0,141081090983940,141081090983940,0,0,0,0,0,0,0,0,0,0,0,0,145390965166735360,149877184036864,0,0,1275777090846720,149877184004096,0,0,0,0,0,0,0,0,0,0,141081091014656,141081091014656,0,0,141081090981888,141081090981888,0,0,0,0,0,0,0,0,145250227678382080,9139695683584,0,0,1135039602493444,9139695650820,0,0,0,0,0,0,0,0,0,0,343602661376,343602661376,0,0,343602628612,343602628612,0,0,0,0,0,0,0,0,0,0,0,0,145250227678380032,9139695681536,0,0,1135039602491392,9139695648768,0,0,0,0,0,0,0,0,0,0,343602659328,343602659328,0,0,343602626560,343602626560,0,0,0,0,0,0,0,0,1275777090881536,149877184038912,0,0,0,0,0,0,145390965166704640,149877184006144,0,0,0,0,0,0,141081091016704,141081091016704,0,0,0,0,0,0,141081090983936,141081090983936,0,0,0,0,0,0,0,0,1275777090879488,149877184036864,0,0,0,0,0,0,145390965166702592,149877184004096,0,0,0,0,0,0,141081091014656,141081091014656,0,0,0,0,0,0,141081090981888,141081090981888,0,0,0,0,1135039602526208,9139695683584,0,0,0,0,0,0,145250227678349312,9139695650816,0,0,0,0,0,0,343602661376,343602661376,0,0,0,0,0,0,343602628608,343602628608,0,0,0,0,0,0,0,0,1135039602524160,9139695681536,0,0,0,0,0,0,145250227678347264,9139695648768,0,0,0,0,0,0,343602659328,343602659328,0,0,0,0,0,0,343602626560,343602626560],mask:1135039602493440,magic:76632730917408769},MagicBishop{attacks:[290500455356698632,0,290500455356694528,0,0,18279391301640,0,18279391297536,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,687205257224,0,687205253120,0,0,687205257224,0,687205253120,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2270079204986888,0,2270079204982784,0,0,18279391301640,0,18279391297536,290500455356698624,0,290500455356694528,0,0,18279391301632,0,18279391297536,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,687205257224,0,687205253120,0,0,687205257224,0,687205253120,687205257216,0,687205253120,0,0,687205257216,0,687205253120,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2270079204986880,0,2270079204982784,0,0,18279391301632,0,18279391297536,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,687205257216,0,687205253120,0,0,687205257216,0,687205253120,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:2269529440784384,magic:55239498558089216},MagicBishop{attacks:[580999811184992272,0,0,35459254198288,0,0,0,0,274882109456,0,0,274882109456,0,0,0,0,0,0,0,0,0,0,0,0,4539058881568768,0,0,35459254198272,0,0,0,0,580999811184984064,0,0,35459254190080,0,0,0,0,274882101248,0,0,274882101248,0,0,0,0,0,0,0,0,0,0,0,0,4539058881560576,0,0,35459254190080,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,274882109456,0,0,274882109456,0,0,0,0,0,0,0,0,0,0,0,0,580999811184992256,0,0,35459254198272,0,0,0,0,274882109440,0,0,274882109440,0,0,0,0,274882101248,0,0,274882101248,0,0,0,0,0,0,0,0,0,0,0,0,580999811184984064,0,0,35459254190080,0,0,0,0,274882101248,0,0,274882101248,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,274882109440,0,0,274882109440,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,274882101248,0,0,274882101248,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4539058881568784,0,0,35459254198288,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4539058881560576,0,0,35459254190080,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:4539058881568768,magic:72691189777697856},MagicBishop{attacks:[577588851267340304,0,577588851267338240,0,2199057074192,0,2199057072128,0,0,0,0,0,0,0,0,0,577588851267340288,0,577588851267338240,0,2199057074176,0,2199057072128,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1128098963916816,0,1128098963914752,0,2199057074192,0,2199057072128,0,0,0,0,0,0,0,0,0,1128098963916800,0,1128098963914752,0,2199057074176,0,2199057072128,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,577588851267076096,0,577588851267076096,0,2199056809984,0,2199056809984,0,0,0,0,0,0,0,0,0,577588851267076096,0,577588851267076096,0,2199056809984,0,2199056809984,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1128098963652608,0,1128098963652608,0,2199056809984,0,2199056809984,0,0,0,0,0,0,0,0,0,1128098963652608,0,1128098963652608,0,2199056809984,0,2199056809984,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:1128098963916800,magic:5764889281747290128},MagicBishop{attacks:[1155178802063085600,0,0,0,2257297456238624,0,1155178802063085568,0,0,0,2257297456238592,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1155178802062557184,0,0,0,2257297455710208,0,1155178802062557184,0,0,0,2257297455710208,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5497642553376,0,0,0,5497642553376,0,5497642553344,0,0,0,5497642553344,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5497642024960,0,0,0,5497642024960,0,5497642024960,0,0,0,5497642024960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1155178802063081472,0,0,0,2257297456234496,0,1155178802063081472,0,0,0,2257297456234496,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1155178802062557184,0,0,0,2257297455710208,0,1155178802062557184,0,0,0,2257297455710208,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5497642549248,0,0,0,5497642549248,0,5497642549248,0,0,0,5497642549248,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5497642024960,0,0,0,5497642024960,0,5497642024960,0,0,0,5497642024960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:2256197927833600,magic:53345642102848},MagicBishop{attacks:[2310639079102947392,2310639079102881856,292470261882944,292470261817408,2310639079101890560,2310639079101825024,292470260826112,292470260760576,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2310639079102947328,2310639079102881792,292470261882880,292470261817344,2310639079101890560,2310639079101825024,292470260826112,292470260760576,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2310639079102939136,2310639079102873600,292470261874688,292470261809152,2310639079101890560,2310639079101825024,292470260826112,292470260760576,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2310639079102939136,2310639079102873600,292470261874688,292470261809152,2310639079101890560,2310639079101825024,292470260826112,292470260760576,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4796069889253440,4796069889187904,292470261882944,292470261817408,4796069888196608,4796069888131072,292470260826112,292470260760576,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4796069889253376,4796069889187840,292470261882880,292470261817344,4796069888196608,4796069888131072,292470260826112,292470260760576,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4796069889245184,4796069889179648,292470261874688,292470261809152,4796069888196608,4796069888131072,292470260826112,292470260760576,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4796069889245184,4796069889179648,292470261874688,292470261809152,4796069888196608,4796069888131072,292470260826112,292470260760576,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2310357604126236736,2310357604126171200,10995285172288,10995285106752,2310357604125179904,2310357604125114368,10995284115456,10995284049920,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2310357604126236672,2310357604126171136,10995285172224,10995285106688,2310357604125179904,2310357604125114368,10995284115456,10995284049920,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2310357604126228480,2310357604126162944,10995285164032,10995285098496,2310357604125179904,2310357604125114368,10995284115456,10995284049920,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2310357604126228480,2310357604126162944,10995285164032,10995285098496,2310357604125179904,2310357604125114368,10995284115456,10995284049920,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4514594912542784,4514594912477248,10995285172288,10995285106752,4514594911485952,4514594911420416,10995284115456,10995284049920,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4514594912542720,4514594912477184,10995285172224,10995285106688,4514594911485952,4514594911420416,10995284115456,10995284049920,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4514594912534528,4514594912468992,10995285164032,10995285098496,4514594911485952,4514594911420416,10995284115456,10995284049920,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4514594912534528,4514594912468992,10995285164032,10995285098496,4514594911485952,4514594911420416,10995284115456,10995284049920,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:4514594912477184,magic:576568505520890884},MagicBishop{attacks:[4693335752243822976,72642534559449088,4693335752243806464,72642534559449088,4693335752241709312,72642534561562624,4693335752241709312,72642534561546240,9592139776262144,584940521652224,9592139776262144,584940521652224,9592139778375680,584940523765760,9592139778359296,584940523749376,4620715208250360064,21990570213504,4620715208250360064,21990570196992,4620715208252473600,21990568099840,4620715208252457216,21990568099840,9029189824954496,21990570344576,9029189824937984,21990570328064,9029189822840832,21990568230912,9029189822840832,21990568230912,4693335752243822720,72642534559449088,4693335752243806208,72642534559449088,4693335752241709056,72642534561562624,4693335752241709056,72642534561546240,4621278158205895040,584940521521152,4621278158205878528,584940521521152,4621278158203781376,584940523634688,4621278158203781376,584940523618304,4620715208250359808,21990570213504,4620715208250359808,21990570196992,4620715208252473344,21990568099840,4620715208252456960,21990568099840,4620715208250360064,21990570213504,4620715208250360064,21990570196992,4620715208252473600,21990568099840,4620715208252457216,21990568099840,4693335752243691648,72642534561694080,4693335752243675136,72642534561677568,4693335752241577984,72642534559580416,4693335752241577984,72642534559580416,4621278158205894784,584940521521152,4621278158205878272,584940521521152,4621278158203781120,584940523634688,4621278158203781120,584940523618304,4620715208250228736,21990568231168,4620715208250228736,21990568231168,4620715208252342272,21990570344704,4620715208252325888,21990570328320,4620715208250359808,21990570213504,4620715208250359808,21990570196992,4620715208252473344,21990568099840,4620715208252456960,21990568099840,4693335752243691648,72642534561693824,4693335752243675136,72642534561677312,4693335752241577984,72642534559580160,4693335752241577984,72642534559580160,4621278158205763712,584940523766144,4621278158205747200,584940523749632,4621278158203650048,584940521652480,4621278158203650048,584940521652480,4620715208250228736,21990568230912,4620715208250228736,21990568230912,4620715208252342272,21990570344448,4620715208252325888,21990570328064,4620715208250228736,21990568231168,4620715208250228736,21990568231168,4620715208252342272,21990570344704,4620715208252325888,21990570328320,81649733816435072,72642534561562752,81649733816418560,72642534561546240,81649733814321408,72642534559449088,81649733814321408,72642534559449088,4621278158205763712,584940523765888,4621278158205747200,584940523749376,4621278158203650048,584940521652224,4621278158203650048,584940521652224,9029189822972160,21990568099840,9029189822972160,21990568099840,9029189825085696,21990570213376,9029189825069312,21990570196992,4620715208250228736,21990568230912,4620715208250228736,21990568230912,4620715208252342272,21990570344448,4620715208252325888,21990570328064,81649733816434816,72642534561562752,81649733816418304,72642534561546240,81649733814321152,72642534559449088,81649733814321152,72642534559449088,9592139778507136,584940523634816,9592139778490624,584940523618304,9592139776393472,584940521521152,9592139776393472,584940521521152,9029189822971904,21990568099840,9029189822971904,21990568099840,9029189825085440,21990570213376,9029189825069056,21990570196992,9029189822972160,21990568099840,9029189822972160,21990568099840,9029189825085696,21990570213376,9029189825069312,21990570196992,81649733816303744,72642534561694080,81649733816287232,72642534561677568,81649733814190080,72642534559580416,81649733814190080,72642534559580416,9592139778506880,584940523634816,9592139778490368,584940523618304,9592139776393216,584940521521152,9592139776393216,584940521521152,9029189822840832,21990568231168,9029189822840832,21990568231168,9029189824954368,21990570344704,9029189824937984,21990570328320,9029189822971904,21990568099840,9029189822971904,21990568099840,9029189825085440,21990570213376,9029189825069056,21990570196992,81649733816303744,72642534561693824,81649733816287232,72642534561677312,81649733814190080,72642534559580160,81649733814190080,72642534559580160,9592139778375808,584940523766144,9592139778359296,584940523749632,9592139776262144,584940521652480,9592139776262144,584940521652480,9029189822840832,21990568230912,9029189822840832,21990568230912,9029189824954368,21990570344448,9029189824937984,21990570328064,9029189822840832,21990568231168,9029189822840832,21990568231168,9029189824954368,21990570344704,9029189824937984,21990570328320,4693335752241709312,72642534561562752,4693335752241709312,72642534561546240,4693335752243822848,72642534559449088,4693335752243806464,72642534559449088,9592139778375808,584940523765888,9592139778359296,584940523749376,9592139776262144,584940521652224,9592139776262144,584940521652224,4620715208252473728,21990568099840,4620715208252457216,21990568099840,4620715208250360064,21990570213376,4620715208250360064,21990570196992,9029189822840832,21990568230912,9029189822840832,21990568230912,9029189824954368,21990570344448,9029189824937984,21990570328064,4693335752241709056,72642534561562752,4693335752241709056,72642534561546240,4693335752243822592,72642534559449088,4693335752243806208,72642534559449088,4621278158203781376,584940523634816,4621278158203781376,584940523618304,4621278158205894912,584940521521152,4621278158205878528,584940521521152,4620715208252473472,21990568099840,4620715208252456960,21990568099840,4620715208250359808,21990570213376,4620715208250359808,21990570196992,4620715208252473728,21990568099840,4620715208252457216,21990568099840,4620715208250360064,21990570213376,4620715208250360064,21990570196992,4693335752241577984,72642534559580416,4693335752241577984,72642534559580416,4693335752243691520,72642534561693952,4693335752243675136,72642534561677568,4621278158203781120,584940523634816,4621278158203781120,584940523618304,4621278158205894656,584940521521152,4621278158205878272,584940521521152,4620715208252342400,21990570344832,4620715208252325888,21990570328320,4620715208250228736,21990568231168,4620715208250228736,21990568231168,4620715208252473472,21990568099840,4620715208252456960,21990568099840,4620715208250359808,21990570213376,4620715208250359808,21990570196992,4693335752241577984,72642534559580160,4693335752241577984,72642534559580160,4693335752243691520,72642534561693696,4693335752243675136,72642534561677312,4621278158203650048,584940521652480,4621278158203650048,584940521652480,4621278158205763584,584940523766016,4621278158205747200,584940523749632,4620715208252342400,21990570344576,4620715208252325888,21990570328064,4620715208250228736,21990568230912,4620715208250228736,21990568230912,4620715208252342400,21990570344832,4620715208252325888,21990570328320,4620715208250228736,21990568231168,4620715208250228736,21990568231168,81649733814321408,72642534559449088,81649733814321408,72642534559449088,81649733816434944,72642534561562624,81649733816418560,72642534561546240,4621278158203650048,584940521652224,4621278158203650048,584940521652224,4621278158205763584,584940523765760,4621278158205747200,584940523749376,9029189825085824,21990570213504,9029189825069312,21990570196992,9029189822972160,21990568099840,9029189822972160,21990568099840,4620715208252342400,21990570344576,4620715208252325888,21990570328064,4620715208250228736,21990568230912,4620715208250228736,21990568230912,81649733814321152,72642534559449088,81649733814321152,72642534559449088,81649733816434688,72642534561562624,81649733816418304,72642534561546240,9592139776393472,584940521521152,9592139776393472,584940521521152,9592139778507008,584940523634688,9592139778490624,584940523618304,9029189825085568,21990570213504,9029189825069056,21990570196992,9029189822971904,21990568099840,9029189822971904,21990568099840,9029189825085824,21990570213504,9029189825069312,21990570196992,9029189822972160,21990568099840,9029189822972160,21990568099840,81649733814190080,72642534559580416,81649733814190080,72642534559580416,81649733816303616,72642534561693952,81649733816287232,72642534561677568,9592139776393216,584940521521152,9592139776393216,584940521521152,9592139778506752,584940523634688,9592139778490368,584940523618304,9029189824954496,21990570344832,9029189824937984,21990570328320,9029189822840832,21990568231168,9029189822840832,21990568231168,9029189825085568,21990570213504,9029189825069056,21990570196992,9029189822971904,21990568099840,9029189822971904,21990568099840,81649733814190080,72642534559580160,81649733814190080,72642534559580160,81649733816303616,72642534561693696,81649733816287232,72642534561677312,9592139776262144,584940521652480,9592139776262144,584940521652480,9592139778375680,584940523766016,9592139778359296,584940523749632,9029189824954496,21990570344576,9029189824937984,21990570328064,9029189822840832,21990568230912,9029189822840832,21990568230912,9029189824954496,21990570344832,9029189824937984,21990570328320,9029189822840832,21990568231168,9029189822840832,21990568231168],mask:9592139778506752,magic:2882875541925530112},MagicBishop{attacks:[9386671504487645697,145285069123355136,9386671504487645184,145285069123354624,9386671504487383040,145285069123092480,9386671504487383040,145285069123092480,9242556316411789825,1169881047499264,9242556316411789312,1169881047498752,9242556316411527168,1169881047236608,9242556316411527168,1169881047236608,9386671504483418625,145285069119160832,9386671504483418112,145285069119160320,9386671504483155968,145285069118898176,9386671504483155968,145285069118898176,9242556316407562753,1169881043304960,9242556316407562240,1169881043304448,9242556316407300096,1169881043042304,9242556316407300096,1169881043042304,9386671504487612929,9241430416504947200,9386671504487612416,9241430416504946688,9386671504487350272,9241430416504684544,9386671504487350272,9241430416504684544,9242556316411757057,9241430416504947200,9242556316411756544,9241430416504946688,9242556316411494400,9241430416504684544,9242556316411494400,9241430416504684544,9386671504483418625,9241430416500720128,9386671504483418112,9241430416500719616,9386671504483155968,9241430416500457472,9386671504483155968,9241430416500457472,9242556316407562753,9241430416500720128,9242556316407562240,9241430416500719616,9242556316407300096,9241430416500457472,9242556316407300096,9241430416500457472,43981140689409,9241430416504914432,43981140688896,9241430416504913920,43981140426752,9241430416504651776,43981140426752,9241430416504651776,43981140689409,9241430416504914432,43981140688896,9241430416504913920,43981140426752,9241430416504651776,43981140426752,9241430416504651776,43981136462337,9241430416500720128,43981136461824,9241430416500719616,43981136199680,9241430416500457472,43981136199680,9241430416500457472,43981136462337,9241430416500720128,43981136461824,9241430416500719616,43981136199680,9241430416500457472,43981136199680,9241430416500457472,43981140656641,145285069123387904,43981140656128,145285069123387392,43981140393984,145285069123125248,43981140393984,145285069123125248,43981140656641,1169881047532032,43981140656128,1169881047531520,43981140393984,1169881047269376,43981140393984,1169881047269376,43981136462337,145285069119160832,43981136461824,145285069119160320,43981136199680,145285069118898176,43981136199680,145285069118898176,43981136462337,1169881043304960,43981136461824,1169881043304448,43981136199680,1169881043042304,43981136199680,1169881043042304,163299467632869889,145285069123355136,163299467632869376,145285069123354624,163299467632607232,145285069123092480,163299467632607232,145285069123092480,19184279557014017,1169881047499264,19184279557013504,1169881047498752,19184279556751360,1169881047236608,19184279556751360,1169881047236608,163299467628642817,145285069119160832,163299467628642304,145285069119160320,163299467628380160,145285069118898176,163299467628380160,145285069118898176,19184279552786945,1169881043304960,19184279552786432,1169881043304448,19184279552524288,1169881043042304,19184279552524288,1169881043042304,163299467632837121,18058379650171392,163299467632836608,18058379650170880,163299467632574464,18058379649908736,163299467632574464,18058379649908736,19184279556981249,18058379650171392,19184279556980736,18058379650170880,19184279556718592,18058379649908736,19184279556718592,18058379649908736,163299467628642817,18058379645944320,163299467628642304,18058379645943808,163299467628380160,18058379645681664,163299467628380160,18058379645681664,19184279552786945,18058379645944320,19184279552786432,18058379645943808,19184279552524288,18058379645681664,19184279552524288,18058379645681664,43981140689409,18058379650138624,43981140688896,18058379650138112,43981140426752,18058379649875968,43981140426752,18058379649875968,43981140689409,18058379650138624,43981140688896,18058379650138112,43981140426752,18058379649875968,43981140426752,18058379649875968,43981136462337,18058379645944320,43981136461824,18058379645943808,43981136199680,18058379645681664,43981136199680,18058379645681664,43981136462337,18058379645944320,43981136461824,18058379645943808,43981136199680,18058379645681664,43981136199680,18058379645681664,43981140656641,9386671504487645696,43981140656128,9386671504487645184,43981140393984,9386671504487383040,43981140393984,9386671504487383040,43981140656641,9242556316411789824,43981140656128,9242556316411789312,43981140393984,9242556316411527168,43981140393984,9242556316411527168,43981136462337,9386671504483418624,43981136461824,9386671504483418112,43981136199680,9386671504483155968,43981136199680,9386671504483155968,43981136462337,9242556316407562752,43981136461824,9242556316407562240,43981136199680,9242556316407300096,43981136199680,9242556316407300096,145285069123387905,9386671504487612928,145285069123387392,9386671504487612416,145285069123125248,9386671504487350272,145285069123125248,9386671504487350272,1169881047532033,9242556316411757056,1169881047531520,9242556316411756544,1169881047269376,9242556316411494400,1169881047269376,9242556316411494400,145285069119160833,9386671504483418624,145285069119160320,9386671504483418112,145285069118898176,9386671504483155968,145285069118898176,9386671504483155968,1169881043304961,9242556316407562752,1169881043304448,9242556316407562240,1169881043042304,9242556316407300096,1169881043042304,9242556316407300096,145285069123355137,43981140689408,145285069123354624,43981140688896,145285069123092480,43981140426752,145285069123092480,43981140426752,1169881047499265,43981140689408,1169881047498752,43981140688896,1169881047236608,43981140426752,1169881047236608,43981140426752,145285069119160833,43981136462336,145285069119160320,43981136461824,145285069118898176,43981136199680,145285069118898176,43981136199680,1169881043304961,43981136462336,1169881043304448,43981136461824,1169881043042304,43981136199680,1169881043042304,43981136199680,9241430416504947201,43981140656640,9241430416504946688,43981140656128,9241430416504684544,43981140393984,9241430416504684544,43981140393984,9241430416504947201,43981140656640,9241430416504946688,43981140656128,9241430416504684544,43981140393984,9241430416504684544,43981140393984,9241430416500720129,43981136462336,9241430416500719616,43981136461824,9241430416500457472,43981136199680,9241430416500457472,43981136199680,9241430416500720129,43981136462336,9241430416500719616,43981136461824,9241430416500457472,43981136199680,9241430416500457472,43981136199680,9241430416504914433,163299467632869888,9241430416504913920,163299467632869376,9241430416504651776,163299467632607232,9241430416504651776,163299467632607232,9241430416504914433,19184279557014016,9241430416504913920,19184279557013504,9241430416504651776,19184279556751360,9241430416504651776,19184279556751360,9241430416500720129,163299467628642816,9241430416500719616,163299467628642304,9241430416500457472,163299467628380160,9241430416500457472,163299467628380160,9241430416500720129,19184279552786944,9241430416500719616,19184279552786432,9241430416500457472,19184279552524288,9241430416500457472,19184279552524288,145285069123387905,163299467632837120,145285069123387392,163299467632836608,145285069123125248,163299467632574464,145285069123125248,163299467632574464,1169881047532033,19184279556981248,1169881047531520,19184279556980736,1169881047269376,19184279556718592,1169881047269376,19184279556718592,145285069119160833,163299467628642816,145285069119160320,163299467628642304,145285069118898176,163299467628380160,145285069118898176,163299467628380160,1169881043304961,19184279552786944,1169881043304448,19184279552786432,1169881043042304,19184279552524288,1169881043042304,19184279552524288,145285069123355137,43981140689408,145285069123354624,43981140688896,145285069123092480,43981140426752,145285069123092480,43981140426752,1169881047499265,43981140689408,1169881047498752,43981140688896,1169881047236608,43981140426752,1169881047236608,43981140426752,145285069119160833,43981136462336,145285069119160320,43981136461824,145285069118898176,43981136199680,145285069118898176,43981136199680,1169881043304961,43981136462336,1169881043304448,43981136461824,1169881043042304,43981136199680,1169881043042304,43981136199680,18058379650171393,43981140656640,18058379650170880,43981140656128,18058379649908736,43981140393984,18058379649908736,43981140393984,18058379650171393,43981140656640,18058379650170880,43981140656128,18058379649908736,43981140393984,18058379649908736,43981140393984,18058379645944321,43981136462336,18058379645943808,43981136461824,18058379645681664,43981136199680,18058379645681664,43981136199680,18058379645944321,43981136462336,18058379645943808,43981136461824,18058379645681664,43981136199680,18058379645681664,43981136199680,18058379650138625,145285069123387904,18058379650138112,145285069123387392,18058379649875968,145285069123125248,18058379649875968,145285069123125248,18058379650138625,1169881047532032,18058379650138112,1169881047531520,18058379649875968,1169881047269376,18058379649875968,1169881047269376,18058379645944321,145285069119160832,18058379645943808,145285069119160320,18058379645681664,145285069118898176,18058379645681664,145285069118898176,18058379645944321,1169881043304960,18058379645943808,1169881043304448,18058379645681664,1169881043042304,18058379645681664,1169881043042304],mask:19184279556981248,magic:15833243392934144},MagicBishop{attacks:[326598935265674242,0,0,0,38368559113962498,0,0,0,290570138238320640,0,0,0,2339762086608896,0,0,0,0,0,87962280787968,0,0,0,87962280787968,0,0,0,87962272399360,0,0,0,87962272399360,0,0,0,326598935265148928,0,0,0,38368559113437184,0,0,0,290570138237796352,0,0,0,2339762086084608,0,326598935265674240,0,0,0,38368559113962496,0,0,0,290570138238320640,0,0,0,2339762086608896,0,0,0,36116759300277250,0,0,0,36116759300277250,0,0,0,87962272923648,0,0,0,87962272923648,0,0,0,0,0,326598935265148928,0,0,0,38368559113437184,0,0,0,290570138237796352,0,0,0,2339762086084608,0,0,0,36116759299751936,0,0,0,36116759299751936,0,0,0,87962272399360,0,0,0,87962272399360,0,36116759300277248,0,0,0,36116759300277248,0,0,0,87962272923648,0,0,0,87962272923648,0,0,0,326598935265673216,0,0,0,38368559113961472,0,0,0,326598935257285634,0,0,0,38368559105573890,0,0,0,0,0,36116759299751936,0,0,0,36116759299751936,0,0,0,87962272399360,0,0,0,87962272399360,0,0,0,326598935265148928,0,0,0,38368559113437184,0,0,0,326598935256760320,0,0,0,38368559105048576,0,326598935265673216,0,0,0,38368559113961472,0,0,0,326598935257285632,0,0,0,38368559105573888,0,0,0,36116759300276224,0,0,0,36116759300276224,0,0,0,36116759291888642,0,0,0,36116759291888642,0,0,0,0,0,326598935265148928,0,0,0,38368559113437184,0,0,0,326598935256760320,0,0,0,38368559105048576,0,0,0,36116759299751936,0,0,0,36116759299751936,0,0,0,36116759291363328,0,0,0,36116759291363328,0,36116759300276224,0,0,0,36116759300276224,0,0,0,36116759291888640,0,0,0,36116759291888640,0,0,0,290570138246710274,0,0,0,2339762094998530,0,0,0,326598935257284608,0,0,0,38368559105572864,0,0,0,0,0,36116759299751936,0,0,0,36116759299751936,0,0,0,36116759291363328,0,0,0,36116759291363328,0,0,0,290570138246184960,0,0,0,2339762094473216,0,0,0,326598935256760320,0,0,0,38368559105048576,0,290570138246710272,0,0,0,2339762094998528,0,0,0,326598935257284608,0,0,0,38368559105572864,0,0,0,87962281313282,0,0,0,87962281313282,0,0,0,36116759291887616,0,0,0,36116759291887616,0,0,0,0,0,290570138246184960,0,0,0,2339762094473216,0,0,0,326598935256760320,0,0,0,38368559105048576,0,0,0,87962280787968,0,0,0,87962280787968,0,0,0,36116759291363328,0,0,0,36116759291363328,0,87962281313280,0,0,0,87962281313280,0,0,0,36116759291887616,0,0,0,36116759291887616,0,0,0,290570138246709248,0,0,0,2339762094997504,0,0,0,290570138238321666,0,0,0,2339762086609922,0,0,0,0,0,87962280787968,0,0,0,87962280787968,0,0,0,36116759291363328,0,0,0,36116759291363328,0,0,0,290570138246184960,0,0,0,2339762094473216,0,0,0,290570138237796352,0,0,0,2339762086084608,0,290570138246709248,0,0,0,2339762094997504,0,0,0,290570138238321664,0,0,0,2339762086609920,0,0,0,87962281312256,0,0,0,87962281312256,0,0,0,87962272924674,0,0,0,87962272924674,0,0,0,0,0,290570138246184960,0,0,0,2339762094473216,0,0,0,290570138237796352,0,0,0,2339762086084608,0,0,0,87962280787968,0,0,0,87962280787968,0,0,0,87962272399360,0,0,0,87962272399360,0,87962281312256,0,0,0,87962281312256,0,0,0,87962272924672,0,0,0,87962272924672,0,0,0],mask:2339762086609920,magic:2307540659730513986},MagicBishop{attacks:[581140276476643332,0,581140276476643328,0,0,0,0,0,0,581140276476641280,0,581140276476641280,0,0,0,0,0,0,0,0,581140276475592704,0,581140276475592704,0,0,0,0,0,0,581140276475592704,0,581140276475592704,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,175924545849348,0,175924545849344,0,0,0,0,0,0,175924545847296,0,175924545847296,0,0,0,0,0,0,0,0,175924544798720,0,175924544798720,0,0,0,0,0,0,175924544798720,0,175924544798720,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4679524173219844,0,4679524173219840,0,0,0,0,0,0,4679524173217792,0,4679524173217792,0,0,0,0,0,0,0,0,4679524172169216,0,4679524172169216,0,0,0,0,0,0,4679524172169216,0,4679524172169216,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,175924545849348,0,175924545849344,0,0,0,0,0,0,175924545847296,0,175924545847296,0,0,0,0,0,0,0,0,175924544798720,0,175924544798720,0,0,0,0,0,0,175924544798720,0,175924544798720,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:4538784537380864,magic:9042694206703616},MagicBishop{attacks:[1161999073681608712,1161999073681608704,0,0,1161999073681604608,1161999073681604608,1161999073679507456,1161999073679507456,0,0,1161999073679507456,1161999073679507456,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,70369820020744,70369820020736,0,0,70369820016640,70369820016640,70369817919488,70369817919488,0,0,70369817919488,70369817919488,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,9077569074761736,9077569074761728,0,0,9077569074757632,9077569074757632,9077569072660480,9077569072660480,0,0,9077569072660480,9077569072660480,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,70369820020744,70369820020736,0,0,70369820016640,70369820016640,70369817919488,70369817919488,0,0,70369817919488,70369817919488,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:9077569074761728,magic:9016064276988420},MagicBishop{attacks:[288793334762704928,288793334695067648,0,0,0,0,0,0,0,288793334762176512,288793334695067648,0,0,0,0,0,562958610993184,562958543355904,0,0,0,0,0,0,0,562958610464768,562958543355904,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,288793334695067648,0,288793334762700800,0,0,0,0,0,0,288793334695067648,0,288793334762176512,0,0,0,0,562958543355904,0,562958610989056,0,0,0,0,0,0,562958543355904,0,562958610464768,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,288793334762704896,288793334695067648,0,0,0,0,0,0,0,288793334762176512,288793334695067648,0,0,0,0,0,562958610993152,562958543355904,0,0,0,0,0,0,0,562958610464768,562958543355904,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,288793334762700800,0,0,0,288793334695067648,0,0,0,0,288793334762176512,0,0,0,288793334695067648,0,0,562958610989056,0,0,0,562958543355904,0,0,0,0,562958610464768,0,0,0,562958543355904,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:562958610993152,magic:182422178023605248},MagicBishop{attacks:[577868148797087808,0,0,0,577868148797087744,0,577868148797079552,0,0,0,577868148797079552,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,577868148661813248,0,0,0,577868148661813248,0,577868148661813248,0,0,0,577868148661813248,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1407396493664320,0,0,0,1407396493664256,0,1407396493656064,0,0,0,1407396493656064,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1407396358389760,0,0,0,1407396358389760,0,1407396358389760,0,0,0,1407396358389760,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,577868148796030976,0,0,0,577868148796030976,0,577868148796030976,0,0,0,577868148796030976,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,577868148661813248,0,0,0,577868148661813248,0,577868148661813248,0,0,0,577868148661813248,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1407396492607488,0,0,0,1407396492607488,0,1407396492607488,0,0,0,1407396492607488,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1407396358389760,0,0,0,1407396358389760,0,1407396358389760,0,0,0,1407396358389760,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:1125917221986304,magic:17798487084065},MagicBishop{attacks:[1227793891648880768,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1227793891632103552,1227793891648864256,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1227793891632087040,1155736297610952832,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1155736297594175616,1155736297610936320,0,0,0,0,0,0,0,0,0,0,0,0,1227793891378331648,0,0,1155736297594159104,1227793891648880640,0,0,0,0,0,0,0,0,0,0,0,1227793891361554432,1227793891378331648,0,0,1227793891632103424,1227793891648864256,0,0,0,0,0,0,0,0,0,0,0,1227793891361554432,1155736297340403712,0,0,1227793891632087040,1155736297610952704,0,0,0,0,0,0,0,0,0,0,0,1155736297323626496,1155736297340403712,0,0,1155736297594175488,1155736297610936320,0,0,0,0,0,0,0,0,1227793891646767104,0,0,1155736297323626496,1227793891378331648,0,0,1155736297594159104,74872387042033792,0,0,0,0,0,0,0,1227793891629989888,1227793891646767104,0,0,1227793891361554432,1227793891378331648,0,0,74872387025256576,74872387042017280,0,0,0,0,0,0,0,1227793891629989888,1155736297608839168,0,0,1227793891361554432,1155736297340403712,0,0,74872387025240064,2814793004105856,0,0,0,0,0,0,0,1155736297592061952,1155736297608839168,0,0,1155736297323626496,1155736297340403712,0,0,2814792987328640,2814793004089344,0,0,0,0,1227793891378331648,0,0,1155736297592061952,1227793891646767104,0,0,1155736297323626496,74872386771484672,0,0,2814792987312128,74872387042033664,0,0,0,1227793891361554432,1227793891378331648,0,0,1227793891629989888,1227793891646767104,0,0,74872386754707456,74872386771484672,0,0,74872387025256448,74872387042017280,0,0,0,1227793891361554432,1155736297340403712,0,0,1227793891629989888,1155736297608839168,0,0,74872386754707456,2814792733556736,0,0,74872387025240064,2814793004105728,0,0,0,1155736297323626496,1155736297340403712,0,0,1155736297592061952,1155736297608839168,0,0,2814792716779520,2814792733556736,0,0,2814792987328512,2814793004089344,0,0,0,1155736297323626496,1227793891378331648,0,0,1155736297592061952,0,74872387039920128,0,2814792716779520,74872386771484672,0,0,2814792987312128,0,0,0,0,1227793891361554432,1227793891378331648,0,0,0,74872387023142912,74872387039920128,0,74872386754707456,74872386771484672,0,0,0,0,0,0,0,1227793891361554432,1155736297340403712,0,0,0,74872387023142912,2814793001992192,0,74872386754707456,2814792733556736,0,0,0,0,0,0,0,1155736297323626496,1155736297340403712,0,0,0,2814792985214976,2814793001992192,0,2814792716779520,2814792733556736,0,0,0,0,0,0,0,1155736297323626496,0,74872386771484672,0,0,2814792985214976,74872387039920128,0,2814792716779520,0,0,0,0,0,0,0,0,0,74872386754707456,74872386771484672,0,0,74872387023142912,74872387039920128,0,0,0,0,0,0,0,0,0,0,0,74872386754707456,2814792733556736,0,0,74872387023142912,2814793001992192,0,0,0,0,0,0,0,0,0,0,0,2814792716779520,2814792733556736,0,0,2814792985214976,2814793001992192,0,0,0,0,0,0,0,0,0,0,0,2814792716779520,74872386771484672,0,0,2814792985214976,0,0,0,0,0,0,0,0,0,0,0,0,74872386754707456,74872386771484672,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,74872386754707456,2814792733556736,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2814792716779520,2814792733556736,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2814792716779520,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:2814792987328512,magic:288380201857976456},MagicBishop{attacks:[2455587783297826816,0,2455587783293599744,0,2455587783297794048,0,2455587783293599744,0,0,0,0,0,0,0,149744773543034880,0,149744773543034880,0,149744773543034880,0,149744773543034880,0,0,0,0,0,0,0,0,0,0,0,2455587783297761280,0,2455587783293534208,0,2455587783297728512,0,2455587783293534208,0,0,0,0,0,0,0,149744773542969344,0,149744773542969344,0,149744773542969344,0,149744773542969344,0,0,0,0,0,0,0,0,0,0,0,2311472595221970944,0,2311472595217743872,2455587783264206848,2311472595221938176,2455587783259979776,2311472595217743872,2455587783264174080,0,2455587783259979776,0,0,0,0,5629585467179008,0,5629585467179008,149744773509414912,5629585467179008,149744773509414912,5629585467179008,149744773509414912,0,149744773509414912,0,0,0,0,0,0,0,0,2311472595221905408,0,2311472595217678336,2455587783264206848,2311472595221872640,2455587783259979776,2311472595217678336,2455587783264174080,0,2455587783259979776,0,0,0,0,5629585467113472,0,5629585467113472,149744773509414912,5629585467113472,149744773509414912,5629585467113472,149744773509414912,0,149744773509414912,0,0,0,0,0,0,0,0,0,0,0,2311472595188350976,0,2311472595184123904,0,2311472595188318208,0,2311472595184123904,0,0,0,0,0,0,0,5629585433559040,0,5629585433559040,0,5629585433559040,0,5629585433559040,0,0,0,0,0,0,0,0,0,0,0,2311472595188350976,0,2311472595184123904,0,2311472595188318208,0,2311472595184123904,0,0,0,0,0,0,0,5629585433559040,0,5629585433559040,0,5629585433559040,0,5629585433559040,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,149744774084132864,0,149744774079905792,0,149744774084100096,0,149744774079905792,0,0,0,0,0,0,0,2455587782756728832,0,2455587782756728832,0,2455587782756728832,0,2455587782756728832,0,0,0,0,0,0,0,0,0,0,0,149744774084067328,0,149744774079840256,0,149744774084034560,0,149744774079840256,0,0,0,0,0,0,0,2455587782756663296,0,2455587782756663296,0,2455587782756663296,0,2455587782756663296,0,0,0,0,0,0,0,0,0,0,0,5629586008276992,0,5629586004049920,149744774050512896,5629586008244224,149744774046285824,5629586004049920,149744774050480128,0,149744774046285824,0,0,0,0,2311472594680872960,0,2311472594680872960,2455587782723108864,2311472594680872960,2455587782723108864,2311472594680872960,2455587782723108864,0,2455587782723108864,0,0,0,0,0,0,0,0,5629586008211456,0,5629586003984384,149744774050512896,5629586008178688,149744774046285824,5629586003984384,149744774050480128,0,149744774046285824,0,0,0,0,2311472594680807424,0,2311472594680807424,2455587782723108864,2311472594680807424,2455587782723108864,2311472594680807424,2455587782723108864,0,2455587782723108864,0,0,0,0,0,0,0,0,0,0,0,5629585974657024,0,5629585970429952,0,5629585974624256,0,5629585970429952,0,0,0,0,0,0,0,2311472594647252992,0,2311472594647252992,0,2311472594647252992,0,2311472594647252992,0,0,0,0,0,0,0,0,0,0,0,5629585974657024,0,5629585970429952,0,5629585974624256,0,5629585970429952,0,0,0,0,0,0,0,2311472594647252992,0,2311472594647252992,0,2311472594647252992,0,2311472594647252992,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:5629586008178688,magic:4683814015710791680},MagicBishop{attacks:[4911175566595588352,0,4911175565513457920,0,0,0,0,0,4622945190443876608,0,4622945189361746176,0,0,0,0,0,0,0,0,0,4911175565446217728,0,4911175566519959552,0,0,0,0,0,4622945189294505984,0,4622945190368247808,0,299489548168200448,0,299489547086070016,0,0,0,0,0,11259172016488704,0,11259170934358272,0,0,0,0,0,0,0,0,0,299489547018829824,0,299489548092571648,0,0,0,0,0,11259170867118080,0,11259171940859904,0,4911175566528348160,0,4911175565446217728,0,0,0,0,0,4622945190376636416,0,4622945189294505984,0,0,0,0,0,4911175566595457024,0,4911175565513326592,0,0,0,0,0,4622945190443745280,0,4622945189361614848,0,0,0,0,0,299489548100960256,0,299489547018829824,0,0,0,0,0,11259171949248512,0,11259170867118080,0,0,0,0,0,299489548168069120,0,299489547085938688,0,0,0,0,0,11259172016357376,0,11259170934226944,0,0,0,0,0,0,0,0,0,4911175566595588096,0,4911175565513457664,0,0,0,0,0,4622945190443876352,0,4622945189361745920,0,4911175566528348160,0,4911175565446217728,0,0,0,0,0,4622945190376636416,0,4622945189294505984,0,0,0,0,0,0,0,0,0,299489548168200192,0,299489547086069760,0,0,0,0,0,11259172016488448,0,11259170934358016,0,299489548100960256,0,299489547018829824,0,0,0,0,0,11259171949248512,0,11259170867118080,0,0,0,0,0,0,0,0,0,4911175566528348160,0,4911175565446217728,0,0,0,0,0,4622945190376636416,0,4622945189294505984,0,0,0,0,0,4911175566595457024,0,4911175565513326592,0,0,0,0,0,4622945190443745280,0,4622945189361614848,0,0,0,0,0,299489548100960256,0,299489547018829824,0,0,0,0,0,11259171949248512,0,11259170867118080,0,0,0,0,0,299489548168069120,0,299489547085938688,0,0,0,0,0,11259172016357376,0,11259170934226944,0,4911175565513457920,0,4911175566587199744,0,0,0,0,0,4622945189361746176,0,4622945190435488000,0,0,0,0,0,0,0,0,0,4911175566528348160,0,4911175565446217728,0,0,0,0,0,4622945190376636416,0,4622945189294505984,0,299489547086070016,0,299489548159811840,0,0,0,0,0,11259170934358272,0,11259172008100096,0,0,0,0,0,0,0,0,0,299489548100960256,0,299489547018829824,0,0,0,0,0,11259171949248512,0,11259170867118080,0,4911175565446217728,0,4911175566519959552,0,0,0,0,0,4622945189294505984,0,4622945190368247808,0,0,0,0,0,4911175565513326592,0,4911175566587068416,0,0,0,0,0,4622945189361614848,0,4622945190435356672,0,0,0,0,0,299489547018829824,0,299489548092571648,0,0,0,0,0,11259170867118080,0,11259171940859904,0,0,0,0,0,299489547085938688,0,299489548159680512,0,0,0,0,0,11259170934226944,0,11259172007968768,0,0,0,0,0,0,0,0,0,4911175565513457664,0,4911175566587199488,0,0,0,0,0,4622945189361745920,0,4622945190435487744,0,4911175565446217728,0,4911175566519959552,0,0,0,0,0,4622945189294505984,0,4622945190368247808,0,0,0,0,0,0,0,0,0,299489547086069760,0,299489548159811584,0,0,0,0,0,11259170934358016,0,11259172008099840,0,299489547018829824,0,299489548092571648,0,0,0,0,0,11259170867118080,0,11259171940859904,0,0,0,0,0,0,0,0,0,4911175565446217728,0,4911175566519959552,0,0,0,0,0,4622945189294505984,0,4622945190368247808,0,0,0,0,0,4911175565513326592,0,4911175566587068416,0,0,0,0,0,4622945189361614848,0,4622945190435356672,0,0,0,0,0,299489547018829824,0,299489548092571648,0,0,0,0,0,11259170867118080,0,11259171940859904,0,0,0,0,0,299489547085938688,0,299489548159680512,0,0,0,0,0,11259170934226944,0,11259172007968768,0],mask:11259172008099840,magic:578748879017615488},MagicBishop{attacks:[9822351133174399489,598979096319623681,9822351133174399488,598979096319623680,0,0,0,0,0,0,0,0,9245890378589011968,22518341734236160,9245890378589011968,22518341734236160,9822351133174398976,598979096319623168,9822351133174398976,598979096319623168,0,0,0,0,0,0,0,0,0,0,0,0,9822351131026915841,598979094172140033,9822351131026915840,598979094172140032,0,0,0,0,0,0,0,0,0,0,0,0,9822351131026915328,598979094172139520,9822351131026915328,598979094172139520,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,9822351133174136832,598979096319361024,9822351133174136832,598979096319361024,0,0,0,0,0,0,0,0,0,0,0,0,9822351133174136832,598979096319361024,9822351133174136832,598979096319361024,0,0,0,0,0,0,0,0,0,0,0,0,9822351131026653184,598979094171877376,9822351131026653184,598979094171877376,0,0,0,0,0,0,0,0,9245890380870976001,22518344016200193,9245890380870976000,22518344016200192,9822351131026653184,598979094171877376,9822351131026653184,598979094171877376,0,0,0,0,0,0,0,0,9245890380870975488,22518344016199680,9245890380870975488,22518344016199680,0,0,0,0,0,0,0,0,0,0,0,0,9245890378723492353,22518341868716545,9245890378723492352,22518341868716544,0,0,0,0,0,0,0,0,0,0,0,0,9245890378723491840,22518341868716032,9245890378723491840,22518341868716032,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,9245890380870713344,22518344015937536,9245890380870713344,22518344015937536,0,0,0,0,0,0,0,0,0,0,0,0,9245890380870713344,22518344015937536,9245890380870713344,22518344015937536,0,0,0,0,0,0,0,0,0,0,0,0,9245890378723229696,22518341868453888,9245890378723229696,22518341868453888,0,0,0,0,0,0,0,0,0,0,0,0,9245890378723229696,22518341868453888,9245890378723229696,22518341868453888,9822351133039919104,598979096185143296,9822351133039919104,598979096185143296,0,0,0,0,0,0,0,0,0,0,0,0,9822351133039919104,598979096185143296,9822351133039919104,598979096185143296,0,0,0,0,0,0,0,0,0,0,0,0,9822351130892435456,598979094037659648,9822351130892435456,598979094037659648,0,0,0,0,0,0,0,0,0,0,0,0,9822351130892435456,598979094037659648,9822351130892435456,598979094037659648,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,9822351133039919104,598979096185143296,9822351133039919104,598979096185143296,0,0,0,0,0,0,0,0,0,0,0,0,9822351133039919104,598979096185143296,9822351133039919104,598979096185143296,0,0,0,0,0,0,0,0,0,0,0,0,9822351130892435456,598979094037659648,9822351130892435456,598979094037659648,0,0,0,0,0,0,0,0,9245890380736495616,22518343881719808,9245890380736495616,22518343881719808,9822351130892435456,598979094037659648,9822351130892435456,598979094037659648,0,0,0,0,0,0,0,0,9245890380736495616,22518343881719808,9245890380736495616,22518343881719808,0,0,0,0,0,0,0,0,0,0,0,0,9245890378589011968,22518341734236160,9245890378589011968,22518341734236160,0,0,0,0,0,0,0,0,0,0,0,0,9245890378589011968,22518341734236160,9245890378589011968,22518341734236160,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,9245890380736495616,22518343881719808,9245890380736495616,22518343881719808,0,0,0,0,0,0,0,0,0,0,0,0,9245890380736495616,22518343881719808,9245890380736495616,22518343881719808,0,0,0,0,0,0,0,0,0,0,0,0,9245890378589011968,22518341734236160,9245890378589011968,22518341734236160],mask:22518341868716544,magic:142959198602242},MagicBishop{attacks:[1197958188344280066,1197958188344280064,0,45036683737433090,0,45036683737433088,0,0,0,0,0,0,0,0,0,0,0,0,1197958188343754752,1197958188343754752,0,45036683736907776,0,45036683736907776,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1197958188075319296,1197958188075319296,0,45036683468472320,0,45036683468472320,0,0,0,0,0,0,0,0,0,0,0,0,1197958188075319296,1197958188075319296,0,45036683468472320,0,45036683468472320,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1197958188344279040,1197958188344279040,0,45036683737432064,0,45036683737432064,0,0,0,0,0,0,0,0,0,0,0,0,1197958188343754752,1197958188343754752,0,45036683736907776,0,45036683736907776,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1197958188075319296,1197958188075319296,0,45036683468472320,0,45036683468472320,0,0,0,0,0,0,0,0,0,0,0,0,1197958188075319296,1197958188075319296,0,45036683468472320,0,45036683468472320,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:9007336962655232,magic:52779007610894},MagicBishop{attacks:[2323857683139004420,0,0,0,2323857682601082880,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2323857683139004416,0,0,0,2323857682601082880,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,18014673924259840,0,0,0,18014673387388928,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,18014673924259840,0,0,0,18014673387388928,0,0,0,0,0,0,0,2323857683137953792,0,0,0,2323857682601082880,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2323857683137953792,0,0,0,2323857682601082880,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,18014673925310468,0,0,0,18014673387388928,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,18014673925310464,0,0,0,18014673387388928,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2323857683139002368,0,0,0,2323857682601082880,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2323857683139002368,0,0,0,2323857682601082880,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,18014673924259840,0,0,0,18014673387388928,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,18014673924259840,0,0,0,18014673387388928,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2323857683137953792,0,0,0,2323857682601082880,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2323857683137953792,0,0,0,2323857682601082880,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,18014673925308416,0,0,0,18014673387388928,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,18014673925308416,0,0,0,18014673387388928,0,0,0,0,0,0,0],mask:18014673925310464,magic:9579091330597280},MagicBishop{attacks:[144117404414255168,144117387099111424,0,0,0,0,0,0,0,0,0,0,0,0,0,0,144117404413198336,144117387099111424,0,0,0,0,0,0,144117404278980608,144117387099111424,0,0,0,0,0,0,0,0,0,0,0,0,0,0,144117404278980608,144117387099111424,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,144117404414255104,144117387099111424,0,0,0,0,0,0,0,0,0,0,0,0,0,0,144117404413198336,144117387099111424,0,0,0,0,0,0,144117404278980608,144117387099111424,0,0,0,0,0,144117404414246912,144117387099111424,0,0,0,0,0,0,0,144117404278980608,144117387099111424,0,0,0,0,0,144117404413198336,144117387099111424,0,0,0,0,0,0,144117404278980608,144117387099111424,0,0,0,0,0,0,0,0,0,0,0,0,0,0,144117404278980608,144117387099111424,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,144117404414246912,144117387099111424,0,0,0,0,0,0,0,0,0,0,0,0,0,0,144117404413198336,0,144117387099111424,0,0,0,0,0,144117404278980608,144117387099111424,0,0,0,0,0,0,0,0,0,0,0,0,0,0,144117404278980608,0,144117387099111424,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:2216338399232,magic:72626045894811648},MagicBishop{attacks:[360293502378066048,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,360293467747778560,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,360293502378049536,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,360293467747778560,0,0,0,0,0,0,360293502375952384,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,360293467747778560,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,360293502375952384,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,360293467747778560,0,0,0,0,0,0,360293502107516928,0,0,0,0,0,0,0,0,0,0,0,0,0,360293502378065920,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,360293502107516928,0,0,0,0,0,0,0,0,0,0,0,0,0,360293502378049536,0,0,0,0,0,0,0,0,0,0,360293502107516928,0,0,0,0,0,0,0,0,0,0,0,0,0,360293502375952384,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,360293502107516928,0,0,0,0,0,0,0,0,0,0,0,0,0,360293502375952384,0,0,0,0,0,0,360293467747778560,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,360293502107516928,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,360293467747778560,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,360293502107516928,0,0,0,0,0,0,360293467747778560,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,360293502107516928,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,360293467747778560,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,360293502107516928,0,0,0,0,0,0,360293467747778560,0,0,0,0,0,0,0,0,0,0,0,0,0,360293467747778560,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,360293467747778560,0,0,0,0,0,0,0,0,0,0,0,0,0,360293467747778560,0,0,0,0,0,0,0,0,0,0,360293467747778560,0,0,0,0,0,0,0,0,0,0,0,0,0,360293467747778560,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,360293467747778560,0,0,0,0,0,0,0,0,0,0,0,0,0,360293467747778560,0,0,0,0,0,0],mask:4432676798464,magic:2306160364483510336},MagicBishop{attacks:[720587009051099136,0,720587008510001152,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,720586939790524416,0,720586939790524416,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,720587004756131840,0,720587004215033856,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,720586935495557120,0,720586935495557120,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,720586939790524416,0,720586939790524416,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,720587009046872064,0,720587008510001152,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,720586935495557120,0,720586935495557120,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,720587004751904768,0,720587004215033856,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,720586939790524416,0,720586939790524416,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,720587009046872064,0,720587008510001152,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,720586935495557120,0,720586935495557120,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,720587004751904768,0,720587004215033856,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,720587009051066368,0,720587008510001152,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,720586939790524416,0,720586939790524416,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,720587004756099072,0,720587004215033856,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,720586935495557120,0,720586935495557120,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:11064376819712,magic:576464138886185040},MagicBishop{attacks:[1441174018118909952,0,0,0,0,1441174009512198144,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1441173879597826048,0,0,0,0,1441173870991114240,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1441174018110521344,0,0,0,0,1441174009503809536,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1441173879597826048,0,0,0,0,1441173870991114240,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1441174017036779520,0,0,0,0,1441174008430067712,0,0,1441174018102132736,0,0,0,0,1441174009512198144,0,0,0,0,0,0,1441173879597826048,0,0,0,0,1441173870991114240,0,0,1441173879581048832,0,0,0,0,1441173870991114240,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1441174017036779520,0,0,0,0,1441174008430067712,0,0,1441174018093744128,0,0,0,0,1441174009503809536,0,0,0,0,0,0,1441173879597826048,0,0,0,0,1441173870991114240,0,0,1441173879581048832,0,0,0,0,1441173870991114240,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1441174017020002304,0,0,0,0,1441174008430067712,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1441173879581048832,0,0,0,0,1441173870991114240,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1441174017020002304,0,0,0,0,1441174008430067712,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1441173879581048832,0,0,0,0,1441173870991114240,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:22137335185408,magic:36169554405138688},MagicBishop{attacks:[2882348036221108224,0,2882348036221042688,0,0,2882348019007619072,2882348036187488256,2882348019007619072,2882348036187488256,0,0,2882348019007619072,0,2882348019007619072,0,0,0,0,0,0,2882347759195717632,0,2882347759195652096,0,0,2882347741982228480,2882347759162097664,2882347741982228480,2882347759162097664,0,0,2882347741982228480,0,2882347741982228480,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2882348034073624576,0,2882348034073559040,0,0,2882348016860135424,2882348034040004608,2882348016860135424,2882348034040004608,0,0,2882348016860135424,0,2882348016860135424,0,0,0,0,0,0,2882347759195717632,0,2882347759195652096,0,0,2882347741982228480,2882347759162097664,2882347741982228480,2882347759162097664,0,0,2882347741982228480,0,2882347741982228480,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:44272556441600,magic:2278190252838928},MagicBishop{attacks:[5764696068147249408,0,5764696068080009216,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5764696068147118080,0,5764696068080009216,0,0,0,0,0,0,0,0,5764696033720270848,0,5764696033720270848,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5764696033720270848,0,5764696033720270848,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5764695518391435520,0,5764695518324195328,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5764695518391304192,0,5764695518324195328,0,0,0,0,0,0,0,0,5764695483964456960,0,5764695483964456960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5764695483964456960,0,5764695483964456960,0,0,5764696068147249152,0,5764696068080009216,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5764696068147118080,0,5764696068080009216,0,0,0,0,0,0,0,0,5764696033720270848,0,5764696033720270848,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5764696033720270848,0,5764696033720270848,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5764695518391435264,0,5764695518324195328,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5764695518391304192,0,5764695518324195328,0,0,0,0,0,0,0,0,5764695483964456960,0,5764695483964456960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5764695483964456960,0,5764695483964456960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:87995357200384,magic:615738324879360},MagicBishop{attacks:[11529391036782871041,11529390967928913920,11529391036782870528,11529390967928913920,0,0,0,0,11529391036648390656,11529390967928913920,11529391036648390656,11529390967928913920,0,0,0,0,11529391036782608384,11529390967928913920,11529391036782608384,11529390967928913920,0,0,0,0,11529391036648390656,11529390967928913920,11529391036648390656,11529390967928913920,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11529391036782871040,11529390967928913920,11529391036782870528,11529390967928913920,0,0,0,0,11529391036648390656,11529390967928913920,11529391036648390656,11529390967928913920,0,0,0,0,11529391036782608384,11529390967928913920,11529391036782608384,11529390967928913920,0,0,0,0,11529391036648390656,11529390967928913920,11529391036648390656,11529390967928913920,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:35253226045952,magic:7066148094521378304},MagicBishop{attacks:[4611756524879479810,4611756524878954496,4611756387171565568,4611756387171565568,0,0,0,0,0,0,0,0,0,0,0,0,4611756524610519040,4611756524610519040,4611756387171565568,4611756387171565568,0,4611756524879478784,4611756524878954496,4611756387171565568,4611756387171565568,0,0,0,0,0,0,0,4611756524879479808,4611756524878954496,4611756387171565568,4611756387171565568,0,4611756524610519040,4611756524610519040,4611756387171565568,4611756387171565568,0,0,0,0,0,0,0,4611756524610519040,4611756524610519040,4611756387171565568,4611756387171565568,0,4611756524879478784,4611756524878954496,4611756387171565568,4611756387171565568,0,0,0,0,0,0,0,0,0,0,0,0,4611756524610519040,4611756524610519040,4611756387171565568,4611756387171565568,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:70506452091904,magic:1127343154267201},MagicBishop{attacks:[567382630219904,567347999932416,0,0,567382359670784,567347999932416,0,0,0,0,0,0,0,0,0,0,567382630219776,567347999932416,0,0,567382359670784,567347999932416,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,567382630203392,567347999932416,0,0,567382359670784,567347999932416,0,0,0,0,0,0,0,0,0,0,567382630203392,567347999932416,0,0,567382359670784,567347999932416,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,562949953421312,562949953421312,0,0,562949953421312,562949953421312,0,0,0,0,0,0,0,0,0,0,562949953421312,562949953421312,0,0,562949953421312,562949953421312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,562949953421312,562949953421312,0,0,562949953421312,562949953421312,0,0,0,0,0,0,0,0,0,0,562949953421312,562949953421312,0,0,562949953421312,562949953421312,0,0,0,567382628106240,567347999932416,0,0,567382359670784,567347999932416,0,0,0,0,0,0,0,0,0,0,567382628106240,567347999932416,0,0,567382359670784,567347999932416,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,567382628106240,567347999932416,0,0,567382359670784,567347999932416,0,0,0,0,0,0,0,0,0,0,567382628106240,567347999932416,0,0,567382359670784,567347999932416,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,562949953421312,562949953421312,0,0,562949953421312,562949953421312,0,0,0,0,0,0,0,0,0,0,562949953421312,562949953421312,0,0,562949953421312,562949953421312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,562949953421312,562949953421312,0,0,562949953421312,562949953421312,0,0,0,0,0,0,0,0,0,0,562949953421312,562949953421312,0,0,562949953421312,562949953421312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:567382630219776,magic:1161728528228480},MagicBishop{attacks:[1416240237150208,0,1416240237117440,1416170976575488,0,1416170976575488,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1407374883553280,0,1407374883553280,0,1407374883553280,0,1407374883553280,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1416239696052224,0,1416239696052224,1416170976575488,0,1416170976575488,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1407374883553280,0,1407374883553280,0,1407374883553280,0,1407374883553280,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1416240232923136,0,1416240232923136,1416170976575488,0,1416170976575488,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1407374883553280,0,1407374883553280,0,1407374883553280,0,1407374883553280,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1416239696052224,0,1416239696052224,1416170976575488,0,1416170976575488,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1407374883553280,0,1407374883553280,0,1407374883553280,0,1407374883553280,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:1134765260406784,magic:18324928656},MagicBishop{attacks:[2833579985862656,0,2833579977474048,0,0,0,0,0,0,0,0,0,0,0,0,0,2832480474234880,0,2832480465846272,0,0,0,0,0,0,0,0,0,0,0,0,0,2833441464778752,0,2833441464778752,0,0,0,0,0,0,0,0,0,0,0,0,0,2832341953150976,0,2832341953150976,0,0,0,0,0,0,0,0,0,0,0,0,0,2815849278734336,0,2815849278734336,0,0,0,0,0,0,0,0,0,0,0,0,0,2814749767106560,0,2814749767106560,0,0,0,0,0,0,0,0,0,0,0,0,0,2815849278734336,0,2815849278734336,0,0,0,0,0,0,0,0,0,0,0,0,0,2814749767106560,0,2814749767106560,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2833578903732224,0,2833578903732224,0,0,0,0,0,0,0,0,0,0,0,0,0,2832479392104448,0,2832479392104448,0,0,0,0,0,0,0,0,0,0,0,0,0,2833441464778752,0,2833441464778752,0,0,0,0,0,0,0,0,0,0,0,0,0,2832341953150976,0,2832341953150976,0,0,0,0,0,0,0,0,0,0,0,0,0,2815849278734336,0,2815849278734336,0,0,0,0,0,0,0,0,0,0,0,0,0,2814749767106560,0,2814749767106560,0,0,0,0,0,0,0,0,0,0,0,0,0,2815849278734336,0,2815849278734336,0,0,0,0,0,0,0,0,0,0,0,0,0,2814749767106560,0,2814749767106560,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:2832480465846272,magic:6070887499314562048},MagicBishop{attacks:[5667164249915392,0,0,5631702852435968,5631702852435968,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5667159954948096,0,0,5631698557468672,5631698557468672,0,0,0,0,0,0,0,5629499534213120,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5666887224524800,5667162102431744,0,0,0,5631702852435968,0,0,0,0,0,0,0,0,0,0,0,5629499534213120,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5666882929557504,5667157807464448,0,0,0,5631698557468672,0,0,0,5664960931692544,0,0,5629499534213120,5629499534213120,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5666887224524800,0,0,0,0,0,0,0,0,0,0,0,5664960931692544,0,0,5629499534213120,5629499534213120,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5666882929557504,0,0,0,0,0,0,5664683906301952,5664958784208896,0,0,0,5629499534213120,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5664683906301952,5664958784208896,0,0,0,5629499534213120,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5664683906301952,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5664683906301952,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5631702852435968,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,5631698557468672,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:5667157807464448,magic:1130299036077568},MagicBishop{attacks:[11334324221640704,0,0,0,0,0,0,0,0,0,0,0,0,11334315614928896,0,0,11334324204863488,0,0,0,0,0,0,0,0,0,0,0,0,11334315614928896,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11329917568417792,0,0,0,0,0,0,0,0,0,0,0,0,11329917568417792,0,0,11329917568417792,0,0,0,0,0,0,0,0,0,0,0,0,11329917568417792,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11263405721649152,0,0,0,0,0,0,0,0,0,0,0,0,11263397114937344,0,0,11263405704871936,11333774465826816,0,0,0,0,0,0,0,0,0,0,0,11263397114937344,11333765859115008,0,0,11333774449049600,0,0,0,0,0,0,0,0,0,0,0,0,11333765859115008,0,11258999068426240,0,0,0,0,0,0,0,0,0,0,0,0,11258999068426240,0,0,11258999068426240,11329367812603904,0,0,0,0,0,0,0,0,0,0,0,11258999068426240,11329367812603904,0,0,11329367812603904,0,0,0,0,0,0,0,0,0,0,0,0,11329367812603904,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11263405721649152,0,0,0,0,0,0,0,0,0,0,0,0,11263397114937344,0,0,11263405704871936,0,0,0,0,0,0,0,0,0,0,0,0,11263397114937344,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11258999068426240,0,0,0,0,0,0,0,0,0,0,0,0,11258999068426240,0,0,11258999068426240,0,0,0,0,0,0,0,0,0,0,0,0,11258999068426240,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:11333774449049600,magic:9077602425873152},MagicBishop{attacks:[22667548931719168,0,22658735625207808,0,22667548898099200,0,22658735625207808,0,22526811443363840,0,22517998136852480,0,22526811409743872,0,22517998136852480,0,0,0,0,0,0,0,0,0,0,0,0,0,22667548931653632,0,22658735625207808,0,22667548898099200,0,22658735625207808,0,22526811443298304,0,22517998136852480,0,22526811409743872,0,22517998136852480,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,22667531718230016,0,22658735625207808,0,22667531718230016,0,22658735625207808,0,22526794229874688,0,22517998136852480,0,22526794229874688,0,22517998136852480,0,0,0,0,0,0,0,0,0,0,0,0,0,22667531718230016,0,22658735625207808,0,22667531718230016,0,22658735625207808,0,22526794229874688,0,22517998136852480,0,22526794229874688,0,22517998136852480,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:22526811443298304,magic:720580368499998736},MagicBishop{attacks:[45053622886727936,45053622886596608,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,45053622819487744,45053622819487744,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,45053622886727680,45053622886596608,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,45053622819487744,45053622819487744,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,45035996273704960,45035996273704960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,45035996273704960,45035996273704960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,45035996273704960,45035996273704960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,45035996273704960,45035996273704960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,45035996273704960,45035996273704960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,45035996273704960,45035996273704960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,45035996273704960,45035996273704960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,45035996273704960,45035996273704960,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,45053588459749376,45053588459749376,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,45053588459749376,45053588459749376,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,45053588459749376,45053588459749376,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,45053588459749376,45053588459749376,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:9024825867763712,magic:18142519362560},MagicBishop{attacks:[18049651735527937,18049651735265280,0,0,18014398509481984,18014398509481984,0,0,0,0,0,0,0,0,0,0,18049582881570816,18049582881570816,0,0,18014398509481984,18014398509481984,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,18049651735527424,18049651735265280,0,0,18014398509481984,18014398509481984,0,0,0,0,0,0,0,0,0,0,18049582881570816,18049582881570816,0,0,18014398509481984,18014398509481984,0,0,18049651601047552,18049651601047552,0,0,18014398509481984,18014398509481984,0,0,0,0,0,0,0,0,0,0,18049582881570816,18049582881570816,0,0,18014398509481984,18014398509481984,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,18049651601047552,18049651601047552,0,0,18014398509481984,18014398509481984,0,0,0,0,0,0,0,0,0,0,18049582881570816,18049582881570816,0,0,18014398509481984,18014398509481984,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,18049651735527936,18049651735265280,0,0,18014398509481984,18014398509481984,0,0,0,0,0,0,0,0,0,0,18049582881570816,18049582881570816,0,0,18014398509481984,18014398509481984,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,18049651735527424,18049651735265280,0,0,18014398509481984,18014398509481984,0,0,0,0,0,0,0,0,0,0,18049582881570816,18049582881570816,0,0,18014398509481984,18014398509481984,0,0,18049651601047552,18049651601047552,0,0,18014398509481984,18014398509481984,0,0,0,0,0,0,0,0,0,0,18049582881570816,18049582881570816,0,0,18014398509481984,18014398509481984,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,18049651601047552,18049651601047552,0,0,18014398509481984,18014398509481984,0,0,0,0,0,0,0,0,0,0,18049582881570816,18049582881570816,0,0,18014398509481984,18014398509481984,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0],mask:18049651735527936,magic:594480717392527368}];
\ No newline at end of file
+// Defines `BISHOPS`, built from `MAGICS` by the build script
+include!(concat!(env!("OUT_DIR"), "/bishops.rs"));
@@ -1,2 +1,4 @@
+mod bishop_magics;
 pub mod bishops;
+mod rook_magics;
 pub mod rooks;
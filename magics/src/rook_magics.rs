@@ -0,0 +1,70 @@
+// Generated by magician, see `magician --help`
+
+pub const NUM_BITS: u8 = 12;
+
+pub const MAGICS: [u64; 64] = [
+    4719773687242571776,
+    5788260286477680640,
+    10475373044657423392,
+    866943066930418692,
+    3458941642575200400,
+    1585267919238005522,
+    1166454920795260992,
+    5440349039206352128,
+    2049208276511359072,
+    74942713558020096,
+    2630454086247385092,
+    1659840347624702464,
+    18165040260057676,
+    9223833836591776770,
+    2312642396640280708,
+    5233393874453467168,
+    216618634081222784,
+    621954196971229184,
+    13724104207337600,
+    41236222065676304,
+    45108667120967944,
+    81638740513147904,
+    10177286911300096,
+    15132130516462223616,
+    2359888955668705865,
+    2035627083279008002,
+    9305617740130697224,
+    2017703136898056736,
+    2458967596104628368,
+    5440357700009592836,
+    577595526250758405,
+    9021493585708224,
+    9531939392495550484,
+    2305852234816244224,
+    9404150513191618560,
+    4831238974699800672,
+    4926949056750030850,
+    9245917522799106179,
+    73184387365340097,
+    6759832663163011,
+    9266160777662840835,
+    2317245223975011616,
+    13839562070053363745,
+    4755818938647990272,
+    9227033421396713488,
+    301745612003606605,
+    2603644640531943456,
+    13853090330007699468,
+    2486005149204529664,
+    5766863892827886624,
+    1301544827813372186,
+    28218039153598480,
+    5190125909193000192,
+    46161905190076752,
+    2324163106319703040,
+    2309362133701378072,
+    101508015135395913,
+    577322907266580498,
+    14699786635987402882,
+    360851129019809794,
+    2887370317574578194,
+    151433555213287714,
+    143285611982852,
+    2306406251501781670,
+];
@@ -17,7 +17,11 @@ use std::io::Result;
 use std::str::FromStr;
 use std::time::Duration;
 use whalecrab_engine::engine::Engine;
+use whalecrab_engine::eval_breakdown::EvalBreakdown;
 use whalecrab_engine::score::Score;
+use whalecrab_engine::search::skill::SkillLevel;
+use whalecrab_engine::toys::EngineKind;
+use whalecrab_lib::engine::SearchLimits;
 use whalecrab_lib::movegen::pieces::piece::PieceColor;
 use whalecrab_lib::{
     bitboard::BitBoard,
@@ -42,12 +46,17 @@ struct App {
     potential_targets: Vec<Square>,
 
     score: Score,
+    /// Every term that went into `score`
+    breakdown: EvalBreakdown,
+    /// What every piece adds to `score` where it stands
+    square_scores: [Score; 64],
+    /// Whether to color pieces by how much their square helps or hurts them
+    show_square_scores: bool,
     /// How long the engine should search for a suggested move
     engine_search_time: Duration,
     /// Whether to show the top engine move in the debug panel
     engine_suggestions: bool,
     engine_suggestion: Option<Move>,
-    last: Option<Move>,
     verbose: bool,
 
     player_white: PlayerType,
@@ -55,6 +64,8 @@ struct App {
 
     focus: Focus,
     fen: Textbox,
+    /// Why the last fen entered was refused
+    fen_error: Option<String>,
     command: Textbox,
     exit: bool,
 }
@@ -69,19 +80,24 @@ impl App {
             potential_targets: Vec::new(),
 
             score: Score::default(),
+            breakdown: EvalBreakdown::default(),
+            square_scores: [Score::default(); 64],
+            show_square_scores: false,
             engine_search_time: Duration::from_millis(500),
             engine_suggestions: false,
             engine_suggestion: None,
             verbose: false,
-            last: None,
 
             player_white: PlayerType::Human,
             player_black: PlayerType::Engine {
                 search_time: Duration::from_secs(3),
+                skill_level: SkillLevel::MAX,
+                kind: EngineKind::default(),
             },
 
             focus: Focus::get_default_menu(),
             fen: Textbox::new(),
+            fen_error: None,
             command: Textbox::new(),
             exit: false,
         };
@@ -97,8 +113,21 @@ impl App {
                 PieceColor::Black => self.player_black,
             };
 
-            if let PlayerType::Engine { search_time } = player {
-                let m = self.engine.search(search_time, u8::MAX).best_move?;
+            if let PlayerType::Engine {
+                search_time,
+                skill_level,
+                kind,
+            } = player
+            {
+                let m = match kind {
+                    EngineKind::Minimax => {
+                        self.engine.set_skill_level(skill_level);
+                        self.engine.search(search_time, u8::MAX).best_move?
+                    }
+                    kind => kind
+                        .build(self.engine.next_seed())
+                        .best_move(&mut self.engine.game, &SearchLimits::duration(search_time))?,
+                };
                 self.play_move(&m);
                 return Some(true);
             }
@@ -149,10 +178,8 @@ impl App {
             Focus::Menu { focus } => {
                 match key_event.code {
                     KeyCode::Char('q') => self.exit(),
-                    KeyCode::Char('c') => {
-                        if key_event.modifiers == KeyModifiers::CONTROL {
-                            self.exit();
-                        }
+                    KeyCode::Char('c') if key_event.modifiers == KeyModifiers::CONTROL => {
+                        self.exit();
                     }
 
                     KeyCode::Esc | KeyCode::Char('m') => self.focus = Focus::Board,
@@ -172,7 +199,7 @@ impl App {
 
                     KeyCode::Left => match focus {
                         MenuFocus::White => {
-                            if let PlayerType::Engine { search_time } = &mut self.player_white {
+                            if let PlayerType::Engine { search_time, .. } = &mut self.player_white {
                                 {
                                     *search_time =
                                         search_time.saturating_sub(Duration::from_secs(1));
@@ -180,7 +207,7 @@ impl App {
                             }
                         }
                         MenuFocus::Black => {
-                            if let PlayerType::Engine { search_time } = &mut self.player_black {
+                            if let PlayerType::Engine { search_time, .. } = &mut self.player_black {
                                 {
                                     *search_time =
                                         search_time.saturating_sub(Duration::from_secs(1));
@@ -190,9 +217,27 @@ impl App {
                         _ => {}
                     },
 
+                    KeyCode::Char('k') => match focus {
+                        MenuFocus::White => self.player_white.cycle_engine_kind(),
+                        MenuFocus::Black => self.player_black.cycle_engine_kind(),
+                        _ => {}
+                    },
+
+                    KeyCode::Char('+') => match focus {
+                        MenuFocus::White => self.player_white.change_skill_level(1),
+                        MenuFocus::Black => self.player_black.change_skill_level(1),
+                        _ => {}
+                    },
+
+                    KeyCode::Char('-') => match focus {
+                        MenuFocus::White => self.player_white.change_skill_level(-1),
+                        MenuFocus::Black => self.player_black.change_skill_level(-1),
+                        _ => {}
+                    },
+
                     KeyCode::Right => match focus {
                         MenuFocus::White => {
-                            if let PlayerType::Engine { search_time } = &mut self.player_white {
+                            if let PlayerType::Engine { search_time, .. } = &mut self.player_white {
                                 {
                                     *search_time =
                                         search_time.saturating_add(Duration::from_secs(1));
@@ -200,7 +245,7 @@ impl App {
                             }
                         }
                         MenuFocus::Black => {
-                            if let PlayerType::Engine { search_time } = &mut self.player_black {
+                            if let PlayerType::Engine { search_time, .. } = &mut self.player_black {
                                 {
                                     *search_time =
                                         search_time.saturating_add(Duration::from_secs(1));
@@ -230,15 +275,23 @@ impl App {
             PlayerType::Human => self.unselect(),
             PlayerType::Engine { .. } => {}
         };
+    }
 
-        self.last = Some(*m);
+    /// Takes back the last move played
+    fn undo(&mut self) {
+        if self.engine.game.undo().is_some() {
+            self.refresh();
+        }
     }
 
     /// Refreshes all position-dependant values
     fn refresh(&mut self) {
-        self.score = self.engine.grade_position();
+        self.breakdown = self.engine.explain_eval();
+        self.score = self.breakdown.total;
+        self.square_scores = self.engine.square_scores();
         self.fen.input = self.engine.game.to_fen();
         if self.engine_suggestions {
+            self.engine.set_skill_level(SkillLevel::MAX);
             self.engine_suggestion = self
                 .engine
                 .search(self.engine_search_time, u8::MAX)
@@ -263,14 +316,8 @@ impl App {
         } else {
             self.select(new);
 
-            if let Some((piece, color)) = self.engine.game.piece_lookup(new)
-                && self.engine.game.turn == color
-            {
-                self.potential_targets = moves_to_targets_vec(
-                    &piece.legal_moves(&self.engine.game, &new),
-                    &self.engine.game,
-                );
-            }
+            self.potential_targets =
+                moves_to_targets_vec(&self.engine.game.moves_from(new), &self.engine.game);
         }
     }
 
@@ -289,12 +336,8 @@ impl App {
                     self.engine_suggestions = !self.engine_suggestions;
                 }
                 KeyCode::Char('v') => self.verbose = !self.verbose,
-                KeyCode::Char('u') => {
-                    if let Some(m) = &self.last {
-                        self.engine.game.unplay(m);
-                        self.last = None;
-                    }
-                }
+                KeyCode::Char('p') => self.show_square_scores = !self.show_square_scores,
+                KeyCode::Char('u') => self.undo(),
 
                 KeyCode::Left => {
                     if let Some(new) = self.highlighted_square.left() {
@@ -348,16 +391,23 @@ impl App {
                 KeyCode::Right => self.fen.move_cursor_right(),
                 KeyCode::Char(c) => self.fen.enter_char(c),
                 KeyCode::Backspace => self.fen.delete_char(),
-                KeyCode::Enter => {
-                    if let Some(valid) = Game::from_fen(&self.fen.input) {
-                        self.engine.with_new_game(valid);
-                    }
-                }
+                KeyCode::Enter => self.load_fen(),
                 _ => {}
             }
         }
     }
 
+    /// Starts a new game from the fen box, or explains why the fen was refused
+    fn load_fen(&mut self) {
+        match Game::from_fen(&self.fen.input) {
+            Ok(game) => {
+                self.fen_error = None;
+                self.engine.with_new_game(game);
+            }
+            Err(e) => self.fen_error = Some(e.to_string()),
+        }
+    }
+
     fn handle_command_key_event(&mut self, key_event: event::KeyEvent) {
         if key_event.modifiers.contains(KeyModifiers::CONTROL) {
             match key_event.code {
@@ -508,8 +558,12 @@ impl App {
             Color::White
         };
 
+        let fen_title = match &self.fen_error {
+            Some(error) => format!("Fen String: {error}"),
+            None => "Fen String:".to_string(),
+        };
         Paragraph::new(self.fen.input.clone())
-            .block(Block::bordered().title("Fen String:"))
+            .block(Block::bordered().title(fen_title))
             .fg(fen_color)
             .render(fen_area, buf);
 
@@ -518,13 +572,20 @@ impl App {
         debug_text.push_str(&format!(
             "Game:
     state: {:?}
+    outcome: {:?}
     evaluation: {}
     turn: {:?}
     position_hash: {}
 ",
-            self.engine.game.state, self.score, self.engine.game.turn, self.engine.game.hash,
+            self.engine.game.state,
+            self.engine.game.outcome(),
+            self.score,
+            self.engine.game.turn,
+            self.engine.game.hash,
         ));
 
+        debug_text.push_str(&format!("Evaluation:\n{}\n", self.breakdown));
+
         debug_text.push_str(&format!(
             "Screen area:
     width: {}
@@ -567,9 +628,9 @@ Selected Square info:
         if self.verbose {
             debug_text.push_str(&format!(
                 "Verbose:
-    seen_positions: {:#?}
+    key_history: {:#x?}
 ",
-                self.engine.game.seen_positions
+                self.engine.game.key_history
             ));
         }
 
@@ -591,7 +652,7 @@ Selected Square info:
                 // Determine color based on even or odd
                 let is_white = (r + f) % 2 == 1;
                 let background;
-                let foreground;
+                let mut foreground;
                 if is_white {
                     background = Color::White;
                     foreground = Color::DarkGray;
@@ -612,6 +673,20 @@ Selected Square info:
                         ""
                     };
 
+                // Color pieces by whether their square is worth more or less than the piece
+                // itself
+                if self.show_square_scores
+                    && let Some((piece, color)) = self.engine.game.piece_lookup(square_index)
+                {
+                    let score = self.square_scores[square_index.index()].for_color(color);
+                    let material = self.engine.eval_params().material(piece);
+                    if score > material {
+                        foreground = Color::Green;
+                    } else if score < material {
+                        foreground = Color::Red;
+                    }
+                }
+
                 // Highlight selected square and suggested square
                 if self.potential_targets.contains(&square_index) {
                     if square_index == self.highlighted_square {
@@ -1,9 +1,15 @@
 use std::time::Duration;
 
+use whalecrab_engine::{search::skill::SkillLevel, toys::EngineKind};
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum PlayerType {
     Human,
-    Engine { search_time: Duration },
+    Engine {
+        search_time: Duration,
+        skill_level: SkillLevel,
+        kind: EngineKind,
+    },
 }
 
 impl PlayerType {
@@ -11,8 +17,24 @@ impl PlayerType {
         *self = match self {
             PlayerType::Human => PlayerType::Engine {
                 search_time: Duration::from_secs(3),
+                skill_level: SkillLevel::MAX,
+                kind: EngineKind::default(),
             },
             PlayerType::Engine { .. } => PlayerType::Human,
         };
     }
+
+    /// Raises or lowers the skill level of an engine player by `by` levels
+    pub fn change_skill_level(&mut self, by: i8) {
+        if let PlayerType::Engine { skill_level, .. } = self {
+            *skill_level = SkillLevel::new(skill_level.to_int().saturating_add_signed(by));
+        }
+    }
+
+    /// Switches an engine player to the next kind of engine
+    pub fn cycle_engine_kind(&mut self) {
+        if let PlayerType::Engine { kind, .. } = self {
+            kind.cycle();
+        }
+    }
 }
@@ -9,6 +9,8 @@ pub enum UciError {
     ParseMove(String),
     ParseOptionName(String),
     ParseOptionValue(String),
+    /// The position is neither `startpos` nor `fen` followed by a fen
+    ParsePosition(String),
 }
 
 impl fmt::Display for UciError {
@@ -18,6 +20,7 @@ impl fmt::Display for UciError {
             Self::ParseMove(cmd) => write!(f, "Failed to parse move string: '{}'", cmd),
             Self::ParseOptionName(cmd) => write!(f, "Failed to name of setoption: '{}'", cmd),
             Self::ParseOptionValue(cmd) => write!(f, "Failed to value of setoption: '{}'", cmd),
+            Self::ParsePosition(cmd) => write!(f, "Failed to parse position: '{}'", cmd),
         }
     }
 }
@@ -34,7 +37,8 @@ pub enum UciCommand {
     /// The position to set up on the internal board. The engine should start with the given fen,
     /// then play all of the uci moves.
     Position {
-        /// The fen to a position. Currently will return the starting fen if the GUI says "startpos" instead of a specific fen
+        /// The fen to a position. This is the starting fen if the GUI says "startpos", and the
+        /// clocks are filled in if the GUI leaves them out.
         fen: String,
         /// A vector of strings such as vec!["e2e4", "e7e5", ...]
         moves: Vec<String>,
@@ -56,11 +60,24 @@ pub enum UciCommand {
         movestogo: Option<u16>,
         /// The maximum depth to search
         depth: Option<u8>,
+        /// The maximum amount of nodes to search
+        nodes: Option<u64>,
+        /// Search until told to `stop`, ignoring the default depth and move time
+        infinite: bool,
     },
+    /// Ends the search, which still reports its best move
+    Stop,
     SetOption {
         name: String,
         value: String,
     },
+    /// Not part of uci. Draws the current board along with its fen
+    Display,
+    /// Not part of uci. Searches a fixed set of positions and reports the nodes and speed
+    Bench {
+        /// The depth to search each position to
+        depth: Option<u8>,
+    },
 }
 
 /// Gets the `n` words after start. Returns None if `start` is not found, or `n` words are not present
@@ -108,21 +125,27 @@ impl FromStr for UciCommand {
             "uci" => Ok(Self::Uci),
             "quit" => Ok(Self::Quit),
             "isready" => Ok(Self::IsReady),
+            "stop" => Ok(Self::Stop),
             "position" => {
-                let starting_position = parse_parameter(line, "position", Some("moves"));
-                let fen = if let Some(pos) = &starting_position
-                    && let Some(fen) = pos.strip_prefix("fen ")
-                {
-                    fen
-                } else {
-                    STARTING_FEN
-                }
-                .to_string();
-
-                let moves = parse_parameter(line, "moves", None)
-                    .map(|s| s.split(' ').map(str::to_string).collect())
-                    .unwrap_or_default();
+                let mut words = line.split_whitespace().skip(1);
+                let fen = match words.next() {
+                    Some("startpos") => match words.next() {
+                        None | Some("moves") => STARTING_FEN.to_string(),
+                        Some(_) => return Err(UciError::ParsePosition(line.to_string())),
+                    },
+                    Some("fen") => {
+                        let fields: Vec<&str> =
+                            words.by_ref().take_while(|&word| word != "moves").collect();
+                        match fields.len() {
+                            // Some GUIs leave out the halfmove clock and fullmove number
+                            4 => format!("{} 0 1", fields.join(" ")),
+                            _ => fields.join(" "),
+                        }
+                    }
+                    _ => return Err(UciError::ParsePosition(line.to_string())),
+                };
 
+                let moves = words.map(str::to_string).collect();
                 Ok(Self::Position { fen, moves })
             }
             "go" => {
@@ -149,6 +172,10 @@ impl FromStr for UciCommand {
                 let parse_u8 =
                     |key: &str| parse_parameter_first(line, key).and_then(|s| s.parse::<u8>().ok());
 
+                let parse_u64 = |key: &str| {
+                    parse_parameter_first(line, key).and_then(|s| s.parse::<u64>().ok())
+                };
+
                 Ok(Self::Go {
                     movetime: parse_duration("movetime"),
                     wtime: parse_duration("wtime"),
@@ -157,24 +184,24 @@ impl FromStr for UciCommand {
                     binc: parse_increment("binc"),
                     movestogo: parse_u16("movestogo"),
                     depth: parse_u8("depth"),
+                    nodes: parse_u64("nodes"),
+                    infinite: line.split_whitespace().any(|word| word == "infinite"),
                 })
             }
             "setoption" => {
-                let split: Vec<&str> = line.split(' ').collect();
-                let name = match split.get(2) {
-                    Some(name) => name,
-                    None => return Err(UciError::ParseOptionName(line.to_string())),
-                };
-                let value = match split.get(4) {
-                    Some(value) => value,
-                    None => return Err(UciError::ParseOptionValue(line.to_string())),
-                };
-
-                Ok(Self::SetOption {
-                    name: name.to_string(),
-                    value: value.to_string(),
-                })
+                // Names and values may be more than one word, such as "Move Overhead"
+                let name = parse_parameter(line, " name ", Some(" value "))
+                    .filter(|name| !name.is_empty())
+                    .ok_or_else(|| UciError::ParseOptionName(line.to_string()))?;
+                let value = parse_parameter(line, " value ", None)
+                    .ok_or_else(|| UciError::ParseOptionValue(line.to_string()))?;
+
+                Ok(Self::SetOption { name, value })
             }
+            "d" => Ok(Self::Display),
+            "bench" => Ok(Self::Bench {
+                depth: parse_parameter_first(line, "bench").and_then(|s| s.parse::<u8>().ok()),
+            }),
             _ => Err(UciError::UnrecognizedCommand(cmd.to_string())),
         }
     }
@@ -246,7 +273,7 @@ mod tests {
 
     #[test]
     fn position_no_moves() {
-        let moves = vec![""];
+        let moves: Vec<&str> = Vec::new();
         let cmd = uci!("position startpos moves");
 
         match cmd {
@@ -287,6 +314,8 @@ mod tests {
                 binc: None,
                 movestogo: None,
                 depth: None,
+                nodes: None,
+                infinite: false,
             }
         ));
     }
@@ -303,6 +332,8 @@ mod tests {
                 binc: None,
                 movestogo: None,
                 depth: None,
+                nodes: None,
+                infinite: false,
             }
         );
     }
@@ -320,6 +351,8 @@ mod tests {
                 binc: Some(bi),
                 movestogo: None,
                 depth: None,
+                nodes: None,
+                infinite: false,
             } if w == Duration::from_millis(60000)
               && b == Duration::from_millis(60000)
               && wi == Duration::from_millis(500)
@@ -342,6 +375,8 @@ mod tests {
                 binc: Some(bi),
                 movestogo: Some(mtg),
                 depth: None,
+                nodes: None,
+                infinite: false,
             } if w == Duration::from_millis(60000)
               && b == Duration::from_millis(60000)
               && wi == Duration::from_millis(500)
@@ -350,6 +385,24 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn go_depth_nodes() {
+        assert_eq!(
+            uci!("go depth 6 nodes 20000"),
+            UciCommand::Go {
+                movetime: None,
+                wtime: None,
+                btime: None,
+                winc: None,
+                binc: None,
+                movestogo: None,
+                depth: Some(6),
+                nodes: Some(20000),
+                infinite: false,
+            }
+        );
+    }
+
     #[test]
     fn setoption_depth() {
         let cmd = UciCommand::from_str("setoption name Depth value 5").unwrap();
@@ -368,6 +421,43 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn setoption_with_spaces() {
+        assert_eq!(
+            uci!("setoption name Move Overhead value 30"),
+            UciCommand::SetOption {
+                name: "Move Overhead".to_string(),
+                value: "30".to_string()
+            }
+        );
+        assert_eq!(
+            uci!("setoption name SyzygyPath value /tables/three men"),
+            UciCommand::SetOption {
+                name: "SyzygyPath".to_string(),
+                value: "/tables/three men".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn go_infinite_and_stop() {
+        assert!(matches!(
+            uci!("go infinite"),
+            UciCommand::Go {
+                infinite: true,
+                depth: None,
+                ..
+            }
+        ));
+        assert_eq!(uci!("stop"), UciCommand::Stop);
+    }
+
+    #[test]
+    fn bench() {
+        assert_eq!(uci!("bench"), UciCommand::Bench { depth: None });
+        assert_eq!(uci!("bench 3"), UciCommand::Bench { depth: Some(3) });
+    }
+
     #[test]
     fn unrecognized_command() {
         assert!(matches!(
@@ -403,6 +493,8 @@ mod tests {
             binc: None,
             movestogo: None,
             depth: None,
+            nodes: None,
+            infinite: false,
         };
         assert_eq!(actual, expected);
     }
@@ -417,6 +509,39 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn position_fen_with_moves() {
+        let fen = "k7/pp6/4n3/8/3K1Q2/8/8/R7 w - - 1 2";
+        assert_eq!(
+            uci!("position fen {fen} moves d4e5 e6c7"),
+            UciCommand::Position {
+                fen: fen.to_string(),
+                moves: vec!["d4e5".to_string(), "e6c7".to_string()]
+            }
+        );
+    }
+
+    #[test]
+    fn position_fen_without_clocks() {
+        assert_eq!(
+            uci!("position fen k7/pp6/4n3/8/3K1Q2/8/8/R7 b -  -"),
+            UciCommand::Position {
+                fen: "k7/pp6/4n3/8/3K1Q2/8/8/R7 b - - 0 1".to_string(),
+                moves: Vec::new()
+            }
+        );
+    }
+
+    #[test]
+    fn position_without_a_position() {
+        for line in ["position", "position moves e2e4", "position startpos e2e4"] {
+            assert!(matches!(
+                UciCommand::from_str(line),
+                Err(UciError::ParsePosition(_))
+            ));
+        }
+    }
+
     #[test]
     fn parse_parameter_movestogo() {
         let line = "go wtime 500 btime 500 movestogo 50";
@@ -2,16 +2,38 @@ use std::{
     io::Stdin,
     ops::{AddAssign, MulAssign},
     str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread::{self, JoinHandle},
     time::Duration,
 };
 
-use whalecrab_engine::{engine::Engine, score::Score};
+use whalecrab_engine::{
+    bench::DEFAULT_BENCH_DEPTH,
+    engine::Engine,
+    eval_params::EvalParams,
+    move_result::SearchResult,
+    score::Score,
+    search::{
+        iterative_deepening::SEARCH_STACK_SIZE, limits::SearchLimits, progress::SearchProgress,
+        skill::SkillLevel,
+    },
+    toys::EngineKind,
+};
 use whalecrab_lib::{
     movegen::{moves::Move, pieces::piece::PieceColor},
     position::game::Game,
 };
 
-use crate::{command::UciCommand, log, logging::flush, received, send};
+use crate::{
+    command::UciCommand,
+    log,
+    logging::flush,
+    options::{OptionValue, find_option, options},
+    received, send,
+};
 
 const ID_NAME: &str = "whalecrab";
 const ID_AUTHOR: &str = "Shringe";
@@ -28,6 +50,7 @@ pub enum BestmoveNotationParseError {
 }
 
 /// The notation to respond with the `bestmove` in
+#[derive(Clone, Copy)]
 pub enum BestmoveNotation {
     UniversalChessInterface,
     StandardAlgebraicNotation,
@@ -45,14 +68,32 @@ impl FromStr for BestmoveNotation {
     }
 }
 
+/// Where responses go. The search thread keeps a copy, so that it can respond on its own.
+pub type Respond = Arc<dyn Fn(String) + Send + Sync>;
+
+/// A search running on its own thread, so that commands can still be read while it runs
+struct SearchThread {
+    stop: Arc<AtomicBool>,
+    /// Returns the score of the search
+    handle: JoinHandle<Score>,
+}
+
 /// Stores the state of the uci interface
 pub struct UciInterface {
     pub engine: Engine,
     pub depth: u8,
     pub duration: Duration,
     pub bestmove_notation: BestmoveNotation,
+    /// Which engine picks the moves
+    pub engine_kind: EngineKind,
+    /// How many of the best moves to report. Only the minimax engine reports more than one.
+    pub multi_pv: usize,
+    /// Time kept back from each move when playing on a clock, for the GUI to receive the move
+    pub move_overhead: Duration,
     /// The last score the engine came up with
     last_score: Score,
+    /// The search that is running, if any
+    search: Option<SearchThread>,
 }
 
 impl Default for UciInterface {
@@ -65,7 +106,11 @@ impl Default for UciInterface {
             #[cfg(not(debug_assertions))]
             duration: Duration::from_secs(3),
             bestmove_notation: BestmoveNotation::UniversalChessInterface,
+            engine_kind: EngineKind::default(),
+            multi_pv: 1,
+            move_overhead: Duration::from_millis(10),
             last_score: Score::default(),
+            search: None,
         }
     }
 }
@@ -73,6 +118,12 @@ impl Default for UciInterface {
 impl UciInterface {
     /// Runs the uci interface by watching stdin
     pub fn watch(&mut self, stdin: Stdin) {
+        // Responses are sent as they come, so that the GUI sees the search progress
+        let respond: Respond = Arc::new(|response| {
+            send!("{}", response);
+            flush();
+        });
+
         for line in stdin.lines() {
             let line = match line {
                 Ok(line) => {
@@ -93,105 +144,99 @@ impl UciInterface {
                 }
             };
 
-            let (responses, action) = self.handle(cmd);
-            if !responses.is_empty() {
-                send!("{}", responses.join("\n"));
-            }
-
-            flush();
-
-            match action {
+            match self.handle_with(cmd, &respond) {
                 UciHandleAction::Quit => break,
                 UciHandleAction::Continue => continue,
             }
         }
+
+        self.stop_search();
     }
 
     /// Handles a single UciCommand. Returns a vector of responses and a UciHandleAction to
-    /// describe things that must be handled by the caller.
+    /// describe things that must be handled by the caller. Searches are waited for, so that
+    /// their best move is among the responses.
+    #[cfg(test)]
     pub fn handle(&mut self, cmd: UciCommand) -> (Vec<String>, UciHandleAction) {
-        let mut out = Vec::new();
+        let out = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let respond: Respond = {
+            let out = out.clone();
+            Arc::new(move |response| out.lock().unwrap().push(response))
+        };
+
+        let action = self.handle_with(cmd, &respond);
+        self.wait_for_search();
+        let out = std::mem::take(&mut *out.lock().unwrap());
+        (out, action)
+    }
 
+    /// Same as `handle`, but gives each response to `respond` as soon as it is ready instead of
+    /// collecting them. `go` returns right away, leaving the search to respond from its own
+    /// thread.
+    pub fn handle_with(&mut self, cmd: UciCommand, respond: &Respond) -> UciHandleAction {
         macro_rules! uci_send {
             ($($arg:tt)*) => {{
-                let msg = format!($($arg)*);
-                out.push(msg);
+                respond(format!($($arg)*));
             }};
         }
 
+        // Only these are meant to be sent during a search, anything else ends it first
+        if !matches!(cmd, UciCommand::IsReady | UciCommand::Display) {
+            self.stop_search();
+        }
+
         match cmd {
             UciCommand::UciNewGame => self.engine.with_new_game(Game::default()),
-            UciCommand::Quit => return (out, UciHandleAction::Quit),
+            UciCommand::Quit => return UciHandleAction::Quit,
+            UciCommand::Stop => {}
             UciCommand::IsReady => uci_send!("readyok"),
 
             UciCommand::Uci => {
                 uci_send!("id name {ID_NAME}");
                 uci_send!("id author {ID_AUTHOR}");
-                uci_send!("option name Depth type spin default 20 min 0 max 200");
-                uci_send!(
-                    "option name MaxMoveTimeMs type spin default {} min 0 max {}",
-                    Duration::from_secs(3).as_millis(),
-                    Duration::from_hours(1).as_millis(),
-                );
-                uci_send!(
-                    "option name BestmoveNotation type combo default UniversalChessInterface var UniversalChessInterface var StandardAlgebraicNotation"
-                );
+                for option in options() {
+                    uci_send!("{option}");
+                }
                 uci_send!("uciok");
             }
 
-            UciCommand::SetOption { name, value } => match name.to_lowercase().as_str() {
-                "depth" => match value.parse::<u8>() {
-                    Ok(depth) => {
-                        log!("Setting depth to {}", depth);
-                        self.depth = depth
-                    }
-                    Err(e) => {
-                        log!("Failed to parse depth: {:?}", e);
-                    }
-                },
-                "maxmovetimems" => match value.parse::<u64>() {
-                    Ok(0) => {
-                        log!("Move time limit disabled");
-                        self.duration = Duration::MAX;
-                    }
-                    Ok(ms) => {
-                        log!("Setting max move time to {}ms", ms);
-                        self.duration = Duration::from_millis(ms);
-                    }
-                    Err(e) => log!("Failed to parse movetime: {:?}", e),
-                },
-                "bestmovenotation" => match value.parse::<BestmoveNotation>() {
-                    Ok(notation) => self.bestmove_notation = notation,
-                    Err(e) => log!("Failed to parse bestmove notation: {:?}", e),
-                },
-                _ => {
-                    log!("Unknown option: {}", name);
+            UciCommand::Display => {
+                for line in self.engine.game.to_ascii().lines() {
+                    uci_send!("{line}");
                 }
-            },
+                uci_send!("Fen: {}", self.engine.game.to_fen());
+            }
+
+            UciCommand::Bench { depth } => {
+                let result = self.engine.bench(depth.unwrap_or(DEFAULT_BENCH_DEPTH));
+                uci_send!("info string bench {}", result);
+            }
+
+            UciCommand::SetOption { name, value } => {
+                match find_option(&name).and_then(|option| Ok((option.name, option.parse(&value)?)))
+                {
+                    Ok((name, value)) => self.set_option(name, value),
+                    Err(e) => log!("{}", e),
+                }
+            }
 
             UciCommand::Position { fen, moves } => {
                 log!("Received position: {fen}");
 
+                // The moves only make sense from the position the GUI gave, so a bad fen keeps the
+                // current position like a bad move does
                 let mut game = match Game::from_fen(&fen) {
-                    Some(g) => g,
-                    None => {
-                        log!("Failed to parse fen {fen}. Defaulting to starting position");
-                        Game::default()
+                    Ok(g) => g,
+                    Err(e) => {
+                        log!("Failed to parse fen {fen}: {e}");
+                        return UciHandleAction::Continue;
                     }
                 };
 
-                // Play all moves in sequence
                 log!("Playing moves: {:#?}", moves);
-                for uci_move in moves {
-                    let move_to_play = match Move::from_uci(&uci_move, &game) {
-                        Ok(m) => m,
-                        Err(e) => {
-                            log!("Failed to parse uci move '{}': {:?}", uci_move, e);
-                            return (out, UciHandleAction::Continue);
-                        }
-                    };
-                    log!("Playing move: {}", move_to_play);
-                    game.play(&move_to_play);
+                if let Err(e) = game.apply_uci_moves(&moves) {
+                    log!("Failed to play the moves: {e}");
+                    return UciHandleAction::Continue;
                 }
                 log!("Final position FEN: {}", game.to_fen());
                 log!("Game state: {:?}", game.state);
@@ -207,58 +252,177 @@ impl UciInterface {
                 binc,
                 movestogo,
                 depth,
+                nodes,
+                infinite,
             } => {
                 log!(
-                    "Movetime {:?} || wtime {:?} || btime {:?} || winc {:?} || binc {:?} || movestogo {:?} || depth {:?}",
+                    "Movetime {:?} || wtime {:?} || btime {:?} || winc {:?} || binc {:?} || movestogo {:?} || depth {:?} || nodes {:?} || infinite {}",
                     movetime,
                     wtime,
                     btime,
                     winc,
                     binc,
                     movestogo,
-                    depth
-                );
-
-                let movetime =
-                    self.determine_movetime(movetime, wtime, btime, winc, binc, movestogo);
-                let depth = depth.unwrap_or(self.depth);
-                log!(
-                    "Engine will target a {:?} move duration and a depth of {}",
-                    movetime,
-                    depth
+                    depth,
+                    nodes,
+                    infinite
                 );
 
-                let result = self.engine.search(movetime, depth);
+                // `go depth` and `go nodes` without a clock should not be cut short by the
+                // default move time, so that they stay deterministic
+                let untimed = infinite
+                    || (depth.is_some() || nodes.is_some())
+                        && movetime.is_none()
+                        && wtime.is_none()
+                        && btime.is_none();
+                let movetime = if untimed {
+                    Duration::MAX
+                } else {
+                    self.determine_movetime(movetime, wtime, btime, winc, binc, movestogo)
+                };
+                let limits = SearchLimits {
+                    duration: movetime,
+                    depth: match infinite {
+                        true => u8::MAX,
+                        false => depth.unwrap_or(self.depth),
+                    },
+                    nodes: nodes.unwrap_or(u64::MAX),
+                };
                 log!(
-                    "Search result:{}",
-                    ("\n".to_string() + &result.to_string()).replace("\n", "\n -- ")
+                    "Engine will target a {:?} move duration, a depth of {} and {} nodes",
+                    limits.duration,
+                    limits.depth,
+                    limits.nodes
                 );
 
-                let best_move = match result.best_move {
-                    Some(m) => m,
-                    None => {
-                        log!("No self.engine move found. Maybe the game is finished?");
-                        log!("Game state: {:?}", self.engine.game.state);
-                        return (out, UciHandleAction::Continue);
-                    }
+                // Each search draws from the engine's seed, so that a seeded game replays the same
+                let mut engine = self.engine.clone();
+                engine.set_seed(self.engine.next_seed());
+
+                let stop = Arc::new(AtomicBool::new(false));
+                let job = SearchJob {
+                    engine,
+                    kind: self.engine_kind,
+                    multi_pv: self.multi_pv,
+                    notation: self.bestmove_notation,
+                    limits,
+                    infinite,
+                    stop: stop.clone(),
+                    respond: respond.clone(),
                 };
+                let handle = thread::Builder::new()
+                    .name("search".to_string())
+                    .stack_size(SEARCH_STACK_SIZE)
+                    .spawn(move || job.run())
+                    .expect("Failed to spawn the search thread");
+                self.search = Some(SearchThread { stop, handle });
+            }
+        }
 
-                let best_move_uci = match self.bestmove_notation {
-                    BestmoveNotation::UniversalChessInterface => {
-                        best_move.to_uci(&self.engine.game)
-                    }
-                    BestmoveNotation::StandardAlgebraicNotation => {
-                        best_move.to_san(&mut self.engine.game)
-                    }
-                };
+        UciHandleAction::Continue
+    }
 
-                log!("Fen before playing the move: {}", self.engine.game.to_fen());
-                uci_send!("bestmove {}", best_move_uci);
-                self.last_score = result.info.score;
+    /// Waits for the running search, if any, to report its best move
+    fn wait_for_search(&mut self) {
+        if let Some(search) = self.search.take() {
+            match search.handle.join() {
+                Ok(score) => self.last_score = score,
+                Err(e) => log!("The search thread panicked: {:?}", e),
             }
         }
+    }
 
-        (out, UciHandleAction::Continue)
+    /// Ends the running search, if any, which still reports its best move
+    fn stop_search(&mut self) {
+        if let Some(search) = &self.search {
+            search.stop.store(true, Ordering::Relaxed);
+            // Infinite searches park themselves until they are stopped
+            search.handle.thread().unpark();
+        }
+        self.wait_for_search();
+    }
+
+    /// Applies a value from `setoption` to the option called `name`, as declared in [`options`]
+    fn set_option(&mut self, name: &'static str, value: OptionValue) {
+        match (name, value) {
+            ("Hash", OptionValue::Spin(megabytes)) => {
+                log!("Setting hash to {}MB", megabytes);
+                self.engine.set_hash_size(megabytes as usize);
+            }
+            ("Threads", OptionValue::Spin(threads)) => {
+                log!("Setting threads to {}", threads);
+                self.engine.set_threads(threads as usize);
+            }
+            ("Depth", OptionValue::Spin(depth)) => {
+                log!("Setting depth to {}", depth);
+                self.depth = depth as u8;
+            }
+            ("MaxMoveTimeMs", OptionValue::Spin(0)) => {
+                log!("Move time limit disabled");
+                self.duration = Duration::MAX;
+            }
+            ("MaxMoveTimeMs", OptionValue::Spin(ms)) => {
+                log!("Setting max move time to {}ms", ms);
+                self.duration = Duration::from_millis(ms as u64);
+            }
+            ("MultiPV", OptionValue::Spin(lines)) => {
+                log!("Reporting the best {} moves", lines);
+                self.multi_pv = lines as usize;
+            }
+            ("Move Overhead", OptionValue::Spin(ms)) => {
+                log!("Setting move overhead to {}ms", ms);
+                self.move_overhead = Duration::from_millis(ms as u64);
+            }
+            // Declared so that GUIs know to leave them off
+            ("Ponder", OptionValue::Check(true)) => log!("Pondering is not supported"),
+            ("OwnBook", OptionValue::Check(true)) => log!("No opening book is available"),
+            ("SyzygyPath", OptionValue::String(path)) if !path.is_empty() => {
+                log!("Syzygy tablebases are not supported, ignoring {}", path)
+            }
+            ("Ponder" | "OwnBook", OptionValue::Check(false)) | ("SyzygyPath", _) => {}
+            ("Seed", OptionValue::Spin(seed)) => {
+                log!("Seeding the engine with {}", seed);
+                self.engine.set_seed(seed as u64);
+            }
+            ("Skill Level", OptionValue::Spin(level)) => {
+                log!("Setting skill level to {}", level);
+                self.engine.set_skill_level(SkillLevel::new(level as u8));
+            }
+            ("BestmoveNotation", OptionValue::String(notation)) => {
+                match notation.parse::<BestmoveNotation>() {
+                    Ok(notation) => self.bestmove_notation = notation,
+                    Err(e) => log!("Failed to parse bestmove notation: {:?}", e),
+                }
+            }
+            ("EngineKind", OptionValue::String(kind)) => match kind.parse::<EngineKind>() {
+                Ok(kind) => {
+                    log!("Setting engine kind to {}", kind);
+                    self.engine_kind = kind;
+                }
+                Err(e) => log!("Failed to parse engine kind: {:?}", e),
+            },
+            ("EvalFile", OptionValue::String(path)) if path.is_empty() => {}
+            ("EvalFile", OptionValue::String(path)) => match EvalParams::load(&path) {
+                Ok(params) => {
+                    log!("Loaded eval params from {}", path);
+                    self.engine.set_eval_params(params);
+                }
+                Err(e) => log!("{}", e),
+            },
+            #[cfg(feature = "nnue")]
+            ("NnueFile", OptionValue::String(path)) if path.is_empty() => {}
+            #[cfg(feature = "nnue")]
+            ("NnueFile", OptionValue::String(path)) => {
+                match whalecrab_engine::nnue::Network::load(&path) {
+                    Ok(network) => {
+                        log!("Loaded the network from {}", path);
+                        self.engine.set_network(network);
+                    }
+                    Err(e) => log!("{}", e),
+                }
+            }
+            (name, value) => log!("{} can not be set to {:?}", name, value),
+        }
     }
 
     /// Decides how long the engine should spend searching for its move
@@ -310,10 +474,130 @@ impl UciInterface {
             allocation += our_increment / 2;
         }
 
-        allocation.0.min(ours.mul_f64(0.9))
+        allocation
+            .0
+            .min(ours.mul_f64(0.9))
+            .saturating_sub(self.move_overhead)
     }
 }
 
+/// A `go` command, handed over to the search thread
+struct SearchJob {
+    /// A copy of the interface's engine, sharing its transposition table
+    engine: Engine,
+    kind: EngineKind,
+    multi_pv: usize,
+    notation: BestmoveNotation,
+    limits: SearchLimits,
+    /// Holds back the best move until the search is stopped, as `go infinite` asks
+    infinite: bool,
+    stop: Arc<AtomicBool>,
+    respond: Respond,
+}
+
+impl SearchJob {
+    /// Searches until the limits are reached or the search is stopped, then responds with the
+    /// best move. Returns the score of the search.
+    fn run(mut self) -> Score {
+        let respond = &self.respond;
+        macro_rules! uci_send {
+            ($($arg:tt)*) => {{
+                respond(format!($($arg)*));
+            }};
+        }
+
+        let limits = self.limits;
+        let result = match self.kind {
+            EngineKind::Minimax if self.multi_pv > 1 => {
+                let lines = self.engine.multi_pv_with_stop(
+                    limits.duration,
+                    limits.depth,
+                    self.multi_pv,
+                    self.stop.clone(),
+                );
+                for (i, line) in lines.iter().enumerate() {
+                    uci_send!(
+                        "info multipv {} depth {} score {} pv {}",
+                        i + 1,
+                        line.info.depth,
+                        line.info.score.for_color(self.engine.game.turn).to_uci(),
+                        pv_to_uci(&self.engine.game, &line.pv)
+                    );
+                }
+                lines.into_iter().next().unwrap_or_default()
+            }
+            EngineKind::Minimax => {
+                let root = self.engine.game.clone();
+                self.engine
+                    .search_with_progress(limits, self.stop.clone(), |progress| {
+                        uci_send!("{}", info_line(&root, progress))
+                    })
+            }
+            kind => SearchResult {
+                best_move: kind
+                    .build_with_stop(self.engine.next_seed(), self.stop.clone())
+                    .best_move(&mut self.engine.game, &limits),
+                ..Default::default()
+            },
+        };
+        log!(
+            "Search result:{}",
+            ("\n".to_string() + &result.to_string()).replace("\n", "\n -- ")
+        );
+
+        uci_send!("info string {}", result.stats);
+
+        if self.infinite {
+            while !self.stop.load(Ordering::Relaxed) {
+                thread::park();
+            }
+        }
+
+        let best_move = match result.best_move {
+            Some(m) => m,
+            None => {
+                log!("No engine move found. Maybe the game is finished?");
+                log!("Game state: {:?}", self.engine.game.state);
+                uci_send!("bestmove (none)");
+                return result.info.score;
+            }
+        };
+
+        let best_move_uci = match self.notation {
+            BestmoveNotation::UniversalChessInterface => best_move.to_uci(&self.engine.game),
+            BestmoveNotation::StandardAlgebraicNotation => best_move.to_san(&self.engine.game),
+        };
+
+        log!("Fen before playing the move: {}", self.engine.game.to_fen());
+        uci_send!("bestmove {}", best_move_uci);
+        result.info.score
+    }
+}
+
+/// The info line reporting a finished depth, with the score from the side to move in `root`
+fn info_line(root: &Game, progress: &SearchProgress) -> String {
+    format!(
+        "info depth {} score {} nodes {} nps {} time {} pv {}",
+        progress.depth,
+        progress.score.for_color(root.turn).to_uci(),
+        progress.nodes,
+        progress.nps(),
+        progress.elapsed.as_millis(),
+        pv_to_uci(root, progress.pv)
+    )
+}
+
+/// Writes `pv`, starting from `game`, as space separated uci moves
+fn pv_to_uci(game: &Game, pv: &[Move]) -> String {
+    let mut game = game.clone();
+    let mut moves = Vec::with_capacity(pv.len());
+    for m in pv {
+        moves.push(m.to_uci(&game));
+        game.play(m);
+    }
+    moves.join(" ")
+}
+
 /// Makes duration arithmetic easier
 struct Allocation(Duration);
 
@@ -406,7 +690,179 @@ mod tests {
         let mut uci = UciInterface::default();
         uci.handle(uci!("position fen {fen}"));
         let binding = uci.handle(uci!("go movetime 100"));
-        let response = binding.0.first().unwrap();
+        let response = binding.0.last().unwrap();
         assert_eq!(response, "bestmove c7e6");
     }
+
+    #[test]
+    fn sets_up_positions_from_fen() {
+        let mut uci = UciInterface::default();
+        uci.handle(uci!(
+            "position fen 4k3/8/8/8/8/8/4P3/4K3 w - - 0 1 moves e2e4 e8d7"
+        ));
+        assert_eq!(uci.engine.game.to_fen(), "8/3k4/8/8/4P3/8/8/4K3 w - - 1 2");
+
+        // Neither a bad fen nor a bad move changes the position
+        let before = uci.engine.game.clone();
+        uci.handle(uci!("position fen 4k3/8/8 w - - 0 1 moves e2e4"));
+        assert_eq!(uci.engine.game, before);
+        uci.handle(uci!("position startpos moves e2e5"));
+        assert_eq!(uci.engine.game, before);
+
+        uci.handle(uci!("position startpos moves"));
+        assert_eq!(uci.engine.game, Game::default());
+    }
+
+    #[test]
+    fn loads_eval_file() {
+        let path = std::env::temp_dir().join("whalecrab_uci_eval_params.json");
+        std::fs::write(&path, r#"{"bishop_pair": 123}"#).unwrap();
+
+        let mut uci = UciInterface::default();
+        uci.handle(uci!("setoption name EvalFile value {}", path.display()));
+        assert_eq!(uci.engine.eval_params().bishop_pair, 123);
+
+        // A missing file keeps the current params
+        uci.handle(uci!("setoption name EvalFile value /does/not/exist.json"));
+        assert_eq!(uci.engine.eval_params().bishop_pair, 123);
+    }
+
+    #[test]
+    fn draws_the_board() {
+        let mut uci = UciInterface::default();
+        uci.handle(uci!("position startpos moves e2e4"));
+        let (responses, _) = uci.handle(uci!("d"));
+        assert_eq!(responses.len(), 10);
+        assert_eq!(responses[4], "4 . . . . P . . .");
+        assert_eq!(
+            responses[9],
+            "Fen: rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+        );
+    }
+
+    #[test]
+    fn reports_each_depth() {
+        let mut uci = UciInterface::default();
+        uci.handle(uci!("position fen 6k1/5ppp/8/8/8/8/5PPP/R5K1 w - - 0 1"));
+        let (responses, _) = uci.handle(uci!("go depth 3"));
+
+        let infos: Vec<_> = responses
+            .iter()
+            .filter(|r| r.starts_with("info depth "))
+            .collect();
+        assert_eq!(infos.len(), 4);
+        assert!(infos[0].starts_with("info depth 0 score "));
+        assert!(infos[3].starts_with("info depth 3 score mate 1 nodes "));
+        assert!(infos[3].contains(" nps "));
+        assert!(infos[3].ends_with(" pv a1a8"));
+        assert_eq!(responses.last().unwrap(), "bestmove a1a8");
+    }
+
+    #[test]
+    fn stops_an_infinite_search() {
+        let out = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let respond: Respond = {
+            let out = out.clone();
+            Arc::new(move |response| out.lock().unwrap().push(response))
+        };
+        let bestmoves = || {
+            out.lock()
+                .unwrap()
+                .iter()
+                .filter(|r: &&String| r.starts_with("bestmove "))
+                .count()
+        };
+
+        let mut uci = UciInterface::default();
+        uci.handle_with(uci!("go infinite"), &respond);
+        std::thread::sleep(Duration::from_millis(100));
+
+        // The search keeps going while commands are read
+        uci.handle_with(uci!("isready"), &respond);
+        assert!(out.lock().unwrap().contains(&"readyok".to_string()));
+        assert_eq!(bestmoves(), 0);
+
+        uci.handle_with(uci!("stop"), &respond);
+        assert_eq!(bestmoves(), 1);
+        assert!(out.lock().unwrap().last().unwrap().starts_with("bestmove "));
+    }
+
+    #[test]
+    fn stops_a_timed_mcts_search() {
+        let respond: Respond = Arc::new(|_| {});
+        let mut uci = UciInterface::default();
+        uci.handle_with(uci!("setoption name EngineKind value Mcts"), &respond);
+        uci.handle_with(uci!("go movetime 60000"), &respond);
+        std::thread::sleep(Duration::from_millis(100));
+
+        let start = Instant::now();
+        uci.handle_with(uci!("stop"), &respond);
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "{:?}",
+            start.elapsed()
+        );
+    }
+
+    #[test]
+    fn declares_and_sets_options() {
+        let mut uci = UciInterface::default();
+        let (responses, _) = uci.handle(uci!("uci"));
+        assert!(
+            responses
+                .contains(&"option name Skill Level type spin default 20 min 0 max 20".to_string())
+        );
+
+        uci.handle(uci!("setoption name Hash value 2"));
+        assert!((2..4).contains(&uci.engine.hash_size()));
+
+        uci.handle(uci!("setoption name move overhead value 50"));
+        assert_eq!(uci.move_overhead, Duration::from_millis(50));
+        // Values out of range are refused
+        uci.handle(uci!("setoption name Move Overhead value 9999"));
+        assert_eq!(uci.move_overhead, Duration::from_millis(50));
+
+        uci.handle(uci!("setoption name EngineKind value greedy"));
+        assert!(matches!(uci.engine_kind, EngineKind::Greedy));
+    }
+
+    #[test]
+    fn seeded_games_replay() {
+        let play = || {
+            let mut uci = UciInterface::default();
+            uci.handle(uci!("setoption name EngineKind value Random"));
+            uci.handle(uci!("setoption name Seed value 42"));
+            (0..5)
+                .map(|_| uci.handle(uci!("go depth 1")).0.last().unwrap().clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(play(), play());
+    }
+
+    #[test]
+    fn reports_multiple_lines() {
+        let mut uci = UciInterface::default();
+        uci.handle(uci!("setoption name MultiPV value 3"));
+        let (responses, _) = uci.handle(uci!("go depth 2"));
+
+        let lines: Vec<_> = responses
+            .iter()
+            .filter(|r| r.starts_with("info multipv "))
+            .collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[2].starts_with("info multipv 3 depth 2 score cp "));
+
+        let best = lines[0].split(" pv ").nth(1).unwrap();
+        let best = best.split(' ').next().unwrap();
+        assert_eq!(responses.last().unwrap(), &format!("bestmove {best}"));
+    }
+
+    #[test]
+    fn bench_reports_nodes() {
+        let mut uci = UciInterface::default();
+        let (responses, _) = uci.handle(uci!("bench 1"));
+        assert_eq!(responses.len(), 1);
+        assert!(responses[0].starts_with("info string bench "));
+        assert!(responses[0].contains(" nps "));
+    }
 }
@@ -1,6 +1,7 @@
 mod command;
 mod interface;
 mod logging;
+mod options;
 #[cfg(test)]
 mod test_utils;
 
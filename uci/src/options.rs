@@ -0,0 +1,254 @@
+use std::fmt;
+
+use whalecrab_engine::{engine::Engine, search::skill::SkillLevel};
+
+/// What a uci option holds, along with its default
+#[derive(Debug, Clone, PartialEq)]
+pub enum OptionKind {
+    Check {
+        default: bool,
+    },
+    Spin {
+        default: i64,
+        min: i64,
+        max: i64,
+    },
+    /// One of `vars`
+    Combo {
+        default: &'static str,
+        vars: &'static [&'static str],
+    },
+    String {
+        default: &'static str,
+    },
+}
+
+/// An option as declared to the GUI in the `uci` handshake
+#[derive(Debug, Clone, PartialEq)]
+pub struct UciOption {
+    pub name: &'static str,
+    pub kind: OptionKind,
+}
+
+/// A value given to an option with `setoption`, checked against the option's kind
+#[derive(Debug, Clone, PartialEq)]
+pub enum OptionValue {
+    Check(bool),
+    Spin(i64),
+    /// The value of combo and string options. Combos are spelled like their var.
+    String(String),
+}
+
+#[derive(Debug)]
+pub enum OptionError {
+    UnknownOption(String),
+    /// The value is not of the option's kind, such as a word for a spin option
+    BadValue {
+        name: &'static str,
+        value: String,
+    },
+    OutOfRange {
+        name: &'static str,
+        value: i64,
+        min: i64,
+        max: i64,
+    },
+}
+
+impl fmt::Display for OptionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownOption(name) => write!(f, "Unknown option: '{}'", name),
+            Self::BadValue { name, value } => {
+                write!(f, "'{}' is not a valid value for {}", value, name)
+            }
+            Self::OutOfRange {
+                name,
+                value,
+                min,
+                max,
+            } => write!(f, "{} must be from {} to {}, not {}", name, min, max, value),
+        }
+    }
+}
+
+/// How the GUI spells an empty string option
+const EMPTY: &str = "<empty>";
+
+impl fmt::Display for UciOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "option name {} type ", self.name)?;
+        match &self.kind {
+            OptionKind::Check { default } => write!(f, "check default {}", default),
+            OptionKind::Spin { default, min, max } => {
+                write!(f, "spin default {} min {} max {}", default, min, max)
+            }
+            OptionKind::Combo { default, vars } => {
+                write!(f, "combo default {}", default)?;
+                for var in *vars {
+                    write!(f, " var {}", var)?;
+                }
+                Ok(())
+            }
+            OptionKind::String { default: "" } => write!(f, "string default {}", EMPTY),
+            OptionKind::String { default } => write!(f, "string default {}", default),
+        }
+    }
+}
+
+impl UciOption {
+    /// Reads `value` as this option's kind. Combo vars and checks are case insensitive.
+    pub fn parse(&self, value: &str) -> Result<OptionValue, OptionError> {
+        let bad_value = || OptionError::BadValue {
+            name: self.name,
+            value: value.to_string(),
+        };
+
+        match &self.kind {
+            OptionKind::Check { .. } => match value.to_lowercase().as_str() {
+                "true" => Ok(OptionValue::Check(true)),
+                "false" => Ok(OptionValue::Check(false)),
+                _ => Err(bad_value()),
+            },
+            OptionKind::Spin { min, max, .. } => {
+                let spin = value.parse::<i64>().map_err(|_| bad_value())?;
+                if (*min..=*max).contains(&spin) {
+                    Ok(OptionValue::Spin(spin))
+                } else {
+                    Err(OptionError::OutOfRange {
+                        name: self.name,
+                        value: spin,
+                        min: *min,
+                        max: *max,
+                    })
+                }
+            }
+            OptionKind::Combo { vars, .. } => vars
+                .iter()
+                .find(|var| var.eq_ignore_ascii_case(value))
+                .map(|var| OptionValue::String(var.to_string()))
+                .ok_or_else(bad_value),
+            OptionKind::String { .. } if value == EMPTY => Ok(OptionValue::String(String::new())),
+            OptionKind::String { .. } => Ok(OptionValue::String(value.to_string())),
+        }
+    }
+}
+
+/// Every option the interface understands, in the order they are declared to the GUI
+pub fn options() -> Vec<UciOption> {
+    let spin = |name, default, min, max| UciOption {
+        name,
+        kind: OptionKind::Spin { default, min, max },
+    };
+    let check = |name, default| UciOption {
+        name,
+        kind: OptionKind::Check { default },
+    };
+    let string = |name| UciOption {
+        name,
+        kind: OptionKind::String { default: "" },
+    };
+
+    let mut options = vec![
+        spin("Hash", Engine::default_hash_size() as i64, 1, 65536),
+        spin("Threads", 1, 1, 256),
+        spin("Depth", 20, 0, 200),
+        spin(
+            "MaxMoveTimeMs",
+            3000,
+            0,
+            std::time::Duration::from_hours(1).as_millis() as i64,
+        ),
+        spin("MultiPV", 1, 1, 256),
+        spin("Move Overhead", 10, 0, 5000),
+        check("Ponder", false),
+        check("OwnBook", false),
+        string("SyzygyPath"),
+        spin(
+            "Skill Level",
+            SkillLevel::MAX.to_int().into(),
+            SkillLevel::MIN.to_int().into(),
+            SkillLevel::MAX.to_int().into(),
+        ),
+        // Engines pick their own seed until this is set
+        spin("Seed", 0, 0, i64::MAX),
+        UciOption {
+            name: "BestmoveNotation",
+            kind: OptionKind::Combo {
+                default: "UniversalChessInterface",
+                vars: &["UniversalChessInterface", "StandardAlgebraicNotation"],
+            },
+        },
+        UciOption {
+            name: "EngineKind",
+            kind: OptionKind::Combo {
+                default: "Minimax",
+                vars: &["Minimax", "Mcts", "Greedy", "Random"],
+            },
+        },
+        string("EvalFile"),
+    ];
+    options.extend(cfg!(feature = "nnue").then(|| string("NnueFile")));
+    options
+}
+
+/// Finds the option called `name`, ignoring case like uci allows
+pub fn find_option(name: &str) -> Result<UciOption, OptionError> {
+    options()
+        .into_iter()
+        .find(|option| option.name.eq_ignore_ascii_case(name))
+        .ok_or_else(|| OptionError::UnknownOption(name.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn declarations() {
+        let lines: Vec<String> = options().iter().map(UciOption::to_string).collect();
+        assert!(lines.contains(
+            &"option name Move Overhead type spin default 10 min 0 max 5000".to_string()
+        ));
+        assert!(lines.contains(&"option name Ponder type check default false".to_string()));
+        assert!(lines.contains(&"option name SyzygyPath type string default <empty>".to_string()));
+        assert!(lines.contains(
+            &"option name EngineKind type combo default Minimax var Minimax var Mcts var Greedy var Random"
+                .to_string()
+        ));
+    }
+
+    #[test]
+    fn parses_values() {
+        let overhead = find_option("move overhead").unwrap();
+        assert_eq!(overhead.parse("30").unwrap(), OptionValue::Spin(30));
+        assert!(matches!(
+            overhead.parse("5001"),
+            Err(OptionError::OutOfRange { value: 5001, .. })
+        ));
+        assert!(matches!(
+            overhead.parse("soon"),
+            Err(OptionError::BadValue { .. })
+        ));
+
+        let ponder = find_option("Ponder").unwrap();
+        assert_eq!(ponder.parse("TRUE").unwrap(), OptionValue::Check(true));
+
+        let kind = find_option("EngineKind").unwrap();
+        assert_eq!(
+            kind.parse("mcts").unwrap(),
+            OptionValue::String("Mcts".to_string())
+        );
+
+        let path = find_option("SyzygyPath").unwrap();
+        assert_eq!(
+            path.parse("<empty>").unwrap(),
+            OptionValue::String(String::new())
+        );
+
+        assert!(matches!(
+            find_option("Contempt"),
+            Err(OptionError::UnknownOption(_))
+        ));
+    }
+}